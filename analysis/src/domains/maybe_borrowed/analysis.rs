@@ -73,16 +73,12 @@ impl<'mir, 'tcx: 'mir> MaybeBorrowedAnalysis<'mir, 'tcx> {
                                 get_blocked_place(self.tcx, (*borrowed_place).into());
                             trace!("      Blocking {:?}: {:?}", borrow_kind, blocked_place);
                             match borrow_kind {
-                                mir::BorrowKind::Shared => {
+                                mir::BorrowKind::Shared | mir::BorrowKind::Shallow => {
                                     state.maybe_shared_borrowed.insert(blocked_place);
                                 }
                                 mir::BorrowKind::Mut { .. } => {
                                     state.maybe_mut_borrowed.insert(blocked_place);
                                 }
-                                _ => {
-                                    error!("Unexpected borrow kind: {:?}", borrow_kind);
-                                    return Err(AnalysisError::UnsupportedStatement(loan_location));
-                                }
                             }
                         } else {
                             error!("Unexpected RHS: {:?}", rhs);
@@ -570,6 +570,10 @@ impl<'v> ToViper<'v, viper::Expr<'v>> for expression::ContainerOp {
             expression::ContainerOpKind::SeqIndex => ast.seq_index(arg(0), arg(1)),
             expression::ContainerOpKind::SeqConcat => ast.seq_append(arg(0), arg(1)),
             expression::ContainerOpKind::SeqLen => ast.seq_length(arg(0)),
+            expression::ContainerOpKind::SeqUpdate => ast.seq_update(arg(0), arg(1), arg(2)),
+            expression::ContainerOpKind::SeqTake => ast.seq_take(arg(0), arg(1)),
+            expression::ContainerOpKind::SeqDrop => ast.seq_drop(arg(0), arg(1)),
+            expression::ContainerOpKind::SeqContains => ast.seq_contains(arg(0), arg(1)),
             expression::ContainerOpKind::MapEmpty => {
                 let (key_ty, val_ty) = key_value_types();
                 ast.empty_map(key_ty, val_ty)
@@ -638,7 +642,7 @@ impl<'v> ToViper<'v, viper::Type<'v>> for Type {
                 ty.val_type.to_viper(_context, ast),
             ),
             Type::Float(Float::F32) => ast.backend_f32_type(),
-            Type::Float(Float::F64) => ast.backend_f64_type(),
+            Type::Float(Float::F64) => ast.backend_f64_type(crate::config::encode_ieee_floats()),
             Type::BitVector(bv_size) => match bv_size {
                 BitVector::Signed(BitVectorSize::BV8) | BitVector::Unsigned(BitVectorSize::BV8) => {
                     ast.backend_bv8_type()
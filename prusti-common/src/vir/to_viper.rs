@@ -109,12 +109,14 @@ impl<'v> ToViper<'v, viper::Type<'v>> for Type {
             Type::Domain(ref name) => ast.domain_type(name, &[], &[]),
             Type::Snapshot(ref name) => ast.domain_type(&format!("Snap${name}"), &[], &[]),
             Type::Seq(ref elem_ty) => ast.seq_type(elem_ty.to_viper(_context, ast)),
+            Type::Set(ref elem_ty) => ast.set_type(elem_ty.to_viper(_context, ast)),
+            Type::MultiSet(ref elem_ty) => ast.multiset_type(elem_ty.to_viper(_context, ast)),
             Type::Map(ref key_type, ref val_type) => ast.map_type(
                 key_type.to_viper(_context, ast),
                 val_type.to_viper(_context, ast),
             ),
             Type::Float(Float::F32) => ast.backend_f32_type(),
-            Type::Float(Float::F64) => ast.backend_f64_type(),
+            Type::Float(Float::F64) => ast.backend_f64_type(config::encode_ieee_floats()),
             Type::BitVector(bv_size) => match bv_size {
                 BitVector::Signed(BitVectorSize::BV8) | BitVector::Unsigned(BitVectorSize::BV8) => {
                     ast.backend_bv8_type()
@@ -426,9 +428,15 @@ impl<'v> ToViper<'v, viper::Expr<'v>> for Expr {
                     let op_kind = match op {
                         UnaryOpKind::Minus => viper::UnOpFloat::Neg,
                         UnaryOpKind::IsNaN => viper::UnOpFloat::IsNan,
+                        UnaryOpKind::IsInfinite => viper::UnOpFloat::IsInfinite,
                         _ => unreachable!("illegal unary operation for floats: {}", op),
                     };
-                    ast.float_unop(op_kind, size, expr.to_viper(context, ast))
+                    ast.float_unop(
+                        op_kind,
+                        size,
+                        config::encode_ieee_floats(),
+                        expr.to_viper(context, ast),
+                    )
                 }
                 Type::BitVector(bitvector_ty) => {
                     let bv_size = lower_bitvector_signed_size(*bitvector_ty);
@@ -460,7 +468,9 @@ impl<'v> ToViper<'v, viper::Expr<'v>> for Expr {
                         BinaryOpKind::Sub => viper::BinOpFloat::Sub,
                         BinaryOpKind::Mul => viper::BinOpFloat::Mul,
                         BinaryOpKind::Div => viper::BinOpFloat::Div,
-                        BinaryOpKind::EqCmp => viper::BinOpFloat::Eq,
+                        // IEEE-754 has no "not equal" predicate, `NaN != NaN`
+                        // holds and is encoded as a negated equality below.
+                        BinaryOpKind::EqCmp | BinaryOpKind::NeCmp => viper::BinOpFloat::Eq,
                         BinaryOpKind::GtCmp => viper::BinOpFloat::Gt,
                         BinaryOpKind::GeCmp => viper::BinOpFloat::Geq,
                         BinaryOpKind::LtCmp => viper::BinOpFloat::Lt,
@@ -469,12 +479,18 @@ impl<'v> ToViper<'v, viper::Expr<'v>> for Expr {
                         BinaryOpKind::Max => viper::BinOpFloat::Max,
                         _ => unreachable!("illegal binary operation for floats: {}", op),
                     };
-                    ast.float_binop(
+                    let result = ast.float_binop(
                         float_op_kind,
                         size,
+                        config::encode_ieee_floats(),
                         left.to_viper(context, ast),
                         right.to_viper(context, ast),
-                    )
+                    );
+                    if *op == BinaryOpKind::NeCmp {
+                        ast.not_with_pos(result, pos.to_viper(context, ast))
+                    } else {
+                        result
+                    }
                 }
                 Some(Type::BitVector(bitvector_ty)) => {
                     let viper_size = lower_bitvector_signed_size(*bitvector_ty);
@@ -618,20 +634,61 @@ impl<'v> ToViper<'v, viper::Expr<'v>> for Expr {
                     ast.seq_append(left.to_viper(context, ast), right.to_viper(context, ast))
                 }
                 ContainerOpKind::SeqLen => ast.seq_length(left.to_viper(context, ast)),
+                ContainerOpKind::SeqTake => {
+                    ast.seq_take(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::SeqDrop => {
+                    ast.seq_drop(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::SeqContains => {
+                    ast.seq_contains(right.to_viper(context, ast), left.to_viper(context, ast))
+                }
+                ContainerOpKind::SetUnion => {
+                    ast.any_set_union(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::SetIntersection => ast.any_set_intersection(
+                    left.to_viper(context, ast),
+                    right.to_viper(context, ast),
+                ),
+                ContainerOpKind::SetMinus => {
+                    ast.any_set_minus(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::SetSubset => {
+                    ast.any_set_subset(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::SetContains => {
+                    ast.any_set_contains(right.to_viper(context, ast), left.to_viper(context, ast))
+                }
+                ContainerOpKind::SetCardinality => {
+                    ast.any_set_cardinality(left.to_viper(context, ast))
+                }
+                ContainerOpKind::MapLookup => {
+                    ast.lookup_map(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::MapContains => {
+                    ast.map_contains(left.to_viper(context, ast), right.to_viper(context, ast))
+                }
+                ContainerOpKind::MapLen => ast.map_len(left.to_viper(context, ast)),
             },
             Expr::Seq(ty, elems, _pos) => {
-                let Type::Seq(box elem_ty) = ty else {
-                    unreachable!()
-                };
-                let viper_elem_ty = elem_ty.to_viper(context, ast);
-                if elems.is_empty() {
-                    ast.empty_seq(viper_elem_ty)
-                } else {
-                    let viper_elems = elems
-                        .iter()
-                        .map(|e| e.to_viper(context, ast))
-                        .collect::<Vec<_>>();
-                    ast.explicit_seq(&viper_elems)
+                let viper_elems = elems
+                    .iter()
+                    .map(|e| e.to_viper(context, ast))
+                    .collect::<Vec<_>>();
+                match ty {
+                    Type::Seq(box elem_ty) if elems.is_empty() => {
+                        ast.empty_seq(elem_ty.to_viper(context, ast))
+                    }
+                    Type::Seq(_) => ast.explicit_seq(&viper_elems),
+                    Type::Set(box elem_ty) if elems.is_empty() => {
+                        ast.empty_set(elem_ty.to_viper(context, ast))
+                    }
+                    Type::Set(_) => ast.explicit_set(&viper_elems),
+                    Type::MultiSet(box elem_ty) if elems.is_empty() => {
+                        ast.empty_multiset(elem_ty.to_viper(context, ast))
+                    }
+                    Type::MultiSet(_) => ast.explicit_multiset(&viper_elems),
+                    _ => unreachable!(),
                 }
             }
             Expr::Map(ty, elems, _pos) => {
@@ -760,6 +817,29 @@ impl<'v> ToViper<'v, viper::Expr<'v>> for Expr {
                     let size = lower_bitvector_signed_size(*size);
                     ast.int_to_backend_bv(size, base.to_viper(context, ast))
                 }
+                CastKind::IntIntoFloat(size, _) => {
+                    let bv_size = lower_bitvector_signed_size(*size);
+                    let bv = ast.int_to_backend_bv(bv_size, base.to_viper(context, ast));
+                    apply_float_cast(context, ast, kind, &Type::Int, bv, *position)
+                }
+                CastKind::FloatIntoFloat(_) => {
+                    let source = base.get_type();
+                    let value = base.to_viper(context, ast);
+                    apply_float_cast(context, ast, kind, source, value, *position)
+                }
+                CastKind::FloatIntoInt(bitvector) => {
+                    let source = base.get_type();
+                    let value = base.to_viper(context, ast);
+                    let bv = apply_float_cast(context, ast, kind, source, value, *position);
+                    match bitvector {
+                        BitVector::Signed(size) => {
+                            viper_unsigned_bv_to_signed_int(context, ast, *size, bv, *position)
+                        }
+                        BitVector::Unsigned(size) => {
+                            ast.backend_bv_to_int(lower_bitvector_size(*size), bv)
+                        }
+                    }
+                }
             },
         };
         if config::simplify_encoding() {
@@ -787,7 +867,9 @@ impl<'v, 'a, 'b> ToViper<'v, viper::Expr<'v>> for (&'a Const, &'b Position) {
             Const::Int(x) => ast.int_lit_with_pos(*x, self.1.to_viper(context, ast)),
             Const::BigInt(ref x) => ast.int_lit_from_ref_with_pos(x, self.1.to_viper(context, ast)),
             Const::Float(FloatConst::F32(val)) => ast.backend_f32_lit(*val),
-            Const::Float(FloatConst::F64(val)) => ast.backend_f64_lit(*val),
+            Const::Float(FloatConst::F64(val)) => {
+                ast.backend_f64_lit(*val, config::encode_ieee_floats())
+            }
             Const::BitVector(bv_const) => match bv_const.typ {
                 BitVector::Signed(BitVectorSize::BV8) | BitVector::Unsigned(BitVectorSize::BV8) => {
                     ast.backend_bv8_lit_str(&bv_const.value)
@@ -809,7 +891,7 @@ impl<'v, 'a, 'b> ToViper<'v, viper::Expr<'v>> for (&'a Const, &'b Position) {
                     ast.backend_bv128_lit_str(&bv_const.value)
                 }
             },
-            Const::FnPtr => ast.null_lit_with_pos(self.1.to_viper(context, ast)),
+            Const::FnPtr | Const::NullPtr => ast.null_lit_with_pos(self.1.to_viper(context, ast)),
         }
     }
 }
@@ -1295,17 +1377,48 @@ fn unsigned_max_for_size(size: BitVectorSize) -> u128 {
     }
 }
 
+/// Applies the backend function that implements the float cast `kind` to a
+/// `value` of type `source`.
+fn apply_float_cast<'v>(
+    context: Context,
+    ast: &AstFactory<'v>,
+    kind: &CastKind,
+    source: &Type,
+    value: viper::Expr<'v>,
+    pos: Position,
+) -> viper::Expr<'v> {
+    let function = kind
+        .get_float_backend_func(source)
+        .unwrap_or_else(|| unreachable!("{:?} is not a float cast of {}", kind, source));
+    ast.backend_func_decl_app(
+        (&function).to_viper(context, ast),
+        &[value],
+        pos.to_viper(context, ast),
+    )
+}
+
 fn unsigned_bv_to_signed_int<'v>(
     context: Context,
     ast: &AstFactory<'v>,
     size: BitVectorSize,
     value: &Expr,
     pos: Position,
+) -> viper::Expr<'v> {
+    let value = value.to_viper(context, ast);
+    viper_unsigned_bv_to_signed_int(context, ast, size, value, pos)
+}
+
+fn viper_unsigned_bv_to_signed_int<'v>(
+    context: Context,
+    ast: &AstFactory<'v>,
+    size: BitVectorSize,
+    value: viper::Expr<'v>,
+    pos: Position,
 ) -> viper::Expr<'v> {
     let viper_size = lower_bitvector_size(size);
     let signed_max_int: Expr = signed_max_for_size(size).into();
     let unsigned_max_int: Expr = unsigned_max_for_size(size).into();
-    let value = ast.backend_bv_to_int(viper_size, value.to_viper(context, ast));
+    let value = ast.backend_bv_to_int(viper_size, value);
     let one: Expr = 1u32.into();
     ast.cond_exp_with_pos(
         ast.lt_cmp_with_pos(
@@ -14,5 +14,6 @@ impl<T, E> ::core::result::Result<T, E> {
 #[extern_spec]
 impl<T, E: ::core::fmt::Debug> ::core::result::Result<T, E> {
     #[requires(matches!(self, Ok(_)))]
+    #[ensures(old(self) === Ok(result))]
     fn unwrap(self) -> T;
 }
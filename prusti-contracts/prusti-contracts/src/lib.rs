@@ -21,6 +21,11 @@ pub use prusti_contracts_proc_macros::trusted;
 /// A macro for marking a function as opted into verification.
 pub use prusti_contracts_proc_macros::verified;
 
+/// A macro for marking a function as a lemma: a ghost-only function whose
+/// contract is verified, which can only be called from `ghost!` blocks and
+/// other lemmas, and which is erased from the compiled program.
+pub use prusti_contracts_proc_macros::lemma;
+
 /// A macro for type invariants.
 pub use prusti_contracts_proc_macros::invariant;
 
@@ -97,19 +102,69 @@ mod private {
 
     /// A sequence type
     #[non_exhaustive]
-    #[derive(PartialEq, Eq, Copy, Clone)]
+    #[derive(PartialEq, Eq)]
     pub struct Seq<T> {
         _phantom: PhantomData<T>,
     }
 
+    // Implemented by hand because deriving would require `T: Copy`.
+    impl<T> Clone for Seq<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Seq<T> {}
+
     /// A map type
     #[non_exhaustive]
-    #[derive(PartialEq, Eq, Copy, Clone)]
+    #[derive(PartialEq, Eq)]
     pub struct Map<K, V> {
         _key_phantom: PhantomData<K>,
         _val_phantom: PhantomData<V>,
     }
 
+    // Implemented by hand because deriving would require `K: Copy` and `V: Copy`.
+    impl<K, V> Clone for Map<K, V> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<K, V> Copy for Map<K, V> {}
+
+    /// A set type
+    #[non_exhaustive]
+    #[derive(PartialEq, Eq)]
+    pub struct Set<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    // Implemented by hand because deriving would require `T: Copy`.
+    impl<T> Clone for Set<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Set<T> {}
+
+    /// A multiset type
+    #[non_exhaustive]
+    #[derive(PartialEq, Eq)]
+    pub struct Multiset<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    // Implemented by hand because deriving would require `T: Copy`.
+    impl<T> Clone for Multiset<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Multiset<T> {}
+
     /// a mathematical (unbounded) integer type
     /// it should not be constructed from running rust code, hence the private unit inside
     pub struct Int(());
@@ -135,6 +190,35 @@ mod private {
         unreachable!();
     }
 
+    pub fn prusti_is_active_union_field<T>(_field: *const T) -> bool {
+        unreachable!();
+    }
+
+    /// Whether the given union field is the active field of its union. To be
+    /// used only in specifications.
+    #[macro_export]
+    macro_rules! is_active_union_field {
+        ($field:expr) => {{
+            #[allow(unused_unsafe)]
+            let is_active =
+                $crate::prusti_is_active_union_field(unsafe { ::core::ptr::addr_of!($field) });
+            is_active
+        }};
+    }
+
+    pub fn prusti_loop_iterator<I>() -> I {
+        unreachable!();
+    }
+
+    /// The iterator of the enclosing `for` loop, whose type has to be given.
+    /// To be used only in body invariants.
+    #[macro_export]
+    macro_rules! loop_iterator {
+        ($iterator_ty:ty) => {
+            $crate::prusti_loop_iterator::<$iterator_ty>()
+        };
+    }
+
     #[pure]
     pub fn prusti_terminates_trusted() -> Int {
         Int::new(1)
@@ -202,18 +286,31 @@ mod private {
 
     /// A sequence type
     #[non_exhaustive]
-    #[derive(PartialEq, Eq, Copy, Clone)]
-    pub struct Seq<T: Copy> {
+    #[derive(PartialEq, Eq)]
+    pub struct Seq<T> {
         _phantom: PhantomData<T>,
     }
 
-    impl<T: Copy> Seq<T> {
+    // Implemented by hand because deriving would require `T: Copy`.
+    impl<T> Clone for Seq<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Seq<T> {}
+
+    impl<T> Seq<T> {
         pub fn empty() -> Self {
             panic!()
         }
         pub fn single(_: T) -> Self {
             panic!()
         }
+        /// The sequence of the snapshots of the elements of the given slice.
+        pub fn from_slice(_: &[T]) -> Self {
+            panic!()
+        }
         pub fn concat(self, _: Self) -> Self {
             panic!()
         }
@@ -223,6 +320,33 @@ mod private {
         pub fn len(self) -> Int {
             panic!()
         }
+        /// The sequence with the element at `index` replaced by `val`.
+        pub fn update(self, _index: usize, _val: T) -> Self {
+            panic!()
+        }
+        /// The first `count` elements of the sequence.
+        pub fn take(self, _count: usize) -> Self {
+            panic!()
+        }
+        /// The sequence without its first `count` elements.
+        pub fn skip(self, _count: usize) -> Self {
+            panic!()
+        }
+        /// The elements from `from` (inclusive) to `to` (exclusive).
+        pub fn subsequence(self, _from: usize, _to: usize) -> Self {
+            panic!()
+        }
+        pub fn contains(self, _val: &T) -> bool {
+            panic!()
+        }
+        /// The index of the first occurrence of `val`, or `-1` if the
+        /// sequence does not contain it.
+        pub fn index_of(self, _val: &T) -> Int {
+            panic!()
+        }
+        pub fn to_multiset(self) -> Multiset<T> {
+            panic!()
+        }
     }
 
     #[macro_export]
@@ -238,14 +362,14 @@ mod private {
         };
     }
 
-    impl<T: Copy> Index<usize> for Seq<T> {
+    impl<T> Index<usize> for Seq<T> {
         type Output = T;
         fn index(&self, _: usize) -> &T {
             panic!()
         }
     }
 
-    impl<T: Copy> Index<Int> for Seq<T> {
+    impl<T> Index<Int> for Seq<T> {
         type Output = T;
         fn index(&self, _: Int) -> &T {
             panic!()
@@ -254,12 +378,21 @@ mod private {
 
     /// A map type
     #[non_exhaustive]
-    #[derive(PartialEq, Eq, Copy, Clone)]
+    #[derive(PartialEq, Eq)]
     pub struct Map<K, V> {
         _key_phantom: PhantomData<K>,
         _val_phantom: PhantomData<V>,
     }
 
+    // Implemented by hand because deriving would require `K: Copy` and `V: Copy`.
+    impl<K, V> Clone for Map<K, V> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<K, V> Copy for Map<K, V> {}
+
     impl<K, V> Map<K, V> {
         pub fn empty() -> Self {
             panic!()
@@ -301,6 +434,112 @@ mod private {
         }
     }
 
+    /// A set type
+    #[non_exhaustive]
+    #[derive(PartialEq, Eq)]
+    pub struct Set<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    // Implemented by hand because deriving would require `T: Copy`.
+    impl<T> Clone for Set<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Set<T> {}
+
+    impl<T> Set<T> {
+        pub fn empty() -> Self {
+            panic!()
+        }
+        pub fn single(_: T) -> Self {
+            panic!()
+        }
+        pub fn union(self, _: Self) -> Self {
+            panic!()
+        }
+        pub fn intersection(self, _: Self) -> Self {
+            panic!()
+        }
+        pub fn difference(self, _: Self) -> Self {
+            panic!()
+        }
+        pub fn is_subset(self, _: Self) -> bool {
+            panic!()
+        }
+        pub fn contains(self, _: T) -> bool {
+            panic!()
+        }
+        pub fn len(self) -> Int {
+            panic!()
+        }
+    }
+
+    #[macro_export]
+    macro_rules! set {
+        ($($val:expr),*) => {
+            $crate::Set::empty()
+            $(
+                .union($crate::Set::single($val))
+            )*
+        };
+    }
+
+    /// A multiset type
+    #[non_exhaustive]
+    #[derive(PartialEq, Eq)]
+    pub struct Multiset<T> {
+        _phantom: PhantomData<T>,
+    }
+
+    // Implemented by hand because deriving would require `T: Copy`.
+    impl<T> Clone for Multiset<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<T> Copy for Multiset<T> {}
+
+    impl<T> Multiset<T> {
+        pub fn empty() -> Self {
+            panic!()
+        }
+        pub fn single(_: T) -> Self {
+            panic!()
+        }
+        pub fn union(self, _: Self) -> Self {
+            panic!()
+        }
+        pub fn intersection(self, _: Self) -> Self {
+            panic!()
+        }
+        pub fn difference(self, _: Self) -> Self {
+            panic!()
+        }
+        pub fn is_subset(self, _: Self) -> bool {
+            panic!()
+        }
+        pub fn count(self, _: T) -> Int {
+            panic!()
+        }
+        pub fn len(self) -> Int {
+            panic!()
+        }
+    }
+
+    #[macro_export]
+    macro_rules! multiset {
+        ($($val:expr),*) => {
+            $crate::Multiset::empty()
+            $(
+                .union($crate::Multiset::single($val))
+            )*
+        };
+    }
+
     #[non_exhaustive]
     #[derive(PartialEq, Eq, Copy, Clone)]
     pub struct Ghost<T> {
@@ -353,6 +592,15 @@ pub fn exists<T, F>(_trigger_set: T, _closure: F) -> bool {
     true
 }
 
+/// Specification entailment.
+///
+/// This is a Prusti-internal representation of the `|=` syntax. `calls` is the
+/// number of calls of the closure that the entailment holds for, or zero if it
+/// holds for all calls.
+pub fn specification_entailment<F, P, Q>(_calls: usize, _closure: &F, _pre: P, _post: Q) -> bool {
+    true
+}
+
 /// Creates an owned copy of a reference. This should only be used from within
 /// ghost code, as it circumvents the borrow checker.
 pub fn snap<T>(_x: &T) -> T {
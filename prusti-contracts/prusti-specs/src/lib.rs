@@ -35,7 +35,7 @@ use crate::{
     specifications::preparser::{parse_prusti, parse_type_cond_spec, NestedSpec},
 };
 pub use extern_spec_rewriter::ExternSpecKind;
-use parse_closure_macro::ClosureWithSpec;
+use parse_closure_macro::{ClosureView, ClosureWithSpec};
 pub use spec_attribute_kind::SpecAttributeKind;
 use specifications::{common::SpecificationId, untyped};
 
@@ -89,7 +89,8 @@ fn extract_prusti_attributes(
                     | SpecAttributeKind::Terminates
                     | SpecAttributeKind::Trusted
                     | SpecAttributeKind::Predicate
-                    | SpecAttributeKind::Verified => {
+                    | SpecAttributeKind::Verified
+                    | SpecAttributeKind::Lemma => {
                         assert!(attr.tokens.is_empty(), "Unexpected shape of an attribute.");
                         attr.tokens
                     }
@@ -140,6 +141,19 @@ pub fn rewrite_prusti_attributes(
         .to_compile_error();
     }
 
+    // Lemmas are ghost code, while pure functions can also be called from executable code.
+    let has_attribute = |kind| prusti_attributes.iter().any(|(ak, _)| ak == &kind);
+    if has_attribute(SpecAttributeKind::Lemma) && has_attribute(SpecAttributeKind::Pure) {
+        return syn::Error::new(item.span(), "`#[lemma]` is incompatible with `#[pure]`")
+            .to_compile_error();
+    }
+
+    // A lemma that does not terminate could prove any postcondition by calling itself.
+    if has_attribute(SpecAttributeKind::Lemma) && !has_attribute(SpecAttributeKind::Terminates) {
+        return syn::Error::new(item.span(), "lemmas must be annotated with `#[terminates]`")
+            .to_compile_error();
+    }
+
     let (generated_spec_items, generated_attributes) =
         handle_result!(generate_spec_and_assertions(prusti_attributes, &item));
 
@@ -169,6 +183,7 @@ fn generate_spec_and_assertions(
             SpecAttributeKind::AssertOnExpiry => generate_for_assert_on_expiry(attr_tokens, item),
             SpecAttributeKind::Pure => generate_for_pure(attr_tokens, item),
             SpecAttributeKind::Verified => generate_for_verified(attr_tokens, item),
+            SpecAttributeKind::Lemma => generate_for_lemma(attr_tokens, item),
             SpecAttributeKind::Terminates => generate_for_terminates(attr_tokens, item),
             SpecAttributeKind::Trusted => generate_for_trusted(attr_tokens, item),
             // Predicates are handled separately below; the entry in the SpecAttributeKind enum
@@ -316,6 +331,23 @@ fn generate_for_verified(attr: TokenStream, item: &untyped::AnyFnItem) -> Genera
     ))
 }
 
+/// Generate spec items and attributes to typecheck and later retrieve "lemma" annotations.
+fn generate_for_lemma(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(
+            attr.span(),
+            "the `#[lemma]` attribute does not take parameters",
+        ));
+    }
+
+    Ok((
+        vec![],
+        vec![parse_quote_spanned! {item.span()=>
+            #[prusti::lemma]
+        }],
+    ))
+}
+
 /// Generate spec items and attributes to typecheck and later retrieve "pure" annotations, but encoded as a referenced separate function that type-conditional spec refinements can apply trait bounds to.
 fn generate_for_pure_refinements(item: &untyped::AnyFnItem) -> GeneratedResult {
     let mut rewriter = rewriter::AstRewriter::new();
@@ -468,8 +500,7 @@ pub fn closure(tokens: TokenStream) -> TokenStream {
 
     for r in cl_spec.pres {
         let spec_id = rewriter.generate_spec_id();
-        let precond =
-            handle_result!(rewriter.process_closure_assertion(spec_id, r.to_token_stream(),));
+        let precond = handle_result!(rewriter.process_closure_assertion(r.to_token_stream()));
         preconds.push((spec_id, precond));
         let spec_id_str = spec_id.to_string();
         cl_annotations.extend(quote_spanned! {callsite_span=>
@@ -479,8 +510,7 @@ pub fn closure(tokens: TokenStream) -> TokenStream {
 
     for e in cl_spec.posts {
         let spec_id = rewriter.generate_spec_id();
-        let postcond =
-            handle_result!(rewriter.process_closure_assertion(spec_id, e.to_token_stream(),));
+        let postcond = handle_result!(rewriter.process_closure_assertion(e.to_token_stream()));
         postconds.push((spec_id, postcond));
         let spec_id_str = spec_id.to_string();
         cl_annotations.extend(quote_spanned! {callsite_span=>
@@ -488,6 +518,37 @@ pub fn closure(tokens: TokenStream) -> TokenStream {
         });
     }
 
+    // A history invariant relates the captured state before and after every
+    // call of the closure, so it is checked and assumed like a postcondition.
+    for i in cl_spec.invariants {
+        if cl_spec.views.is_empty() {
+            return syn::Error::new(
+                i.span(),
+                "history invariants of closures must refer to the captured state through views",
+            )
+            .to_compile_error();
+        }
+        let spec_id = rewriter.generate_spec_id();
+        let invariant = handle_result!(rewriter.process_closure_assertion(i.to_token_stream()));
+        postconds.push((spec_id, invariant));
+        let spec_id_str = spec_id.to_string();
+        cl_annotations.extend(quote_spanned! {callsite_span=>
+            #[prusti::post_spec_id_ref = #spec_id_str]
+        });
+    }
+
+    let mut view_checks = TokenStream::new();
+    for ClosureView { ident, ty } in &cl_spec.views {
+        let view_str = ident.to_string();
+        cl_annotations.extend(quote_spanned! {callsite_span=>
+            #[prusti::closure_view = #view_str]
+        });
+        // Makes sure that the view is a variable captured by the closure.
+        view_checks.extend(quote_spanned! {ident.span()=>
+            let _: &#ty = &#ident;
+        });
+    }
+
     let syn::ExprClosure {
         attrs,
         asyncness,
@@ -508,8 +569,13 @@ pub fn closure(tokens: TokenStream) -> TokenStream {
         syn::ReturnType::Type(_, ref ty) => (**ty).clone(),
     };
 
-    let (spec_toks_pre, spec_toks_post) =
-        handle_result!(rewriter.process_closure(inputs.clone(), output_type, preconds, postconds,));
+    let (spec_toks_pre, spec_toks_post) = handle_result!(rewriter.process_closure(
+        inputs.clone(),
+        &cl_spec.views,
+        output_type,
+        preconds,
+        postconds,
+    ));
 
     let mut attrs_ts = TokenStream::new();
     for a in attrs {
@@ -528,6 +594,7 @@ pub fn closure(tokens: TokenStream) -> TokenStream {
                 {
                     #[allow(unused_must_use, unused_braces, unused_parens)]
                     if false {
+                        #view_checks
                         #spec_toks_pre
                     }
                     let result = #body ;
@@ -877,6 +944,7 @@ fn extract_prusti_attributes_for_types(
                     SpecAttributeKind::RefineSpec => unreachable!("refine_spec on type"),
                     SpecAttributeKind::Pure => unreachable!("pure on type"),
                     SpecAttributeKind::Verified => unreachable!("verified on type"),
+                    SpecAttributeKind::Lemma => unreachable!("lemma on type"),
                     SpecAttributeKind::Invariant => unreachable!("invariant on type"),
                     SpecAttributeKind::Predicate => unreachable!("predicate on type"),
                     SpecAttributeKind::Terminates => unreachable!("terminates on type"),
@@ -923,6 +991,7 @@ fn generate_spec_and_assertions_for_types(
             SpecAttributeKind::AssertOnExpiry => unreachable!(),
             SpecAttributeKind::Pure => unreachable!(),
             SpecAttributeKind::Verified => unreachable!(),
+            SpecAttributeKind::Lemma => unreachable!(),
             SpecAttributeKind::Predicate => unreachable!(),
             SpecAttributeKind::Invariant => unreachable!(),
             SpecAttributeKind::RefineSpec => unreachable!(),
@@ -950,7 +1019,7 @@ fn generate_for_model(attr: TokenStream, item: &mut syn::DeriveInput) -> Generat
                     match result.first() {
                         Some(syn::Item::Struct(new_item)) => {
                             *item = syn::DeriveInput::from(new_item.clone()); //the internal model replaces the original struct
-                            Ok((vec![result[1].clone(), result[2].clone()], vec![]))
+                            Ok((result[1..].to_vec(), vec![]))
                         }
                         _ => unreachable!(),
                     }
@@ -1122,6 +1191,7 @@ pub fn ghost(tokens: TokenStream) -> TokenStream {
             {
                 #begin
                 #[prusti::specs_version = #SPECS_VERSION]
+                #[prusti::ghost_block]
                 let ghost_result = Ghost::new(#tokens);
                 #end
                 ghost_result
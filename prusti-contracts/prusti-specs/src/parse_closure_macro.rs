@@ -3,9 +3,29 @@ use syn::parse::{Parse, ParseStream};
 pub(crate) struct ClosureWithSpec {
     pub pres: Vec<syn::Expr>,
     pub posts: Vec<syn::Expr>,
+    pub invariants: Vec<syn::Expr>,
+    pub views: Vec<ClosureView>,
     pub cl: syn::ExprClosure,
 }
 
+/// A variable captured by a closure that its specification refers to, given
+/// as `#[view(name: Type)]`.
+pub(crate) struct ClosureView {
+    pub ident: syn::Ident,
+    pub ty: syn::Type,
+}
+
+impl Parse for ClosureView {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let ident = content.parse()?;
+        content.parse::<syn::Token![:]>()?;
+        let ty = content.parse()?;
+        Ok(Self { ident, ty })
+    }
+}
+
 impl Parse for ClosureWithSpec {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut attrs = input.call(syn::Attribute::parse_outer)?;
@@ -13,6 +33,8 @@ impl Parse for ClosureWithSpec {
 
         let mut pres: Vec<syn::Result<syn::Expr>> = vec![];
         let mut posts: Vec<syn::Result<syn::Expr>> = vec![];
+        let mut invariants: Vec<syn::Result<syn::Expr>> = vec![];
+        let mut views: Vec<syn::Result<ClosureView>> = vec![];
 
         // collect and remove any specification attributes
         // leave other attributes intact
@@ -21,6 +43,8 @@ impl Parse for ClosureWithSpec {
                 match id.to_string().as_ref() {
                     "requires" => pres.push(syn::parse2(attr.tokens.clone())),
                     "ensures" => posts.push(syn::parse2(attr.tokens.clone())),
+                    "view" => views.push(syn::parse2(attr.tokens.clone())),
+                    "invariant" => invariants.push(syn::parse2(attr.tokens.clone())),
                     _ => return true,
                 }
                 false
@@ -33,6 +57,8 @@ impl Parse for ClosureWithSpec {
         Ok(Self {
             pres: pres.into_iter().collect::<syn::Result<Vec<_>>>()?,
             posts: posts.into_iter().collect::<syn::Result<Vec<_>>>()?,
+            invariants: invariants.into_iter().collect::<syn::Result<Vec<_>>>()?,
+            views: views.into_iter().collect::<syn::Result<Vec<_>>>()?,
             cl,
         })
     }
@@ -1,5 +1,6 @@
 use crate::{
     common::HasSignature,
+    parse_closure_macro::ClosureView,
     specifications::{
         common::{SpecificationId, SpecificationIdGenerator},
         preparser::{parse_prusti, parse_prusti_assert_pledge, parse_prusti_pledge},
@@ -7,7 +8,7 @@ use crate::{
     },
 };
 use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{parse_quote_spanned, punctuated::Punctuated, spanned::Spanned, Pat, Token, Type};
 
 pub(crate) struct AstRewriter {
@@ -109,7 +110,7 @@ impl AstRewriter {
         //   expression with the correct error message, i.e. that the expected
         //   type is `bool`, not that the expected *return* type is `bool`
         let return_type = match &spec_type {
-            SpecItemType::Termination => quote_spanned! {item_span => Int},
+            SpecItemType::Termination => termination_measure_type(&expr, item_span),
             SpecItemType::Predicate(return_type) => return_type.clone(),
             _ => quote_spanned! {item_span => bool},
         };
@@ -207,12 +208,13 @@ impl AstRewriter {
     ) -> syn::Result<TokenStream> {
         let expr = parse_prusti(tokens)?;
         let spec_id_str = spec_id.to_string();
+        let variant_type = termination_measure_type(&expr, expr.span());
         Ok(quote_spanned! {expr.span()=>
             {
                 #[prusti::spec_only]
                 #[prusti::loop_body_variant_spec]
                 #[prusti::spec_id = #spec_id_str]
-                || -> Int {
+                || -> #variant_type {
                     #expr
                 };
             }
@@ -275,11 +277,13 @@ impl AstRewriter {
         })
     }
 
-    /// Parse a closure with specifications into a Rust expression
-    /// TODO: arguments, result (types are typically not known yet after parsing...)
+    /// Generate the specification items of a closure. They are nested in the
+    /// body of the closure and take the arguments, the views and the result of
+    /// the closure, but not the closure itself.
     pub fn process_closure(
         &mut self,
         inputs: Punctuated<Pat, Token![,]>,
+        views: &[ClosureView],
         output: Type,
         preconds: Vec<(SpecificationId, syn::Expr)>,
         postconds: Vec<(SpecificationId, syn::Expr)>,
@@ -293,18 +297,21 @@ impl AstRewriter {
                     spec_id_str
                 );
                 let callsite_span = Span::call_site();
-                let result = if is_post && !inputs.empty_or_trailing() {
-                    quote_spanned! {callsite_span=> , result: #output }
-                } else if is_post {
-                    quote_spanned! {callsite_span=> result: #output }
-                } else {
-                    TokenStream::new()
-                };
+                let mut params: Vec<TokenStream> =
+                    inputs.iter().map(|input| input.to_token_stream()).collect();
+                params.extend(views.iter().map(
+                    |ClosureView { ident, ty }| quote_spanned! {callsite_span=> #ident: #ty },
+                ));
+                if is_post {
+                    params.push(quote_spanned! {callsite_span=> result: #output });
+                }
                 quote_spanned! {callsite_span=>
+                    #[allow(unused_parens, unused_variables, dead_code)]
                     #[prusti::spec_only]
                     #[prusti::spec_id = #spec_id_str]
-                    fn #name(#inputs #result) {
-                        #assertion
+                    fn #name(#(#params),*) -> bool {
+                        let prusti_result: bool = #assertion;
+                        prusti_result
                     }
                 }
             };
@@ -322,24 +329,24 @@ impl AstRewriter {
         Ok((pre_ts, post_ts))
     }
 
-    /// Parse an assertion into a Rust expression
-    pub fn process_closure_assertion(
-        &mut self,
-        spec_id: SpecificationId,
-        tokens: TokenStream,
-    ) -> syn::Result<syn::Expr> {
+    /// Parse an assertion of a closure specification into a Rust expression
+    pub fn process_closure_assertion(&mut self, tokens: TokenStream) -> syn::Result<syn::Expr> {
         let expr = parse_prusti(tokens)?;
-        let spec_id_str = spec_id.to_string();
-        let callsite_span = Span::call_site();
-        Ok(parse_quote_spanned! {callsite_span=>
-            #[allow(unused_must_use, unused_variables)]
-            {
-                #[prusti::spec_only]
-                #[prusti::spec_id = #spec_id_str]
-                || -> bool {
-                    #expr
-                };
-            }
-        })
+        Ok(parse_quote_spanned! {expr.span()=> (#expr)})
+    }
+}
+
+/// The type of a termination measure or a loop variant. A measure written as
+/// a tuple is lexicographic and has one `Int` component per tuple element.
+fn termination_measure_type(expr: &TokenStream, span: Span) -> TokenStream {
+    let mut parsed = syn::parse2::<syn::Expr>(expr.clone()).ok();
+    while let Some(syn::Expr::Paren(syn::ExprParen { expr, .. })) = parsed {
+        parsed = Some(*expr);
+    }
+    if let Some(syn::Expr::Tuple(tuple)) = parsed {
+        let components = tuple.elems.iter().map(|_| quote_spanned! {span => Int});
+        quote_spanned! {span => (#(#components,)*)}
+    } else {
+        quote_spanned! {span => Int}
     }
 }
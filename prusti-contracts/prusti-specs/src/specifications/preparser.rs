@@ -351,9 +351,10 @@ impl PrustiTokenStream {
                         .pop_closure_args()
                         .ok_or_else(|| error(span, "expected closure arguments"))?;
                     let nested_closure_specs = self.pop_group_of_nested_specs(span)?;
+                    let calls = spec_ent_calls(span, once, &nested_closure_specs)?;
                     lhs = translate_spec_ent(
                         span,
-                        once,
+                        calls,
                         lhs,
                         args.split(PrustiBinaryOp::Rust(RustOp::Comma), true)
                             .into_iter()
@@ -452,6 +453,7 @@ impl PrustiTokenStream {
                 "requires" => Ok(NestedSpec::Requires(self.pop_parenthesized_group()?)),
                 "ensures" => Ok(NestedSpec::Ensures(self.pop_parenthesized_group()?)),
                 "pure" => Ok(NestedSpec::Pure),
+                "calls" => Ok(NestedSpec::Calls(self.pop_parenthesized_group()?)),
                 other => err(
                     self.source_span,
                     format!("unexpected nested spec type: {other}").as_ref(),
@@ -628,6 +630,9 @@ pub enum NestedSpec<T> {
     Requires(T),
     Ensures(T),
     Pure,
+    /// A bound on the number of calls of a closure, only allowed in spec
+    /// entailments.
+    Calls(T),
 }
 
 impl NestedSpec<PrustiTokenStream> {
@@ -636,6 +641,7 @@ impl NestedSpec<PrustiTokenStream> {
             NestedSpec::Requires(stream) => NestedSpec::Requires(stream.parse()?),
             NestedSpec::Ensures(stream) => NestedSpec::Ensures(stream.parse()?),
             NestedSpec::Pure => NestedSpec::Pure,
+            NestedSpec::Calls(stream) => NestedSpec::Calls(stream.parse()?),
         })
     }
 }
@@ -652,44 +658,52 @@ enum PrustiToken {
     CallDesc(Span, bool),
 }
 
-fn translate_spec_ent(
+/// Returns the number of calls of the closure that a spec entailment holds
+/// for, or zero if it holds for all calls. `|=!` holds for a single call, while
+/// `calls(n)` bounds the number of calls to `n`.
+fn spec_ent_calls(
     span: Span,
     once: bool,
+    contract: &[NestedSpec<TokenStream>],
+) -> syn::Result<usize> {
+    let mut calls = if once { Some(1) } else { None };
+    for spec in contract {
+        if let NestedSpec::Calls(stream) = spec {
+            if calls.is_some() {
+                return err(
+                    span,
+                    "the number of calls of a closure is bounded more than once",
+                );
+            }
+            let bound: usize = syn::parse2::<syn::LitInt>(stream.clone())?.base10_parse()?;
+            if bound == 0 {
+                return err(
+                    stream.span(),
+                    "the number of calls of a closure must be bounded by a positive number",
+                );
+            }
+            calls = Some(bound);
+        }
+    }
+    Ok(calls.unwrap_or(0))
+}
+
+fn translate_spec_ent(
+    span: Span,
+    calls: usize,
     cl_expr: TokenStream,
     cl_args: Vec<TokenStream>,
     contract: Vec<NestedSpec<TokenStream>>,
 ) -> TokenStream {
-    let once = if once {
-        quote_spanned! { span => true }
-    } else {
-        quote_spanned! { span => false }
-    };
+    let mut calls = proc_macro2::Literal::usize_suffixed(calls);
+    calls.set_span(span);
 
-    // TODO: move extraction function generation into "fn_type_extractor"
     let arg_count = cl_args.len();
     let generics_args = (0..arg_count)
         .map(|i| TokenTree::Ident(proc_macro2::Ident::new(&format!("GA{i}"), span)))
         .collect::<Vec<_>>();
     let generic_res = TokenTree::Ident(proc_macro2::Ident::new("GR", span));
 
-    let extract_args = (0..arg_count)
-        .map(|i| TokenTree::Ident(proc_macro2::Ident::new(&format!("__extract_arg{i}"), span)))
-        .collect::<Vec<_>>();
-    let extract_args_decl = extract_args
-        .iter()
-        .zip(generics_args.iter())
-        .map(|(ident, arg_type)| {
-            quote_spanned! { span =>
-                #[prusti::spec_only]
-                fn #ident<
-                    #(#generics_args),* ,
-                    #generic_res,
-                    F: FnOnce( #(#generics_args),* ) -> #generic_res
-                >(_f: &F) -> #arg_type { unreachable!() }
-            }
-        })
-        .collect::<Vec<_>>();
-
     let preconds = contract
         .iter()
         .filter_map(|spec| match spec {
@@ -705,24 +719,39 @@ fn translate_spec_ent(
         })
         .collect::<Vec<_>>();
 
+    // The pre- and postconditions are closures over the arguments (and the
+    // result) of the specified closure. They are passed through helper
+    // functions whose bounds fix the types of the arguments and of `result`.
     // TODO: figure out `outer`
-
     quote_spanned! { span => {
-        let __cl_ref = & #cl_expr;
-        #(#extract_args_decl)*
         #[prusti::spec_only]
-        fn __extract_res<
-            #(#generics_args),* ,
+        #[prusti::spec_entailment_contract]
+        fn __spec_ent_pre<
+            #(#generics_args,)*
+            #generic_res,
+            F: FnOnce( #(#generics_args),* ) -> #generic_res,
+            P: Fn( #(#generics_args),* ) -> bool
+        >(_f: &F, pre: P) -> P { pre }
+        #[prusti::spec_only]
+        #[prusti::spec_entailment_contract]
+        fn __spec_ent_post<
+            #(#generics_args,)*
             #generic_res,
-            F: FnOnce( #(#generics_args),* ) -> #generic_res
-        >(_f: &F) -> #generic_res { unreachable!() }
-        #( let #cl_args = #extract_args(__cl_ref); )*
-        let result = __extract_res(__cl_ref);
-        specification_entailment(
-            #once,
+            F: FnOnce( #(#generics_args),* ) -> #generic_res,
+            P: Fn( #(#generics_args,)* #generic_res ) -> bool
+        >(_f: &F, post: P) -> P { post }
+        let __cl_ref = & #cl_expr;
+        ::prusti_contracts::specification_entailment(
+            #calls,
             __cl_ref,
-            ( #( #[prusti::spec_only] || -> bool { #preconds }, )* ),
-            ( #( #[prusti::spec_only] || -> bool { #postconds }, )* ),
+            __spec_ent_pre(
+                __cl_ref,
+                #[prusti::spec_only] | #(#cl_args),* | -> bool { true #( && ( #preconds ) )* },
+            ),
+            __spec_ent_post(
+                __cl_ref,
+                #[prusti::spec_only] | #(#cl_args,)* result | -> bool { true #( && ( #postconds ) )* },
+            ),
         )
     } }
 }
@@ -1106,6 +1135,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn spec_ent_calls() {
+        assert_error!(
+            parse_prusti("f |=! |x: i32| [calls(2)]".parse().unwrap()),
+            "the number of calls of a closure is bounded more than once"
+        );
+        assert_error!(
+            parse_prusti("f |= |x: i32| [calls(0)]".parse().unwrap()),
+            "the number of calls of a closure must be bounded by a positive number"
+        );
+    }
+
     mod type_cond_specs {
         use std::assert_matches::assert_matches;
 
@@ -19,6 +19,12 @@ pub fn generate(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult
             NestedSpec::Ensures(tokens) => generate_for_ensures(tokens, item)?,
             NestedSpec::Requires(tokens) => generate_for_requires(tokens, item)?,
             NestedSpec::Pure => generate_for_pure_refinements(item)?,
+            NestedSpec::Calls(tokens) => {
+                return Err(syn::Error::new(
+                    tokens.span(),
+                    "`calls` is only allowed in specification entailments",
+                ))
+            }
         };
 
         for generated_item in generated_items.iter_mut() {
@@ -2,13 +2,16 @@
 //!
 //! Usage documentation can be found in the corresponding macro definition.
 //!
-//! Given a `#[model]` attributed type `T`, this logic creates the following three items:
+//! Given a `#[model]` attributed type `T`, this logic creates the following items:
 //! * A struct `M` which holds the model's fields
+//! * `Clone` and `Copy` implementations for `M`
 //! * A trait which provides a `model` method to be used in specifications
 //! * An implementation of the aforementioned trait for `T`.
 //!   The implementation is `unimplemented!()`, `#[pure]` and `#[trusted]`
 //!
-//! The model struct `M` must be copyable.
+//! The model struct `M` must be copyable. The `Copy` implementation only has the bounds that
+//! were declared on the type parameters, so a type parameter needs a `Copy` bound only if a
+//! field stores it directly. The struct and the trait get the visibility of `T`.
 //!
 //! # Note
 //! This macro always generates a trait with a `model` method on the fly for every modelled type.
@@ -33,6 +36,8 @@ pub fn rewrite(item_struct: syn::ItemStruct) -> syn::Result<Vec<syn::Item>> {
     match res {
         Ok(result) => Ok(vec![
             syn::Item::Struct(result.model_struct),
+            syn::Item::Impl(result.model_clone_impl),
+            syn::Item::Impl(result.model_copy_impl),
             syn::Item::Trait(result.to_model_trait),
             syn::Item::Impl(result.model_impl),
         ]),
@@ -46,11 +51,14 @@ fn rewrite_internal(item_struct: syn::ItemStruct) -> TypeModelGenerationResult<T
     let idents = GeneratedIdents::generate(&item_struct);
 
     let model_struct = ModelStruct::create(&item_struct, &idents)?;
+    let (model_clone_impl, model_copy_impl) = create_model_copy_impls(&model_struct);
     let to_model_trait = ToModelTrait::create(&item_struct, &model_struct, &idents);
     let model_impl = create_model_impl(&item_struct, &model_struct, &to_model_trait)?;
 
     Ok(TypeModel {
         model_struct: model_struct.item,
+        model_clone_impl,
+        model_copy_impl,
         to_model_trait: to_model_trait.item,
         model_impl,
     })
@@ -75,10 +83,10 @@ impl ModelStruct {
         }
 
         let model_struct_ident = &idents.model_struct_ident;
+        let vis = &item_struct.vis;
         let mut model_struct: syn::ItemStruct = parse_quote_spanned! {item_struct.span()=>
-            #[derive(Copy, Clone)]
             #[allow(non_camel_case_types)]
-            struct #model_struct_ident {}
+            #vis struct #model_struct_ident {}
         };
 
         let params = item_struct
@@ -140,9 +148,10 @@ impl ToModelTrait {
         let model_path = &model_struct.path;
 
         let to_model_trait_ident = &idents.to_model_trait_ident;
+        let vis = &item_struct.vis;
         let item = parse_quote_spanned! {item_struct.span()=>
             #[allow(non_camel_case_types)]
-            trait #to_model_trait_ident<#(#generic_params),*> {
+            #vis trait #to_model_trait_ident<#(#generic_params),*> {
                 #[pure]
                 #[trusted]
                 #[prusti::type_models_to_model_fn]
@@ -158,6 +167,27 @@ impl ToModelTrait {
     }
 }
 
+/// Implements `Clone` and `Copy` for the model struct. Deriving them would add a `Copy` bound to
+/// every type parameter, even to those that only appear in ghost types like `Seq<T>`.
+fn create_model_copy_impls(model_struct: &ModelStruct) -> (syn::ItemImpl, syn::ItemImpl) {
+    let generic_params: Vec<syn::GenericParam> =
+        model_struct.item.generics.params.iter().cloned().collect();
+    let model_struct_path = &model_struct.path;
+    let span = model_struct.item.span();
+
+    let clone_impl = parse_quote_spanned! {span=>
+        impl<#(#generic_params),*> ::core::clone::Clone for #model_struct_path {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+    };
+    let copy_impl = parse_quote_spanned! {span=>
+        impl<#(#generic_params),*> ::core::marker::Copy for #model_struct_path {}
+    };
+    (clone_impl, copy_impl)
+}
+
 fn create_model_impl(
     item_struct: &syn::ItemStruct,
     model_struct: &ModelStruct,
@@ -272,6 +302,12 @@ struct TypeModel {
     /// The struct which represents the model
     model_struct: syn::ItemStruct,
 
+    /// The `Clone` implementation of the [TypeModel::model_struct]
+    model_clone_impl: syn::ItemImpl,
+
+    /// The `Copy` implementation of the [TypeModel::model_struct]
+    model_copy_impl: syn::ItemImpl,
+
     /// A trait which will be implemented on the modelled type
     /// to return the [TypeModel::model_struct]
     to_model_trait: syn::ItemTrait,
@@ -284,6 +320,8 @@ impl ToTokens for TypeModel {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.to_model_trait.to_tokens(tokens);
         self.model_struct.to_tokens(tokens);
+        self.model_clone_impl.to_tokens(tokens);
+        self.model_copy_impl.to_tokens(tokens);
         self.model_impl.to_tokens(tokens);
     }
 }
@@ -300,11 +338,15 @@ mod tests {
         let model_struct: syn::ItemStruct = parse_quote!(
             struct Foo {}
         );
+        let clone_impl: syn::ItemImpl = parse_quote!(impl Clone for Foo {});
+        let copy_impl: syn::ItemImpl = parse_quote!(impl Copy for Foo {});
         let trait_impl: syn::ItemImpl = parse_quote!(impl ToModel for Foo {});
 
         let rewritten_model = TypeModel {
             to_model_trait: to_model_trait.clone(),
             model_struct: model_struct.clone(),
+            model_clone_impl: clone_impl.clone(),
+            model_copy_impl: copy_impl.clone(),
             model_impl: trait_impl.clone(),
         };
         let actual_ts = rewritten_model.into_token_stream();
@@ -312,6 +354,8 @@ mod tests {
         let mut expected_ts = TokenStream::new();
         to_model_trait.to_tokens(&mut expected_ts);
         model_struct.to_tokens(&mut expected_ts);
+        clone_impl.to_tokens(&mut expected_ts);
+        copy_impl.to_tokens(&mut expected_ts);
         trait_impl.to_tokens(&mut expected_ts);
 
         assert_eq!(expected_ts.to_string(), actual_ts.to_string());
@@ -342,7 +386,6 @@ mod tests {
 
         let model_ident = check_model_ident(&model, "PrustiFooModel");
         let expected: syn::ItemStruct = syn::parse_quote!(
-            #[derive(Copy, Clone)]
             #[allow(non_camel_case_types)]
             struct #model_ident {
                 fld1: usize,
@@ -363,7 +406,6 @@ mod tests {
         let model_ident = check_model_ident(&model, "PrustiFooModel");
 
         let expected: syn::ItemStruct = parse_quote!(
-            #[derive(Copy, Clone)]
             #[allow(non_camel_case_types)]
             struct #model_ident(i32, u32, usize);
         );
@@ -409,7 +451,6 @@ mod tests {
         let trait_ident_str = trait_ident.to_string();
 
         let expected_struct: syn::ItemStruct = parse_quote!(
-            #[derive(Copy, Clone)]
             #[allow(non_camel_case_types)]
             struct #model_ident(i32, u32, usize);
         );
@@ -442,7 +483,6 @@ mod tests {
         let trait_ident_str = trait_ident.to_string();
 
         let expected_struct: syn::ItemStruct = parse_quote!(
-            #[derive(Copy, Clone)]
             #[allow(non_camel_case_types)]
             struct #model_ident<T, U> (i32,::core::marker::PhantomData<T> , ::core::marker::PhantomData<U>);
         );
@@ -500,6 +540,62 @@ mod tests {
         assert_eq_tokenizable(actual, expected);
     }
 
+    #[test]
+    fn ok_implements_copy_with_declared_bounds_only() {
+        let input: syn::ItemStruct = parse_quote!(
+            struct Foo<#[generic] T, #[generic] U: Copy>(U);
+        );
+        let model = expect_ok(rewrite_internal(input));
+
+        let model_ident = check_model_ident(&model, "PrustiFooTUModel");
+
+        let expected_clone_impl: syn::ItemImpl = parse_quote!(
+            impl<T, U: Copy> ::core::clone::Clone for #model_ident<T, U> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        );
+        let expected_copy_impl: syn::ItemImpl = parse_quote!(
+            impl<T, U: Copy> ::core::marker::Copy for #model_ident<T, U> {}
+        );
+
+        assert_eq_tokenizable(model.model_clone_impl, expected_clone_impl);
+        assert_eq_tokenizable(model.model_copy_impl, expected_copy_impl);
+    }
+
+    #[test]
+    fn ok_keeps_visibility() {
+        let input: syn::ItemStruct = parse_quote!(
+            pub struct Foo {
+                pub fld: usize,
+            }
+        );
+        let model = expect_ok(rewrite_internal(input));
+
+        let model_ident = check_model_ident(&model, "PrustiFooModel");
+        let trait_ident = check_trait_ident(&model, "PrustiFooToModel");
+
+        let expected_struct: syn::ItemStruct = parse_quote!(
+            #[allow(non_camel_case_types)]
+            pub struct #model_ident {
+                pub fld: usize,
+            }
+        );
+        let expected_trait: syn::ItemTrait = parse_quote!(
+            #[allow(non_camel_case_types)]
+            pub trait #trait_ident {
+                #[pure]
+                #[trusted]
+                #[prusti::type_models_to_model_fn]
+                fn model(&self) -> #model_ident<>;
+            }
+        );
+
+        assert_eq_tokenizable(model.model_struct, expected_struct);
+        assert_eq_tokenizable(model.to_model_trait, expected_trait);
+    }
+
     #[test]
     fn err_const_generics_disallowed() {
         let input: syn::ItemStruct = parse_quote!(
@@ -0,0 +1,7 @@
+use prusti_contracts::*;
+
+#[extern_spec]
+impl<T> ::std::boxed::Box<T> {
+    #[ensures(*result === x)]
+    pub fn new(x: T) -> Self;
+}
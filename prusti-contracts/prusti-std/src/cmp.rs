@@ -0,0 +1,75 @@
+use core::cmp::Ordering;
+use prusti_contracts::*;
+
+// The integer types, whose `cmp` is specified as pure. Implementations of `Ord`
+// for other types may have side effects, so `min` and `max` are only specified
+// for the types implementing this trait.
+trait IntegerOrd: Ord {}
+
+macro_rules! specify_integer_cmp {
+    ($($ty:ty),*) => {
+        $(
+            impl IntegerOrd for $ty {}
+
+            #[extern_spec]
+            impl Ord for $ty {
+                #[pure]
+                #[ensures(result.is_lt() == (*self < *other))]
+                #[ensures(result.is_eq() == (*self == *other))]
+                #[ensures(result.is_gt() == (*self > *other))]
+                fn cmp(&self, other: &Self) -> Ordering;
+            }
+        )*
+    };
+}
+
+specify_integer_cmp!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Like `core::cmp::min`, the first argument is returned if both are equal.
+#[extern_spec(core::cmp)]
+#[refine_spec(where T: IntegerOrd, [
+    ensures(old(v1.cmp(&v2)).is_le() ==> result === old(snap(&v1))),
+    ensures(old(v1.cmp(&v2)).is_gt() ==> result === old(snap(&v2))),
+])]
+fn min<T: Ord>(v1: T, v2: T) -> T;
+
+// Like `core::cmp::max`, the second argument is returned if both are equal.
+#[extern_spec(core::cmp)]
+#[refine_spec(where T: IntegerOrd, [
+    ensures(old(v1.cmp(&v2)).is_le() ==> result === old(snap(&v2))),
+    ensures(old(v1.cmp(&v2)).is_gt() ==> result === old(snap(&v1))),
+])]
+fn max<T: Ord>(v1: T, v2: T) -> T;
+
+#[extern_spec]
+impl ::core::cmp::Ordering {
+    #[pure]
+    #[ensures(result == matches!(self, Ordering::Equal))]
+    pub fn is_eq(self) -> bool;
+
+    #[pure]
+    #[ensures(result == !matches!(self, Ordering::Equal))]
+    pub fn is_ne(self) -> bool;
+
+    #[pure]
+    #[ensures(result == matches!(self, Ordering::Less))]
+    pub fn is_lt(self) -> bool;
+
+    #[pure]
+    #[ensures(result == matches!(self, Ordering::Greater))]
+    pub fn is_gt(self) -> bool;
+
+    #[pure]
+    #[ensures(result == !matches!(self, Ordering::Greater))]
+    pub fn is_le(self) -> bool;
+
+    #[pure]
+    #[ensures(result == !matches!(self, Ordering::Less))]
+    pub fn is_ge(self) -> bool;
+
+    #[pure]
+    #[ensures(self.is_lt() == result.is_gt())]
+    #[ensures(self.is_eq() == result.is_eq())]
+    #[ensures(self.is_gt() == result.is_lt())]
+    pub fn reverse(self) -> Ordering;
+}
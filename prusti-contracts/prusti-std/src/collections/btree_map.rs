@@ -0,0 +1,62 @@
+use prusti_contracts::*;
+use std::{borrow::Borrow, collections::BTreeMap};
+
+/// The entries of the map.
+#[model]
+pub struct BTreeMap<#[generic] K, #[generic] V> {
+    pub entries: Map<K, V>,
+}
+
+// The methods are specified for the global allocator only, because the
+// `Allocator` trait is unstable.
+
+#[extern_spec]
+impl<K, V> BTreeMap<K, V> {
+    #[ensures(result.is_empty())]
+    #[ensures(result.model().entries === Map::empty())]
+    pub fn new() -> Self;
+
+    #[pure]
+    #[ensures(Int::new_usize(result) == self.model().entries.len())]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.is_empty())]
+    #[ensures(self.model().entries === Map::empty())]
+    pub fn clear(&mut self);
+
+    #[pure]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord;
+
+    #[pure]
+    #[ensures(result.is_some() == self.contains_key(key))]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord;
+
+    #[ensures(self.contains_key(&key))]
+    #[ensures(result.is_some() == old(self.contains_key(&key)))]
+    #[ensures(old(self.contains_key(&key)) ==> self.len() == old(self.len()))]
+    #[ensures(!old(self.contains_key(&key)) ==> self.len() == old(self.len()) + 1)]
+    #[ensures(self.get(&key) === Some(&value))]
+    #[ensures(self.model().entries === old(self.model().entries).insert(key, value))]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Ord;
+
+    #[ensures(!self.contains_key(key))]
+    #[ensures(result.is_some() == old(self.contains_key(key)))]
+    #[ensures(old(self.contains_key(key)) ==> self.len() == old(self.len()) - 1)]
+    #[ensures(!old(self.contains_key(key)) ==> self.len() == old(self.len()))]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord;
+}
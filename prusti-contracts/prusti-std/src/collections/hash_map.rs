@@ -0,0 +1,75 @@
+use prusti_contracts::*;
+use std::{
+    borrow::Borrow,
+    collections::hash_map::{HashMap, RandomState},
+    hash::{BuildHasher, Hash},
+};
+
+/// The entries of the map.
+#[model]
+pub struct HashMap<#[generic] K, #[generic] V, #[generic] S> {
+    pub entries: Map<K, V>,
+}
+
+#[extern_spec]
+impl<K, V> HashMap<K, V, RandomState> {
+    #[ensures(result.is_empty())]
+    #[ensures(result.model().entries === Map::empty())]
+    pub fn new() -> Self;
+
+    #[ensures(result.is_empty())]
+    #[ensures(result.model().entries === Map::empty())]
+    pub fn with_capacity(capacity: usize) -> Self;
+}
+
+#[extern_spec]
+impl<K, V, S> HashMap<K, V, S> {
+    #[pure]
+    #[ensures(Int::new_usize(result) == self.model().entries.len())]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.is_empty())]
+    #[ensures(self.model().entries === Map::empty())]
+    pub fn clear(&mut self);
+}
+
+#[extern_spec]
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    #[pure]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq;
+
+    #[pure]
+    #[ensures(result.is_some() == self.contains_key(k))]
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq;
+
+    #[ensures(self.contains_key(&k))]
+    #[ensures(result.is_some() == old(self.contains_key(&k)))]
+    #[ensures(old(self.contains_key(&k)) ==> self.len() == old(self.len()))]
+    #[ensures(!old(self.contains_key(&k)) ==> self.len() == old(self.len()) + 1)]
+    #[ensures(self.get(&k) === Some(&v))]
+    #[ensures(self.model().entries === old(self.model().entries).insert(k, v))]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V>;
+
+    #[ensures(!self.contains_key(k))]
+    #[ensures(result.is_some() == old(self.contains_key(k)))]
+    #[ensures(old(self.contains_key(k)) ==> self.len() == old(self.len()) - 1)]
+    #[ensures(!old(self.contains_key(k)) ==> self.len() == old(self.len()))]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq;
+}
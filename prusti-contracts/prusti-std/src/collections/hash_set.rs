@@ -0,0 +1,56 @@
+use prusti_contracts::*;
+use std::{
+    borrow::Borrow,
+    collections::{hash_map::RandomState, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+#[extern_spec]
+impl<T> HashSet<T, RandomState> {
+    #[ensures(result.is_empty())]
+    pub fn new() -> Self;
+
+    #[ensures(result.is_empty())]
+    pub fn with_capacity(capacity: usize) -> Self;
+}
+
+#[extern_spec]
+impl<T, S> HashSet<T, S> {
+    #[pure]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.is_empty())]
+    pub fn clear(&mut self);
+}
+
+#[extern_spec]
+impl<T, S> HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    #[pure]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq;
+
+    #[ensures(self.contains(&value))]
+    #[ensures(result == !old(self.contains(&value)))]
+    #[ensures(result ==> self.len() == old(self.len()) + 1)]
+    #[ensures(!result ==> self.len() == old(self.len()))]
+    pub fn insert(&mut self, value: T) -> bool;
+
+    #[ensures(!self.contains(value))]
+    #[ensures(result == old(self.contains(value)))]
+    #[ensures(result ==> self.len() == old(self.len()) - 1)]
+    #[ensures(!result ==> self.len() == old(self.len()))]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq;
+}
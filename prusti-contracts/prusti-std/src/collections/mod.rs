@@ -0,0 +1,6 @@
+mod btree_map;
+mod hash_map;
+mod hash_set;
+mod vec_deque;
+
+pub use self::{btree_map::*, hash_map::*, vec_deque::*};
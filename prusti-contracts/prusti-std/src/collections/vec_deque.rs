@@ -0,0 +1,71 @@
+use prusti_contracts::*;
+use std::collections::VecDeque;
+
+/// The elements of the deque, from front to back.
+#[model]
+pub struct VecDeque<#[generic] T> {
+    pub elements: Seq<T>,
+}
+
+// The methods are specified for the global allocator only, because the
+// `Allocator` trait is unstable.
+
+#[extern_spec]
+impl<T> VecDeque<T> {
+    #[ensures(result.is_empty())]
+    #[ensures(result.model().elements === Seq::empty())]
+    pub fn new() -> Self;
+
+    #[ensures(result.is_empty())]
+    #[ensures(result.model().elements === Seq::empty())]
+    pub fn with_capacity(capacity: usize) -> Self;
+
+    #[pure]
+    #[ensures(Int::new_usize(result) == self.model().elements.len())]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.is_empty())]
+    #[ensures(self.model().elements === Seq::empty())]
+    pub fn clear(&mut self);
+
+    #[pure]
+    #[ensures(result.is_some() == (self.len() > 0))]
+    #[ensures(self.len() > 0 ==> result === Some(&self.model().elements.lookup(0)))]
+    pub fn front(&self) -> Option<&T>;
+
+    #[pure]
+    #[ensures(result.is_some() == (self.len() > 0))]
+    #[ensures(self.len() > 0 ==>
+        result === Some(&self.model().elements.lookup(self.len() - 1)))]
+    pub fn back(&self) -> Option<&T>;
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(self.back() === Some(&value))]
+    #[ensures(self.model().elements === old(self.model().elements).concat(Seq::single(value)))]
+    pub fn push_back(&mut self, value: T);
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(self.front() === Some(&value))]
+    #[ensures(self.model().elements === Seq::single(value).concat(old(self.model().elements)))]
+    pub fn push_front(&mut self, value: T);
+
+    #[ensures(result.is_some() == (old(self.len()) > 0))]
+    #[ensures(old(self.len()) > 0 ==> self.len() == old(self.len()) - 1)]
+    #[ensures(old(self.len()) == 0 ==> self.len() == 0)]
+    #[ensures(old(self.len()) > 0 ==>
+        result === Some(old(self.model().elements.lookup(self.len() - 1))))]
+    #[ensures(old(self.len()) > 0 ==>
+        self.model().elements === old(self.model().elements.take(self.len() - 1)))]
+    pub fn pop_back(&mut self) -> Option<T>;
+
+    #[ensures(result.is_some() == (old(self.len()) > 0))]
+    #[ensures(old(self.len()) > 0 ==> self.len() == old(self.len()) - 1)]
+    #[ensures(old(self.len()) == 0 ==> self.len() == 0)]
+    #[ensures(old(self.len()) > 0 ==> result === Some(old(self.model().elements.lookup(0))))]
+    #[ensures(old(self.len()) > 0 ==> self.model().elements === old(self.model().elements.skip(1)))]
+    pub fn pop_front(&mut self) -> Option<T>;
+}
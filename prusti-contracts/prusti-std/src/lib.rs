@@ -1,14 +1,33 @@
-use prusti_contracts::*;
+//! External specifications for items of `core`, `alloc` and `std`.
+//!
+//! The specifications are stated in terms of pure methods of the specified
+//! types, such as `len` or `contains_key`, and of the type models in
+//! [models]. They are exported to the crates that add `extern crate prusti_std;`.
 
-#[extern_spec]
-impl<K, V, S> ::std::collections::hash_map::HashMap<K, V, S>
-where
-    K: Eq + ::core::hash::Hash,
-    S: ::std::hash::BuildHasher,
-{
-    #[pure]
-    pub fn contains_key<Q>(&self, k: &Q) -> bool
-    where
-        K: ::core::borrow::Borrow<Q>,
-        Q: ::core::hash::Hash + Eq;
+#[cfg(feature = "prusti")]
+mod boxed;
+#[cfg(feature = "prusti")]
+mod cmp;
+#[cfg(feature = "prusti")]
+mod collections;
+#[cfg(feature = "prusti")]
+mod mem;
+#[cfg(feature = "prusti")]
+mod option;
+#[cfg(feature = "prusti")]
+mod result;
+#[cfg(feature = "prusti")]
+mod slice;
+#[cfg(feature = "prusti")]
+mod str;
+#[cfg(feature = "prusti")]
+mod string;
+
+/// The type models of the specified types: a `Seq` of the elements of a
+/// `VecDeque`, a `Map` of the entries of a `HashMap` or a `BTreeMap`, and a
+/// `Seq` of the chars of a `str`. Import them with `use prusti_std::models::*;`
+/// to call `model()` in specifications.
+pub mod models {
+    #[cfg(feature = "prusti")]
+    pub use crate::{collections::*, str::*};
 }
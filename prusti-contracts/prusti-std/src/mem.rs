@@ -0,0 +1,22 @@
+use prusti_contracts::*;
+
+#[extern_spec(core::mem)]
+#[ensures(snap(a) === old(snap(b)))]
+#[ensures(snap(b) === old(snap(a)))]
+fn swap<T>(a: &mut T, b: &mut T);
+
+#[extern_spec(core::mem)]
+#[ensures(snap(dest) === src)]
+#[ensures(result === old(snap(dest)))]
+fn replace<T>(dest: &mut T, src: T) -> T;
+
+#[extern_spec(core::mem)]
+#[ensures(result === old(snap(dest)))]
+fn take<T: Default>(dest: &mut T) -> T;
+
+#[extern_spec(core::mem)]
+fn drop<T>(x: T);
+
+#[extern_spec(core::mem)]
+#[pure]
+fn size_of<T>() -> usize;
@@ -0,0 +1,38 @@
+use prusti_contracts::*;
+
+#[extern_spec]
+impl<T> ::core::option::Option<T> {
+    #[pure]
+    #[ensures(result == matches!(self, Some(_)))]
+    pub fn is_some(&self) -> bool;
+
+    #[pure]
+    #[ensures(result == matches!(self, None))]
+    pub fn is_none(&self) -> bool;
+
+    #[requires(self.is_some())]
+    #[ensures(old(self) === Some(result))]
+    pub fn unwrap(self) -> T;
+
+    #[requires(self.is_some())]
+    #[ensures(old(self) === Some(result))]
+    pub fn expect(self, msg: &str) -> T;
+
+    #[ensures(old(self.is_some()) ==> old(self) === Some(result))]
+    #[ensures(old(self.is_none()) ==> result === default)]
+    pub fn unwrap_or(self, default: T) -> T;
+
+    #[ensures(result.is_some() == self.is_some())]
+    pub fn as_ref(&self) -> Option<&T>;
+
+    #[ensures(result === old(snap(self)))]
+    #[ensures(self.is_none())]
+    pub fn take(&mut self) -> Option<T>;
+
+    #[ensures(result === old(snap(self)))]
+    #[ensures(snap(self) === Some(value))]
+    pub fn replace(&mut self, value: T) -> Option<T>;
+
+    #[ensures(result.is_ok() == old(self.is_some()))]
+    pub fn ok_or<E>(self, err: E) -> Result<T, E>;
+}
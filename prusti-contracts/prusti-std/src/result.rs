@@ -0,0 +1,37 @@
+use prusti_contracts::*;
+
+// `is_ok`, `is_err` and `unwrap` are specified in `prusti_contracts::core_spec`.
+
+#[extern_spec]
+impl<T, E> ::core::result::Result<T, E> {
+    #[ensures(old(self.is_ok()) ==> old(self) === Ok(result))]
+    #[ensures(old(self.is_err()) ==> result === default)]
+    pub fn unwrap_or(self, default: T) -> T;
+
+    #[ensures(result.is_some() == old(self.is_ok()))]
+    pub fn ok(self) -> Option<T>;
+
+    #[ensures(result.is_some() == old(self.is_err()))]
+    pub fn err(self) -> Option<E>;
+
+    #[ensures(result.is_ok() == self.is_ok())]
+    pub fn as_ref(&self) -> Result<&T, &E>;
+}
+
+#[extern_spec]
+impl<T, E: ::core::fmt::Debug> ::core::result::Result<T, E> {
+    #[requires(self.is_ok())]
+    #[ensures(old(self) === Ok(result))]
+    pub fn expect(self, msg: &str) -> T;
+}
+
+#[extern_spec]
+impl<T: ::core::fmt::Debug, E> ::core::result::Result<T, E> {
+    #[requires(self.is_err())]
+    #[ensures(old(self) === Err(result))]
+    pub fn unwrap_err(self) -> E;
+
+    #[requires(self.is_err())]
+    #[ensures(old(self) === Err(result))]
+    pub fn expect_err(self, msg: &str) -> E;
+}
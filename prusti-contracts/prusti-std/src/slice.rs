@@ -0,0 +1,24 @@
+use prusti_contracts::*;
+
+// `len` is built into Prusti.
+
+#[extern_spec]
+impl<T> [T] {
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(result.is_some() == (self.len() > 0))]
+    pub fn first(&self) -> Option<&T>;
+
+    #[ensures(result.is_some() == (self.len() > 0))]
+    pub fn last(&self) -> Option<&T>;
+
+    #[requires(a < self.len() && b < self.len())]
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(snap(&self[a]) === old(snap(&self[b])))]
+    #[ensures(snap(&self[b]) === old(snap(&self[a])))]
+    #[ensures(forall(|i: usize| i < self.len() && i != a && i != b ==>
+        snap(&self[i]) === old(snap(&self[i]))))]
+    pub fn swap(&mut self, a: usize, b: usize);
+}
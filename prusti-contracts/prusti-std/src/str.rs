@@ -0,0 +1,52 @@
+use core::str::Chars;
+use prusti_contracts::*;
+
+// `len`, `as_bytes` and `starts_with` with a `&str` pattern are built into
+// Prusti, which models a string as the sequence of its UTF-8 bytes.
+
+/// The chars of a string, a view of it next to its UTF-8 bytes.
+#[model]
+pub struct str {
+    pub chars: Seq<char>,
+}
+
+#[extern_spec]
+impl str {
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    #[ensures(result == (self.model().chars.len() == Int::new(0)))]
+    pub fn is_empty(&self) -> bool;
+
+    /// An index is a char boundary unless it points to a UTF-8 continuation
+    /// byte, that is, a byte of the form `0b10xx_xxxx`.
+    #[pure]
+    #[ensures(result == (index == 0 || index == self.len() || (index < self.len()
+        && (self.as_bytes()[index] < 128 || self.as_bytes()[index] >= 192))))]
+    pub fn is_char_boundary(&self, index: usize) -> bool;
+
+    #[ensures(result.as_str() === self)]
+    pub fn chars(&self) -> Chars<'_>;
+}
+
+#[extern_spec]
+impl<'a> Chars<'a> {
+    #[pure]
+    pub fn as_str(&self) -> &'a str;
+}
+
+// A char takes one to four bytes in UTF-8, and an ASCII char exactly one.
+#[extern_spec]
+impl<'a> Iterator for Chars<'a> {
+    #[ensures(Int::new_usize(result) == old(self.as_str().model().chars.len()))]
+    #[ensures(result <= old(self.as_str().len()))]
+    #[ensures(result >= old(self.as_str().len()) / 4)]
+    #[ensures(old(forall(|i: usize| i < self.as_str().len() ==> self.as_str().as_bytes()[i] < 128))
+        ==> result == old(self.as_str().len()))]
+    fn count(self) -> usize;
+}
+
+#[extern_spec]
+impl ToString for str {
+    #[ensures(result.as_str() === self)]
+    fn to_string(&self) -> String;
+}
@@ -0,0 +1,57 @@
+use crate::models::*;
+use core::ops::Add;
+use prusti_contracts::*;
+
+// `len`, `as_str`, `as_bytes` and the dereference to `str` are built into
+// Prusti, which models a string as the sequence of its UTF-8 bytes.
+
+#[extern_spec]
+impl String {
+    #[ensures(result.len() == 0)]
+    #[ensures(result.as_str().model().chars === Seq::empty())]
+    pub fn new() -> Self;
+
+    #[ensures(result.len() == 0)]
+    pub fn with_capacity(capacity: usize) -> Self;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    #[ensures(self.len() == 0)]
+    #[ensures(self.as_str().model().chars === Seq::empty())]
+    pub fn clear(&mut self);
+
+    // A char takes one to four bytes in UTF-8.
+    #[ensures(self.len() >= old(self.len()) + 1 && self.len() <= old(self.len()) + 4)]
+    #[ensures(self.as_str().model().chars
+        === old(self.as_str().model().chars).concat(Seq::single(ch)))]
+    pub fn push(&mut self, ch: char);
+
+    #[ensures(self.len() == old(self.len()) + string.len())]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==>
+        self.as_bytes()[i] == old(self.as_bytes()[i])))]
+    #[ensures(forall(|i: usize| i < string.len() ==>
+        self.as_bytes()[old(self.len()) + i] == string.as_bytes()[i]))]
+    #[ensures(self.as_str().model().chars
+        === old(self.as_str().model().chars).concat(string.model().chars))]
+    pub fn push_str(&mut self, string: &str);
+}
+
+#[extern_spec]
+impl<'a> From<&'a str> for String {
+    #[ensures(result.as_str() === s)]
+    fn from(s: &'a str) -> String;
+}
+
+#[extern_spec]
+impl<'a> Add<&'a str> for String {
+    #[ensures(result.len() == old(self.len()) + other.len())]
+    #[ensures(forall(|i: usize| i < old(self.len()) ==>
+        result.as_bytes()[i] == old(self.as_bytes()[i])))]
+    #[ensures(forall(|i: usize| i < other.len() ==>
+        result.as_bytes()[old(self.len()) + i] == other.as_bytes()[i]))]
+    #[ensures(result.as_str().model().chars
+        === old(self.as_str().model().chars).concat(other.model().chars))]
+    fn add(self, other: &'a str) -> String;
+}
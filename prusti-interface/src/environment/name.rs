@@ -41,6 +41,24 @@ impl<'tcx> EnvName<'tcx> {
         self.crate_name(prusti_rustc_interface::span::def_id::LOCAL_CRATE)
     }
 
+    /// Returns the kind of the crate that is being compiled, e.g. `lib`, `bin`
+    /// or `test`. Together with the crate name, this distinguishes the targets
+    /// of a package.
+    pub fn local_crate_target(self) -> String {
+        let opts = &self.tcx.sess.opts;
+        if opts.test {
+            "test".to_string()
+        } else if opts.crate_types.is_empty() {
+            "bin".to_string()
+        } else {
+            opts.crate_types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("-")
+        }
+    }
+
     /// Get an absolute `def_path`. Note: not preserved across compilations!
     pub fn get_item_def_path(self, def_id: DefId) -> String {
         let def_path = self.tcx.def_path(def_id);
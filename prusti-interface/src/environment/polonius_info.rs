@@ -249,7 +249,7 @@ impl ReborrowingDAG {
 
 #[derive(Clone, Debug)]
 pub enum PoloniusInfoError {
-    /// Loans depending on branches inside loops are not implemented yet
+    /// Loans kept alive by a variable across loop iterations are not supported yet
     UnsupportedLoanInLoop {
         loop_head: mir::BasicBlock,
         variable: mir::Local,
@@ -396,6 +396,20 @@ pub struct PoloniusInfo<'a, 'tcx: 'a> {
     /// Two loans are conflicting if they borrow overlapping places and
     /// are alive at overlapping regions.
     pub(crate) loan_conflict_sets: FxHashMap<facts::Loan, FxHashSet<facts::Loan>>,
+    /// Two-phase borrows → the reservation.
+    pub(crate) two_phase_borrows: FxHashMap<facts::Loan, TwoPhaseBorrow>,
+}
+
+/// A two-phase borrow, such as the mutable borrow of `v` in `v.push(v.len())`,
+/// is reserved where it is created and activated where the reference is first
+/// used. Until its activation the borrowed place can still be read.
+#[derive(Clone, Debug)]
+pub struct TwoPhaseBorrow {
+    /// The locations after the creation of the borrow at which it is
+    /// reserved but not yet active.
+    pub reserved_at: FxHashSet<mir::Location>,
+    /// The location at which the borrow is activated.
+    pub activation: mir::Location,
 }
 
 /// This creates a new loan for each move of a borrow. Moves occur either due to assignments or
@@ -614,10 +628,101 @@ fn get_borrowed_places<'a, 'tcx: 'a>(
     }
 }
 
+/// Find the two-phase borrows and where they are activated. The reference
+/// created by a two-phase borrow is a temporary used exactly once, so the
+/// activation is the first use of the reference after its creation, on every
+/// path that does not unwind.
+fn compute_two_phase_borrows(
+    mir: &mir::Body<'_>,
+    loan_position: &FxHashMap<facts::Loan, mir::Location>,
+) -> FxHashMap<facts::Loan, TwoPhaseBorrow> {
+    let mut two_phase_borrows = FxHashMap::default();
+    for (&loan, &location) in loan_position {
+        let Some(mir::Statement {
+            kind:
+                mir::StatementKind::Assign(box (
+                    reference,
+                    mir::Rvalue::Ref(
+                        _,
+                        mir::BorrowKind::Mut {
+                            kind: mir::MutBorrowKind::TwoPhaseBorrow,
+                        },
+                        _,
+                    ),
+                )),
+            ..
+        }) = mir[location.block].statements.get(location.statement_index)
+        else {
+            continue;
+        };
+        let mut reserved_at = FxHashSet::default();
+        let mut activations = FxHashSet::default();
+        let mut to_visit = vec![location.successor_within_block()];
+        while let Some(current) = to_visit.pop() {
+            if current == location || !reserved_at.insert(current) {
+                continue;
+            }
+            let mut finder = LocalUseFinder {
+                local: reference.local,
+                found: false,
+            };
+            mir::visit::Visitor::visit_location(&mut finder, mir, current);
+            if finder.found {
+                reserved_at.remove(&current);
+                activations.insert(current);
+                continue;
+            }
+            let block_data = &mir[current.block];
+            if current.statement_index < block_data.statements.len() {
+                to_visit.push(current.successor_within_block());
+            } else {
+                to_visit.extend(
+                    block_data
+                        .terminator()
+                        .successors()
+                        .filter(|target| !mir[*target].is_cleanup)
+                        .map(|target| target.start_location()),
+                );
+            }
+        }
+        // The borrow is encoded as an ordinary one if it is not activated at
+        // a single location.
+        if activations.len() == 1 {
+            two_phase_borrows.insert(
+                loan,
+                TwoPhaseBorrow {
+                    reserved_at,
+                    activation: activations.into_iter().next().unwrap(),
+                },
+            );
+        }
+    }
+    two_phase_borrows
+}
+
+struct LocalUseFinder {
+    local: mir::Local,
+    found: bool,
+}
+
+impl<'tcx> mir::visit::Visitor<'tcx> for LocalUseFinder {
+    fn visit_local(
+        &mut self,
+        local: mir::Local,
+        context: mir::visit::PlaceContext,
+        _location: mir::Location,
+    ) {
+        if local == self.local && context.is_use() {
+            self.found = true;
+        }
+    }
+}
+
 #[tracing::instrument(level = "trace", skip_all, ret)]
 fn compute_loan_conflict_sets(
     procedure: &Procedure,
     loan_position: &FxHashMap<facts::Loan, mir::Location>,
+    two_phase_borrows: &FxHashMap<facts::Loan, TwoPhaseBorrow>,
     borrowck_in_facts: &facts::AllInputFacts,
     borrowck_out_facts: &facts::AllOutputFacts,
 ) -> Result<FxHashMap<facts::Loan, FxHashSet<facts::Loan>>, PoloniusInfoError> {
@@ -641,6 +746,12 @@ fn compute_loan_conflict_sets(
                     if loan_created == *loan_alive {
                         continue;
                     }
+                    if let Some(two_phase_borrow) = two_phase_borrows.get(loan_alive) {
+                        // A reserved borrow does not conflict with reads.
+                        if two_phase_borrow.reserved_at.contains(&location) {
+                            continue;
+                        }
+                    }
                     for place in get_borrowed_places(mir, loan_position, *loan_alive)? {
                         if utils::is_prefix(*borrowed_place, *place)
                             || utils::is_prefix(*place, *borrowed_place)
@@ -748,8 +859,14 @@ impl<'a, 'tcx: 'a> PoloniusInfo<'a, 'tcx> {
             &incompatible_loans,
         );
         // FIXME: Check whether the new info in Polonius could be used for computing initialization.
-        let loan_conflict_sets =
-            compute_loan_conflict_sets(procedure, &loan_position, &all_facts, &output)?;
+        let two_phase_borrows = compute_two_phase_borrows(mir, &loan_position);
+        let loan_conflict_sets = compute_loan_conflict_sets(
+            procedure,
+            &loan_position,
+            &two_phase_borrows,
+            &all_facts,
+            &output,
+        )?;
 
         let info = Self {
             tcx,
@@ -769,6 +886,7 @@ impl<'a, 'tcx: 'a> PoloniusInfo<'a, 'tcx> {
             reference_moves,
             argument_moves,
             loan_conflict_sets,
+            two_phase_borrows,
         };
         // info.compute_loop_magic_wands(loop_invariant_block)?; FIXME
         Ok(info)
@@ -1141,6 +1259,26 @@ impl<'a, 'tcx: 'a> PoloniusInfo<'a, 'tcx> {
         self.loan_at_position[&location]
     }
 
+    /// Whether the loan created at `location` is a two-phase borrow that is
+    /// activated later.
+    pub fn is_two_phase_reservation(&self, location: mir::Location) -> bool {
+        self.loan_at_position
+            .get(&location)
+            .map_or(false, |loan| self.two_phase_borrows.contains_key(loan))
+    }
+
+    /// The two-phase borrows that are activated at `location`.
+    pub fn get_two_phase_borrows_activated_at(&self, location: mir::Location) -> Vec<facts::Loan> {
+        let mut loans: Vec<_> = self
+            .two_phase_borrows
+            .iter()
+            .filter(|(_, two_phase_borrow)| two_phase_borrow.activation == location)
+            .map(|(loan, _)| *loan)
+            .collect();
+        loans.sort();
+        loans
+    }
+
     pub fn get_call_loan_at_location(&self, location: mir::Location) -> Option<facts::Loan> {
         self.call_loan_at_position.get(&location).cloned()
     }
@@ -1345,7 +1483,59 @@ impl<'a, 'tcx: 'a> PoloniusInfo<'a, 'tcx> {
         )
     }
 
-    /// Get loops in which loans are defined (if any).
+    /// Check whether the loan (or its zombie) is alive at the start of the
+    /// given loop head, that is, whether it is kept alive across iterations.
+    fn is_loan_alive_at_loop_head(&self, loan: facts::Loan, loop_head: mir::BasicBlock) -> bool {
+        let location = mir::Location {
+            block: loop_head,
+            statement_index: 0,
+        };
+        let point = self.get_point(location, facts::PointType::Start);
+        [false, true].into_iter().any(|zombie| {
+            self.get_borrow_live_at(zombie)
+                .get(&point)
+                .map_or(false, |loans| loans.contains(&loan))
+        })
+    }
+
+    /// Find a local whose region keeps the loan alive at the start of the given
+    /// loop head. Variables declared by the user are preferred over temporaries
+    /// so that error messages can name them.
+    fn find_variable_keeping_loan_alive(
+        &self,
+        loan: facts::Loan,
+        loop_head: mir::BasicBlock,
+    ) -> Option<mir::Local> {
+        let location = mir::Location {
+            block: loop_head,
+            statement_index: 0,
+        };
+        let point = self.get_point(location, facts::PointType::Start);
+        let variables: Vec<_> = self
+            .mir
+            .local_decls
+            .indices()
+            .filter(|&local| {
+                self.place_regions.for_local(local).map_or(false, |region| {
+                    self.get_all_loans_kept_alive_by(point, region)
+                        .0
+                        .contains(&loan)
+                })
+            })
+            .collect();
+        variables
+            .iter()
+            .find(|&&local| self.mir.local_decls[local].is_user_variable())
+            .or_else(|| variables.first())
+            .copied()
+    }
+
+    /// Get loops in which loans are defined (if any). A loan is attributed to
+    /// the innermost loop that encloses its creation and across whose
+    /// iterations it is kept alive. Loans that are confined to a single
+    /// iteration of every enclosing loop, for example, a loan created in an
+    /// inner loop and expired before the next iteration, do not need a loop
+    /// magic wand and are handled like loans created outside of loops.
     #[tracing::instrument(level = "debug", skip_all)]
     pub fn get_loan_loops(
         &self,
@@ -1362,8 +1552,11 @@ impl<'a, 'tcx: 'a> PoloniusInfo<'a, 'tcx> {
                     return None;
                 };
                 self.loops
-                    .get_loop_head(loan_location.block)
-                    .map(|loop_head| (*loan, loop_head))
+                    .get_enclosing_loop_heads(loan_location.block)
+                    .iter()
+                    .rev()
+                    .find(|&&loop_head| self.is_loan_alive_at_loop_head(*loan, loop_head))
+                    .map(|&loop_head| (*loan, loop_head))
             })
             .collect();
         for (loan1, loop1) in pairs.iter() {
@@ -1420,6 +1613,13 @@ impl<'a, 'tcx: 'a> PoloniusInfo<'a, 'tcx> {
                 let (_, loop_head) = loan_loops[0];
                 debug!("loop_head = {:?}", loop_head);
                 if self.loop_magic_wands.is_empty() {
+                    let (loan, loop_head) = loan_loops[0];
+                    if let Some(variable) = self.find_variable_keeping_loan_alive(loan, loop_head) {
+                        return Err(PoloniusInfoError::UnsupportedLoanInLoop {
+                            loop_head,
+                            variable,
+                        });
+                    }
                     return Err(PoloniusInfoError::ReborrowingDagHasNoMagicWands(location));
                 }
                 let loop_magic_wands = &self.loop_magic_wands[&loop_head];
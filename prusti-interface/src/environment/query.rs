@@ -4,7 +4,7 @@ use crate::data::ProcedureDefId;
 use log::debug;
 use prusti_rustc_interface::{
     ast::ast::Attribute,
-    hir::hir_id::HirId,
+    hir::{def::DefKind, hir_id::HirId, AsyncGeneratorKind, GeneratorKind, IsAsync},
     middle::{
         hir::map::Map,
         ty::{self, GenericArgsRef, ImplPolarity, ParamEnv, TraitPredicate, TyCtxt},
@@ -182,6 +182,39 @@ impl<'tcx> EnvQuery<'tcx> {
         self.tcx.is_closure(def_id.into_param())
     }
 
+    /// Returns the names of the captured variables that the specification of
+    /// the closure `def_id` refers to as views, in the order in which they are
+    /// passed to its specification items.
+    pub fn get_closure_views(self, def_id: impl IntoParam<DefId>) -> Vec<String> {
+        let Some(local_def_id) = def_id.into_param().as_local() else {
+            return vec![];
+        };
+        // The attributes of a closure with a specification are attached to
+        // the `let` statement that binds it.
+        let parent_id = self.hir().parent_id(self.as_hir_id(local_def_id));
+        crate::utils::read_prusti_attrs("closure_view", self.get_local_attributes(parent_id))
+    }
+
+    /// Returns true iff `def_id` is an `async fn`.
+    pub fn is_async_fn(self, def_id: impl IntoParam<DefId>) -> bool {
+        let def_id = def_id.into_param();
+        matches!(self.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            && self.tcx.asyncness(def_id) == IsAsync::Async
+    }
+
+    /// If `def_id` is the generator that implements the body of an `async fn`,
+    /// returns the `async fn`.
+    pub fn get_async_fn_of_generator(self, def_id: impl IntoParam<DefId>) -> Option<DefId> {
+        let def_id = def_id.into_param();
+        if matches!(self.tcx.def_kind(def_id), DefKind::Generator)
+            && self.tcx.generator_kind(def_id) == Some(GeneratorKind::Async(AsyncGeneratorKind::Fn))
+        {
+            Some(self.tcx.parent(def_id))
+        } else {
+            None
+        }
+    }
+
     // /// Returns the `DefId` of the corresponding trait method, if any.
     // /// This should not be used to resolve calls (where substs are known): use
     // /// `find_trait_method_substs` instead!
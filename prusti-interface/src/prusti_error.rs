@@ -160,6 +160,18 @@ impl PrustiError {
         self.is_disabled
     }
 
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn span(&self) -> &MultiSpan {
+        &self.span
+    }
+
+    pub fn notes(&self) -> &[(String, Option<MultiSpan>)] {
+        &self.notes
+    }
+
     #[must_use]
     pub fn set_help<S: ToString>(mut self, message: S) -> Self {
         self.help = Some(message.to_string());
@@ -0,0 +1,159 @@
+use super::common::*;
+use crate::{
+    environment::{EnvQuery, Environment},
+    utils::{has_prusti_attr, has_spec_only_attr},
+    PrustiError,
+};
+use log::debug;
+use prusti_rustc_interface::{
+    errors::MultiSpan,
+    hir::{
+        self as hir,
+        def::{DefKind, Res},
+        def_id::{DefId, LocalDefId},
+        intravisit,
+    },
+    middle::{hir::map::Map, mir, ty},
+    span::Span,
+};
+
+/// Checks that lemmas are only called from ghost code, i.e. from `ghost!`
+/// blocks, specifications and other lemmas, and that lemmas do not take
+/// mutable references
+pub struct IllegalLemmaUsagesChecker;
+
+impl<'tcx> SpecCheckerStrategy<'tcx> for IllegalLemmaUsagesChecker {
+    #[tracing::instrument(
+        name = "IllegalLemmaUsagesChecker::check",
+        level = "debug",
+        skip(self, env)
+    )]
+    fn check(&self, env: &Environment<'tcx>) -> Vec<PrustiError> {
+        let mut visit = CheckLemmasVisitor {
+            env_query: env.query,
+            lemma_usages: Vec::new(),
+            mutable_reference_params: Vec::new(),
+        };
+        env.query.hir().walk_toplevel_module(&mut visit);
+        env.query.hir().walk_attributes(&mut visit);
+        debug!("Lemma usages: {:?}", visit.lemma_usages);
+        debug!(
+            "Lemma mutable reference parameters: {:?}",
+            visit.mutable_reference_params
+        );
+
+        let usage_errors = visit
+            .lemma_usages
+            .into_iter()
+            .map(|(usage_span, def_span)| {
+                PrustiError::incorrect(
+                    "calling a lemma from executable code is not allowed".to_string(),
+                    MultiSpan::from_span(usage_span),
+                )
+                .add_note("this is a ghost-only lemma function", Some(def_span))
+            });
+        let param_errors = visit
+            .mutable_reference_params
+            .into_iter()
+            .map(|param_span| {
+                PrustiError::unsupported(
+                    "lemmas cannot take mutable references as parameters".to_string(),
+                    MultiSpan::from_span(param_span),
+                )
+            });
+        usage_errors.chain(param_errors).collect()
+    }
+}
+
+/// Collects references to lemmas from executable code. Unlike the
+/// [NonSpecExprVisitor], this visitor also skips the bodies of `ghost!` blocks
+/// and of lemmas themselves. While doing so, it also collects the parameters
+/// of lemmas that are mutable references.
+struct CheckLemmasVisitor<'tcx> {
+    env_query: EnvQuery<'tcx>,
+    lemma_usages: Vec<(Span, Span)>,
+    mutable_reference_params: Vec<Span>,
+}
+
+impl<'tcx> CheckLemmasVisitor<'tcx> {
+    fn check_called_def_id(&mut self, usage_span: Span, def_id: DefId) {
+        let is_fn = matches!(
+            self.env_query.tcx().def_kind(def_id),
+            DefKind::Fn | DefKind::AssocFn
+        );
+        if is_fn && self.env_query.has_prusti_attribute(def_id, "lemma") {
+            let def_span = self.env_query.get_def_span(def_id);
+            self.lemma_usages.push((usage_span, def_span));
+        }
+    }
+
+    fn check_lemma_params(&mut self, fd: &'tcx hir::FnDecl<'tcx>, local_id: LocalDefId) {
+        // Lemmas are ghost code, so they must not modify their arguments
+        let sig = self.env_query.tcx().fn_sig(local_id).instantiate_identity();
+        for (param_ty, hir_ty) in sig.skip_binder().inputs().iter().zip(fd.inputs) {
+            if matches!(param_ty.kind(), ty::TyKind::Ref(_, _, mir::Mutability::Mut)) {
+                self.mutable_reference_params.push(hir_ty.span);
+            }
+        }
+    }
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for CheckLemmasVisitor<'tcx> {
+    type Map = Map<'tcx>;
+    type NestedFilter = prusti_rustc_interface::middle::hir::nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.env_query.hir()
+    }
+
+    fn visit_fn(
+        &mut self,
+        fk: intravisit::FnKind<'tcx>,
+        fd: &'tcx hir::FnDecl<'tcx>,
+        b: hir::BodyId,
+        _s: Span,
+        local_id: LocalDefId,
+    ) {
+        // Specifications and lemmas are ghost code, so they may call lemmas
+        let attrs = self.env_query.get_local_attributes(local_id);
+        if has_prusti_attr(attrs, "lemma") {
+            self.check_lemma_params(fd, local_id);
+            return;
+        }
+        if has_spec_only_attr(attrs) {
+            return;
+        }
+
+        intravisit::walk_fn(self, fk, fd, b, local_id);
+    }
+
+    fn visit_local(&mut self, local: &'tcx hir::Local<'tcx>) {
+        // The result of a `ghost!` block is bound by a marked `let` statement
+        let attrs = self.env_query.get_local_attributes(local.hir_id);
+        if has_prusti_attr(attrs, "ghost_block") {
+            return;
+        }
+
+        intravisit::walk_local(self, local);
+    }
+
+    fn visit_expr(&mut self, ex: &'tcx hir::Expr<'tcx>) {
+        let owner_def_id = ex.hir_id.owner.def_id;
+        if self.env_query.has_body(owner_def_id) {
+            let typeck_results = self.env_query.tcx().typeck(owner_def_id);
+            let called_def_id = match ex.kind {
+                hir::ExprKind::Path(ref path) => match typeck_results.qpath_res(path, ex.hir_id) {
+                    Res::Def(_, def_id) => Some(def_id),
+                    _ => None,
+                },
+                hir::ExprKind::MethodCall(..) => typeck_results.type_dependent_def_id(ex.hir_id),
+                _ => None,
+            };
+            if let Some(called_def_id) = called_def_id {
+                self.check_called_def_id(ex.span, called_def_id);
+            }
+        }
+
+        intravisit::walk_expr(self, ex);
+    }
+}
@@ -1,12 +1,14 @@
 //! Module for verifying user-provided specifications after macro expansion
 
 mod common;
+mod lemma_checks;
 mod predicate_checks;
 mod type_model_checks;
 mod version_checks;
 
 use crate::environment::Environment;
 use common::*;
+use lemma_checks::IllegalLemmaUsagesChecker;
 use predicate_checks::IllegalPredicateUsagesChecker;
 use type_model_checks::{IllegalModelUsagesChecker, ModelDefinedOnTypeWithoutFields};
 use version_checks::MismatchedVersionsChecker;
@@ -29,6 +31,7 @@ impl<'tcx> SpecChecker<'tcx> {
             checks: vec![
                 Box::new(MismatchedVersionsChecker {}),
                 Box::new(IllegalPredicateUsagesChecker {}),
+                Box::new(IllegalLemmaUsagesChecker {}),
                 Box::new(IllegalModelUsagesChecker {}),
                 Box::new(ModelDefinedOnTypeWithoutFields {}),
             ],
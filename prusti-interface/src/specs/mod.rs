@@ -426,7 +426,10 @@ fn get_procedure_spec_ids(def_id: DefId, attrs: &[ast::Attribute]) -> Option<Pro
 
     let pure = has_prusti_attr(attrs, "pure");
     let trusted = has_prusti_attr(attrs, "trusted")
-        || (!is_predicate && config::opt_in_verification() && !has_prusti_attr(attrs, "verified"));
+        || (!is_predicate
+            && config::opt_in_verification()
+            && !has_prusti_attr(attrs, "verified")
+            && !has_prusti_attr(attrs, "lemma"));
     let abstract_predicate = has_abstract_predicate_attr(attrs);
 
     if abstract_predicate || pure || trusted || !spec_id_refs.is_empty() {
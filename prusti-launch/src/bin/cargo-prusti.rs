@@ -5,8 +5,12 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 #![feature(let_chains)]
 
-use prusti_utils::{config, launch};
-use std::{env, fs, io, path::PathBuf, process::Command};
+use prusti_utils::{config, launch, report::verification_report};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 fn main() {
     if let Err(code) = process(env::args().skip(1)) {
@@ -30,7 +34,16 @@ where
     let args = args.skip_while(|arg| arg == "prusti");
     // Remove the "-- -Pflag" arguments since these won't apply to `cargo check`.
     // They have already been loaded (and the Category B flags are used below).
-    let args = args.take_while(|arg| arg != "--");
+    let mut args = args.take_while(|arg| arg != "--").peekable();
+
+    let cargo_target = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".to_string());
+    let cargo_target: PathBuf = [cargo_target, "verify".to_string()].into_iter().collect();
+
+    // `cargo prusti cache ...` maintains the verification cache of the package.
+    if args.peek().map(String::as_str) == Some("cache") {
+        args.next();
+        return maintain_cache(&cargo_target, args);
+    }
 
     // Category B flags (see dev-guide flags table):
     let cargo_path = config::cargo_path();
@@ -41,8 +54,20 @@ where
     } else {
         [].iter()
     };
-    let cargo_target = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".to_string());
-    let cargo_target: PathBuf = [cargo_target, "verify".to_string()].into_iter().collect();
+    let report_dir = config::report_dir().unwrap_or_else(|| cargo_target.join("report"));
+    let report_format = verification_report::report_format().map_err(|error| {
+        eprintln!("{error}");
+        1
+    })?;
+    if report_format.is_some() {
+        // Only the crates verified by this run should appear in the report.
+        if let Err(error) = verification_report::clear_crate_reports(&report_dir) {
+            eprintln!(
+                "Failed to clear the verification reports in {}: {error}",
+                report_dir.display()
+            );
+        }
+    }
     let exit_status = Command::new(cargo_path)
         .arg(&command)
         .args(features)
@@ -63,9 +88,20 @@ where
         .env("DEFAULT_PRUSTI_FULL_COMPILATION", "true")
         .env("DEFAULT_PRUSTI_LOG_DIR", cargo_target.join("log"))
         .env("DEFAULT_PRUSTI_CACHE_PATH", cargo_target.join("cache.bin"))
+        .env("DEFAULT_PRUSTI_REPORT_DIR", &report_dir)
         .status()
         .expect("could not run cargo");
 
+    // The report is also useful when the verification fails.
+    if let Some(format) = report_format {
+        if let Err(error) = write_package_report(&report_dir, format) {
+            eprintln!(
+                "Failed to write the verification report to {}: {error}",
+                report_dir.display()
+            );
+        }
+    }
+
     if exit_status.success() {
         if command == "build" {
             copy_exported_specs(cargo_target).ok();
@@ -76,6 +112,46 @@ where
     }
 }
 
+/// Run the `cache` command of `prusti-server` on the cache used by
+/// `cargo prusti`, unless another cache has been configured.
+fn maintain_cache<I>(cargo_target: &Path, args: I) -> Result<(), i32>
+where
+    I: Iterator<Item = String>,
+{
+    let mut prusti_server_path = env::current_exe()
+        .expect("current executable path invalid")
+        .with_file_name("prusti-server");
+    if cfg!(windows) {
+        prusti_server_path.set_extension("exe");
+    }
+    let mut cache_path = config::cache_path();
+    if cache_path.as_os_str().is_empty() {
+        cache_path = cargo_target.join("cache.bin");
+    }
+    let exit_status = Command::new(prusti_server_path)
+        .arg("cache")
+        .arg("--path")
+        .arg(cache_path)
+        .args(args)
+        .status()
+        .expect("could not run prusti-server");
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+/// Aggregate the verification reports of all the crates of the package.
+fn write_package_report(
+    report_dir: &Path,
+    format: verification_report::ReportFormat,
+) -> io::Result<()> {
+    let reports = verification_report::read_crate_reports(report_dir)?;
+    verification_report::write_final_report(report_dir, format, &reports)?;
+    Ok(())
+}
+
 /// Copy specs from '{cargo_target}/*/deps/*.specs' to '{cargo_target}/*/*.specs'
 fn copy_exported_specs(cargo_target: PathBuf) -> io::Result<()> {
     for de in fs::read_dir(cargo_target)? {
@@ -5,6 +5,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use crate::VerificationRequest;
+use futures::{stream, Stream, StreamExt};
 use prusti_common::config;
 use reqwest::Client;
 use url::{ParseError, Url};
@@ -27,7 +28,21 @@ impl PrustiClient {
         })
     }
 
-    pub async fn verify(
+    /// Send the verification requests to the server one after the other.
+    /// The returned stream yields the result of each request, in the order of
+    /// the requests, as soon as the server replies to it.
+    pub fn verify<'a, I>(
+        &'a self,
+        requests: I,
+    ) -> impl Stream<Item = reqwest::Result<VerificationResult>> + 'a
+    where
+        I: IntoIterator<Item = VerificationRequest>,
+        I::IntoIter: 'a,
+    {
+        stream::iter(requests).then(move |request| self.verify_request(request))
+    }
+
+    pub async fn verify_request(
         &self,
         request: VerificationRequest,
     ) -> reqwest::Result<VerificationResult> {
@@ -4,8 +4,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use prusti_utils::config;
+use std::path::PathBuf;
+use viper::PersistentCache;
 
 /// A verification server to handle Prusti verification requests.
 #[derive(Parser, Debug)]
@@ -15,6 +17,41 @@ struct Args {
     /// Pass 0 to get a free one assigned by the OS.
     #[clap(short, long, value_name = "PORT", default_value_t = 0)]
     port: u16,
+
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Inspect or prune a verification cache instead of starting the server.
+    Cache {
+        /// The cache file. Defaults to the `CACHE_PATH` configuration flag.
+        #[clap(long, value_name = "FILE")]
+        path: Option<PathBuf>,
+
+        #[clap(subcommand)]
+        action: CacheAction,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheAction {
+    /// Print statistics about the entries of the cache.
+    Stats,
+    /// Evict entries that are stale or exceed the size limit. Incompatible
+    /// cache files are dropped.
+    Prune {
+        /// Evict the entries that have not been used in the last DAYS days.
+        #[clap(long, value_name = "DAYS")]
+        older_than_days: Option<u64>,
+        /// Evict the least recently used entries until the remaining ones
+        /// take at most BYTES bytes.
+        #[clap(long, value_name = "BYTES")]
+        max_size: Option<u64>,
+    },
+    /// Remove all the entries of the cache.
+    Clear,
 }
 
 fn main() {
@@ -26,5 +63,47 @@ fn main() {
 
     let args = Args::parse();
 
-    prusti_server::start_server_on_port(args.port);
+    match args.command {
+        None => prusti_server::start_server_on_port(args.port),
+        Some(Command::Cache { path, action }) => {
+            let path = path.unwrap_or_else(config::cache_path);
+            if path.as_os_str().is_empty() {
+                eprintln!("No cache file given. Pass --path or set PRUSTI_CACHE_PATH.");
+                std::process::exit(2);
+            }
+            maintain_cache(path, action);
+        }
+    }
+}
+
+fn maintain_cache(path: PathBuf, action: CacheAction) {
+    // Loading drops caches with an incompatible version; the result is
+    // written back when the cache is dropped, if it changed.
+    match action {
+        CacheAction::Stats => {
+            // Inspecting the cache must not overwrite it, even if it is unusable.
+            let cache = PersistentCache::load_cache_read_only(path.clone());
+            println!("cache:         {}", path.display());
+            println!("{}", cache.statistics());
+        }
+        CacheAction::Prune {
+            older_than_days,
+            max_size,
+        } => {
+            let mut cache = PersistentCache::load_cache(path.clone());
+            let mut evicted = 0;
+            if let Some(days) = older_than_days {
+                evicted += cache.evict_older_than(days);
+            }
+            if let Some(max_size) = max_size {
+                evicted += cache.evict_to_size(max_size);
+            }
+            println!("Evicted {evicted} entries from {}", path.display());
+        }
+        CacheAction::Clear => {
+            let mut cache = PersistentCache::load_cache(path.clone());
+            let evicted = cache.clear();
+            println!("Removed {evicted} entries from {}", path.display());
+        }
+    }
 }
@@ -22,7 +22,7 @@ use viper::{
 pub fn process_verification_request<'v, 't: 'v>(
     verification_context: &'v Lazy<VerificationContext<'t>, impl Fn() -> VerificationContext<'t>>,
     mut request: VerificationRequest,
-    cache: impl Cache,
+    mut cache: impl Cache,
 ) -> viper::VerificationResult {
     let ast_utils = verification_context.new_ast_utils();
 
@@ -81,21 +81,33 @@ pub fn process_verification_request<'v, 't: 'v>(
         return viper::VerificationResult::Success;
     }
 
+    let cache_key = request.get_cache_key();
+    let fingerprint = request.get_fingerprint();
+
     // Early return in case of cache hit
     if config::enable_cache() {
-        if let Some(mut result) = cache.get(hash) {
-            info!(
-                "Using cached result {:?} for program {}",
-                &result,
-                request.program.get_name()
-            );
-            if config::dump_viper_program() {
-                ast_utils.with_local_frame(16, || {
-                    let _ = build_or_dump_viper_program();
-                });
+        match cache.get(&cache_key, &fingerprint) {
+            Ok(mut result) => {
+                info!(
+                    "Using cached result {:?} for program {}",
+                    &result,
+                    request.program.get_name()
+                );
+                if config::dump_viper_program() {
+                    ast_utils.with_local_frame(16, || {
+                        let _ = build_or_dump_viper_program();
+                    });
+                }
+                normalization_info.denormalize_result(&mut result);
+                return result;
+            }
+            Err(cache_miss) => {
+                info!(
+                    "Verifying program {} because {}",
+                    request.program.get_name(),
+                    cache_miss
+                );
             }
-            normalization_info.denormalize_result(&mut result);
-            return result;
         }
     };
 
@@ -124,7 +136,7 @@ pub fn process_verification_request<'v, 't: 'v>(
             &result,
             request.program.get_name()
         );
-        cache.insert(hash, result.clone());
+        cache.insert(cache_key, fingerprint, result.clone());
     }
 
     normalization_info.denormalize_result(&mut result);
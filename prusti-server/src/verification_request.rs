@@ -7,12 +7,16 @@
 use prusti_common::{config, vir::program::Program};
 use rustc_hash::FxHasher;
 use std::hash::{Hash, Hasher};
-use viper::{self, VerificationBackend};
+use viper::{self, Fingerprint, VerificationBackend};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Hash)]
 pub struct VerificationRequest {
     pub program: Program,
     pub backend_config: ViperBackendConfig,
+    /// Identifies the crate of the program. Program names are unique only
+    /// within a crate, so this distinguishes the cached results of programs
+    /// of different crates that share the same cache.
+    pub crate_id: String,
 }
 
 impl VerificationRequest {
@@ -21,6 +25,106 @@ impl VerificationRequest {
         self.hash(&mut hasher);
         hasher.finish()
     }
+
+    /// The name under which the result of the request is cached.
+    pub(crate) fn get_cache_key(&self) -> String {
+        format!(
+            "{}::{}",
+            self.crate_id,
+            self.program.get_name_with_check_mode()
+        )
+    }
+
+    /// Hash separately each component of the program that can influence the
+    /// verification result. In contrast to `get_hash`, the fingerprint does
+    /// not depend on the order of the components and it allows to tell which
+    /// components changed between two versions of the program.
+    pub(crate) fn get_fingerprint(&self) -> Fingerprint {
+        let mut fingerprint = Fingerprint::default();
+        fingerprint.add("the backend configuration", &self.backend_config);
+        match &self.program {
+            Program::Legacy(program) => {
+                for method in &program.methods {
+                    fingerprint.add(format!("method `{}`", method.name()), method);
+                }
+                for method in &program.builtin_methods {
+                    fingerprint.add(format!("the contract of method `{}`", method.name), method);
+                }
+                for function in &program.functions {
+                    fingerprint.add(
+                        format!("the contract of function `{}`", function.name),
+                        &(
+                            &function.formal_args,
+                            &function.return_type,
+                            &function.pres,
+                            &function.posts,
+                        ),
+                    );
+                    fingerprint.add(
+                        format!("the body of function `{}`", function.name),
+                        &function.body,
+                    );
+                }
+                for predicate in &program.viper_predicates {
+                    fingerprint.add(format!("predicate `{}`", predicate.name()), predicate);
+                }
+                for domain in &program.domains {
+                    fingerprint.add(format!("domain `{}`", domain.name), domain);
+                }
+                for backend_type in &program.backend_types {
+                    fingerprint.add(format!("type `{}`", backend_type.name), backend_type);
+                }
+                for field in &program.fields {
+                    fingerprint.add(format!("field `{}`", field.name), field);
+                }
+            }
+            Program::Low(program) => {
+                fingerprint.add("the check mode", &program.check_mode);
+                for procedure in &program.procedures {
+                    fingerprint.add(format!("procedure `{}`", procedure.name), procedure);
+                }
+                for method in &program.methods {
+                    fingerprint.add(
+                        format!("the contract of method `{}`", method.name),
+                        &(
+                            &method.kind,
+                            &method.parameters,
+                            &method.targets,
+                            &method.pres,
+                            &method.posts,
+                        ),
+                    );
+                    fingerprint.add(
+                        format!("the body of method `{}`", method.name),
+                        &method.body,
+                    );
+                }
+                for function in &program.functions {
+                    fingerprint.add(
+                        format!("the contract of function `{}`", function.name),
+                        &(
+                            &function.kind,
+                            &function.parameters,
+                            &function.return_type,
+                            &function.pres,
+                            &function.posts,
+                        ),
+                    );
+                    fingerprint.add(
+                        format!("the body of function `{}`", function.name),
+                        &function.body,
+                    );
+                }
+                for predicate in &program.predicates {
+                    fingerprint.add(format!("predicate `{}`", predicate.name), predicate);
+                }
+                for domain in &program.domains {
+                    fingerprint.add(format!("domain `{}`", domain.name), domain);
+                }
+            }
+        }
+        fingerprint
+    }
 }
 
 /// The configuration for the viper backend, (i.e. verifier).
@@ -84,3 +188,74 @@ impl ViperBackendConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prusti_common::vir::{Function, Program as LegacyProgram, Type};
+
+    fn request(crate_id: &str, function_body: bool) -> VerificationRequest {
+        let program = LegacyProgram {
+            name: "program".to_string(),
+            backend_types: vec![],
+            domains: vec![],
+            fields: vec![],
+            builtin_methods: vec![],
+            methods: vec![],
+            functions: vec![Function {
+                name: "f".to_string(),
+                formal_args: vec![],
+                return_type: Type::Bool,
+                pres: vec![],
+                posts: vec![],
+                body: Some(function_body.into()),
+            }],
+            viper_predicates: vec![],
+        };
+        VerificationRequest {
+            program: Program::Legacy(program),
+            backend_config: ViperBackendConfig {
+                backend: VerificationBackend::Silicon,
+                verifier_args: vec![],
+            },
+            crate_id: crate_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_cache_key_depends_on_crate() {
+        assert_eq!(
+            request("foo-1", true).get_cache_key(),
+            request("foo-1", false).get_cache_key()
+        );
+        assert_ne!(
+            request("foo-1", true).get_cache_key(),
+            request("bar-2", true).get_cache_key()
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_changes() {
+        let old = request("foo-1", true).get_fingerprint();
+        assert!(request("foo-1", true)
+            .get_fingerprint()
+            .changes_since(&old)
+            .is_empty());
+        assert_eq!(
+            request("foo-1", false)
+                .get_fingerprint()
+                .changes_since(&old),
+            vec!["the body of function `f` changed".to_string()]
+        );
+
+        let mut with_precondition = request("foo-1", true);
+        let Program::Legacy(program) = &mut with_precondition.program else {
+            unreachable!()
+        };
+        program.functions[0].pres.push(false.into());
+        assert_eq!(
+            with_precondition.get_fingerprint().changes_since(&old),
+            vec!["the contract of function `f` changed".to_string()]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use futures::StreamExt;
 use lazy_static::lazy_static;
 use prusti_common::vir::*;
 use prusti_server::{
@@ -59,12 +60,16 @@ where
         backend_config: ViperBackendConfig::new(
             prusti_common::config::viper_backend().parse().unwrap(),
         ),
+        crate_id: "basic_requests".to_string(),
     };
 
+    let results = client.verify([request]);
+    futures::pin_mut!(results);
     Builder::new_current_thread()
         .enable_all()
         .build()
         .expect("failed to construct Tokio runtime")
-        .block_on(client.verify(request))
+        .block_on(results.next())
+        .expect("The server did not reply to the verification request")
         .expect("Verification request failed")
 }
@@ -78,6 +78,64 @@ error: could not compile `foo` (bin \"foo\") due to previous error; 1 warning em
         .run();
 }
 
+#[cargo_test]
+fn simple_json_report() {
+    let p = project()
+        .file("src/main.rs", "fn main() { assert!(false); }")
+        .build();
+    p.process(cargo_prusti_path())
+        .env("PRUSTI_REPORT_FORMAT", "json")
+        .with_status(101)
+        .run();
+    let report = fs::read_to_string(p.root().join("target/verify/report/prusti-report.json"))
+        .expect("the verification report has not been written");
+    assert!(report.contains(r#""crate_name": "foo""#), "{report}");
+    assert!(report.contains(r#""def_path": "foo::main""#), "{report}");
+    assert!(report.contains(r#""result": "failed""#), "{report}");
+    assert!(
+        report.contains(r#""kind": "assert.failed:assertion.false""#),
+        "{report}"
+    );
+}
+
+#[cargo_test]
+fn json_report_of_lib_and_bin_crates() {
+    let p = project()
+        .file("src/lib.rs", "pub fn lib_fn() {}")
+        .file("src/main.rs", "fn main() { foo::lib_fn(); }")
+        .build();
+    // A stale report of a crate that no longer exists.
+    fs::create_dir_all(p.root().join("target/verify/report/crates")).unwrap();
+    fs::write(
+        p.root()
+            .join("target/verify/report/crates/removed.lib.json"),
+        r#"{ "crate_name": "removed", "crate_target": "lib", "items": [] }"#,
+    )
+    .unwrap();
+    p.process(cargo_prusti_path())
+        .env("PRUSTI_REPORT_FORMAT", "json")
+        .run();
+    let crates_dir = p.root().join("target/verify/report/crates");
+    assert!(crates_dir.join("foo.lib.json").is_file());
+    assert!(crates_dir.join("foo.bin.json").is_file());
+    assert!(!crates_dir.join("removed.lib.json").exists());
+    let report = fs::read_to_string(p.root().join("target/verify/report/prusti-report.json"))
+        .expect("the verification report has not been written");
+    assert!(report.contains(r#""def_path": "foo::lib_fn""#), "{report}");
+    assert!(report.contains(r#""def_path": "foo::main""#), "{report}");
+    assert!(!report.contains(r#""crate_name": "removed""#), "{report}");
+}
+
+#[cargo_test]
+fn invalid_report_format() {
+    let p = project().file("src/main.rs", "fn main() {}").build();
+    p.process(cargo_prusti_path())
+        .env("PRUSTI_REPORT_FORMAT", "xml")
+        .with_status(1)
+        .with_stderr_contains("Invalid report format: 'xml'. Allowed values are 'json' and 'sarif'")
+        .run();
+}
+
 /// Test `cargo-prusti` on one of the crates in `test/cargo_verify`.
 ///
 /// Special files and folders in the root of the test crate:
@@ -0,0 +1,28 @@
+use prusti_contracts::*;
+
+fn main() {}
+
+#[requires(s.len() >= 2)]
+fn wrong_rest(s: &[i32]) {
+    if let [_, rest @ ..] = s {
+        assert!(rest.len() == s.len()); //~ ERROR the asserted expression might not hold
+    }
+}
+
+#[requires(s.len() >= 2)]
+fn wrong_last(s: &[i32]) {
+    if let [.., x] = s {
+        assert!(*x == s[0]); //~ ERROR the asserted expression might not hold
+    }
+}
+
+#[requires(s.len() > 4)]
+fn out_of_bounds(s: &[i32]) {
+    let _t = &s[2..=s.len()]; //~ ERROR the range end value may be out of bounds when slicing
+}
+
+#[requires(s.len() > 4)]
+fn wrong_inclusive(s: &[i32]) {
+    let t = &s[1..=3];
+    assert!(t.len() == 2); //~ ERROR the asserted expression might not hold
+}
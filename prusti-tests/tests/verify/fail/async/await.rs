@@ -0,0 +1,45 @@
+use prusti_contracts::*;
+
+#[requires(x < 100)]
+#[ensures(result == x + 1)]
+async fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+#[ensures(result > x)] //~ ERROR postcondition might not hold
+async fn identity(x: u32) -> u32 {
+    x
+}
+
+async fn too_large() -> u32 {
+    increment(100).await //~ ERROR precondition might not hold
+}
+
+async fn wrong_output() {
+    let y = increment(1).await;
+    assert!(y == 1); //~ ERROR the asserted expression might not hold
+}
+
+#[ensures(result == *x)]
+async fn read(x: &u32) -> u32 {
+    *x
+}
+
+async fn wrong_read() {
+    let a = 5;
+    let b = read(&a).await;
+    assert!(b == 6); //~ ERROR the asserted expression might not hold
+}
+
+struct Counter {
+    value: u32,
+}
+
+impl Counter {
+    #[ensures(result == self.value)] //~ ERROR postcondition might not hold
+    async fn next(&self) -> u32 {
+        self.value + 1
+    }
+}
+
+fn main() {}
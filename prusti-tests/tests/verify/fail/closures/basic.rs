@@ -1,5 +1,3 @@
-// ignore-test
-
 use prusti_contracts::*;
 
 fn main() {
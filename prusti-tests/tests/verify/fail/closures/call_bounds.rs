@@ -0,0 +1,43 @@
+use prusti_contracts::*;
+
+#[requires(f |= |x: i32| [requires(x >= 0 && x < 10), ensures(result == x), calls(3)])]
+fn call_four_times(mut f: impl FnMut(i32) -> i32) -> i32 {
+    f(1);
+    f(2);
+    f(3);
+    f(4) //~ ERROR precondition might not hold
+}
+
+#[requires(f |=! |x: i32| [requires(x > 0), ensures(result > x)])]
+fn call_twice<F: FnMut(i32) -> i32>(mut f: F) -> i32 {
+    f(1);
+    f(2) //~ ERROR precondition might not hold
+}
+
+#[requires(f |= |x: i32| [requires(x >= 0 && x < 10), ensures(result == x)])]
+fn call_any_number<F: FnMut(i32) -> i32>(f: F) {}
+
+#[requires(f |= |x: i32| [requires(x >= 0 && x < 10), ensures(result == x), calls(4)])]
+fn call_at_most_four<F: FnMut(i32) -> i32>(f: F) {}
+
+fn main() {
+    let mut calls = 0;
+    let counted = closure!(
+        #[view(calls: i32)]
+        #[requires(calls >= 0 && calls < 3)]
+        #[ensures(calls == old(calls) + 1)]
+        #[ensures(result == x)]
+        |x: i32| -> i32 { calls += 1; x }
+    );
+    call_any_number(counted); //~ ERROR precondition might not hold
+
+    let mut calls = 0;
+    let counted = closure!(
+        #[view(calls: i32)]
+        #[requires(calls >= 0 && calls < 3)]
+        #[ensures(calls == old(calls) + 1)]
+        #[ensures(result == x)]
+        |x: i32| -> i32 { calls += 1; x }
+    );
+    call_at_most_four(counted); //~ ERROR precondition might not hold
+}
@@ -0,0 +1,19 @@
+use prusti_contracts::*;
+
+#[requires(keep |= |x: &i32| [ensures(true)])]
+#[requires(forall(|j: usize| j < values.len() ==> values[j] <= 0))]
+#[ensures(result == 0)] //~ ERROR postcondition might not hold
+fn count_kept(values: &[i32], keep: impl for<'a> Fn(&'a i32) -> bool) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < values.len() {
+        body_invariant!(i < values.len() && count <= i);
+        if keep(&values[i]) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+fn main() {}
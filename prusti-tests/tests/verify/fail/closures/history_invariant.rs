@@ -0,0 +1,22 @@
+use prusti_contracts::*;
+
+fn main() {
+    let mut count = 0;
+    let mut counter = closure!(
+        #[view(count: i32)]
+        #[invariant(count >= old(count))] //~ ERROR postcondition might not hold
+        #[requires(count > -10 && count < 10)]
+        || -> i32 { count -= 1; count }
+    );
+    counter();
+
+    let mut total = 0;
+    let mut add = closure!(
+        #[view(total: i32)]
+        #[invariant(total >= old(total))]
+        #[requires(x >= 0 && x < 10 && total >= 0 && total < 100)]
+        |x: i32| -> () { total += x; }
+    );
+    add(3);
+    assert!(total == 3); //~ ERROR the asserted expression might not hold
+}
@@ -0,0 +1,8 @@
+use prusti_contracts::*;
+
+fn main() {
+    let one = closure!(
+        #[invariant(true)] //~ ERROR history invariants of closures must refer to the captured state through views
+        || -> i32 { 1 }
+    );
+}
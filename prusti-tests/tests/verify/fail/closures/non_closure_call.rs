@@ -0,0 +1,13 @@
+use prusti_contracts::*;
+
+pub fn max_by_key<A, B: Ord>(a: A, b: A, key: impl Fn(&A) -> B) -> A {
+    let key_a = key(&a); //~ ERROR precondition might not hold
+    let key_b = key(&b);
+    if key_a > key_b {
+        a
+    } else {
+        b
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use prusti_contracts::*;
+
+#[requires(f |= |x: i32| [requires(x > 0), ensures(result > x)])]
+fn call_with_zero<F: Fn(i32) -> i32>(f: F) -> i32 {
+    f(0) //~ ERROR precondition might not hold
+}
+
+#[requires(f |= |x: i32| [requires(x > 0), ensures(result > x)])]
+#[ensures(result > 2)] //~ ERROR postcondition might not hold
+fn call_with_one<F: Fn(i32) -> i32>(f: F) -> i32 {
+    f(1)
+}
+
+fn call_without_spec<F: Fn(i32) -> i32>(f: F) -> i32 {
+    f(1) //~ ERROR precondition might not hold
+}
+
+#[requires(f |= |x: i32| [requires(x > 0), ensures(result > x)])]
+fn take<F: Fn(i32) -> i32>(f: F) {}
+
+fn main() {
+    let id = closure!(
+        #[ensures(result == x)]
+        |x: i32| -> i32 { x }
+    );
+    take(id); //~ ERROR precondition might not hold
+}
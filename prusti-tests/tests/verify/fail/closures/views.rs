@@ -0,0 +1,20 @@
+use prusti_contracts::*;
+
+fn main() {
+    let mut last = 0;
+    let mut record = closure!(
+        #[view(last: i32)]
+        #[ensures(last == x)]
+        |x: i32| -> i32 { last = x; x }
+    );
+    record(5);
+    assert!(last == 6); //~ ERROR the asserted expression might not hold
+
+    let mut count = 0;
+    let mut counter = closure!(
+        #[view(count: i32)]
+        #[ensures(count == old(count) + 1)] //~ ERROR postcondition might not hold
+        || -> i32 { count += 2; count }
+    );
+    counter();
+}
@@ -0,0 +1,23 @@
+// compile-flags: -Pencode_ieee_floats=true
+use prusti_contracts::*;
+
+fn nan_comparison(x: f32) {
+    assert!(x == x); //~ ERROR the asserted expression might not hold
+}
+
+fn addition_may_overflow(x: f64) {
+    let y = x + 1.0;
+    assert!(y.is_finite()); //~ ERROR the asserted expression might not hold
+}
+
+#[ensures(result.is_finite())] //~ ERROR postcondition might not hold
+fn double(x: f64) -> f64 {
+    x * 2.0
+}
+
+fn truncating_cast() {
+    let x = 2.5f32;
+    assert!(x as u8 == 3); //~ ERROR the asserted expression might not hold
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use prusti_contracts::*;
+
+type Map = prusti_contracts::Map<u32, i32>;
+
+fn map_len() {
+    prusti_assert!(map![1 => 10, 2 => 20].len() == Int::new(1)); //~ ERROR the asserted expression might not hold
+}
+
+fn map_lookup() {
+    prusti_assert!(map![1 => 10, 1 => 20].lookup(1) == 10); //~ ERROR the asserted expression might not hold
+}
+
+fn map_equality() {
+    prusti_assert!(map![1 => 10] === map![1 => 20]); //~ ERROR the asserted expression might not hold
+}
+
+#[pure]
+#[ensures(result.len() == m.len() - Int::new(1))] //~ ERROR postcondition might not hold
+fn remove(m: Map, k: u32) -> Map {
+    m.delete(k)
+}
+
+fn main() {}
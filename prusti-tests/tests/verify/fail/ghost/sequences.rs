@@ -0,0 +1,38 @@
+use prusti_contracts::*;
+
+type Seq = prusti_contracts::Seq<u32>;
+
+fn seq_len() {
+    prusti_assert!(seq![1, 2].len() == Int::new(1)); //~ ERROR the asserted expression might not hold
+}
+
+#[pure]
+#[requires(Int::new_usize(i) < s.len())]
+#[ensures(result === s)] //~ ERROR postcondition might not hold
+fn update(s: Seq, i: usize, v: u32) -> Seq {
+    s.update(i, v)
+}
+
+fn seq_subsequence() {
+    prusti_assert!(seq![1, 2, 3, 4].subsequence(1, 3) === seq![1, 2]); //~ ERROR the asserted expression might not hold
+}
+
+fn seq_take() {
+    prusti_assert!(seq![1, 2, 3].take(2) === seq![2, 3]); //~ ERROR the asserted expression might not hold
+}
+
+fn seq_contains() {
+    prusti_assert!(seq![1, 2, 3].contains(&4)); //~ ERROR the asserted expression might not hold
+}
+
+fn seq_index_of() {
+    prusti_assert!(seq![1, 2, 2].index_of(&2) == Int::new(2)); //~ ERROR the asserted expression might not hold
+}
+
+#[pure]
+#[ensures(result.len() == Int::new(0))] //~ ERROR postcondition might not hold
+fn from_slice(s: &[u32]) -> Seq {
+    Seq::from_slice(s)
+}
+
+fn main() {}
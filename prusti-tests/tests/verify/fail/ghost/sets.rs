@@ -0,0 +1,36 @@
+use prusti_contracts::*;
+
+type Set = prusti_contracts::Set<u32>;
+type Multiset = prusti_contracts::Multiset<u32>;
+
+fn set_len() {
+    prusti_assert!(set![1, 2].len() == Int::new(1)); //~ ERROR the asserted expression might not hold
+}
+
+fn set_equality() {
+    prusti_assert!(set![1] === Set::empty()); //~ ERROR the asserted expression might not hold
+}
+
+#[pure]
+#[ensures(result.len() == s.len())] //~ ERROR postcondition might not hold
+fn insert(s: Set, v: u32) -> Set {
+    s.union(Set::single(v))
+}
+
+#[pure]
+#[ensures(result.is_subset(s1))] //~ ERROR postcondition might not hold
+fn union(s1: Set, s2: Set) -> Set {
+    s1.union(s2)
+}
+
+fn multiset_equality() {
+    prusti_assert!(multiset![1, 2] === multiset![1, 2, 2]); //~ ERROR the asserted expression might not hold
+}
+
+#[pure]
+#[ensures(result.count(v) == m.count(v))] //~ ERROR postcondition might not hold
+fn add(m: Multiset, v: u32) -> Multiset {
+    m.union(Multiset::single(v))
+}
+
+fn main() {}
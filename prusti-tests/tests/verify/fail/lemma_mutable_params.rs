@@ -0,0 +1,32 @@
+// compile-flags: -Punsafe_core_proof=true
+#![allow(unused)]
+use prusti_contracts::*;
+
+type Alias<'a> = &'a mut i64;
+
+#[lemma]
+#[terminates]
+#[ensures(*x == 0)]
+fn reset(x: &mut i64) {} //~ ERROR lemmas cannot take mutable references as parameters
+
+#[lemma]
+#[terminates]
+#[ensures(*x == 0)]
+fn reset_alias(x: Alias) {} //~ ERROR lemmas cannot take mutable references as parameters
+
+struct Counter(i64);
+
+impl Counter {
+    #[lemma]
+    #[terminates]
+    #[ensures(self.0 == 0)]
+    fn reset(&mut self) {} //~ ERROR lemmas cannot take mutable references as parameters
+
+    #[lemma]
+    #[terminates]
+    #[requires(self.0 >= 0)]
+    #[ensures(self.0 + 1 > 0)]
+    fn positive_next(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// compile-flags: -Punsafe_core_proof=true
+#![allow(unused, unconditional_recursion)]
+use prusti_contracts::*;
+
+#[lemma]
+#[requires(x >= 0)]
+#[ensures(false)]
+fn unsound(x: i64) { //~ ERROR lemmas must be annotated with `#[terminates]`
+    unsound(x);
+}
+
+fn main() {}
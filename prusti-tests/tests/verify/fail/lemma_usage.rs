@@ -0,0 +1,45 @@
+// compile-flags: -Punsafe_core_proof=true
+#![allow(unused)]
+use prusti_contracts::*;
+
+#[lemma]
+#[terminates]
+#[requires(x >= 0)]
+#[ensures(x + 1 > 0)]
+fn positive_successor(x: i64) {}
+
+#[lemma]
+#[terminates]
+#[requires(x >= 0)]
+#[ensures(x + 2 > 0)]
+fn positive_second_successor(x: i64) {
+    positive_successor(x);
+}
+
+struct Counter(i64);
+
+impl Counter {
+    #[lemma]
+    #[terminates]
+    #[requires(self.0 >= 0)]
+    #[ensures(self.0 + 1 > 0)]
+    fn positive_next(&self) {}
+}
+
+#[requires(x >= 0)]
+fn ghost_calls(x: i64) {
+    let counter = Counter(x);
+    ghost! {
+        positive_second_successor(x);
+        counter.positive_next();
+    };
+}
+
+#[requires(x >= 0)]
+fn executable_calls(x: i64) {
+    positive_successor(x); //~ ERROR: calling a lemma from executable code is not allowed
+    let counter = Counter(x);
+    counter.positive_next(); //~ ERROR: calling a lemma from executable code is not allowed
+}
+
+fn main() {}
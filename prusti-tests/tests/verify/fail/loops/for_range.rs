@@ -0,0 +1,30 @@
+use prusti_contracts::*;
+use std::ops::Range;
+
+#[requires(n <= 100)]
+#[ensures(result == n)]
+fn count(n: usize) -> usize {
+    let mut count = 0;
+    for i in 0..n {
+        body_invariant!(count == i);
+        body_invariant!(loop_iterator!(Range<usize>).start == i); //~ ERROR loop invariant might not hold
+        count += 1;
+    }
+    count
+}
+
+fn next_of_range() {
+    let mut range = 3..5;
+    range.next();
+    assert!(range.start == 3); //~ ERROR the asserted expression might not hold
+}
+
+fn not_in_for_loop(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(loop_iterator!(Range<usize>).end == n); //~ ERROR `loop_iterator!` must be used in a `for` loop
+        i += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use prusti_contracts::*;
+
+struct Counter {
+    value: u32,
+}
+
+impl Counter {
+    #[pure]
+    fn get(&self) -> u32 {
+        self.value
+    }
+
+    #[requires(self.value <= 1000 && amount <= 1000)]
+    #[ensures(self.value == old(self.value) + amount)]
+    fn add(&mut self, amount: u32) {
+        self.value += amount;
+    }
+}
+
+#[requires(counter.value <= 1000)]
+#[ensures(counter.value == old(counter.value) + 1)] //~ ERROR postcondition might not hold
+fn increment(counter: &mut Counter) {
+    counter.add(counter.get());
+}
+
+fn main() {
+    let mut counter = Counter { value: 3 };
+    counter.add(counter.value);
+    assert!(counter.value == 7); //~ ERROR the asserted expression might not hold
+}
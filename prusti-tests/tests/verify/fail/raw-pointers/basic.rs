@@ -0,0 +1,37 @@
+use prusti_contracts::*;
+
+fn read_null() {
+    let p: *const i32 = std::ptr::null();
+    let _y = unsafe { *p }; //~ ERROR the memory pointed to by this raw pointer might not be readable
+}
+
+fn read_argument(p: *const i32) -> i32 {
+    let q = p;
+    unsafe { *q } //~ ERROR the memory pointed to by this raw pointer might not be readable
+}
+
+fn write_null() {
+    let p: *mut i32 = std::ptr::null_mut();
+    unsafe {
+        *p = 3; //~ ERROR the memory pointed to by this raw pointer might not be writable
+    }
+}
+
+fn write_through_const(x: u8) {
+    let p = &x as *const u8 as *mut u8;
+    unsafe {
+        *p = 3; //~ ERROR the memory pointed to by this raw pointer might not be writable
+    }
+}
+
+fn wrong_value() {
+    let mut x = 5;
+    let p = &mut x as *mut i32;
+    unsafe {
+        *p = 7;
+    }
+    let y = unsafe { *p };
+    assert!(y == 5); //~ ERROR the asserted expression might not hold
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+extern crate prusti_std;
+use prusti_contracts::*;
+use prusti_std::models::*;
+use std::collections::{HashMap, VecDeque};
+
+fn option() {
+    let x: Option<i32> = None;
+    x.unwrap(); //~ ERROR precondition might not hold
+}
+
+fn slice(a: &mut [i32]) {
+    a.swap(0, 1); //~ ERROR precondition might not hold
+}
+
+fn hash_map() {
+    let mut map = HashMap::new();
+    map.insert(1, 10);
+    map.insert(2, 20);
+    assert!(map.len() == 1); //~ ERROR the asserted expression might not hold
+}
+
+fn hash_map_get() {
+    let mut map = HashMap::new();
+    map.insert(1, 10);
+    assert!(*map.get(&1).unwrap() == 20); //~ ERROR the asserted expression might not hold
+}
+
+fn vec_deque_ends() {
+    let mut queue = VecDeque::new();
+    queue.push_back(1);
+    queue.push_back(2);
+    assert!(*queue.back().unwrap() == 1); //~ ERROR the asserted expression might not hold
+}
+
+fn min_max(a: u32, b: u32) {
+    assert!(std::cmp::max(a, b) == a); //~ ERROR the asserted expression might not hold
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Version(u32);
+
+// `min` and `max` are only specified for the integer types.
+fn min_max_non_integer(a: Version, b: Version) {
+    let m = std::cmp::min(a, b);
+    assert!(m.0 <= 5); //~ ERROR the asserted expression might not hold
+}
+
+fn vec_deque() {
+    let mut queue = VecDeque::new();
+    queue.push_back(1);
+    assert!(queue.pop_front().is_none()); //~ ERROR the asserted expression might not hold
+}
+
+fn string() {
+    let mut s = String::new();
+    s.push_str("ab");
+    assert!(s.len() == 3); //~ ERROR the asserted expression might not hold
+}
+
+#[requires(word.model().chars.len() == Int::new(2))]
+fn char_count(word: &str) {
+    assert!(word.chars().count() == 3); //~ ERROR the asserted expression might not hold
+}
+
+fn main() {}
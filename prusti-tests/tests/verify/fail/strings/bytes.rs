@@ -0,0 +1,23 @@
+use prusti_contracts::*;
+
+fn literals() {
+    let s = "abc";
+    assert!(s.len() == 3);
+    assert!(s.len() == 4); //~ ERROR the asserted expression might not hold
+}
+
+fn prefixes() {
+    assert!("-v".starts_with("--")); //~ ERROR the asserted expression might not hold
+}
+
+#[ensures(result.len() > 0)] //~ ERROR postcondition might not hold
+fn any(s: &str) -> &str {
+    s
+}
+
+#[ensures(result == s.as_bytes()[0])]
+fn first_byte(s: &str) -> u8 {
+    s.as_bytes()[0] //~ ERROR the array or slice index may be out of bounds
+}
+
+fn main() {}
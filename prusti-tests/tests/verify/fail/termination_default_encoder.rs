@@ -0,0 +1,128 @@
+// compile-flags: -Pcheck_pure_function_termination=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+#[terminates]
+fn main() {
+    let mut x = 0;
+    while x < 10 { //~ ERROR: this loop might not terminate
+        x += 1;
+    }
+}
+
+fn non_terminating() {}
+
+#[terminates]
+fn terminating_calls_nonterminating() {
+    non_terminating(); //~ ERROR: this function call might not terminate
+}
+
+#[trusted]
+#[terminates(trusted)]
+fn trusted_to_terminate() {
+    loop {}
+}
+
+#[terminates]
+fn terminating_calls_trusted() {
+    trusted_to_terminate();
+}
+
+#[pure]
+fn pure_fns_need_to_terminate() { //~ ERROR: Pure functions need to terminate
+}
+
+#[terminates]
+fn valid_loop_variant(mut x: i64) {
+    while x > 1 {
+        body_variant!(Int::new(x));
+        x -= 1;
+    }
+}
+
+#[terminates]
+fn invalid_loop_variant(mut u: i64) {
+    while u > 0 {
+        body_variant!(Int::new(u)); //~ ERROR: The loop variant might not have decreased
+    }
+}
+
+#[terminates]
+fn invalid_nested_inner_loop(mut a: i64) {
+    while a > 0 {
+        body_variant!(Int::new(a));
+        let mut b = a;
+        while b > 0 { //~ ERROR: this loop might not terminate
+            b -= 1;
+        }
+        a -= 1;
+    }
+}
+
+#[terminates(Int::new(x))]
+fn valid_recursion(x: i64) {
+    if x > 0 {
+        valid_recursion(x - 1);
+    }
+}
+
+#[terminates(Int::new(x))]
+fn invalid_recursion(x: i64) {
+    invalid_recursion(x - 1); //~ ERROR: the termination measure of this call might become negative
+}
+
+#[terminates(Int::new(x))]
+fn valid_mutual_recursion1(x: i64) {
+    if x > 0 {
+        valid_mutual_recursion2(x - 1);
+    }
+}
+
+#[terminates(Int::new(x))]
+fn valid_mutual_recursion2(x: i64) {
+    if x > 0 {
+        valid_mutual_recursion1(x - 1);
+    }
+}
+
+#[requires(m >= 0 && n >= 0)]
+#[terminates((Int::new(m), Int::new(n)))]
+fn wrong_order(m: i64, n: i64) {
+    if n > 0 {
+        wrong_order(m + 1, n - 1); //~ ERROR: the termination measure of this call is not necessarily lower
+    }
+}
+
+#[terminates]
+#[requires(x >= 0)]
+#[ensures(result == fib(x))]
+fn fibi(x: i64) -> i64 {
+    let mut i = 0;
+    let mut a = 0;
+    let mut b = 1;
+    while i < x {
+        body_variant!(Int::new(x) - Int::new(i));
+        body_invariant!(i < x);
+        body_invariant!(i >= 0);
+        body_invariant!(a == fib(i));
+        body_invariant!(b == fib(i + 1));
+        let c = a + b;
+        a = b;
+        b = c;
+        i += 1;
+    }
+    a
+}
+
+#[pure]
+#[requires(x >= 0)]
+#[terminates(Int::new(x))]
+fn fib(x: i64) -> i64 {
+    if x <= 1 {
+        x
+    } else {
+        fib(x - 1) + fib(x - 2)
+    }
+}
@@ -0,0 +1,99 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+fn main() {}
+
+#[requires(m >= 0 && n >= 0)]
+#[terminates((Int::new(m), Int::new(n)))]
+fn ackermann(m: i64, n: i64) -> i64 {
+    if m == 0 {
+        0
+    } else if n == 0 {
+        ackermann(m - 1, 1)
+    } else {
+        let r = ackermann(m, n - 1);
+        if r >= 0 {
+            ackermann(m - 1, r)
+        } else {
+            0
+        }
+    }
+}
+
+#[requires(m >= 0 && n >= 0)]
+#[terminates((Int::new(m), Int::new(n)))]
+fn wrong_order(m: i64, n: i64) {
+    if n > 0 {
+        wrong_order(m + 1, n - 1); //~ ERROR: the termination measure of this call is not necessarily lower
+    }
+}
+
+#[requires(x >= 0)]
+#[terminates((Int::new(x), Int::new(1)))]
+fn is_even(x: i64) -> bool {
+    if x == 0 {
+        true
+    } else {
+        is_odd(x)
+    }
+}
+
+#[requires(x >= 0)]
+#[terminates((Int::new(x), Int::new(0)))]
+fn is_odd(x: i64) -> bool {
+    if x == 0 {
+        false
+    } else {
+        is_even(x - 1)
+    }
+}
+
+#[requires(x >= 0)]
+#[terminates(Int::new(x))]
+fn mutual_without_decrease1(x: i64) {
+    mutual_without_decrease2(x); //~ ERROR: the termination measure of this call is not necessarily lower
+}
+
+#[requires(x >= 0)]
+#[terminates(Int::new(x))]
+fn mutual_without_decrease2(x: i64) {
+    if x > 0 {
+        mutual_without_decrease1(x - 1);
+    }
+}
+
+#[requires(Int::new_usize(n) == s.len())]
+#[terminates(s.len())]
+fn sequence_length(s: Seq<i64>, n: usize) {
+    if n > 0 {
+        sequence_length(s.skip(1), n - 1);
+    }
+}
+
+#[requires(b >= 0)]
+#[terminates]
+fn valid_lexicographic_loop_variant(mut a: i64, mut b: i64) {
+    while a > 0 {
+        body_invariant!(b >= 0);
+        body_variant!((Int::new(a), Int::new(b)));
+        if b > 0 {
+            b -= 1;
+        } else {
+            a -= 1;
+            b = 10;
+        }
+    }
+}
+
+#[requires(b >= 0)]
+#[terminates]
+fn invalid_lexicographic_loop_variant(mut a: i64, mut b: i64) {
+    while a > 0 {
+        body_invariant!(b >= 0);
+        body_variant!((Int::new(a), Int::new(b))); //~ ERROR: The loop variant might not have decreased
+        b += 1;
+    }
+}
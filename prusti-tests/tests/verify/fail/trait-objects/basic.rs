@@ -0,0 +1,80 @@
+use prusti_contracts::*;
+
+trait Counter {
+    #[ensures(result >= 0)]
+    fn count(&self) -> i32;
+
+    #[requires(n >= 0)]
+    #[ensures(result >= n)]
+    fn at_least(&self, n: i32) -> i32;
+
+    #[requires(n >= 0)]
+    #[ensures(result >= n)]
+    fn advance(&mut self, n: i32) -> i32;
+}
+
+struct Five;
+
+#[refine_trait_spec]
+impl Counter for Five {
+    #[ensures(result == 5)]
+    fn count(&self) -> i32 {
+        5
+    }
+
+    fn at_least(&self, n: i32) -> i32 {
+        if n > 5 {
+            n
+        } else {
+            5
+        }
+    }
+
+    fn advance(&mut self, n: i32) -> i32 {
+        n
+    }
+}
+
+struct Unchecked;
+
+#[refine_trait_spec]
+impl Counter for Unchecked {
+    #[trusted]
+    #[ensures(result == 1)]
+    fn count(&self) -> i32 {
+        1
+    }
+
+    fn at_least(&self, n: i32) -> i32 {
+        n
+    }
+
+    fn advance(&mut self, n: i32) -> i32 {
+        n
+    }
+}
+
+fn only_trait_spec(counter: &dyn Counter) {
+    // The refined postcondition of `Five` is not known here.
+    assert!(counter.count() == 5); //~ ERROR the asserted expression might not hold
+}
+
+fn trait_precondition(counter: &dyn Counter) {
+    counter.at_least(-1); //~ ERROR precondition might not hold
+}
+
+fn boxed_trait_precondition(mut counter: Box<dyn Counter>) {
+    counter.advance(1);
+    counter.advance(-1); //~ ERROR precondition might not hold
+}
+
+fn trusted_refinement() {
+    let unchecked = Unchecked;
+    let counter: &dyn Counter = &unchecked; //~ ERROR the refined specification of `<Unchecked as Counter>::count` cannot be checked
+    counter.count();
+}
+
+fn main() {
+    let five = Five;
+    only_trait_spec(&five);
+}
@@ -0,0 +1,35 @@
+use prusti_contracts::*;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union IntOrFloat {
+    int: u32,
+    float: f32,
+}
+
+fn read_inactive() {
+    let x = IntOrFloat { float: 1.0 };
+    let _y = unsafe { x.int }; //~ ERROR the union field might not be the active one when it is read
+}
+
+fn read_after_switch() {
+    let mut x = IntOrFloat { int: 1 };
+    x.float = 2.0;
+    let _y = unsafe { x.int }; //~ ERROR the union field might not be the active one when it is read
+}
+
+fn read_unknown(x: IntOrFloat) -> u32 {
+    unsafe { x.int } //~ ERROR the union field might not be the active one when it is read
+}
+
+#[requires(is_active_union_field!(x.float))]
+fn read_wrong_precondition(x: IntOrFloat) -> u32 {
+    unsafe { x.int } //~ ERROR the union field might not be the active one when it is read
+}
+
+#[ensures(is_active_union_field!(result.int))] //~ ERROR postcondition might not hold
+fn wrong_postcondition() -> IntOrFloat {
+    IntOrFloat { float: 0.0 }
+}
+
+fn main() {}
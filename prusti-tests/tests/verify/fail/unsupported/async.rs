@@ -1,7 +1,5 @@
-pub async fn connect<D>(dst: D) //~ ERROR unsupported type
-where
-    D: std::convert::TryInto<u32>
-{ //~ ERROR unsupported type
+async fn five() -> i32 {
+    std::future::ready(5).await //~ ERROR awaiting futures that are not returned by an `async fn` is not supported
 }
 
 fn main(){}
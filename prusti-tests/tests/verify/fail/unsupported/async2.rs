@@ -2,10 +2,10 @@ pub struct QueryClient {
 }
 impl QueryClient
 {
-    pub async fn accounts( //~ ERROR unsupported type
+    pub async fn accounts( //~ ERROR `async fn` with mutable reference or raw pointer arguments is not supported
         &mut self,
         _request: u32
-    ) -> Result<u32, u32> { //~ ERROR unsupported type
+    ) -> Result<u32, u32> {
         unimplemented!()
     }
 }
@@ -0,0 +1,15 @@
+use std::future::Future;
+
+async fn reset(x: &mut u32) { //~ ERROR `async fn` with mutable reference or raw pointer arguments is not supported
+    *x = 0;
+}
+
+async fn read(x: *const u32) -> u32 { //~ ERROR `async fn` with mutable reference or raw pointer arguments is not supported
+    unsafe { *x }
+}
+
+async fn wait(future: impl Future<Output = u32>) -> u32 {
+    future.await //~ ERROR awaiting futures that are not returned by an `async fn` is not supported
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+fn sum(v: &Vec<u32>) -> u32 {
+    let mut sum = 0;
+    for x in v.iter() { //~ ERROR iterators are not fully supported yet
+        sum += *x;
+    }
+    sum
+}
+
+fn main() {}
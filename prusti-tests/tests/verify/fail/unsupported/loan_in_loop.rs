@@ -0,0 +1,19 @@
+use prusti_contracts::*;
+
+struct Tree {
+    value: i32,
+    child: Box<Tree>,
+}
+
+fn descend(tree: &mut Tree, depth: u32) {
+    let mut node = tree;
+    let mut i = 0;
+    while i < depth { //~ ERROR creation of loan 'node' in loop is unsupported
+        body_invariant!(i < depth);
+        node = &mut node.child;
+        i += 1;
+    }
+    node.value = 0;
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+trait Counter {
+    fn increment(&mut self);
+}
+
+struct Count {
+    value: u32,
+}
+
+impl Counter for Count {
+    fn increment(&mut self) {
+        self.value = 1;
+    }
+}
+
+fn increment(counter: &mut dyn Counter) {
+    counter.increment();
+}
+
+fn main() {
+    let mut count = Count { value: 0 };
+    increment(&mut count); //~ ERROR coercing a mutable reference into a
+    // The change made through the trait object must not be lost.
+    assert!(count.value == 0);
+}
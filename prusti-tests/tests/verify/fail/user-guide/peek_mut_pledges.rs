@@ -139,7 +139,7 @@ impl<T> List<T> {
     //         }
     //         index -= 1;
     //     }
-    //     if let Some(node) = curr_node { // ERROR: [Prusti: unsupported feature] the creation of loans in this loop is not supported (ReborrowingDagHasNoMagicWands)
+    //     if let Some(node) = curr_node { // ERROR: [Prusti: unsupported feature] creation of loan 'curr_node' in loop is unsupported
     //         &mut node.elem
     //     } else {
     //         unreachable!()
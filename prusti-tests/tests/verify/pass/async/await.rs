@@ -0,0 +1,72 @@
+use prusti_contracts::*;
+
+#[requires(x < 100)]
+#[ensures(result == x + 1)]
+async fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+#[requires(x < 50)]
+#[ensures(result == x as u32 + 2)]
+async fn increment_twice(x: u8) -> u32 {
+    let y = increment(x as u32).await;
+    increment(y).await
+}
+
+#[ensures(result.0 <= result.1)]
+async fn sorted(a: i32, b: i32) -> (i32, i32) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+async fn use_sorted() {
+    let (low, high) = sorted(3, -2).await;
+    assert!(low <= high);
+}
+
+#[trusted]
+#[ensures(result > 0)]
+async fn positive() -> i32 {
+    loop {}
+}
+
+async fn use_positive() -> i32 {
+    let x = positive().await;
+    assert!(x > 0);
+    x
+}
+
+#[ensures(result == *x)]
+async fn read(x: &u32) -> u32 {
+    *x
+}
+
+async fn use_read() {
+    let a = 5;
+    let b = read(&a).await;
+    assert!(b == 5);
+    assert!(a == 5);
+}
+
+struct Counter {
+    value: u32,
+}
+
+impl Counter {
+    #[requires(self.value < 100)]
+    #[ensures(result == self.value + 1)]
+    async fn next(&self) -> u32 {
+        self.value + 1
+    }
+}
+
+async fn use_next() {
+    let counter = Counter { value: 1 };
+    let next = counter.next().await;
+    assert!(next == 2);
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+pub async fn connect<D>(dst: D)
+where
+    D: std::convert::TryInto<u32>
+{
+}
+
+fn main(){}
@@ -0,0 +1,41 @@
+use prusti_contracts::*;
+
+#[requires(f |= |x: i32| [
+    requires(x >= 0 && x < 10),
+    ensures(result == x),
+    calls(3)
+])]
+#[ensures(result == 3)]
+fn call_three_times(mut f: impl FnMut(i32) -> i32) -> i32 {
+    f(1);
+    f(2);
+    f(3)
+}
+
+#[requires(f |=! |x: i32| [requires(x > 0 && x < 10), ensures(result > x)])]
+#[ensures(result > 5)]
+fn call_once<F: FnOnce(i32) -> i32>(f: F) -> i32 {
+    f(5)
+}
+
+fn main() {
+    let mut calls = 0;
+    let counted = closure!(
+        #[view(calls: i32)]
+        #[requires(calls >= 0 && calls < 3)]
+        #[ensures(calls == old(calls) + 1)]
+        #[ensures(result == x)]
+        |x: i32| -> i32 { calls += 1; x }
+    );
+    assert!(call_three_times(counted) == 3);
+
+    let mut budget = 2;
+    let spend = closure!(
+        #[view(budget: u32)]
+        #[requires(budget > 0 && x < 10)]
+        #[ensures(budget == old(budget) - 1)]
+        #[ensures(result == x + 1)]
+        move |x: i32| -> i32 { budget -= 1; x + 1 }
+    );
+    assert!(call_once(spend) > 5);
+}
@@ -0,0 +1,66 @@
+use prusti_contracts::*;
+
+#[requires(f |= |x: i32| [
+    requires(x >= 0 && x < 10),
+    ensures(result >= x)
+])]
+#[ensures(result >= 1)]
+fn apply_twice<F: FnMut(i32) -> i32>(mut f: F) -> i32 {
+    let a = f(1);
+    let b = f(2);
+    if a < b { a } else { b }
+}
+
+#[requires(f |= |x: i32| [
+    requires(x >= 0 && x < 10),
+    ensures(result >= x)
+])]
+#[ensures(result >= 3)]
+fn apply_impl(mut f: impl FnMut(i32) -> i32) -> i32 {
+    f(3)
+}
+
+fn main() {
+    let mut last = 0;
+    let mut record = closure!(
+        #[view(last: i32)]
+        #[ensures(result == x)]
+        #[ensures(last == x)]
+        |x: i32| -> i32 { last = x; x }
+    );
+    let r = record(5);
+    assert!(r == 5);
+    record(7);
+    assert!(last == 7);
+
+    let mut total = 0;
+    let mut add = closure!(
+        #[view(total: i32)]
+        #[requires(x >= 0 && x < 10 && total >= 0 && total < 100)]
+        #[ensures(total == old(total) + x)]
+        |x: i32| -> () { total += x; }
+    );
+    add(3);
+    add(4);
+    assert!(total == 7);
+
+    let mut count = 0;
+    let mut counter = closure!(
+        #[view(count: i32)]
+        #[requires(count >= 0 && count < 100)]
+        #[ensures(result == old(count))]
+        #[ensures(count == old(count) + 1)]
+        move || -> i32 { let r = count; count += 1; r }
+    );
+    assert!(counter() == 0);
+    assert!(counter() == 1);
+    assert!(count == 0);
+
+    let double = closure!(
+        #[requires(x >= 0 && x < 10)]
+        #[ensures(result == 2 * x)]
+        |x: i32| -> i32 { 2 * x }
+    );
+    assert!(apply_twice(double) >= 1);
+    assert!(apply_impl(double) >= 3);
+}
@@ -0,0 +1,39 @@
+use prusti_contracts::*;
+
+#[requires(keep |= |x: &i32| [
+    requires(*x >= 0),
+    ensures(result ==> *x > 0)
+])]
+#[requires(value >= 0)]
+#[ensures(result ==> value > 0)]
+fn check<F: Fn(&i32) -> bool>(value: i32, keep: F) -> bool {
+    keep(&value)
+}
+
+#[requires(keep |= |x: &i32| [ensures(result ==> *x > 0)])]
+#[requires(forall(|j: usize| j < values.len() ==> values[j] <= 0))]
+#[ensures(result == 0)]
+fn count_kept(values: &[i32], keep: impl for<'a> Fn(&'a i32) -> bool) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < values.len() {
+        body_invariant!(i < values.len() && count == 0);
+        body_invariant!(forall(|j: usize| j < values.len() ==> values[j] <= 0));
+        if keep(&values[i]) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+fn main() {
+    let _ = (0..1).filter(|_| true);
+
+    let positive = closure!(
+        #[requires(*x >= 0)]
+        #[ensures(result == (*x > 0))]
+        |x: &i32| -> bool { *x > 0 }
+    );
+    let _ = check(5, positive);
+}
@@ -0,0 +1,24 @@
+use prusti_contracts::*;
+
+fn main() {
+    let mut total = 0;
+    let mut add = closure!(
+        #[view(total: i32)]
+        #[invariant(old(total) <= total && total <= old(total) + 9)]
+        #[requires(x >= 0 && x < 10 && total >= 0 && total < 100)]
+        |x: i32| -> () { total += x; }
+    );
+    add(3);
+    add(4);
+    assert!(total >= 0 && total <= 18);
+
+    let mut count = 0;
+    let mut counter = closure!(
+        #[view(count: u32)]
+        #[invariant(count == old(count) + 1)]
+        #[requires(count < 10)]
+        move || -> u32 { count += 1; count }
+    );
+    counter();
+    counter();
+}
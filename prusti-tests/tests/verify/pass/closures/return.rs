@@ -0,0 +1,15 @@
+use prusti_contracts::*;
+
+fn make_adder() -> impl Fn(i32) -> i32 {
+    closure!(
+        #[requires(x < 100)]
+        #[ensures(result == x + 1)]
+        |x: i32| -> i32 { x + 1 }
+    )
+}
+
+fn main() {
+    let add = make_adder();
+    let r = add(41);
+    assert!(r == 42);
+}
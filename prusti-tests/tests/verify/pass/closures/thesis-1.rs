@@ -2,12 +2,13 @@ use prusti_contracts::*;
 
 /// Examples from Fabian Wolff's thesis.
 
-// ignore-test
-// TODO: history invariants
-
 fn main() {
     let mut count = 0;
     let mut cl = closure!(
+        #[view(count: i32)]
+        #[requires(count < 100)]
+        #[ensures(result == old(count))]
+        #[ensures(count == old(count) + 1)]
         || -> i32 { let r = count; count += 1; r }
     );
 
@@ -0,0 +1,15 @@
+use prusti_contracts::*;
+
+struct Counter {
+    value: u32,
+}
+
+fn main() {
+    let mut counter = Counter { value: 5 };
+    let r = &mut counter;
+    // `r` is captured by a unique immutable borrow.
+    let mut reset = || {
+        r.value = 0;
+    };
+    reset();
+}
@@ -0,0 +1,55 @@
+// compile-flags: -Pencode_ieee_floats=true
+use prusti_contracts::*;
+
+fn overflow_to_infinity() {
+    let x = f32::MAX;
+    let y = x * 2.0;
+    assert!(y.is_infinite());
+    assert!(!y.is_finite());
+}
+
+fn nan_is_not_equal_to_itself() {
+    let x = 0.0f64 / 0.0;
+    assert!(x.is_nan());
+    assert!(x != x);
+    assert!(!(x < 1.0) && !(x >= 1.0));
+}
+
+fn signed_zeros_are_equal() {
+    let x = 0.0f32;
+    let y = -0.0f32;
+    assert!(x == y);
+}
+
+fn casts_between_floats(x: f32) {
+    let y = x as f64;
+    if x.is_nan() {
+        assert!(y.is_nan());
+    } else {
+        assert!(y as f32 == x);
+    }
+}
+
+fn casts_from_integers(x: u8) {
+    let y = x as f32;
+    assert!(y >= 0.0 && y <= 255.0);
+    assert!(y as u8 == x);
+}
+
+fn saturating_casts() {
+    let x = 1e10f64;
+    assert!(x as i32 == i32::MAX);
+    assert!(-x as u8 == 0);
+    let nan = f64::NAN;
+    assert!(nan as i64 == 0);
+    assert!(2.9f32 as i32 == 2);
+    assert!(-2.9f32 as i32 == -2);
+}
+
+#[requires(x.is_finite())]
+#[ensures(result.is_finite())]
+fn halve(x: f64) -> f64 {
+    x / 2.0
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use prusti_contracts::*;
+
+type Map = prusti_contracts::Map<u32, i32>;
+
+fn map_len() {
+    prusti_assert!(Map::empty().len() == Int::new(0));
+    prusti_assert!(map![1 => 10, 2 => 20].len() == Int::new(2));
+    prusti_assert!(map![1 => 10, 1 => 20].len() == Int::new(1));
+}
+
+fn map_lookup() {
+    prusti_assert!(map![1 => 10, 2 => 20].lookup(1) == 10);
+    prusti_assert!(map![1 => 10, 1 => 20].lookup(1) == 20);
+    prusti_assert!(map![1 => 10].contains(1) && !map![1 => 10].contains(2));
+}
+
+fn map_equality() {
+    prusti_assert!(map![1 => 10, 2 => 20] === map![2 => 20, 1 => 10]);
+    prusti_assert!(map![1 => 10].delete(1) === Map::empty());
+}
+
+#[pure]
+#[ensures(!result.contains(k))]
+#[ensures(m.contains(k) ==> result.len() == m.len() - Int::new(1))]
+#[ensures(forall(|k2: u32| k2 != k && m.contains(k2) ==> result.lookup(k2) == m.lookup(k2)))]
+fn remove(m: Map, k: u32) -> Map {
+    m.delete(k)
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use prusti_contracts::*;
+
+type Seq = prusti_contracts::Seq<u32>;
+
+fn seq_len() {
+    prusti_assert!(Seq::empty().len() == Int::new(0));
+    prusti_assert!(seq![1, 2, 2].len() == Int::new(3));
+}
+
+fn seq_lookup() {
+    prusti_assert!(seq![1, 2, 3].lookup(1) == 2);
+    prusti_assert!(seq![1, 2] === seq![1].concat(seq![2]));
+}
+
+#[pure]
+#[requires(Int::new_usize(i) < s.len())]
+#[ensures(result.len() == s.len())]
+#[ensures(result.lookup(i) == v)]
+#[ensures(forall(|j: usize| (Int::new_usize(j) < s.len() && j != i) ==> result.lookup(j) == s.lookup(j)))]
+fn update(s: Seq, i: usize, v: u32) -> Seq {
+    s.update(i, v)
+}
+
+#[pure]
+#[requires(Int::new_usize(n) <= s.len())]
+#[ensures(result === s)]
+fn split_and_join(s: Seq, n: usize) -> Seq {
+    s.take(n).concat(s.skip(n))
+}
+
+fn seq_subsequence() {
+    prusti_assert!(seq![1, 2, 3, 4].subsequence(1, 3) === seq![2, 3]);
+    prusti_assert!(seq![1, 2, 3].take(2) === seq![1, 2]);
+    prusti_assert!(seq![1, 2, 3].skip(2) === seq![3]);
+}
+
+fn seq_search() {
+    prusti_assert!(seq![1, 2, 3].contains(&2));
+    prusti_assert!(!seq![1, 2, 3].contains(&4));
+    prusti_assert!(seq![1, 2, 2].index_of(&2) == Int::new(1));
+    prusti_assert!(seq![1, 2, 3].index_of(&4) == Int::new(-1));
+}
+
+#[pure]
+#[ensures(result.len() == Int::new_usize(s.len()))]
+#[ensures(forall(|i: usize| i < s.len() ==> result.lookup(i) == s[i]))]
+fn from_slice(s: &[u32]) -> Seq {
+    Seq::from_slice(s)
+}
+
+fn main() {}
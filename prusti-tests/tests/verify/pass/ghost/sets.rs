@@ -0,0 +1,44 @@
+use prusti_contracts::*;
+
+type Set = prusti_contracts::Set<u32>;
+type Multiset = prusti_contracts::Multiset<u32>;
+
+fn set_len() {
+    prusti_assert!(Set::empty().len() == Int::new(0));
+    prusti_assert!(set![1, 2, 2].len() == Int::new(2));
+}
+
+fn set_equality() {
+    prusti_assert!(set![1, 2] === set![2, 1]);
+    prusti_assert!(set![1, 1] === set![1]);
+}
+
+#[pure]
+#[ensures(result.contains(v))]
+#[ensures(s.contains(v) ==> result.len() == s.len())]
+#[ensures(!s.contains(v) ==> result.len() == s.len() + Int::new(1))]
+fn insert(s: Set, v: u32) -> Set {
+    s.union(Set::single(v))
+}
+
+#[pure]
+#[ensures(result.is_subset(s1) && result.is_subset(s2))]
+#[ensures(!result.contains(v))]
+fn common_without(s1: Set, s2: Set, v: u32) -> Set {
+    s1.intersection(s2).difference(Set::single(v))
+}
+
+#[pure]
+#[ensures(result.count(v) == m.count(v) + Int::new(1))]
+#[ensures(result.len() == m.len() + Int::new(1))]
+fn add(m: Multiset, v: u32) -> Multiset {
+    m.union(Multiset::single(v))
+}
+
+fn multiset_count() {
+    prusti_assert!(multiset![1, 2, 1].count(1) == Int::new(2));
+    prusti_assert!(multiset![1, 2, 1].len() == Int::new(3));
+    prusti_assert!(multiset![1, 2] === multiset![2, 1]);
+}
+
+fn main() {}
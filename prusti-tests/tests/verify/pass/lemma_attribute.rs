@@ -0,0 +1,54 @@
+// compile-flags: -Punsafe_core_proof=true
+#![allow(unused)]
+use prusti_contracts::*;
+
+#[pure]
+#[terminates(Int::new(x))]
+#[requires(x >= 0)]
+fn add_3(x: i64) -> i64 {
+    if x == 0 {
+        0
+    } else {
+        assert!(x - 1 < x && x - 1 >= 0);
+        3 + add_3(x - 1)
+    }
+}
+
+#[lemma]
+#[terminates(Int::new(x))]
+#[requires(x >= 0)]
+#[ensures((x % 2 == 0) == (add_3(x) % 2 == 0))]
+fn add_3_parity(x: i64) {
+    if x > 0 {
+        add_3_parity(x - 1);
+        if x % 2 == 0 {
+            prusti_assert!((x - 1) % 2 == 1);
+            prusti_assert!((add_3(x) % 2 == 0));
+        } else {
+            prusti_assert!(x % 2 == 1);
+            prusti_assert!((x - 1) % 2 == 0);
+            prusti_assert!((add_3(x) % 2 == 1));
+        }
+    } else {
+        prusti_assert!(add_3(x) % 2 == 0);
+    }
+}
+
+#[lemma]
+#[terminates]
+#[requires(x >= 0)]
+#[ensures(add_3(2 * x) % 2 == 0)]
+fn add_3_even(x: i64) {
+    add_3_parity(2 * x);
+}
+
+#[requires(x >= 0)]
+fn foo(x: i64) {
+    let z = add_3(2 * x);
+    ghost! {
+        add_3_even(x);
+    };
+    assert!(z % 2 == 0);
+}
+
+fn main() {}
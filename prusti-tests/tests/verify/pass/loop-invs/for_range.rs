@@ -0,0 +1,45 @@
+use prusti_contracts::*;
+use std::ops::Range;
+
+#[requires(n <= 100)]
+#[ensures(result == n)]
+fn count(n: usize) -> usize {
+    let mut count = 0;
+    for i in 0..n {
+        body_invariant!(i < n);
+        body_invariant!(count == i);
+        body_invariant!(loop_iterator!(Range<usize>).start == i + 1);
+        body_invariant!(loop_iterator!(Range<usize>).end == n);
+        count += 1;
+    }
+    count
+}
+
+#[requires(n <= 100)]
+#[ensures(result == 2 * n)]
+fn double(n: u32) -> u32 {
+    let mut result = 0;
+    for i in 1..n + 1 {
+        body_invariant!(1 <= i && i <= n);
+        body_invariant!(result == 2 * (i - 1));
+        body_invariant!(loop_iterator!(Range<u32>).start == i + 1);
+        body_invariant!(loop_iterator!(Range<u32>).end == n + 1);
+        result += 2;
+    }
+    result
+}
+
+fn next_of_empty_range() {
+    let mut range = 5..5;
+    assert!(range.next().is_none());
+    assert!(range.start == 5);
+}
+
+fn next_of_range() {
+    let mut range = 3..5;
+    assert!(range.next() == Some(3));
+    assert!(range.next() == Some(4));
+    assert!(range.next().is_none());
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use prusti_contracts::*;
+
+fn clear(grid: &mut [[i32; 3]; 3]) {
+    let mut i = 0;
+    while i < 3 {
+        body_invariant!(i < 3);
+        let mut j = 0;
+        while j < 3 {
+            body_invariant!(i < 3 && j < 3);
+            let cell = &mut grid[i][j];
+            *cell = 0;
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+fn last_positive(grid: &mut [[i32; 3]; 3]) -> i32 {
+    let mut found = 0;
+    let mut i = 0;
+    while i < 3 {
+        body_invariant!(i < 3);
+        let row = &mut grid[i];
+        let mut j = 0;
+        while j < 3 {
+            body_invariant!(j < 3);
+            let cell = &mut row[j];
+            if *cell > 0 {
+                found = *cell;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    found
+}
+
+fn main() {
+    let mut grid = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    last_positive(&mut grid);
+    clear(&mut grid);
+}
@@ -0,0 +1,48 @@
+use prusti_contracts::*;
+
+#[requires(l == a.len())]
+fn foo(a: &mut [i32], l: usize) {}
+
+fn bar(a: &mut [i32]) {
+    foo(a, a.len());
+}
+
+struct Counter {
+    value: u32,
+}
+
+impl Counter {
+    #[pure]
+    fn get(&self) -> u32 {
+        self.value
+    }
+
+    #[requires(self.value <= 1000 && amount <= 1000)]
+    #[ensures(self.value == old(self.value) + amount)]
+    fn add(&mut self, amount: u32) {
+        self.value += amount;
+    }
+}
+
+#[requires(counter.value <= 1000)]
+#[ensures(counter.value == 2 * old(counter.value))]
+fn double(counter: &mut Counter) {
+    counter.add(counter.get());
+}
+
+// The borrowed place is read on both branches before the borrow is activated.
+#[requires(counter.value <= 1000)]
+#[ensures(counter.value <= 2 * old(counter.value) + 1)]
+fn double_or_increment(counter: &mut Counter) {
+    counter.add(if counter.get() > 10 {
+        counter.get()
+    } else {
+        1
+    });
+}
+
+fn main() {
+    let mut counter = Counter { value: 3 };
+    counter.add(counter.value);
+    assert!(counter.value == 6);
+}
@@ -0,0 +1,31 @@
+/// From the serde crate
+
+fn iterator_len_hint<I>(iter: &I) -> Option<usize>
+    where
+        I: Iterator,
+{
+    match iter.size_hint() {
+        (lo, Some(hi)) if lo == hi => Some(lo),
+        _ => None,
+    }
+}
+
+fn positive_or_zero(x: &Option<i32>) -> i32 {
+    match *x {
+        Some(v) if v > 0 => v,
+        _ => 0,
+    }
+}
+
+fn clear_if_small(x: &mut Option<u32>) {
+    match x {
+        Some(v) if *v < 10 => *v = 0,
+        _ => {}
+    }
+}
+
+fn main() {
+    let mut x = Some(3);
+    clear_if_small(&mut x);
+    positive_or_zero(&Some(2));
+}
@@ -0,0 +1,40 @@
+use prusti_contracts::*;
+
+fn null_pointers() {
+    let p: *const i32 = std::ptr::null();
+    assert!(p.is_null());
+    let q: *mut u8 = std::ptr::null_mut();
+    assert!(q.is_null());
+    let r: *const u8 = q;
+    assert!(r.is_null());
+}
+
+fn read(x: i32) {
+    let p = &x as *const i32;
+    assert!(!p.is_null());
+    let y = unsafe { *p };
+    assert!(y == x);
+}
+
+fn write() {
+    let mut x = 5u32;
+    let p = &mut x as *mut u32;
+    unsafe {
+        *p = 7;
+    }
+    let y = unsafe { *p };
+    assert!(y == 7);
+}
+
+fn aliases() {
+    let mut x = true;
+    let p = std::ptr::addr_of_mut!(x);
+    let q = p as *const bool;
+    assert!(p as *const bool == q);
+    unsafe {
+        *p = false;
+    }
+    assert!(!unsafe { *q });
+}
+
+fn main() {}
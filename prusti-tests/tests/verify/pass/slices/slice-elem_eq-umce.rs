@@ -14,9 +14,8 @@ fn slice(a: &[i32]) {
     assert!(s[2] == a[3]);
     let s = &a[..];
     assert!(s[3] == a[3]);
-    // Unsupported
-    //let s = &a[1..=4];
-    //assert!(s[3] == a[4]);
+    let s = &a[1..=4];
+    assert!(s[3] == a[4]);
     let s = &a[..=5];
     assert!(s[5] == a[5]);
 }
@@ -12,9 +12,8 @@ fn slice(a: &[i32]) {
     assert!(s.len() == a.len()-1);
     let s = &a[..];
     assert!(s.len() == a.len());
-    // Unsupported
-    //let s = &a[1..=4];
-    //assert!(s.len() == 4);
+    let s = &a[1..=4];
+    assert!(s.len() == 4);
     let s = &a[..=4];
     assert!(s.len() == 5);
 }
@@ -0,0 +1,73 @@
+use prusti_contracts::*;
+
+fn main() {}
+
+#[pure]
+#[requires(s.len() > 0)]
+fn first(s: &[i32]) -> i32 {
+    match s {
+        [head, ..] => *head,
+        [] => unreachable!(),
+    }
+}
+
+#[pure]
+#[requires(s.len() > 0)]
+fn last(s: &[i32]) -> i32 {
+    match s {
+        [.., tail] => *tail,
+        [] => unreachable!(),
+    }
+}
+
+#[requires(s.len() >= 2)]
+fn first_and_last(s: &[i32]) {
+    let x = first(s);
+    let y = last(s);
+    assert!(x == s[0]);
+    assert!(y == s[s.len() - 1]);
+}
+
+#[pure]
+fn rest_len(s: &[i32]) -> usize {
+    match s {
+        [_, rest @ ..] => rest.len(),
+        [] => 0,
+    }
+}
+
+#[ensures(s.len() > 0 ==> result == s.len() - 1)]
+fn rest_len_impure(s: &[i32]) -> usize {
+    match s {
+        [_, rest @ ..] => rest.len(),
+        [] => 0,
+    }
+}
+
+#[requires(s.len() >= 3)]
+fn middle(s: &[i32]) {
+    if let [a, mid @ .., b] = s {
+        assert!(mid.len() == s.len() - 2);
+        assert!(mid[0] == s[1]);
+        assert!(*a == s[0]);
+        assert!(*b == s[s.len() - 1]);
+    }
+}
+
+#[requires(s.len() > 4)]
+fn inclusive_ranges(s: &[i32]) {
+    let t = &s[1..=3];
+    assert!(t.len() == 3);
+    assert!(t[2] == s[3]);
+    let r = 2..=4;
+    let u = &s[r];
+    assert!(u[0] == s[2]);
+}
+
+#[pure]
+#[requires(s.len() > 4)]
+#[ensures(result == s[3])]
+fn inclusive_pure(s: &[i32]) -> i32 {
+    let t = &s[2..=4];
+    t[1]
+}
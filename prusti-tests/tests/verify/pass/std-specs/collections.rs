@@ -0,0 +1,48 @@
+extern crate prusti_std;
+use prusti_contracts::*;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+fn hash_map() {
+    let mut map = HashMap::new();
+    assert!(map.is_empty());
+    assert!(map.insert(1, 10).is_none());
+    assert!(map.contains_key(&1));
+    assert!(map.len() == 1);
+    assert!(map.insert(1, 20).is_some());
+    assert!(map.len() == 1);
+    assert!(map.get(&1).is_some());
+    assert!(map.remove(&1).is_some());
+    assert!(!map.contains_key(&1));
+    assert!(map.is_empty());
+}
+
+fn btree_map() {
+    let mut map = BTreeMap::new();
+    map.insert(1, 10);
+    map.insert(2, 20);
+    assert!(map.len() == 2);
+    map.clear();
+    assert!(map.get(&1).is_none());
+}
+
+fn hash_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(1));
+    assert!(!set.insert(1));
+    assert!(set.len() == 1);
+    assert!(set.remove(&1));
+    assert!(set.is_empty());
+}
+
+fn vec_deque() {
+    let mut queue = VecDeque::new();
+    queue.push_back(1);
+    queue.push_front(0);
+    assert!(queue.len() == 2);
+    assert!(queue.pop_front().is_some());
+    assert!(queue.pop_back().is_some());
+    assert!(queue.pop_back().is_none());
+    assert!(queue.is_empty());
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+extern crate prusti_std;
+use prusti_contracts::*;
+use prusti_std::models::*;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+fn hash_map_get() {
+    let mut map = HashMap::new();
+    map.insert(1, 10);
+    map.insert(2, 20);
+    assert!(*map.get(&2).unwrap() == 20);
+    map.insert(2, 30);
+    assert!(*map.get(&2).unwrap() == 30);
+}
+
+fn btree_map_get() {
+    let mut map = BTreeMap::new();
+    map.insert(1, 10);
+    assert!(*map.get(&1).unwrap() == 10);
+}
+
+fn vec_deque_ends() {
+    let mut queue = VecDeque::new();
+    queue.push_back(1);
+    assert!(*queue.back().unwrap() == 1);
+    queue.push_front(0);
+    assert!(*queue.front().unwrap() == 0);
+}
+
+#[requires(map.model().entries.len() == Int::new(0))]
+#[ensures(map.len() == 1)]
+fn insert_into_empty_map(map: &mut HashMap<u32, u32>) {
+    map.insert(1, 10);
+}
+
+#[requires(queue.model().elements.len() == Int::new(0))]
+#[ensures(queue.len() == 1)]
+fn push_to_empty_queue(queue: &mut VecDeque<u32>) {
+    queue.push_back(1);
+}
+
+fn min_max(a: u32, b: u32) {
+    let m = std::cmp::min(a, b);
+    assert!(m <= a && m <= b);
+    let n = std::cmp::max(a, b);
+    assert!(n >= a && n >= b);
+    assert!(std::cmp::min(3, 3) == 3);
+}
+
+fn main() {}
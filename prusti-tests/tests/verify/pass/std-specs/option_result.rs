@@ -0,0 +1,32 @@
+extern crate prusti_std;
+use prusti_contracts::*;
+
+#[requires(x.is_some())]
+#[ensures(old(x) === Some(result))]
+fn unwrap(x: Option<i32>) -> i32 {
+    x.unwrap()
+}
+
+fn option() {
+    let mut x = Some(3);
+    assert!(x.is_some());
+    let y = x.take();
+    assert!(x.is_none());
+    assert!(y.unwrap() == 3);
+    let z = x.replace(5);
+    assert!(z.is_none());
+    assert!(x.unwrap_or(7) == 5);
+    assert!(x.ok_or(1).is_ok());
+}
+
+fn result() {
+    let x: Result<i32, u32> = Ok(3);
+    assert!(x.is_ok());
+    assert!(x.ok().is_some());
+    assert!(x.unwrap_or(4) == 3);
+    let y: Result<i32, u32> = Err(2);
+    assert!(y.err().is_some());
+    assert!(y.unwrap_err() == 2);
+}
+
+fn main() {}
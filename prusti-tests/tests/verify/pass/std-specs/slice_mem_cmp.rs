@@ -0,0 +1,32 @@
+extern crate prusti_std;
+use prusti_contracts::*;
+use std::cmp::Ordering;
+
+#[requires(a.len() == 3 && a[0] == 1 && a[2] == 3)]
+#[ensures(a[0] == 3 && a[2] == 1)]
+fn swap_ends(a: &mut [i32]) {
+    assert!(!a.is_empty());
+    assert!(a.first().is_some());
+    a.swap(0, 2);
+}
+
+fn mem() {
+    let mut a = 1;
+    let mut b = 2;
+    std::mem::swap(&mut a, &mut b);
+    assert!(a == 2 && b == 1);
+    let c = std::mem::replace(&mut a, 5);
+    assert!(c == 2 && a == 5);
+    let d = std::mem::take(&mut b);
+    assert!(d == 1);
+}
+
+fn cmp(a: u32, b: u32) {
+    let m = std::cmp::min(a, b);
+    assert!(m == a || m == b);
+    let o = Ordering::Less;
+    assert!(o.is_lt() && o.is_le() && o.is_ne());
+    assert!(o.reverse().is_gt());
+}
+
+fn main() {}
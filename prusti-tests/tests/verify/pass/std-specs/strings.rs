@@ -0,0 +1,77 @@
+extern crate prusti_std;
+use prusti_contracts::*;
+use prusti_std::models::*;
+
+fn building() {
+    let mut s = String::new();
+    assert!(s.is_empty());
+    s.push_str("ab");
+    s.push_str("c");
+    assert!(s.len() == 3);
+    assert!(s.as_bytes()[2] == b'c');
+    let t = s + "de";
+    assert!(t.len() == 5);
+    assert!(t.starts_with("abc"));
+}
+
+fn conversions() {
+    let s = String::from("key");
+    assert!(s.len() == 3);
+    let t = "key".to_string();
+    assert!(t.as_bytes()[0] == s.as_bytes()[0]);
+    let u: &str = &t;
+    assert!(u.len() == 3);
+}
+
+fn chars() {
+    assert!("abc".chars().count() == 3);
+    let n = "päd".chars().count();
+    assert!(n >= 1 && n <= 4);
+    assert!("é".is_char_boundary(0) && !"é".is_char_boundary(1));
+}
+
+/// An identifier is a non-empty ASCII string that does not start with a digit.
+#[ensures(result ==> name.len() > 0)]
+#[ensures(result ==> forall(|j: usize| j < name.len() ==> name.as_bytes()[j] < 128))]
+fn is_identifier(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    if bytes.is_empty() || (bytes[0] >= b'0' && bytes[0] <= b'9') {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        body_invariant!(i < bytes.len());
+        body_invariant!(forall(|j: usize| j < i ==> bytes[j] < 128));
+        if bytes[i] >= 128 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[requires(word.model().chars.len() == Int::new(2))]
+#[ensures(result == 2)]
+fn char_count(word: &str) -> usize {
+    word.chars().count()
+}
+
+#[requires(!word.is_empty())]
+#[ensures(result > 0)]
+fn non_empty_char_count(word: &str) -> usize {
+    let n = word.chars().count();
+    prusti_assert!(word.model().chars.len() != Int::new(0));
+    n
+}
+
+fn identifier_length(name: &str) -> usize {
+    if is_identifier(name) {
+        let n = name.chars().count();
+        assert!(n == name.len());
+        n
+    } else {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use prusti_contracts::*;
+
+fn literals() {
+    let s = "abc";
+    assert!(s.len() == 3);
+    assert!(s.as_bytes()[0] == b'a');
+    assert!(s.as_bytes()[2] == 99);
+    assert!("".len() == 0);
+    assert!("é".len() == 2);
+}
+
+fn prefixes() {
+    let s = "--verbose";
+    assert!(s.starts_with("--"));
+    assert!(s.starts_with(""));
+    assert!(!s.starts_with("-v"));
+}
+
+#[pure]
+fn is_flag(arg: &str) -> bool {
+    arg.starts_with("--") && arg.len() > 2
+}
+
+#[requires(is_flag(arg))]
+#[ensures(result == arg.as_bytes()[2])]
+fn first_flag_byte(arg: &str) -> u8 {
+    arg.as_bytes()[2]
+}
+
+#[pure]
+fn byte_len(s: &String) -> usize {
+    s.as_str().len()
+}
+
+#[ensures(result == byte_len(s))]
+fn same_len(s: &String) -> usize {
+    s.len()
+}
+
+fn main() {
+    assert!(is_flag("--help"));
+    assert!(first_flag_byte("--help") == b'h');
+}
@@ -0,0 +1,83 @@
+use prusti_contracts::*;
+
+trait Counter {
+    #[ensures(result >= 0)]
+    fn count(&self) -> i32;
+
+    #[requires(n >= 0)]
+    #[ensures(result >= n)]
+    fn at_least(&self, n: i32) -> i32;
+
+    #[requires(n >= 0)]
+    #[ensures(result >= n)]
+    fn advance(&mut self, n: i32) -> i32;
+}
+
+struct Zero;
+
+impl Counter for Zero {
+    fn count(&self) -> i32 {
+        0
+    }
+
+    fn at_least(&self, n: i32) -> i32 {
+        n
+    }
+
+    fn advance(&mut self, n: i32) -> i32 {
+        n
+    }
+}
+
+struct Five;
+
+#[refine_trait_spec]
+impl Counter for Five {
+    #[ensures(result == 5)]
+    fn count(&self) -> i32 {
+        5
+    }
+
+    #[requires(true)]
+    #[ensures(result >= n && result >= 5)]
+    fn at_least(&self, n: i32) -> i32 {
+        if n > 5 {
+            n
+        } else {
+            5
+        }
+    }
+
+    fn advance(&mut self, n: i32) -> i32 {
+        if n > 5 {
+            n
+        } else {
+            5
+        }
+    }
+}
+
+#[ensures(result >= 0)]
+fn count(counter: &dyn Counter) -> i32 {
+    counter.count()
+}
+
+#[ensures(result >= 3)]
+fn at_least_three(counter: Box<dyn Counter>) -> i32 {
+    counter.at_least(3)
+}
+
+#[ensures(result >= 2)]
+fn advance_boxed(mut counter: Box<dyn Counter>) -> i32 {
+    counter.advance(1);
+    counter.advance(2)
+}
+
+fn main() {
+    let zero = Zero;
+    let five = Five;
+    assert!(count(&zero) >= 0);
+    assert!(count(&five) >= 0);
+    assert!(at_least_three(Box::new(Five)) >= 3);
+    assert!(advance_boxed(Box::new(Zero)) >= 2);
+}
@@ -0,0 +1,69 @@
+use prusti_contracts::*;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union IntOrFloat {
+    int: u32,
+    float: f32,
+}
+
+#[derive(Clone, Copy)]
+struct Pair {
+    first: i32,
+    second: i32,
+}
+
+#[repr(C)]
+union Wrapper {
+    pair: Pair,
+    raw: u64,
+}
+
+fn read_active() {
+    let x = IntOrFloat { int: 5 };
+    let y = unsafe { x.int };
+    assert!(y == 5);
+}
+
+fn switch_active_field() {
+    let mut x = IntOrFloat { float: 1.0 };
+    x.int = 3;
+    let y = unsafe { x.int };
+    assert!(y == 3);
+    x.int = 4;
+    assert!(unsafe { x.int } == 4);
+}
+
+fn nested_fields() {
+    let mut w = Wrapper {
+        pair: Pair {
+            first: 1,
+            second: 2,
+        },
+    };
+    unsafe {
+        w.pair.second = 5;
+    }
+    let pair = unsafe { w.pair };
+    assert!(pair.first == 1);
+    assert!(pair.second == 5);
+}
+
+#[ensures(is_active_union_field!(result.int))]
+#[ensures(unsafe { result.int } == value)]
+fn make_int(value: u32) -> IntOrFloat {
+    IntOrFloat { int: value }
+}
+
+#[requires(is_active_union_field!(x.int))]
+#[ensures(result == unsafe { x.int })]
+fn get_int(x: IntOrFloat) -> u32 {
+    unsafe { x.int }
+}
+
+fn contracts() {
+    let x = make_int(7);
+    assert!(get_int(x) == 7);
+}
+
+fn main() {}
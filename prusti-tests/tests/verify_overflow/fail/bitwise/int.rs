@@ -0,0 +1,29 @@
+use prusti_contracts::*;
+
+fn wrong_and() {
+    let a = 3u8;
+    let b = a & 2;
+    assert!(b == 3); //~ ERROR: the asserted expression might not hold
+}
+
+fn wrong_shift() {
+    let a = -1i8;
+    let b = a >> 2u32;
+    assert!(b == 63); //~ ERROR: the asserted expression might not hold
+}
+
+fn shift_too_far(x: u16, shift: u32) -> u16 {
+    x << shift //~ ERROR: assertion might fail with "attempt to shift left with overflow"
+}
+
+#[requires(shift <= 32)]
+fn shift_right_too_far(x: u32, shift: u32) -> u32 {
+    x >> shift //~ ERROR: assertion might fail with "attempt to shift right with overflow"
+}
+
+#[ensures(result <= x)] //~ ERROR postcondition might not hold
+fn or_is_smaller(x: u8, y: u8) -> u8 {
+    x | y
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+// compile-flags: -Punsafe_core_proof=true
+
+use prusti_contracts::*;
+
+fn bit_and(a: u8, b: u8) -> u8 {
+    a & b //~ ERROR: bitwise operation
+}
+
+fn main() {}
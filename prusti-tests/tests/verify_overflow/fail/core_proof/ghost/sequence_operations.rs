@@ -0,0 +1,65 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+type Seq = prusti_contracts::Seq<u32>;
+
+fn update() {
+    let seq = seq![1, 2, 3];
+    prusti_assert!(seq.update(1, 5) == seq![1, 5, 3]);
+    prusti_assert!(seq.update(1, 5).len() == seq.len());
+    prusti_assert!(seq.update(0, 5) == seq); //~ ERROR: the asserted expression might not hold
+}
+
+fn take_and_skip() {
+    let seq = seq![1, 2, 3, 4];
+    prusti_assert!(seq.take(2) == seq![1, 2]);
+    prusti_assert!(seq.skip(2) == seq![3, 4]);
+    prusti_assert!(seq.take(2).concat(seq.skip(2)) == seq);
+    prusti_assert!(seq.subsequence(1, 3) == seq![2, 3]);
+    prusti_assert!(seq.subsequence(1, 3).len() == Int::new(3)); //~ ERROR: the asserted expression might not hold
+}
+
+fn contains(seq: Seq, v: u32) {
+    prusti_assert!(seq![1, 2, 3].contains(&2));
+    prusti_assert!(!seq![1, 2, 3].contains(&4));
+    prusti_assert!(seq.concat(Seq::single(v)).contains(&v));
+    prusti_assert!(seq.contains(&v)); //~ ERROR: the asserted expression might not hold
+}
+
+fn index_of(seq: Seq, v: u32) {
+    prusti_assert!(seq![1, 2, 1].index_of(&1) == Int::new(0));
+    prusti_assert!(seq![1, 2, 1].index_of(&2) == Int::new(1));
+    prusti_assert!(seq![1, 2, 1].index_of(&3) == Int::new(-1));
+    prusti_assert!(seq.index_of(&v) >= Int::new(0)); //~ ERROR: the asserted expression might not hold
+}
+
+#[ensures(Seq::from_slice(s).len() == Int::new_usize(s.len()))]
+fn from_slice_len(s: &[u32]) {}
+
+#[requires(s.len() > 0)]
+#[ensures(Seq::from_slice(s)[0] == s[0])]
+fn from_slice_lookup(s: &[u32]) {}
+
+#[ensures(Seq::from_slice(s).contains(&0))] //~ ERROR: postcondition might not hold.
+fn from_slice_fail(s: &[u32]) {}
+
+struct NonCopy {
+    value: u32,
+}
+
+fn non_copy_elements(seq: prusti_contracts::Seq<NonCopy>) {
+    prusti_assert!(seq.take(1).len() <= Int::new(1));
+    prusti_assert!(seq.len() == Int::new(0)); //~ ERROR: the asserted expression might not hold
+}
+
+#[requires(seq.contains(value))]
+#[ensures(seq.index_of(value) >= Int::new(0))]
+fn non_copy_contains(seq: prusti_contracts::Seq<NonCopy>, value: &NonCopy) {}
+
+#[ensures(seq.contains(value))] //~ ERROR: postcondition might not hold.
+fn non_copy_contains_fail(seq: prusti_contracts::Seq<NonCopy>, value: &NonCopy) {}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+type Set = prusti_contracts::Set<u32>;
+type Multiset = prusti_contracts::Multiset<u32>;
+
+fn should_fail() {
+    prusti_assert!(false); //~ ERROR: the asserted expression might not hold
+}
+
+fn set_len() {
+    prusti_assert!(Set::empty().len() == Int::new(0));
+    prusti_assert!(set![1, 2, 2].len() == Int::new(2));
+    prusti_assert!(set![1, 2].len() == Int::new(1)); //~ ERROR: the asserted expression might not hold
+}
+
+fn set_equality() {
+    prusti_assert!(set![1, 2] == set![2, 1]);
+    prusti_assert!(set![1, 1] == set![1]);
+    prusti_assert!(set![1] == Set::empty()); //~ ERROR: the asserted expression might not hold
+}
+
+fn set_operations(s1: Set, s2: Set, v: u32) {
+    prusti_assert!(Set::single(v).contains(v));
+    prusti_assert!(s1.union(Set::single(v)).contains(v));
+    prusti_assert!(!s1.difference(Set::single(v)).contains(v));
+    prusti_assert!(s1.intersection(s2).is_subset(s1));
+    prusti_assert!(s1.is_subset(s1.union(s2)));
+    prusti_assert!(s1.union(s2).is_subset(s1)); //~ ERROR: the asserted expression might not hold
+}
+
+fn multiset_count(m: Multiset, v: u32) {
+    prusti_assert!(multiset![1, 2, 1].count(1) == Int::new(2));
+    prusti_assert!(multiset![1, 2, 1].len() == Int::new(3));
+    prusti_assert!(m.union(Multiset::single(v)).count(v) == m.count(v) + Int::new(1));
+    prusti_assert!(multiset![1, 2] == multiset![1, 2, 2]); //~ ERROR: the asserted expression might not hold
+}
+
+fn sequence_to_multiset() {
+    prusti_assert!(seq![1u32, 2, 1].to_multiset().count(1) == Int::new(2));
+    prusti_assert!(seq![1u32, 2].to_multiset() == multiset![2, 1]);
+    prusti_assert!(seq![1u32, 2].to_multiset().len() == Int::new(3)); //~ ERROR: the asserted expression might not hold
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// compile-flags: -Punsafe_core_proof=true
+
+use prusti_contracts::*;
+fn main() {}
+
+fn call<F: Fn(&i32) -> bool>(keep: F, x: &i32) -> bool {
+    keep(x)
+}
+fn call_assert_false<F: Fn(&i32) -> bool>(keep: F, x: &i32) -> bool {
+    let result = keep(x);
+    assert!(false);      //~ ERROR: the asserted expression might not hold
+    result
+}
+
+fn call_twice<F: for<'a> Fn(&'a i32) -> bool>(keep: F) -> bool {
+    let a = 1;
+    let b = 2;
+    keep(&a) && keep(&b)
+}
+fn call_twice_assert_false<F: for<'a> Fn(&'a i32) -> bool>(keep: F) -> bool {
+    let a = 1;
+    let b = 2;
+    let result = keep(&a) && keep(&b);
+    assert!(false);      //~ ERROR: the asserted expression might not hold
+    result
+}
+
+// `'a` is early-bound because of the `T: 'a` bound, while `'b` is late-bound.
+fn f7<'a, 'b, T: 'a>(x: &'a mut T, y: &'b mut i32) -> &'a mut T {
+    *y = 3;
+    x
+}
+fn function_call_mixed_lifetimes() {
+    let mut a = 1;
+    let mut b = 2;
+    let aa = f7(&mut a, &mut b);
+    *aa = 4;
+    b = 5;
+}
+fn function_call_mixed_lifetimes_assert_false() {
+    let mut a = 1;
+    let mut b = 2;
+    let aa = f7(&mut a, &mut b);
+    *aa = 4;
+    b = 5;
+    assert!(false);      //~ ERROR: the asserted expression might not hold
+}
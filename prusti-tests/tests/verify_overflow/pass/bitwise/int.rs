@@ -0,0 +1,48 @@
+use prusti_contracts::*;
+
+#[pure]
+fn low_nibble(x: u8) -> u8 {
+    x & 0x0f
+}
+
+#[pure]
+#[requires(shift < 32)]
+fn bit(x: u32, shift: u32) -> u32 {
+    (x >> shift) & 1
+}
+
+#[ensures(result == a ^ b)]
+fn xor(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+fn constants() {
+    assert!(3u8 & 2 == 2);
+    assert!(5u16 | 2 == 7);
+    assert!(6u32 ^ 3 == 5);
+    assert!(1u64 << 3u32 == 8);
+    assert!(0x80u8 >> 7u32 == 1);
+    assert!(-8i32 >> 1u32 == -4);
+    assert!(-1i8 & 0x7f == 127);
+    assert!(1i16 << 15u32 == i16::MIN);
+}
+
+fn pure_calls() {
+    assert!(low_nibble(0xab) == 0x0b);
+    assert!(bit(4, 2) == 1);
+    assert!(bit(4, 1) == 0);
+}
+
+#[requires(flags & 1 == 1)]
+#[ensures(result & 1 == 0)]
+fn clear_lowest(flags: u32) -> u32 {
+    flags ^ 1
+}
+
+#[requires(shift < 64)]
+#[ensures(result == x >> shift)]
+fn shift_right(x: i64, shift: u32) -> i64 {
+    x >> shift
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+type Set = prusti_contracts::Set<u32>;
+type Multiset = prusti_contracts::Multiset<u32>;
+
+struct Tracked {
+    seen: Set,
+    counts: Multiset,
+    last: u32,
+}
+
+fn local_sets(v: u32) {
+    let mut s = Set::empty();
+    s = s.union(Set::single(v));
+    let m = Multiset::single(v);
+    prusti_assert!(s.contains(v));
+    prusti_assert!(m.count(v) == Int::new(1));
+}
+
+fn sets_in_fields(t: &mut Tracked, v: u32) {
+    t.seen = t.seen.union(Set::single(v));
+    t.counts = t.counts.union(Multiset::single(v));
+    t.last = v;
+    prusti_assert!(t.seen.contains(t.last));
+    prusti_assert!(t.counts.count(v) >= Int::new(1));
+}
+
+fn sets_behind_references(s: &Set, m: &mut Multiset, v: u32) {
+    *m = m.union(Multiset::single(v));
+    prusti_assert!(s.union(Set::single(v)).contains(v));
+    prusti_assert!(m.count(v) >= Int::new(1));
+}
+
+fn main() {}
@@ -80,8 +80,10 @@ lazy_static::lazy_static! {
         settings.set_default("check_foldunfold_state", false).unwrap();
         settings.set_default("check_overflows", true).unwrap();
         settings.set_default("check_panics", true).unwrap();
+        settings.set_default("check_pure_function_termination", false).unwrap();
         settings.set_default("encode_unsigned_num_constraint", true).unwrap();
         settings.set_default("encode_bitvectors", false).unwrap();
+        settings.set_default("encode_ieee_floats", false).unwrap();
         settings.set_default("simplify_encoding", true).unwrap();
         settings.set_default("log", "").unwrap();
         settings.set_default("log_style", "auto").unwrap();
@@ -112,6 +114,7 @@ lazy_static::lazy_static! {
         settings.set_default("opt_in_verification", false).unwrap();
         settings.set_default("full_compilation", false).unwrap();
         settings.set_default("json_communication", false).unwrap();
+        settings.set_default("local_verifier_threads", 1).unwrap();
         settings.set_default("optimizations", "all").unwrap();
         settings.set_default("intern_names", true).unwrap();
         settings.set_default("enable_purification_optimization", false).unwrap();
@@ -141,6 +144,8 @@ lazy_static::lazy_static! {
         settings.set_default::<Option<String>>("execute_only_failing_trace", None).unwrap();
         settings.set_default::<Option<String>>("dump_fold_unfold_state_of_blocks", None).unwrap();
         settings.set_default("print_hash", false).unwrap();
+        settings.set_default::<Option<String>>("report_format", None).unwrap();
+        settings.set_default::<Option<String>>("report_dir", None).unwrap();
         settings.set_default("enable_cache", true).unwrap();
 
         settings.set_default("cargo_path", "cargo").unwrap();
@@ -446,6 +451,19 @@ pub fn log_tracing() -> bool {
     read_setting("log_tracing")
 }
 
+/// When set to `"json"` or `"sarif"`, Prusti writes a machine-readable report
+/// of the verification outcomes of all the verified items to `report_dir`.
+pub fn report_format() -> Option<String> {
+    read_setting("report_format")
+}
+
+/// Path to the directory in which the verification reports will be stored.
+/// If not set, the reports are stored in the `report` folder of the current
+/// directory.
+pub fn report_dir() -> Option<PathBuf> {
+    read_setting::<Option<String>>("report_dir").map(PathBuf::from)
+}
+
 /// Path to a cache file, where verification cache will be loaded from and
 /// saved to. The default empty string disables saving any cache to disk.
 /// A path to a file which does not yet exist will result in using an empty
@@ -460,6 +478,12 @@ pub fn check_overflows() -> bool {
     read_setting("check_overflows")
 }
 
+/// When enabled, the default encoder reports pure functions that are not
+/// marked with `#[terminates]`. The core proof encoder always does so.
+pub fn check_pure_function_termination() -> bool {
+    read_setting("check_pure_function_termination")
+}
+
 /// When enabled, non-negativity of unsigned integers will be encoded and
 /// checked.
 pub fn encode_unsigned_num_constraint() -> bool {
@@ -473,6 +497,13 @@ pub fn encode_bitvectors() -> bool {
     read_setting("encode_bitvectors")
 }
 
+/// When enabled, `f32` and `f64` values follow IEEE-754 semantics: arithmetic
+/// may produce infinities and NaN instead of being checked for overflow, and
+/// casts and the `is_nan`, `is_infinite` and `is_finite` methods are encoded.
+pub fn encode_ieee_floats() -> bool {
+    read_setting("encode_ieee_floats")
+}
+
 /// Additional arguments to pass to the JVM when launching a verifier backend.
 pub fn extra_jvm_args() -> Vec<String> {
     read_setting("extra_jvm_args")
@@ -661,6 +692,14 @@ pub fn json_communication() -> bool {
     read_setting("json_communication")
 }
 
+/// Number of threads that verify Viper programs concurrently when Prusti does
+/// not use a server (i.e. when `SERVER_ADDRESS` is not set). Each thread is
+/// attached to the same JVM and verifies a different program. The errors are
+/// reported in the same order as with a single thread.
+pub fn local_verifier_threads() -> usize {
+    read_setting("local_verifier_threads")
+}
+
 /// When enabled, Viper name mangling will be disabled.
 ///
 /// **Note:** This is very likely to result in invalid programs being generated
@@ -0,0 +1,304 @@
+// © 2023, ETH Zurich
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! This module defines the machine-readable report of the verification
+//! outcomes, which is written when `config::report_format()` is set.
+//!
+//! Each Prusti invocation writes the report of its crate as JSON to
+//! `{report_dir}/crates/{crate_name}.{crate_target}.json`, where the target
+//! (e.g. `lib` or `bin`) distinguishes the crates of a package that share the
+//! same name. The final report, in the format
+//! selected by the user, is written to `{report_dir}/prusti-report.{json,sarif}`
+//! by `prusti-rustc` or, when verifying a whole package, by `cargo-prusti`,
+//! which aggregates the reports of all the verified crates.
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Sarif,
+}
+
+impl ReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Sarif => "sarif",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UnknownReportFormatError(String);
+
+impl fmt::Display for UnknownReportFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid report format: '{}'. Allowed values are 'json' and 'sarif'",
+            self.0
+        )
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = UnknownReportFormatError;
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "json" => Ok(ReportFormat::Json),
+            "sarif" => Ok(ReportFormat::Sarif),
+            _ => Err(UnknownReportFormatError(format.to_string())),
+        }
+    }
+}
+
+/// The verification outcomes of all the items of a crate.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CrateReport {
+    pub crate_name: String,
+    /// The kind of the compiled crate, e.g. `lib`, `bin` or `test`.
+    #[serde(default)]
+    pub crate_target: String,
+    pub items: Vec<ItemReport>,
+}
+
+/// The outcome of the verification of a single Viper program, which usually
+/// corresponds to a single Rust item.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ItemReport {
+    /// The definition path of the Rust item, or the name of the Viper program
+    /// if it does not correspond to a Rust item.
+    pub def_path: String,
+    pub span: Option<ReportSpan>,
+    /// The name of the Viper program, if the item has been encoded.
+    pub program: Option<String>,
+    pub backend: Option<String>,
+    pub duration_ms: Option<u64>,
+    /// Whether the verification result was taken from the cache. `None` if
+    /// unknown, for example when the item has been verified by a server.
+    pub cache_hit: Option<bool>,
+    /// Why the item had to be verified again instead of using the cached
+    /// result, e.g. because the contract of a callee changed.
+    pub cache_miss_reason: Option<String>,
+    pub result: ItemResult,
+    pub errors: Vec<ErrorReport>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemResult {
+    Verified,
+    Failed,
+    /// The item has not been verified, e.g. because it is trusted or because
+    /// its encoding failed.
+    NotVerified,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ErrorReport {
+    /// The kind of the error, e.g. the Viper error identifier
+    /// `assert.failed:assertion.false`.
+    pub kind: String,
+    pub message: String,
+    pub span: Option<ReportSpan>,
+    pub counterexample: Option<String>,
+}
+
+/// A source location. Lines and columns start from 1.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReportSpan {
+    pub file: String,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+/// Returns the selected report format, or `None` if no report should be
+/// written. Fails if the configured format is not valid.
+pub fn report_format() -> Result<Option<ReportFormat>, UnknownReportFormatError> {
+    config::report_format()
+        .map(|format| format.parse())
+        .transpose()
+}
+
+/// Returns whether a valid report format has been selected.
+pub fn is_report_enabled() -> bool {
+    matches!(report_format(), Ok(Some(_)))
+}
+
+/// Returns the directory in which the reports are stored.
+pub fn report_dir() -> PathBuf {
+    config::report_dir().unwrap_or_else(|| PathBuf::from("report"))
+}
+
+fn crate_reports_dir(report_dir: &Path) -> PathBuf {
+    report_dir.join("crates")
+}
+
+/// Write the report of a single crate, to be later aggregated by
+/// `cargo-prusti`.
+pub fn write_crate_report(report_dir: &Path, report: &CrateReport) -> io::Result<()> {
+    let crates_dir = crate_reports_dir(report_dir);
+    fs::create_dir_all(&crates_dir)?;
+    let path = crates_dir.join(format!(
+        "{}.{}.json",
+        report.crate_name, report.crate_target
+    ));
+    let file = io::BufWriter::new(fs::File::create(path)?);
+    serde_json::to_writer_pretty(file, report)?;
+    Ok(())
+}
+
+/// Remove the reports of the crates verified by previous runs, so that the
+/// reports of crates that no longer exist do not end up in the final report.
+pub fn clear_crate_reports(report_dir: &Path) -> io::Result<()> {
+    let crates_dir = crate_reports_dir(report_dir);
+    if crates_dir.is_dir() {
+        fs::remove_dir_all(crates_dir)?;
+    }
+    Ok(())
+}
+
+/// Read the reports of all the crates that have been verified so far.
+pub fn read_crate_reports(report_dir: &Path) -> io::Result<Vec<CrateReport>> {
+    let crates_dir = crate_reports_dir(report_dir);
+    if !crates_dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut reports = vec![];
+    for entry in fs::read_dir(crates_dir)? {
+        let path = entry?.path();
+        if path.extension().map_or(false, |ext| ext == "json") {
+            let file = io::BufReader::new(fs::File::open(&path)?);
+            reports.push(serde_json::from_reader(file)?);
+        }
+    }
+    reports.sort_by(|a: &CrateReport, b| {
+        (&a.crate_name, &a.crate_target).cmp(&(&b.crate_name, &b.crate_target))
+    });
+    Ok(reports)
+}
+
+/// Write the given crate reports to `{report_dir}/prusti-report.{json,sarif}`.
+pub fn write_final_report(
+    report_dir: &Path,
+    format: ReportFormat,
+    reports: &[CrateReport],
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(report_dir)?;
+    let path = report_dir.join(format!("prusti-report.{}", format.extension()));
+    let file = io::BufWriter::new(fs::File::create(&path)?);
+    match format {
+        ReportFormat::Json => serde_json::to_writer_pretty(file, reports)?,
+        ReportFormat::Sarif => serde_json::to_writer_pretty(file, &to_sarif(reports))?,
+    }
+    Ok(path)
+}
+
+/// Convert the reports to the SARIF 2.1.0 format, with one run per crate.
+/// Verified items are reported as results of kind `pass`.
+pub fn to_sarif(reports: &[CrateReport]) -> serde_json::Value {
+    let runs: Vec<_> = reports
+        .iter()
+        .map(|report| {
+            let mut rules: Vec<&str> = report
+                .items
+                .iter()
+                .flat_map(|item| item.errors.iter().map(|error| error.kind.as_str()))
+                .collect();
+            rules.sort_unstable();
+            rules.dedup();
+            let results: Vec<_> = report.items.iter().flat_map(sarif_results).collect();
+            serde_json::json!({
+                "tool": {
+                    "driver": {
+                        "name": "Prusti",
+                        "informationUri": "https://github.com/viperproject/prusti-dev",
+                        "rules": rules
+                            .into_iter()
+                            .map(|rule| serde_json::json!({ "id": rule }))
+                            .collect::<Vec<_>>(),
+                    }
+                },
+                "automationDetails": {
+                    "id": format!("{}/{}", report.crate_name, report.crate_target),
+                },
+                "results": results,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": runs,
+    })
+}
+
+fn sarif_results(item: &ItemReport) -> Vec<serde_json::Value> {
+    let properties = serde_json::json!({
+        "defPath": item.def_path,
+        "program": item.program,
+        "backend": item.backend,
+        "durationMs": item.duration_ms,
+        "cacheHit": item.cache_hit,
+        "cacheMissReason": item.cache_miss_reason,
+    });
+    if item.errors.is_empty() {
+        let (kind, message) = match item.result {
+            ItemResult::Verified => ("pass", "verified successfully"),
+            ItemResult::Failed => ("fail", "verification failed"),
+            ItemResult::NotVerified => ("notApplicable", "not verified"),
+        };
+        return vec![serde_json::json!({
+            "kind": kind,
+            "level": if kind == "fail" { "error" } else { "none" },
+            "message": { "text": format!("{}: {message}", item.def_path) },
+            "locations": sarif_locations(item.span.as_ref()),
+            "properties": properties,
+        })];
+    }
+    item.errors
+        .iter()
+        .map(|error| {
+            let mut properties = properties.clone();
+            properties["counterexample"] = serde_json::json!(error.counterexample);
+            serde_json::json!({
+                "ruleId": error.kind,
+                "kind": "fail",
+                "level": "error",
+                "message": { "text": error.message },
+                "locations": sarif_locations(error.span.as_ref().or(item.span.as_ref())),
+                "properties": properties,
+            })
+        })
+        .collect()
+}
+
+fn sarif_locations(span: Option<&ReportSpan>) -> Vec<serde_json::Value> {
+    span.map(|span| {
+        serde_json::json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": span.file },
+                "region": {
+                    "startLine": span.start_line,
+                    "startColumn": span.start_column,
+                    "endLine": span.end_line,
+                    "endColumn": span.end_column,
+                }
+            }
+        })
+    })
+    .into_iter()
+    .collect()
+}
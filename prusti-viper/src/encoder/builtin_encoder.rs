@@ -17,7 +17,7 @@ use vir_crate::{
 const PRIMITIVE_VALID_DOMAIN_NAME: &str = "PrimitiveValidDomain";
 
 #[allow(clippy::enum_variant_names)]
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum BuiltinMethodKind {
     HavocBool,
     HavocInt,
@@ -26,6 +26,11 @@ pub enum BuiltinMethodKind {
     HavocBV(vir::BitVector),
     HavocRef,
     BumpMemVersion,
+    /// write a primitive value to the memory pointed to by a raw pointer
+    WriteRawPointer {
+        pointee_pred_type: vir::Type,
+        value_field: vir::Field,
+    },
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
@@ -52,6 +57,29 @@ pub enum BuiltinFunctionKind {
         slice_pred_type: vir::Type,
         elem_pred_type: vir::Type,
     },
+    /// read a primitive value from the memory pointed to by a raw pointer
+    ReadRawPointer {
+        pointee_pred_type: vir::Type,
+        value_field: vir::Field,
+    },
+    /// identity on a value that is about to be written through a raw pointer,
+    /// which checks that the pointed memory is writable
+    CheckRawPointerWrite {
+        pointee_pred_type: vir::Type,
+        value_field: vir::Field,
+    },
+    /// index of the first occurrence of an element in a sequence, or `-1`
+    SeqIndexOf { elem_snap_type: vir::Type },
+    /// the map with an entry inserted or replaced
+    MapInsert {
+        key_snap_type: vir::Type,
+        val_snap_type: vir::Type,
+    },
+    /// the map with the entry of a key removed
+    MapDelete {
+        key_snap_type: vir::Type,
+        val_snap_type: vir::Type,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -78,6 +106,12 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinEncoder<'p, 'v, 'tcx> {
             BuiltinMethodKind::HavocF64 => "builtin$havoc_f64".to_string(),
             BuiltinMethodKind::HavocRef => "builtin$havoc_ref".to_string(),
             BuiltinMethodKind::BumpMemVersion => versioning::bump_mem_version_name().to_string(),
+            BuiltinMethodKind::WriteRawPointer {
+                pointee_pred_type, ..
+            } => format!(
+                "builtin$write_raw_pointer${}",
+                pointee_pred_type.encode_as_string()
+            ),
         }
     }
 
@@ -85,7 +119,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinEncoder<'p, 'v, 'tcx> {
         &self,
         method: BuiltinMethodKind,
     ) -> EncodingResult<vir::BodylessMethod> {
-        let method_name = self.encode_builtin_method_name(method);
+        let method_name = self.encode_builtin_method_name(method.clone());
         let return_type = match method {
             BuiltinMethodKind::HavocBool => vir::Type::Bool,
             BuiltinMethodKind::HavocInt => vir::Type::Int,
@@ -96,6 +130,16 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinEncoder<'p, 'v, 'tcx> {
             BuiltinMethodKind::BumpMemVersion => {
                 return Ok(versioning::bump_mem_version_definition());
             }
+            BuiltinMethodKind::WriteRawPointer {
+                pointee_pred_type,
+                value_field,
+            } => {
+                return Ok(self.encode_write_raw_pointer_method_def(
+                    method_name,
+                    pointee_pred_type,
+                    value_field,
+                ));
+            }
         };
         Ok(vir::BodylessMethod {
             name: method_name,
@@ -106,6 +150,47 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinEncoder<'p, 'v, 'tcx> {
         })
     }
 
+    /// ```viper
+    /// method builtin$write_raw_pointer$T(address: Ref, value: V)
+    ///     requires acc(T(address), write)
+    ///     ensures acc(T(address), write)
+    ///     ensures builtin$read_raw_pointer<T>(address) == value
+    /// ```
+    fn encode_write_raw_pointer_method_def(
+        &self,
+        method_name: String,
+        pointee_pred_type: vir::Type,
+        value_field: vir::Field,
+    ) -> vir::BodylessMethod {
+        let address = vir::LocalVar::new("address", pointee_pred_type.clone());
+        let value = vir::LocalVar::new("value", value_field.typ.clone());
+        let access = vir::Expr::predicate_access_predicate(
+            pointee_pred_type.clone(),
+            vir::Expr::local(address.clone()),
+            vir::PermAmount::Write,
+        );
+        let read_kind = BuiltinFunctionKind::ReadRawPointer {
+            pointee_pred_type,
+            value_field: value_field.clone(),
+        };
+        let (read_name, read_type_arguments) = self.encoder.encode_builtin_function_use(read_kind);
+        let read = vir::Expr::func_app(
+            read_name,
+            read_type_arguments,
+            vec![vir::Expr::local(address.clone())],
+            vec![address.clone()],
+            value_field.typ,
+            vir::Position::default(),
+        );
+        vir::BodylessMethod {
+            name: method_name,
+            formal_args: vec![address, value.clone()],
+            formal_returns: vec![],
+            pres: vec![access.clone()],
+            posts: vec![access, vir_expr! { [read] == [vir::Expr::local(value)] }],
+        }
+    }
+
     pub fn encode_builtin_function_def(&self, function: BuiltinFunctionKind) -> vir::Function {
         let (fn_name, type_arguments) = self
             .encoder
@@ -230,6 +315,262 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinEncoder<'p, 'v, 'tcx> {
                     body: None,
                 }
             }
+            BuiltinFunctionKind::ReadRawPointer {
+                pointee_pred_type,
+                value_field,
+            } => {
+                let address = vir::LocalVar::new("address", pointee_pred_type.clone());
+                let address_expr = vir::Expr::local(address.clone());
+
+                vir::Function {
+                    name: fn_name,
+                    type_arguments,
+                    formal_args: vec![address],
+                    return_type: value_field.typ.clone(),
+                    // acc(T(address), read$())
+                    pres: vec![vir::Expr::predicate_access_predicate(
+                        pointee_pred_type.clone(),
+                        address_expr.clone(),
+                        vir::PermAmount::Read,
+                    )],
+                    posts: vec![],
+                    // unfolding acc(T(address), read$()) in address.val_X
+                    body: Some(vir::Expr::unfolding(
+                        pointee_pred_type,
+                        vec![address_expr.clone()],
+                        address_expr.field(value_field),
+                        vir::PermAmount::Read,
+                        None,
+                    )),
+                }
+            }
+            BuiltinFunctionKind::CheckRawPointerWrite {
+                pointee_pred_type,
+                value_field,
+            } => {
+                let address = vir::LocalVar::new("address", pointee_pred_type.clone());
+                let value = vir::LocalVar::new("value", value_field.typ.clone());
+
+                vir::Function {
+                    name: fn_name,
+                    type_arguments,
+                    formal_args: vec![address.clone(), value.clone()],
+                    return_type: value_field.typ,
+                    // acc(T(address), write)
+                    pres: vec![vir::Expr::predicate_access_predicate(
+                        pointee_pred_type,
+                        vir::Expr::local(address),
+                        vir::PermAmount::Write,
+                    )],
+                    posts: vec![],
+                    body: Some(vir::Expr::local(value)),
+                }
+            }
+            BuiltinFunctionKind::SeqIndexOf { elem_snap_type } => {
+                self.encode_seq_index_of_function_def(fn_name, type_arguments, elem_snap_type)
+            }
+            BuiltinFunctionKind::MapInsert {
+                key_snap_type,
+                val_snap_type,
+            } => self.encode_map_update_function_def(
+                fn_name,
+                type_arguments,
+                key_snap_type,
+                Some(val_snap_type.clone()),
+                val_snap_type,
+            ),
+            BuiltinFunctionKind::MapDelete {
+                key_snap_type,
+                val_snap_type,
+            } => self.encode_map_update_function_def(
+                fn_name,
+                type_arguments,
+                key_snap_type,
+                None,
+                val_snap_type,
+            ),
+        }
+    }
+
+    /// The legacy encoding has no ternary map update, so inserting and
+    /// deleting entries are axiomatized by bodyless functions. With `val`,
+    /// the function inserts:
+    ///
+    /// ```viper
+    /// function builtin$map_insert<K, V>(map: Map[K, V], key: K, val: V): Map[K, V]
+    ///     ensures key in result && result[key] == val
+    ///     ensures |result| == (key in map ? |map| : |map| + 1)
+    ///     ensures forall k: K :: { k in result } { k in map }
+    ///         k != key ==> ((k in result) == (k in map))
+    ///     ensures forall k: K :: { result[k] } { map[k] }
+    ///         k != key && k in map ==> result[k] == map[k]
+    /// ```
+    ///
+    /// Without it, the function deletes, ensuring `!(key in result)` and
+    /// `|result| == (key in map ? |map| - 1 : |map|)` instead of the first two
+    /// postconditions.
+    fn encode_map_update_function_def(
+        &self,
+        fn_name: String,
+        type_arguments: Vec<vir::Type>,
+        key_snap_type: vir::Type,
+        val_snap_type: Option<vir::Type>,
+        map_val_snap_type: vir::Type,
+    ) -> vir::Function {
+        let map_type = vir::Type::Map(vir::MapType {
+            key_type: Box::new(key_snap_type.clone()),
+            val_type: Box::new(map_val_snap_type),
+        });
+        let map = vir::LocalVar::new("map", map_type.clone());
+        let key = vir::LocalVar::new("key", key_snap_type.clone());
+        let map_expr = vir::Expr::local(map.clone());
+        let key_expr = vir::Expr::local(key.clone());
+        let result = vir::Expr::local(vir::LocalVar::new("__result", map_type.clone()));
+        let k = vir::LocalVar::new("k", key_snap_type);
+        let k_expr = vir::Expr::local(k.clone());
+        let container_op = |op_kind, left: &vir::Expr, right: &vir::Expr| {
+            vir::Expr::ContainerOp(vir::ContainerOp {
+                op_kind,
+                left: Box::new(left.clone()),
+                right: Box::new(right.clone()),
+                position: vir::Position::default(),
+            })
+        };
+        let len = |map: &vir::Expr| {
+            container_op(vir::ContainerOpKind::MapLen, map, &true.into()) // unused
+        };
+        let contains = |map: &vir::Expr, key: &vir::Expr| {
+            container_op(vir::ContainerOpKind::MapContains, map, key)
+        };
+        let lookup = |map: &vir::Expr, key: &vir::Expr| {
+            container_op(vir::ContainerOpKind::MapLookup, map, key)
+        };
+        let key_in_map = contains(&map_expr, &key_expr);
+        let key_in_result = contains(&result, &key_expr);
+        let k_in_map = contains(&map_expr, &k_expr);
+        let k_in_result = contains(&result, &k_expr);
+        let other_key = vir_expr! { [k_expr] != [key_expr] };
+        let same_keys = vir::Expr::forall(
+            vec![k.clone()],
+            vec![
+                vir::Trigger::new(vec![k_in_result.clone()]),
+                vir::Trigger::new(vec![k_in_map.clone()]),
+            ],
+            vir_expr! { [other_key] ==> ([k_in_result] == [k_in_map]) },
+        );
+        let result_k = lookup(&result, &k_expr);
+        let map_k = lookup(&map_expr, &k_expr);
+        let same_values = vir::Expr::forall(
+            vec![k],
+            vec![
+                vir::Trigger::new(vec![result_k.clone()]),
+                vir::Trigger::new(vec![map_k.clone()]),
+            ],
+            vir_expr! { ([other_key] && [k_in_map]) ==> ([result_k] == [map_k]) },
+        );
+        let map_len = len(&map_expr);
+        let result_len = len(&result);
+
+        let mut formal_args = vec![map, key];
+        let mut posts = if let Some(val_snap_type) = val_snap_type {
+            let val = vir::LocalVar::new("val", val_snap_type);
+            let val_expr = vir::Expr::local(val.clone());
+            formal_args.push(val);
+            let result_val = lookup(&result, &key_expr);
+            let len_after = vir::Expr::ite(
+                key_in_map,
+                map_len.clone(),
+                vir_expr! { [map_len] + [vir::Expr::from(1)] },
+            );
+            vec![
+                key_in_result,
+                vir_expr! { [result_val] == [val_expr] },
+                vir_expr! { [result_len] == [len_after] },
+            ]
+        } else {
+            let len_after = vir::Expr::ite(
+                key_in_map,
+                vir_expr! { [map_len] - [vir::Expr::from(1)] },
+                map_len.clone(),
+            );
+            vec![
+                key_in_result.negate(),
+                vir_expr! { [result_len] == [len_after] },
+            ]
+        };
+        posts.push(same_keys);
+        posts.push(same_values);
+
+        vir::Function {
+            name: fn_name,
+            type_arguments,
+            formal_args,
+            return_type: map_type,
+            pres: vec![],
+            posts,
+            body: None,
+        }
+    }
+
+    /// ```viper
+    /// function builtin$seq_index_of<T>(seq: Seq[T], elem: T): Int
+    ///     ensures -1 <= result && result < |seq|
+    ///     ensures (result >= 0) == (elem in seq)
+    ///     ensures result >= 0 ==> seq[result] == elem
+    ///     ensures forall i: Int :: { seq[i] } 0 <= i && i < result ==> seq[i] != elem
+    /// ```
+    fn encode_seq_index_of_function_def(
+        &self,
+        fn_name: String,
+        type_arguments: Vec<vir::Type>,
+        elem_snap_type: vir::Type,
+    ) -> vir::Function {
+        let seq_type = vir::Type::Seq(vir::SeqType {
+            typ: Box::new(elem_snap_type.clone()),
+        });
+        let seq = vir::LocalVar::new("seq", seq_type);
+        let elem = vir::LocalVar::new("elem", elem_snap_type);
+        let seq_expr = vir::Expr::local(seq.clone());
+        let elem_expr = vir::Expr::local(elem.clone());
+        let result: vir::Expr = vir_local! { __result: Int }.into();
+        let i = vir_local! { i: Int };
+        let i_expr: vir::Expr = i.clone().into();
+        let container_op = |op_kind, right: vir::Expr| {
+            vir::Expr::ContainerOp(vir::ContainerOp {
+                op_kind,
+                left: Box::new(seq_expr.clone()),
+                right: Box::new(right),
+                position: vir::Position::default(),
+            })
+        };
+        let seq_len = container_op(vir::ContainerOpKind::SeqLen, true.into()); // unused
+        let contains = container_op(vir::ContainerOpKind::SeqContains, elem_expr.clone());
+        let result_elem = container_op(vir::ContainerOpKind::SeqIndex, result.clone());
+        let i_elem = container_op(vir::ContainerOpKind::SeqIndex, i_expr.clone());
+        let found = vir_expr! { [result] >= [vir::Expr::from(0)] };
+        let before_first = vir::Expr::forall(
+            vec![i],
+            vec![vir::Trigger::new(vec![i_elem.clone()])],
+            vir_expr! {
+                (([vir::Expr::from(0)] <= [i_expr]) && ([i_expr] < [result]))
+                ==> ([i_elem] != [elem_expr])
+            },
+        );
+
+        vir::Function {
+            name: fn_name,
+            type_arguments,
+            formal_args: vec![seq, elem],
+            return_type: vir::Type::Int,
+            pres: vec![],
+            posts: vec![
+                vir_expr! { [vir::Expr::from(-1)] <= [result] },
+                vir_expr! { [result] < [seq_len] },
+                vir_expr! { [found] == [contains] },
+                vir_expr! { [found] ==> ([result_elem] == [elem_expr]) },
+                before_first,
+            ],
+            body: None,
         }
     }
 
@@ -4,7 +4,7 @@ use super::{
     Encoder,
 };
 use crate::encoder::high::types::HighTypeEncoderInterface;
-use prusti_common::vir_local;
+use prusti_common::{config, vir_local};
 use prusti_rustc_interface::span::Span;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -130,6 +130,17 @@ impl<'p, 'v: 'p, 'tcx: 'v> Collector<'p, 'v, 'tcx> {
             }
         }
         vir::utils::fallible_walk_methods(methods, self)?;
+        // All builtin methods are emitted (see `get_all_methods`), so we also
+        // need everything mentioned in their contracts.
+        let builtin_method_specs: Vec<_> = self
+            .encoder
+            .get_builtin_methods()
+            .values()
+            .flat_map(|method| method.pres.iter().chain(&method.posts).cloned())
+            .collect();
+        for expr in &builtin_method_specs {
+            self.fallible_walk_expr(expr)?;
+        }
         self.used_predicates
             .extend(self.unfolded_predicates.iter().cloned());
         self.used_functions
@@ -508,7 +519,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> Collector<'p, 'v, 'tcx> {
             .map(|(k, v)| (k.to_string(), v.to_string()))
             .collect(),
         };
-        let f64_name = "FloatDomain52e12".to_string();
+        let f64_name = "FloatDomain53e11".to_string();
         let f64_type = vir::Type::Float(vir::Float::F64);
         let b64_type = vir::Type::BitVector(vir::BitVector::Unsigned(vir::BitVectorSize::BV64));
         let f64 = vir::BackendType {
@@ -519,7 +530,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> Collector<'p, 'v, 'tcx> {
                     formal_args: vec![vir::LocalVar::new("a", b64_type)],
                     return_type: f64_type.clone(),
                     domain_name: f64_name.clone(),
-                    interpretation: "(_ to_fp 12 52)".to_string(),
+                    interpretation: "(_ to_fp 11 53)".to_string(),
                 },
                 vir::BackendFuncDecl {
                     name: "f64_fp_add".to_string(),
@@ -689,8 +700,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> Collector<'p, 'v, 'tcx> {
                 },
             ],
             interpretations: vec![
-                ("Boogie", "float52e12"),
-                ("SMTLIB", "(_ FloatingPoint 12 52)"),
+                ("Boogie", "float53e11"),
+                ("SMTLIB", "(_ FloatingPoint 11 53)"),
             ]
             .into_iter()
             .map(|(k, v)| (k.to_string(), v.to_string()))
@@ -842,6 +853,40 @@ impl<'p, 'v: 'p, 'tcx: 'v> Collector<'p, 'v, 'tcx> {
                 .collect(),
             });
         }
+        if config::encode_ieee_floats() {
+            let floats = [vir::Float::F32, vir::Float::F64];
+            let mut casts = Vec::new();
+            for size in [8, 16, 32, 64, 128] {
+                let size = vir::BitVectorSize::from(size);
+                for bv in [vir::BitVector::Signed(size), vir::BitVector::Unsigned(size)] {
+                    for float in floats {
+                        let float_type = vir::Type::Float(float);
+                        casts.push(
+                            vir::CastKind::IntIntoFloat(bv, float)
+                                .get_float_backend_func(&vir::Type::Int),
+                        );
+                        casts.push(
+                            vir::CastKind::FloatIntoInt(bv).get_float_backend_func(&float_type),
+                        );
+                    }
+                }
+            }
+            for source in floats {
+                for target in floats {
+                    casts.push(
+                        vir::CastKind::FloatIntoFloat(target)
+                            .get_float_backend_func(&vir::Type::Float(source)),
+                    );
+                }
+            }
+            for function in casts.into_iter().flatten() {
+                let backend_type = backend_types
+                    .iter_mut()
+                    .find(|backend_type| backend_type.name == function.domain_name)
+                    .unwrap();
+                backend_type.functions.push(function);
+            }
+        }
         backend_types
     }
     fn contains_unfolded_predicates(&self, exprs: &[vir::Expr]) -> bool {
@@ -1035,7 +1080,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> FallibleExprWalker for Collector<'p, 'v, 'tcx> {
     }
     fn fallible_walk_type(&mut self, typ: &vir::Type) -> SpannedEncodingResult<()> {
         match typ {
-            vir::Type::Seq(vir::SeqType { box typ }) => {
+            vir::Type::Seq(vir::SeqType { box typ })
+            | vir::Type::Set(vir::SetType { box typ })
+            | vir::Type::MultiSet(vir::MultiSetType { box typ }) => {
                 self.fallible_walk_type(typ)?;
             }
             vir::Type::TypedRef(..) | vir::Type::TypeVar(..) => {
@@ -14,9 +14,11 @@ use super::{
     },
     middle::core_proof::{MidCoreProofEncoderInterface, MidCoreProofEncoderState},
     mir::{
-        contracts::ContractsEncoderState,
+        contracts::{ContractsEncoderInterface, ContractsEncoderState},
         procedures::MirProcedureEncoderState,
-        pure::{PureFunctionEncoderInterface, PureFunctionEncoderState},
+        pure::{
+            PureFunctionEncoderInterface, PureFunctionEncoderState, SpecificationEncoderInterface,
+        },
         sequences::{MirSequencesEncoderInterface, MirSequencesEncoderState},
         specifications::{SpecificationsInterface, SpecificationsState},
         type_invariants::TypeInvariantEncoderState,
@@ -28,7 +30,7 @@ use crate::{
         builtin_encoder::{BuiltinEncoder, BuiltinMethodKind},
         errors::{
             EncodingError, EncodingResult, ErrorManager, SpannedEncodingError,
-            SpannedEncodingResult,
+            SpannedEncodingResult, WithSpan,
         },
         foldunfold,
         mirror_function_encoder::MirrorEncoder,
@@ -53,6 +55,7 @@ use prusti_interface::{
 use prusti_rustc_interface::{
     hir::def_id::DefId,
     middle::{mir, ty},
+    span::Span,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
@@ -64,7 +67,7 @@ use std::{
 };
 use vir_crate::{
     common::{check_mode::CheckMode, identifier::WithIdentifier},
-    polymorphic::{self as vir},
+    polymorphic::{self as vir, ExprIterator},
 };
 
 pub struct Encoder<'v, 'tcx: 'v> {
@@ -90,6 +93,7 @@ pub struct Encoder<'v, 'tcx: 'v> {
     pub(super) specifications_state: SpecificationsState<'tcx>,
     type_discriminant_funcs: RefCell<FxHashMap<String, vir::FunctionIdentifier>>,
     type_cast_functions: RefCell<FxHashMap<(ty::Ty<'tcx>, ty::Ty<'tcx>), vir::FunctionIdentifier>>,
+    closure_spec_funcs: RefCell<FxHashSet<String>>,
     pub(super) snapshot_encoder_state: SnapshotEncoderState,
     pub(super) mirror_encoder: RefCell<MirrorEncoder>,
     encoding_queue: RefCell<Vec<EncodingTask<'tcx>>>,
@@ -167,6 +171,7 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
             typed_type_encoder_state: Default::default(),
             type_discriminant_funcs: RefCell::new(FxHashMap::default()),
             type_cast_functions: RefCell::new(FxHashMap::default()),
+            closure_spec_funcs: RefCell::new(FxHashSet::default()),
             encoding_queue: RefCell::new(vec![]),
             queued_types: Default::default(),
             vir_program_before_foldunfold_writer,
@@ -360,6 +365,19 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         })
     }
 
+    /// Extract the UTF-8 bytes of a string literal.
+    pub fn const_eval_str(&self, value: mir::ConstantKind<'tcx>) -> EncodingResult<Vec<u8>> {
+        match value {
+            mir::ConstantKind::Val(mir::interpret::ConstValue::Slice { data, start, end }, _) => {
+                Ok(data
+                    .inner()
+                    .inspect_with_uninit_and_ptr_outside_interpreter(start..end)
+                    .to_vec())
+            }
+            _ => error_unsupported!("unsupported string constant: {:?}", value),
+        }
+    }
+
     /// Encodes a value in a field if the base expression is a reference or
     /// a primitive types.
     /// For composed data structures, the base expression is returned.
@@ -495,11 +513,11 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
     ) -> EncodingResult<vir::BodylessMethod> {
         if !self.builtin_methods.borrow().contains_key(&method_kind) {
             let builtin_encoder = BuiltinEncoder::new(self);
-            let method = builtin_encoder.encode_builtin_method_def(method_kind)?;
+            let method = builtin_encoder.encode_builtin_method_def(method_kind.clone())?;
             self.log_vir_program_before_viper(method.to_string());
             self.builtin_methods
                 .borrow_mut()
-                .insert(method_kind, method);
+                .insert(method_kind.clone(), method);
         }
         Ok(self.builtin_methods.borrow()[&method_kind].clone())
     }
@@ -510,7 +528,7 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         method_kind: BuiltinMethodKind,
     ) -> EncodingResult<String> {
         // Trigger encoding of definition
-        self.encode_builtin_method_def(method_kind)?;
+        self.encode_builtin_method_def(method_kind.clone())?;
         let builtin_encoder = BuiltinEncoder::new(self);
         Ok(builtin_encoder.encode_builtin_method_name(method_kind))
     }
@@ -612,6 +630,153 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         Ok(function_name)
     }
 
+    /// Encodes an application of the function that describes the functional
+    /// precondition (or postcondition, if `is_post`) of calling a value of type
+    /// `closure_ty` through one of the `Fn*` traits. The precondition takes the
+    /// snapshots of the closure and of the arguments. The postcondition takes
+    /// the snapshots of the closure before and after the call, of the arguments
+    /// and of the result. For closures of a generic type the functions are
+    /// abstract, otherwise they are defined by the specification of the closure.
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub fn encode_closure_spec_func_app(
+        &self,
+        closure_ty: ty::Ty<'tcx>,
+        arg_tys: &[ty::Ty<'tcx>],
+        result_ty: ty::Ty<'tcx>,
+        is_post: bool,
+        args: Vec<vir::Expr>,
+        span: Span,
+        position: vir::Position,
+    ) -> SpannedEncodingResult<vir::Expr> {
+        let closure_type = self.encode_type(closure_ty).with_span(span)?;
+        let function_name = format!(
+            "closure${}${}",
+            if is_post { "post" } else { "pre" },
+            closure_type.name()
+        );
+        let closure_snap_ty = self.encode_snapshot_type(closure_ty).with_span(span)?;
+        let old_closure = vir_local! { old_closure: {closure_snap_ty.clone()} };
+        let closure = vir_local! { closure: {closure_snap_ty} };
+        let mut formal_args = vec![];
+        if is_post {
+            formal_args.push(old_closure.clone());
+        }
+        formal_args.push(closure.clone());
+        let mut closure_args = vec![];
+        for (index, arg_ty) in arg_tys.iter().enumerate() {
+            let arg_snap_ty = self.encode_snapshot_type(*arg_ty).with_span(span)?;
+            closure_args.push(vir::LocalVar::new(format!("arg{index}"), arg_snap_ty));
+        }
+        formal_args.extend(closure_args.iter().cloned());
+        let result = vir_local! { result: {self.encode_snapshot_type(result_ty).with_span(span)?} };
+        if is_post {
+            formal_args.push(result.clone());
+        }
+
+        let identifier =
+            vir::compute_identifier(&function_name, &[], &formal_args, &vir::Type::Bool);
+        if !self.closure_spec_funcs.borrow().contains(&identifier) {
+            let body = if let ty::TyKind::Closure(cl_def_id, cl_substs) = closure_ty.kind() {
+                let cl_span = self.env.query.get_def_span(cl_def_id);
+                let contract = self
+                    .get_procedure_contract_for_def(*cl_def_id, cl_substs)
+                    .with_span(cl_span)?;
+                let mut target_args: Vec<vir::Expr> =
+                    closure_args.iter().cloned().map(vir::Expr::local).collect();
+                // The views are the snapshots of the captured variables, in
+                // which captured references are replaced by their targets.
+                let views = self.env.query.get_closure_views(*cl_def_id);
+                if !views.is_empty() {
+                    let captures = self.env.tcx().closure_captures(cl_def_id.expect_local());
+                    let upvar_tys = cl_substs.as_closure().upvar_tys();
+                    for view in &views {
+                        let Some(index) = captures.iter().position(|capture| {
+                            capture.place.projections.is_empty()
+                                && capture.to_symbol().as_str() == view
+                        }) else {
+                            return Err(SpannedEncodingError::unsupported(
+                                format!("the view `{view}` is not a variable captured as a whole"),
+                                cl_span,
+                            ));
+                        };
+                        let field = self
+                            .encode_raw_ref_field(format!("closure_{index}"), upvar_tys[index])
+                            .with_span(cl_span)?;
+                        target_args.push(vir::Expr::snap_app(
+                            vir::Expr::local(closure.clone()).field(field),
+                        ));
+                    }
+                }
+                let target_return = vir::Expr::local(result.clone());
+                let assertions = if is_post {
+                    contract.functional_postcondition(self.env, cl_substs)
+                } else {
+                    contract.functional_precondition(self.env, cl_substs)
+                };
+                let mut conjuncts = vec![];
+                for (assertion, assertion_substs) in assertions {
+                    conjuncts.push(self.encode_assertion(
+                        &assertion,
+                        None,
+                        &target_args,
+                        is_post.then_some(&target_return),
+                        true,
+                        *cl_def_id,
+                        assertion_substs,
+                    )?);
+                }
+                // The state of the closure before the call is `old_closure`,
+                // while the arguments are only known before the call.
+                let closure_expr = vir::Expr::local(closure);
+                let old_closure_expr = vir::Expr::local(old_closure);
+                let body = conjuncts
+                    .into_iter()
+                    .conjoin()
+                    .fold_expr(|expr| match expr {
+                        vir::Expr::LabelledOld(vir::LabelledOld { base, .. })
+                            if is_post
+                                && !closure_args
+                                    .iter()
+                                    .any(|arg| base.find(&vir::Expr::local(arg.clone()))) =>
+                        {
+                            base.replace_place(&closure_expr, &old_closure_expr)
+                        }
+                        expr => expr,
+                    });
+                if body.has_old_expression() {
+                    return Err(SpannedEncodingError::unsupported(
+                        "old expressions over the arguments of a closure that is called \
+                        through a generic argument are not supported",
+                        cl_span,
+                    ));
+                }
+                Some(body)
+            } else {
+                None
+            };
+            let function = vir::Function {
+                name: function_name.clone(),
+                type_arguments: Vec::new(),
+                formal_args: formal_args.clone(),
+                return_type: vir::Type::Bool,
+                pres: Vec::new(),
+                posts: Vec::new(),
+                body,
+            };
+            self.insert_function(function);
+            self.closure_spec_funcs.borrow_mut().insert(identifier);
+        }
+        Ok(vir::Expr::FuncApp(vir::FuncApp {
+            function_name,
+            type_arguments: Vec::new(),
+            arguments: args,
+            formal_arguments: formal_args,
+            return_type: vir::Type::Bool,
+            position,
+        }))
+    }
+
     /// This encodes the Rust function as a Viper method for verification. It
     /// does this also for pure functions.
     #[tracing::instrument(level = "debug", skip(self))]
@@ -657,6 +822,7 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
             | ty::TyKind::Never
             | ty::TyKind::Array(..)
             | ty::TyKind::Slice(..)
+            | ty::TyKind::Str
             | ty::TyKind::Param(_) => true, // TODO(tymap): this is weird, use substs properly?
             ty::TyKind::Adt(_, _) | ty::TyKind::Closure(_, _) => {
                 self.env.tcx().has_structural_eq_impls(ty)
@@ -671,6 +837,18 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         ty: ty::Ty<'tcx>,
         value: mir::ConstantKind<'tcx>,
     ) -> EncodingResult<vir::Expr> {
+        if let ty::TyKind::Ref(_, str_ty, _) = ty.kind() && str_ty.is_str() {
+            // String literals are encoded as the sequence of their UTF-8 bytes.
+            let byte_ty = self.encode_snapshot_type(self.env.tcx().types.u8)?;
+            let bytes = self.const_eval_str(value)?;
+            return Ok(vir::Expr::Seq(vir::Seq {
+                typ: vir::Type::Seq(vir::SeqType {
+                    typ: Box::new(byte_ty),
+                }),
+                elements: bytes.into_iter().map(|byte| byte.into()).collect(),
+                position: vir::Position::default(),
+            }));
+        }
         let scalar_value = self.const_eval_intlike(value)?;
 
         let expr = match ty.kind() {
@@ -943,4 +1121,22 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         let field = strct.field(self.encode_struct_field(field_name, ty)?);
         self.encode_value_expr(field, ty)
     }
+
+    /// The first index of a slice taken with a `RangeInclusive`. An exhausted
+    /// range yields an empty slice that starts after the end of the range.
+    pub fn encode_range_inclusive_slice_start(
+        &self,
+        range: vir::Expr,
+        idx_ty: ty::Ty<'tcx>,
+    ) -> EncodingResult<vir::Expr> {
+        let bool_ty = self.env().tcx().types.bool;
+        let start = self.encode_struct_field_value(range.clone(), "start", idx_ty)?;
+        let end = self.encode_struct_field_value(range.clone(), "end", idx_ty)?;
+        let exhausted = self.encode_struct_field_value(range, "exhausted", bool_ty)?;
+        Ok(vir::Expr::ite(
+            exhausted,
+            vir::Expr::add(end, vir::Expr::from(1usize)),
+            start,
+        ))
+    }
 }
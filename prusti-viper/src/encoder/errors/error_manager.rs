@@ -186,6 +186,12 @@ pub enum ErrorCtxt {
     /// The state that fold-unfold algorithm deduced as unreachable, is actually
     /// reachable.
     UnreachableFoldingState,
+    /// A read of the memory pointed to by a raw pointer.
+    RawPointerRead,
+    /// A write to the memory pointed to by a raw pointer.
+    RawPointerWrite,
+    /// A read of a union field that might not be the active one.
+    UnionFieldRead,
 }
 
 /// The error manager
@@ -605,6 +611,30 @@ impl<'tcx> ErrorManager<'tcx> {
                 .add_note("Prusti does not support yet reinterpreting memory of Rust unions' fields and allow reading only the field that was previously initialized.", None)
             }
 
+            ("application.precondition:insufficient.permission", ErrorCtxt::RawPointerRead) => {
+                PrustiError::verification(
+                    "the memory pointed to by this raw pointer might not be readable.".to_string(),
+                    error_span
+                ).set_failing_assertion(opt_cause_span)
+                .set_help("check that the pointer is not null and that the pointed place is still owned or borrowed.")
+            }
+
+            ("application.precondition:insufficient.permission", ErrorCtxt::RawPointerWrite) => {
+                PrustiError::verification(
+                    "the memory pointed to by this raw pointer might not be writable.".to_string(),
+                    error_span
+                ).set_failing_assertion(opt_cause_span)
+                .set_help("check that the pointer is not null and that the pointed place is still owned or mutably borrowed.")
+            }
+
+            ("assert.failed:assertion.false", ErrorCtxt::UnionFieldRead) => {
+                PrustiError::verification(
+                    "the union field might not be the active one when it is read.".to_string(),
+                    error_span
+                ).set_failing_assertion(opt_cause_span)
+                .set_help("the active field of a union is the one that was last assigned.")
+            }
+
             ("assert.failed:assertion.false", ErrorCtxt::AssertMethodPreconditionWeakening) => {
                 PrustiError::verification("the method's precondition may not be a valid weakening of the trait's precondition.".to_string(), error_span)
                     .set_help("The trait's precondition should imply the implemented method's precondition.")
@@ -95,14 +95,19 @@ impl RequiredStmtPermissionsGetter for vir::Stmt {
                 ref targets,
                 ..
             }) => {
-                // Preconditions and postconditions are empty
-                assert!(arguments.is_empty());
-                FxHashSet::from_iter(
+                // Preconditions and postconditions are empty, except for the
+                // builtin methods that access memory through raw pointers. Their
+                // contracts are checked by Viper.
+                let mut res = FxHashSet::from_iter(
                     targets
                         .iter()
                         .cloned()
                         .map(|v| Acc(vir::Expr::local(v), PermAmount::Write)),
-                )
+                );
+                for argument in arguments {
+                    res.extend(get_all_required_expr_permissions(argument, predicates).0);
+                }
+                res
             }
 
             &vir::Stmt::Assign(vir::Assign {
@@ -462,7 +467,12 @@ impl RequiredExprPermissionsGetter for vir::Expr {
                 arguments
                     .iter()
                     .flat_map(|arg| {
-                        if arg.is_place() && arg.get_type().is_typed_ref_or_type_var() {
+                        if arg.is_place() && arg.is_raw_pointer_target() {
+                            // The memory pointed to by a raw pointer is not
+                            // tracked by the fold-unfold algorithm; Viper checks
+                            // the function precondition.
+                            None
+                        } else if arg.is_place() && arg.get_type().is_typed_ref_or_type_var() {
                             // FIXME: A hack: have unfolded Rust references in the precondition to
                             // simplify our life. A proper solution would be to look up the
                             // real function precondition.
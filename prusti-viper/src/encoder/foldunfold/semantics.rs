@@ -94,10 +94,13 @@ impl ApplyOnState for vir::Stmt {
                     assert!(source.is_place());
                     assert!(source.get_type().is_typed_ref_or_type_var());
 
-                    // Check that the rhs contains no moved paths
+                    // Check that the rhs contains no moved paths. Two-phase borrows
+                    // move the permissions of the rhs only when they are activated, so
+                    // this happens only when a place is read while it is mutably borrowed.
                     if state.is_prefix_of_some_moved(source) {
                         return Err(FoldUnfoldError::Unsupported(
-                            "two-phase borrows are not supported".to_string(),
+                            "reading a place while it is mutably borrowed is not supported"
+                                .to_string(),
                         ));
                     }
                     for prefix in source.all_proper_prefixes() {
@@ -152,7 +155,9 @@ impl ApplyOnState for vir::Stmt {
                                 state.insert_moved(source.clone());
                             }
                         }
-                        vir::AssignKind::SharedBorrow(_) => {
+                        vir::AssignKind::SharedBorrow(_) | vir::AssignKind::TwoPhaseBorrow(_) => {
+                            // The permissions of a two-phase borrow are transferred from
+                            // the rhs when it is activated.
                             // We lose permission on the lhs
                             state.remove_pred_matching(|p| p.has_prefix(target));
                             state.remove_acc_matching(|p| {
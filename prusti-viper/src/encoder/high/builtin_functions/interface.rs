@@ -235,6 +235,36 @@ impl<'v, 'tcx: 'v> HighBuiltinFunctionEncoderInterface<'tcx>
             BuiltinFunctionKind::SliceLen { elem_pred_type, .. } => {
                 ("Slice$len".to_string(), vec![elem_pred_type.clone()])
             }
+            BuiltinFunctionKind::ReadRawPointer {
+                pointee_pred_type, ..
+            } => (
+                "builtin$read_raw_pointer".to_string(),
+                vec![pointee_pred_type.clone()],
+            ),
+            BuiltinFunctionKind::CheckRawPointerWrite {
+                pointee_pred_type, ..
+            } => (
+                "builtin$check_raw_pointer_write".to_string(),
+                vec![pointee_pred_type.clone()],
+            ),
+            BuiltinFunctionKind::SeqIndexOf { elem_snap_type } => (
+                "builtin$seq_index_of".to_string(),
+                vec![elem_snap_type.clone()],
+            ),
+            BuiltinFunctionKind::MapInsert {
+                key_snap_type,
+                val_snap_type,
+            } => (
+                "builtin$map_insert".to_string(),
+                vec![key_snap_type.clone(), val_snap_type.clone()],
+            ),
+            BuiltinFunctionKind::MapDelete {
+                key_snap_type,
+                val_snap_type,
+            } => (
+                "builtin$map_delete".to_string(),
+                vec![key_snap_type.clone(), val_snap_type.clone()],
+            ),
         }
     }
 }
@@ -1,7 +1,11 @@
 use super::super::types::interface::HighTypeEncoderInterfacePrivate;
 
 use super::IntoPolymorphic;
-use vir_crate::{high as vir_high, polymorphic as vir_poly};
+use prusti_common::config;
+use vir_crate::{
+    high::{self as vir_high, operations::ty::Typed},
+    polymorphic as vir_poly,
+};
 
 impl IntoPolymorphic<Box<vir_poly::Expr>> for Box<vir_high::Expression> {
     fn lower(&self, encoder: &impl HighTypeEncoderInterfacePrivate) -> Box<vir_poly::Expr> {
@@ -45,9 +49,7 @@ impl IntoPolymorphic<vir_poly::Expr> for vir_high::Expression {
             vir_high::Expression::UnaryOp(expression) => {
                 vir_poly::Expr::UnaryOp(expression.lower(encoder))
             }
-            vir_high::Expression::BinaryOp(expression) => {
-                vir_poly::Expr::BinOp(expression.lower(encoder))
-            }
+            vir_high::Expression::BinaryOp(expression) => expression.lower(encoder),
             vir_high::Expression::ContainerOp(expression) => {
                 vir_poly::Expr::ContainerOp(expression.lower(encoder))
             }
@@ -185,13 +187,34 @@ impl IntoPolymorphic<vir_poly::UnaryOpKind> for vir_high::expression::UnaryOpKin
     }
 }
 
-impl IntoPolymorphic<vir_poly::BinOp> for vir_high::expression::BinaryOp {
-    fn lower(&self, encoder: &impl HighTypeEncoderInterfacePrivate) -> vir_poly::BinOp {
-        vir_poly::BinOp {
-            op_kind: self.op_kind.lower(encoder),
-            left: self.left.lower(encoder),
-            right: self.right.lower(encoder),
-            position: self.position.lower(encoder),
+impl IntoPolymorphic<vir_poly::Expr> for vir_high::expression::BinaryOp {
+    fn lower(&self, encoder: &impl HighTypeEncoderInterfacePrivate) -> vir_poly::Expr {
+        let is_bitwise = matches!(
+            self.op_kind,
+            vir_high::expression::BinaryOpKind::BitAnd
+                | vir_high::expression::BinaryOpKind::BitOr
+                | vir_high::expression::BinaryOpKind::BitXor
+        ) || self.op_kind.is_shift();
+        if is_bitwise && !config::encode_bitvectors() {
+            // Integers are encoded as `Int`, so the operation has to go
+            // through a bitvector of the same width.
+            let vir_high::Type::Int(int) = self.left.get_type() else {
+                unreachable!("bitwise operation on a non-integer: {}", self);
+            };
+            vir_poly::Expr::int_bit_op(
+                self.op_kind.lower(encoder),
+                int.lower(encoder),
+                self.left.lower(encoder),
+                self.right.lower(encoder),
+            )
+            .set_pos(self.position.lower(encoder))
+        } else {
+            vir_poly::Expr::BinOp(vir_poly::BinOp {
+                op_kind: self.op_kind.lower(encoder),
+                left: self.left.lower(encoder),
+                right: self.right.lower(encoder),
+                position: self.position.lower(encoder),
+            })
         }
     }
 }
@@ -214,6 +237,31 @@ impl IntoPolymorphic<vir_poly::BinaryOpKind> for vir_high::expression::BinaryOpK
             vir_high::expression::BinaryOpKind::Or => vir_poly::BinaryOpKind::Or,
             vir_high::expression::BinaryOpKind::Implies => vir_poly::BinaryOpKind::Implies,
             vir_high::expression::BinaryOpKind::LifetimeIntersection => unreachable!(),
+            vir_high::expression::BinaryOpKind::BitAnd => vir_poly::BinaryOpKind::BitAnd,
+            vir_high::expression::BinaryOpKind::BitOr => vir_poly::BinaryOpKind::BitOr,
+            vir_high::expression::BinaryOpKind::BitXor => vir_poly::BinaryOpKind::BitXor,
+            vir_high::expression::BinaryOpKind::Shl => vir_poly::BinaryOpKind::Shl,
+            vir_high::expression::BinaryOpKind::LShr => vir_poly::BinaryOpKind::LShr,
+            vir_high::expression::BinaryOpKind::AShr => vir_poly::BinaryOpKind::AShr,
+        }
+    }
+}
+
+impl IntoPolymorphic<vir_poly::BitVector> for vir_high::ty::Int {
+    fn lower(&self, _encoder: &impl HighTypeEncoderInterfacePrivate) -> vir_poly::BitVector {
+        use vir_poly::{BitVector, BitVectorSize};
+        match self {
+            vir_high::ty::Int::U8 => BitVector::Unsigned(BitVectorSize::BV8),
+            vir_high::ty::Int::U16 => BitVector::Unsigned(BitVectorSize::BV16),
+            vir_high::ty::Int::U32 => BitVector::Unsigned(BitVectorSize::BV32),
+            vir_high::ty::Int::U64 => BitVector::Unsigned(BitVectorSize::BV64),
+            vir_high::ty::Int::U128 => BitVector::Unsigned(BitVectorSize::BV128),
+            vir_high::ty::Int::I8 => BitVector::Signed(BitVectorSize::BV8),
+            vir_high::ty::Int::I16 => BitVector::Signed(BitVectorSize::BV16),
+            vir_high::ty::Int::I32 => BitVector::Signed(BitVectorSize::BV32),
+            vir_high::ty::Int::I64 => BitVector::Signed(BitVectorSize::BV64),
+            vir_high::ty::Int::I128 => BitVector::Signed(BitVectorSize::BV128),
+            _ => unreachable!("integer type without a known width: {:?}", self),
         }
     }
 }
@@ -1,5 +1,9 @@
 use super::super::types::{create_value_field, interface::HighTypeEncoderInterfacePrivate};
-use crate::encoder::{errors::EncodingResult, high::lower::IntoPolymorphic};
+use crate::encoder::{
+    encoder::encode_field_name,
+    errors::{EncodingError, EncodingResult},
+    high::lower::IntoPolymorphic,
+};
 use vir_crate::{
     high as vir_high,
     polymorphic::{self as vir_poly, ExprIterator},
@@ -30,11 +34,16 @@ impl IntoPredicates for vir_high::TypeDecl {
             vir_high::TypeDecl::Tuple(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Struct(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Enum(ty_decl) => ty_decl.lower(ty, encoder),
-            vir_high::TypeDecl::Union(_ty_decl) => unreachable!("Unions are not supported"),
+            vir_high::TypeDecl::Union(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Array(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Slice(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Sequence(_ty_decl) => unimplemented!(),
             vir_high::TypeDecl::Map(_ty_decl) => unimplemented!(),
+            vir_high::TypeDecl::Set(_) | vir_high::TypeDecl::MultiSet(_) => {
+                Err(EncodingError::unsupported(format!(
+                    "predicates of the ghost type `{ty}` are not supported"
+                )))
+            }
             vir_high::TypeDecl::Reference(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Pointer(ty_decl) => ty_decl.lower(ty, encoder),
             vir_high::TypeDecl::Never => construct_never_predicate(encoder),
@@ -142,49 +151,100 @@ impl IntoPredicates for vir_high::type_decl::Struct {
     }
 }
 
+/// Lower a type whose value is one of `variants`, selected by a discriminant.
+fn lower_variants(
+    ty: &vir_high::Type,
+    discriminant_bounds: &[vir_high::type_decl::DiscriminantRange],
+    discriminant_values: &[vir_high::type_decl::DiscriminantValue],
+    variants: &[vir_high::type_decl::Struct],
+    encoder: &impl HighTypeEncoderInterfacePrivate,
+) -> Predicates {
+    let lower_type = ty.lower(encoder);
+
+    let discriminant_field = vir_high::FieldDecl::discriminant(vir_high::Type::MInt).lower(encoder);
+    let this = Predicate::construct_this(lower_type);
+    let discriminant_loc = vir_poly::Expr::from(this.clone()).field(discriminant_field.clone());
+
+    let mut lowered_variants = Vec::new();
+    for (variant, &discriminant) in variants.iter().zip(discriminant_values) {
+        let guard = vir_poly::Expr::eq_cmp(discriminant_loc.clone(), discriminant.into());
+        let variant_ty = ty.clone().variant(variant.name.clone().into());
+        let predicate = lower_struct(variant, &variant_ty, encoder)?;
+        lowered_variants.push((guard, variant.name.clone(), predicate));
+    }
+    let mut predicates: Vec<_> = lowered_variants
+        .iter()
+        .filter(|(_, _, predicate)| !predicate.has_empty_body())
+        .map(|(_, _, predicate)| Predicate::Struct(predicate.clone()))
+        .collect();
+    let discriminant_bounds = discriminant_bounds
+        .iter()
+        .map(|&(from, to)| {
+            if from == to {
+                vir_poly::Expr::eq_cmp(discriminant_loc.clone(), from.into())
+            } else {
+                vir_poly::Expr::and(
+                    vir_poly::Expr::le_cmp(from.into(), discriminant_loc.clone()),
+                    vir_poly::Expr::le_cmp(discriminant_loc.clone(), to.into()),
+                )
+            }
+        })
+        .disjoin();
+    let enum_predicate = Predicate::new_enum(
+        this,
+        discriminant_field,
+        discriminant_bounds,
+        lowered_variants,
+    );
+    predicates.push(enum_predicate);
+    Ok(predicates)
+}
+
 impl IntoPredicates for vir_high::type_decl::Enum {
     fn lower(
         &self,
         ty: &vir_high::Type,
         encoder: &impl HighTypeEncoderInterfacePrivate,
     ) -> Predicates {
-        let lower_type = ty.lower(encoder);
-
-        let discriminant_field =
-            vir_high::FieldDecl::discriminant(vir_high::Type::MInt).lower(encoder);
-        let this = Predicate::construct_this(lower_type);
-        let discriminant_loc = vir_poly::Expr::from(this.clone()).field(discriminant_field.clone());
+        lower_variants(
+            ty,
+            &self.discriminant_bounds,
+            &self.discriminant_values,
+            &self.variants,
+            encoder,
+        )
+    }
+}
 
-        let mut variants = Vec::new();
-        for (variant, discriminant) in self.variants.iter().zip(self.discriminant_values.clone()) {
-            let guard = vir_poly::Expr::eq_cmp(discriminant_loc.clone(), discriminant.into());
-            let variant_ty = ty.clone().variant(variant.name.clone().into());
-            let predicate = lower_struct(variant, &variant_ty, encoder)?;
-            variants.push((guard, variant.name.clone(), predicate));
-        }
-        let mut predicates: Vec<_> = variants
-            .iter()
-            .filter(|(_, _, predicate)| !predicate.has_empty_body())
-            .map(|(_, _, predicate)| Predicate::Struct(predicate.clone()))
-            .collect();
-        let discriminant_bounds = self
-            .discriminant_bounds
+impl IntoPredicates for vir_high::type_decl::Union {
+    fn lower(
+        &self,
+        ty: &vir_high::Type,
+        encoder: &impl HighTypeEncoderInterfacePrivate,
+    ) -> Predicates {
+        // The fields of a union are its variants and the discriminant is the
+        // index of the active field. The single field of each variant gets the
+        // name of the union field, so that places have the same shape as for
+        // structs.
+        let variants: Vec<_> = self
+            .variants
             .iter()
-            .map(|&(from, to)| {
-                if from == to {
-                    vir_poly::Expr::eq_cmp(discriminant_loc.clone(), from.into())
-                } else {
-                    vir_poly::Expr::and(
-                        vir_poly::Expr::le_cmp(from.into(), discriminant_loc.clone()),
-                        vir_poly::Expr::le_cmp(discriminant_loc.clone(), to.into()),
-                    )
+            .map(|variant| {
+                let mut variant = variant.clone();
+                let field_name = encode_field_name(&variant.name);
+                for field in &mut variant.fields {
+                    field.name = field_name.clone();
                 }
+                variant
             })
-            .disjoin();
-        let enum_predicate =
-            Predicate::new_enum(this, discriminant_field, discriminant_bounds, variants);
-        predicates.push(enum_predicate);
-        Ok(predicates)
+            .collect();
+        lower_variants(
+            ty,
+            &self.discriminant_bounds,
+            &self.discriminant_values,
+            &variants,
+            encoder,
+        )
     }
 }
 
@@ -228,8 +288,10 @@ impl IntoPredicates for vir_high::type_decl::Pointer {
         ty: &vir_high::Type,
         encoder: &impl HighTypeEncoderInterfacePrivate,
     ) -> Predicates {
-        // Pointers are unsupported.
-        let predicate = Predicate::new_abstract(ty.lower(encoder));
+        // A raw pointer owns only its address and not the memory it points
+        // to; that permission is held by whoever owns the pointed place.
+        let field = create_value_field(ty.clone())?.lower(encoder);
+        let predicate = Predicate::new_primitive_value(ty.lower(encoder), field, None, None);
         Ok(vec![predicate])
     }
 }
@@ -24,6 +24,12 @@ impl IntoPolymorphic<vir_poly::Type> for vir_high::Type {
                 key_type: Box::new(ty.key_type.lower(encoder)),
                 val_type: Box::new(ty.val_type.lower(encoder)),
             }),
+            vir_high::Type::Set(ty) => vir_poly::Type::Set(vir_poly::SetType {
+                typ: Box::new(ty.element_type.lower(encoder)),
+            }),
+            vir_high::Type::MultiSet(ty) => vir_poly::Type::MultiSet(vir_poly::MultiSetType {
+                typ: Box::new(ty.element_type.lower(encoder)),
+            }),
             vir_high::Type::Float(float) => {
                 vir_poly::Type::typed_ref(float.to_string().to_lowercase())
             }
@@ -102,7 +108,15 @@ impl IntoPolymorphic<vir_poly::TypedRef> for vir_high::ty::Enum {
 
 impl IntoPolymorphic<vir_poly::TypedRef> for vir_high::ty::Union {
     fn lower(&self, encoder: &impl HighTypeEncoderInterfacePrivate) -> vir_poly::TypedRef {
-        vir_poly::TypedRef::new(self.name.clone(), self.arguments.lower(encoder))
+        vir_poly::TypedRef {
+            label: self.name.clone(),
+            arguments: self.arguments.lower(encoder),
+            variant: self
+                .variant
+                .as_ref()
+                .map(|variant| variant.to_string())
+                .unwrap_or_default(),
+        }
     }
 }
 
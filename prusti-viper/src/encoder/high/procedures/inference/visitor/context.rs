@@ -1,6 +1,6 @@
 use super::{super::ensurer::ExpandedPermissionKind, Visitor};
 use crate::encoder::{
-    errors::{ErrorCtxt, SpannedEncodingResult},
+    errors::{ErrorCtxt, SpannedEncodingError, SpannedEncodingResult},
     high::to_typed::types::HighToTypedTypeEncoderInterface,
     mir::errors::ErrorInterface,
 };
@@ -111,6 +111,18 @@ impl<'p, 'v, 'tcx> super::super::ensurer::Context for Visitor<'p, 'v, 'tcx> {
             }
             vir_typed::TypeDecl::Sequence(_) => unimplemented!("ty: {}", ty),
             vir_typed::TypeDecl::Map(_) => unimplemented!("ty: {}", ty),
+            vir_typed::TypeDecl::Set(_) | vir_typed::TypeDecl::MultiSet(_) => {
+                let span = self
+                    .get_span(place.position())
+                    .unwrap_or_else(MultiSpan::new);
+                return Err(SpannedEncodingError::unsupported(
+                    format!(
+                        "accessing a part of a value of the ghost type `{ty}` is not supported \
+                            in the core proof"
+                    ),
+                    span,
+                ));
+            }
             vir_typed::TypeDecl::Never => unimplemented!("ty: {}", ty),
             vir_typed::TypeDecl::Closure(_) => unimplemented!("ty: {}", ty),
             vir_typed::TypeDecl::Unsupported(_) => unimplemented!("ty: {}", ty),
@@ -22,6 +22,10 @@ pub(crate) fn create_value_field(ty: vir::Type) -> EncodingResult<vir::FieldDecl
 
         vir::Type::Map(ref _map) => vir::FieldDecl::new("val_map", 0usize, ty),
 
+        vir::Type::Set(ref _set) => vir::FieldDecl::new("val_set", 0usize, ty),
+
+        vir::Type::MultiSet(ref _multiset) => vir::FieldDecl::new("val_multiset", 0usize, ty),
+
         // For composed data structures, we typically use a snapshot rather than a field.
         // To unify how parameters are passed to functions, we treat them like a reference.
         vir::Type::Tuple(_)
@@ -34,7 +38,8 @@ pub(crate) fn create_value_field(ty: vir::Type) -> EncodingResult<vir::FieldDecl
         | vir::Type::TypeVar(_)
         | vir::Type::Projection(_) => vir::FieldDecl::new("val_ref", 0usize, ty),
 
-        vir::Type::Reference(vir::ty::Reference { target_type, .. }) => {
+        vir::Type::Reference(vir::ty::Reference { target_type, .. })
+        | vir::Type::Pointer(vir::ty::Pointer { target_type, .. }) => {
             vir::FieldDecl::new("val_ref", 0usize, (*target_type).clone())
         }
 
@@ -42,11 +47,7 @@ pub(crate) fn create_value_field(ty: vir::Type) -> EncodingResult<vir::FieldDecl
             error_internal!("create_value_field should not be called for {}", ty);
         }
 
-        vir::Type::Union(_)
-        | vir::Type::Pointer(_)
-        | vir::Type::Never
-        | vir::Type::Str
-        | vir::Type::Unsupported(_) => {
+        vir::Type::Union(_) | vir::Type::Never | vir::Type::Str | vir::Type::Unsupported(_) => {
             error_unsupported!("{} type is not supported", ty);
         }
 
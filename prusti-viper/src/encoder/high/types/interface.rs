@@ -270,7 +270,9 @@ impl<'v, 'tcx: 'v> HighTypeEncoderInterface<'tcx> for super::super::super::Encod
             | vir_mid::TypeDecl::Reference(_)
             | vir_mid::TypeDecl::Pointer(_)
             | vir_mid::TypeDecl::Sequence(_)
-            | vir_mid::TypeDecl::Map(_) => false,
+            | vir_mid::TypeDecl::Map(_)
+            | vir_mid::TypeDecl::Set(_)
+            | vir_mid::TypeDecl::MultiSet(_) => false,
             vir_mid::TypeDecl::Struct(decl) => decl.fields.is_empty(),
             vir_mid::TypeDecl::Enum(decl) => decl.variants.is_empty(),
             vir_mid::TypeDecl::Array(_decl) => unimplemented!(),
@@ -137,6 +137,50 @@ impl<'p, 'tcx: 'p> LoopEncoder<'p, 'tcx> {
             .any(|def_init_place| utils::is_prefix(place, *def_init_place))
     }
 
+    /// Return the place of the iterator of a `for` loop, i.e. the place that
+    /// is mutably borrowed to call `Iterator::next` in the loop.
+    pub fn get_for_loop_iterator(&self, loop_head: BasicBlockIndex) -> Option<mir::Place<'tcx>> {
+        let loop_info = self.loops();
+        let loop_depth = loop_info.get_loop_head_depth(loop_head);
+        let next_fn = self.tcx.lang_items().next_fn()?;
+        loop_info
+            .get_loop_body(loop_head)
+            .iter()
+            .copied()
+            .filter(|&bb| loop_info.get_loop_depth(bb) == loop_depth)
+            .find_map(|bb| {
+                let basic_block = &self.mir()[bb];
+                let mir::TerminatorKind::Call { func, args, .. } = &basic_block.terminator().kind
+                else {
+                    return None;
+                };
+                match func.constant()?.ty().kind() {
+                    ty::TyKind::FnDef(def_id, _) if *def_id == next_fn => {}
+                    _ => return None,
+                }
+                // Follow the (re)borrows of the argument back to the iterator.
+                let mut place = args.first()?.place()?;
+                for stmt in basic_block.statements.iter().rev() {
+                    if let mir::StatementKind::Assign(box (
+                        lhs,
+                        mir::Rvalue::Ref(_, mir::BorrowKind::Mut { .. }, borrowed),
+                    )) = &stmt.kind
+                    {
+                        if *lhs == place {
+                            place = match borrowed.as_ref() {
+                                mir::PlaceRef {
+                                    local,
+                                    projection: [mir::ProjectionElem::Deref],
+                                } => mir::Place::from(local),
+                                _ => return Some(*borrowed),
+                            };
+                        }
+                    }
+                }
+                None
+            })
+    }
+
     /// Return the block at whose end the loop invariant holds
     #[tracing::instrument(level = "debug", skip(self))]
     pub fn get_loop_invariant_block(
@@ -1470,6 +1470,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinMethodsInterface for Lowerer<'p, 'v, 'tcx> {
                 | vir_mid::TypeDecl::Float(_)
                 | vir_mid::TypeDecl::Pointer(_)
                 | vir_mid::TypeDecl::Sequence(_)
+                | vir_mid::TypeDecl::Set(_)
+                | vir_mid::TypeDecl::MultiSet(_)
                 | vir_mid::TypeDecl::Map(_) => {
                     builder.add_memory_block_copy_call()?;
                 }
@@ -1638,6 +1640,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinMethodsInterface for Lowerer<'p, 'v, 'tcx> {
                 | vir_mid::TypeDecl::Float(_)
                 | vir_mid::TypeDecl::Pointer(_)
                 | vir_mid::TypeDecl::Sequence(_)
+                | vir_mid::TypeDecl::Set(_)
+                | vir_mid::TypeDecl::MultiSet(_)
                 | vir_mid::TypeDecl::Map(_) => {
                     builder.add_memory_block_copy_call()?;
                 }
@@ -1709,6 +1713,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinMethodsInterface for Lowerer<'p, 'v, 'tcx> {
                 | vir_mid::TypeDecl::Float(_)
                 | vir_mid::TypeDecl::Pointer(_)
                 | vir_mid::TypeDecl::Sequence(_)
+                | vir_mid::TypeDecl::Set(_)
+                | vir_mid::TypeDecl::MultiSet(_)
                 | vir_mid::TypeDecl::Map(_) => {
                     builder.add_write_address_constant_call()?;
                 }
@@ -2021,6 +2027,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinMethodsInterface for Lowerer<'p, 'v, 'tcx> {
                 | vir_mid::TypeDecl::Reference(_)
                 | vir_mid::TypeDecl::Pointer(_)
                 | vir_mid::TypeDecl::Sequence(_)
+                | vir_mid::TypeDecl::Set(_)
+                | vir_mid::TypeDecl::MultiSet(_)
                 | vir_mid::TypeDecl::Map(_) => {
                     // Primitive type. Nothing to do.
                 }
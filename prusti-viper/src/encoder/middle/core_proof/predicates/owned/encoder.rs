@@ -84,6 +84,8 @@ impl<'l, 'p, 'v, 'tcx> PredicateEncoder<'l, 'p, 'v, 'tcx> {
             | vir_mid::TypeDecl::Float(_)
             | vir_mid::TypeDecl::Pointer(_)
             | vir_mid::TypeDecl::Sequence(_)
+            | vir_mid::TypeDecl::Set(_)
+            | vir_mid::TypeDecl::MultiSet(_)
             | vir_mid::TypeDecl::Map(_) => {
                 builder.add_base_memory_block()?;
                 builder.add_bytes_snapshot_equality()?;
@@ -212,6 +214,8 @@ impl<'l, 'p, 'v, 'tcx> PredicateEncoder<'l, 'p, 'v, 'tcx> {
                 | vir_mid::TypeDecl::Float(_)
                 | vir_mid::TypeDecl::Pointer(_)
                 | vir_mid::TypeDecl::Sequence(_)
+                | vir_mid::TypeDecl::Set(_)
+                | vir_mid::TypeDecl::MultiSet(_)
                 | vir_mid::TypeDecl::Trusted(_)
                 | vir_mid::TypeDecl::Map(_)
                 | vir_mid::TypeDecl::TypeVar(_)
@@ -226,6 +230,8 @@ impl<'l, 'p, 'v, 'tcx> PredicateEncoder<'l, 'p, 'v, 'tcx> {
             | vir_mid::TypeDecl::Float(_)
             | vir_mid::TypeDecl::Pointer(_)
             | vir_mid::TypeDecl::Sequence(_)
+            | vir_mid::TypeDecl::Set(_)
+            | vir_mid::TypeDecl::MultiSet(_)
             | vir_mid::TypeDecl::Trusted(_)
             | vir_mid::TypeDecl::Map(_)
             | vir_mid::TypeDecl::TypeVar(_) => {}
@@ -311,6 +317,8 @@ impl<'l, 'p, 'v, 'tcx> PredicateEncoder<'l, 'p, 'v, 'tcx> {
                 | vir_mid::TypeDecl::Float(_)
                 | vir_mid::TypeDecl::Pointer(_)
                 | vir_mid::TypeDecl::Sequence(_)
+                | vir_mid::TypeDecl::Set(_)
+                | vir_mid::TypeDecl::MultiSet(_)
                 | vir_mid::TypeDecl::Trusted(_)
                 | vir_mid::TypeDecl::Map(_)
                 | vir_mid::TypeDecl::TypeVar(_)
@@ -325,6 +333,8 @@ impl<'l, 'p, 'v, 'tcx> PredicateEncoder<'l, 'p, 'v, 'tcx> {
             | vir_mid::TypeDecl::Float(_)
             | vir_mid::TypeDecl::Pointer(_)
             | vir_mid::TypeDecl::Sequence(_)
+            | vir_mid::TypeDecl::Set(_)
+            | vir_mid::TypeDecl::MultiSet(_)
             | vir_mid::TypeDecl::Trusted(_)
             | vir_mid::TypeDecl::Map(_)
             | vir_mid::TypeDecl::TypeVar(_) => {}
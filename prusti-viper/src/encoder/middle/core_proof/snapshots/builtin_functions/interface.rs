@@ -16,6 +16,8 @@ trait Private {
         &mut self,
         ty: &vir_mid::Type,
     ) -> SpannedEncodingResult<()>;
+    fn encode_sequence_to_multiset_def(&mut self, ty: &vir_mid::Type) -> SpannedEncodingResult<()>;
+    fn encode_sequence_index_of_def(&mut self, ty: &vir_mid::Type) -> SpannedEncodingResult<()>;
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> Private for Lowerer<'p, 'v, 'tcx> {
@@ -96,6 +98,200 @@ impl<'p, 'v: 'p, 'tcx: 'v> Private for Lowerer<'p, 'v, 'tcx> {
         }
         Ok(())
     }
+
+    /// Viper has no built-in conversion from sequences to multisets, so we
+    /// axiomatise it structurally: the conversion maps the empty sequence to
+    /// the empty multiset, a singleton sequence to a singleton multiset, and
+    /// distributes over concatenation.
+    fn encode_sequence_to_multiset_def(&mut self, ty: &vir_mid::Type) -> SpannedEncodingResult<()> {
+        if !self
+            .snapshots_state
+            .encoded_sequence_to_multiset
+            .contains(ty)
+        {
+            self.snapshots_state
+                .encoded_sequence_to_multiset
+                .insert(ty.clone());
+
+            let element_type = match ty {
+                vir_mid::Type::MultiSet(vir_mid::ty::MultiSet { element_type, .. }) => element_type,
+                _ => {
+                    unreachable!("ty: {}", ty);
+                }
+            };
+
+            let domain_name = self.encode_snapshot_domain_name(ty)?;
+            let element_type_snapshot = element_type.to_snapshot(self)?;
+            let sequence_type = vir_low::Type::seq(element_type_snapshot.clone());
+            let multiset_type = vir_low::Type::multi_set(element_type_snapshot.clone());
+            use vir_low::macros::*;
+            var_decls! {
+                element: {element_type_snapshot},
+                left: {sequence_type.clone()},
+                right: {sequence_type.clone()}
+            };
+            let sequence_op = |kind, arguments| {
+                vir_low::Expression::container_op_no_pos(kind, sequence_type.clone(), arguments)
+            };
+            let multiset_op = |kind, arguments| {
+                vir_low::Expression::container_op_no_pos(kind, multiset_type.clone(), arguments)
+            };
+
+            let empty_sequence = sequence_op(vir_low::ContainerOpKind::SeqConstructor, Vec::new());
+            let empty_multiset =
+                multiset_op(vir_low::ContainerOpKind::MultiSetConstructor, Vec::new());
+            let empty_call = self.encode_sequence_to_multiset_call(ty, empty_sequence)?;
+            let empty_body = expr! { [empty_call] == [empty_multiset] };
+
+            let single_sequence = sequence_op(
+                vir_low::ContainerOpKind::SeqConstructor,
+                vec![element.clone().into()],
+            );
+            let single_multiset = multiset_op(
+                vir_low::ContainerOpKind::MultiSetConstructor,
+                vec![element.clone().into()],
+            );
+            let single_call = self.encode_sequence_to_multiset_call(ty, single_sequence)?;
+            let single_body = vir_low::Expression::forall(
+                vec![element],
+                vec![vir_low::Trigger::new(vec![single_call.clone()])],
+                expr! { [single_call] == [single_multiset] },
+            );
+
+            let concat = sequence_op(
+                vir_low::ContainerOpKind::SeqConcat,
+                vec![left.clone().into(), right.clone().into()],
+            );
+            let concat_call = self.encode_sequence_to_multiset_call(ty, concat)?;
+            let left_call = self.encode_sequence_to_multiset_call(ty, left.clone().into())?;
+            let right_call = self.encode_sequence_to_multiset_call(ty, right.clone().into())?;
+            let union = multiset_op(
+                vir_low::ContainerOpKind::MultiSetUnion,
+                vec![left_call.clone(), right_call],
+            );
+            let concat_body = vir_low::Expression::forall(
+                vec![left.clone(), right],
+                vec![vir_low::Trigger::new(vec![concat_call.clone()])],
+                expr! { [concat_call] == [union] },
+            );
+
+            let multiset_len = multiset_op(
+                vir_low::ContainerOpKind::MultiSetCardinality,
+                vec![left_call.clone()],
+            );
+            let sequence_len =
+                sequence_op(vir_low::ContainerOpKind::SeqLen, vec![left.clone().into()]);
+            let len_body = vir_low::Expression::forall(
+                vec![left],
+                vec![vir_low::Trigger::new(vec![left_call])],
+                expr! { [multiset_len] == [sequence_len] },
+            );
+
+            for (suffix, body) in [
+                ("empty", empty_body),
+                ("single", single_body),
+                ("concat", concat_body),
+                ("len", len_body),
+            ] {
+                let axiom = vir_low::DomainAxiomDecl {
+                    comment: None,
+                    name: format!("{domain_name}$sequence_to_multiset_{suffix}"),
+                    body,
+                };
+                self.declare_axiom(&domain_name, axiom)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// `index_of` returns the index of the first occurrence of the element
+    /// in the sequence and `-1` if the element does not occur in it.
+    fn encode_sequence_index_of_def(&mut self, ty: &vir_mid::Type) -> SpannedEncodingResult<()> {
+        if !self.snapshots_state.encoded_sequence_index_of.contains(ty) {
+            self.snapshots_state
+                .encoded_sequence_index_of
+                .insert(ty.clone());
+
+            let element_type = match ty {
+                vir_mid::Type::Sequence(vir_mid::ty::Sequence { element_type, .. }) => element_type,
+                _ => {
+                    unreachable!("ty: {}", ty);
+                }
+            };
+
+            let domain_name = self.encode_snapshot_domain_name(ty)?;
+            let element_type_snapshot = element_type.to_snapshot(self)?;
+            let sequence_type = vir_low::Type::seq(element_type_snapshot.clone());
+            use vir_low::macros::*;
+            var_decls! {
+                sequence: {sequence_type.clone()},
+                element: {element_type_snapshot},
+                index: Int
+            };
+            let sequence_op = |kind, arguments| {
+                vir_low::Expression::container_op_no_pos(kind, sequence_type.clone(), arguments)
+            };
+            let call = self.encode_sequence_index_of_call(
+                ty,
+                sequence.clone().into(),
+                element.clone().into(),
+            )?;
+            let contains = sequence_op(
+                vir_low::ContainerOpKind::SeqContains,
+                vec![element.clone().into(), sequence.clone().into()],
+            );
+            let len = sequence_op(
+                vir_low::ContainerOpKind::SeqLen,
+                vec![sequence.clone().into()],
+            );
+            let found = sequence_op(
+                vir_low::ContainerOpKind::SeqIndex,
+                vec![sequence.clone().into(), call.clone()],
+            );
+            let other = sequence_op(
+                vir_low::ContainerOpKind::SeqIndex,
+                vec![sequence.clone().into(), index.clone().into()],
+            );
+
+            let contained_body = vir_low::Expression::forall(
+                vec![sequence.clone(), element.clone()],
+                vec![vir_low::Trigger::new(vec![call.clone()])],
+                expr! {
+                    [contains.clone()] ==> (
+                        ([0.into()] <= [call.clone()]) &&
+                        ([call.clone()] < [len]) &&
+                        ([found] == element)
+                    )
+                },
+            );
+            let not_contained_body = vir_low::Expression::forall(
+                vec![sequence.clone(), element.clone()],
+                vec![vir_low::Trigger::new(vec![call.clone()])],
+                expr! { (![contains]) ==> ([call.clone()] == (-[1.into()])) },
+            );
+            let first_body = vir_low::Expression::forall(
+                vec![sequence, element.clone(), index.clone()],
+                vec![vir_low::Trigger::new(vec![call.clone(), other.clone()])],
+                expr! {
+                    (([0.into()] <= index) && (index < [call])) ==> ([other] != element)
+                },
+            );
+
+            for (suffix, body) in [
+                ("contained", contained_body),
+                ("not_contained", not_contained_body),
+                ("first", first_body),
+            ] {
+                let axiom = vir_low::DomainAxiomDecl {
+                    comment: None,
+                    name: format!("{domain_name}$sequence_index_of_{suffix}"),
+                    body,
+                };
+                self.declare_axiom(&domain_name, axiom)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 pub(in super::super::super) trait BuiltinFunctionsInterface {
@@ -105,6 +301,17 @@ pub(in super::super::super) trait BuiltinFunctionsInterface {
         argument: vir_low::Expression,
         count: vir_low::Expression,
     ) -> SpannedEncodingResult<vir_low::Expression>;
+    fn encode_sequence_to_multiset_call(
+        &mut self,
+        ty: &vir_mid::Type,
+        sequence: vir_low::Expression,
+    ) -> SpannedEncodingResult<vir_low::Expression>;
+    fn encode_sequence_index_of_call(
+        &mut self,
+        ty: &vir_mid::Type,
+        sequence: vir_low::Expression,
+        element: vir_low::Expression,
+    ) -> SpannedEncodingResult<vir_low::Expression>;
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> BuiltinFunctionsInterface for Lowerer<'p, 'v, 'tcx> {
@@ -126,4 +333,42 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinFunctionsInterface for Lowerer<'p, 'v, 'tcx> {
             Default::default(),
         )
     }
+
+    /// `ty` is the type of the resulting multiset.
+    fn encode_sequence_to_multiset_call(
+        &mut self,
+        ty: &vir_mid::Type,
+        sequence: vir_low::Expression,
+    ) -> SpannedEncodingResult<vir_low::Expression> {
+        self.encode_sequence_to_multiset_def(ty)?;
+        let domain_name = self.encode_snapshot_domain_name(ty)?;
+        let function_name = format!("sequence_to_multiset${domain_name}");
+        let result_type = ty.to_snapshot(self)?;
+        self.create_domain_func_app(
+            domain_name,
+            function_name,
+            vec![sequence],
+            result_type,
+            Default::default(),
+        )
+    }
+
+    /// `ty` is the type of the sequence. The result is a mathematical integer.
+    fn encode_sequence_index_of_call(
+        &mut self,
+        ty: &vir_mid::Type,
+        sequence: vir_low::Expression,
+        element: vir_low::Expression,
+    ) -> SpannedEncodingResult<vir_low::Expression> {
+        self.encode_sequence_index_of_def(ty)?;
+        let domain_name = self.encode_snapshot_domain_name(ty)?;
+        let function_name = format!("sequence_index_of${domain_name}");
+        self.create_domain_func_app(
+            domain_name,
+            function_name,
+            vec![sequence, element],
+            vir_low::Type::Int,
+            Default::default(),
+        )
+    }
 }
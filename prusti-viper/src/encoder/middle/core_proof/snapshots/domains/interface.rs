@@ -54,10 +54,11 @@ pub(in super::super::super) trait SnapshotDomainsInterface {
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> SnapshotDomainsInterface for Lowerer<'p, 'v, 'tcx> {
-    /// Note: Even though we directly use Viper maps and sequences as snapshots
-    /// for `vir_mid::Type::Map(_)` and `vir_mid::Type::Sequence(_)`
-    /// respectively, we still need a domain in which we put their custom
-    /// `validity` and `to_bytes` functions.
+    /// Note: Even though we directly use Viper maps, sequences, sets, and
+    /// multisets as snapshots for `vir_mid::Type::Map(_)`,
+    /// `vir_mid::Type::Sequence(_)`, `vir_mid::Type::Set(_)`, and
+    /// `vir_mid::Type::MultiSet(_)` respectively, we still need a domain in
+    /// which we put their custom `validity` and `to_bytes` functions.
     fn encode_snapshot_domain_name(&mut self, ty: &vir_mid::Type) -> SpannedEncodingResult<String> {
         assert!(
             !matches!(
@@ -108,6 +109,18 @@ impl<'p, 'v: 'p, 'tcx: 'v> SnapshotDomainsInterface for Lowerer<'p, 'v, 'tcx> {
                 self.register_type_domain(ty, &low_ty)?;
                 Ok(low_ty)
             }
+            vir_mid::Type::Set(set) => {
+                let enc_elem = self.encode_snapshot_domain_type(&set.element_type)?;
+                let low_ty = vir_low::Type::set(enc_elem);
+                self.register_type_domain(ty, &low_ty)?;
+                Ok(low_ty)
+            }
+            vir_mid::Type::MultiSet(multiset) => {
+                let enc_elem = self.encode_snapshot_domain_type(&multiset.element_type)?;
+                let low_ty = vir_low::Type::multi_set(enc_elem);
+                self.register_type_domain(ty, &low_ty)?;
+                Ok(low_ty)
+            }
             vir_mid::Type::Array(array) => {
                 let enc_elem = self.encode_snapshot_domain_type(&array.element_type)?;
                 let low_ty = vir_low::Type::seq(enc_elem);
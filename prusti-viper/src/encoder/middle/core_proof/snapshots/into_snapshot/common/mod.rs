@@ -6,7 +6,10 @@ use crate::encoder::{
         lifetimes::*,
         lowerer::DomainsLowererInterface,
         references::ReferencesInterface,
-        snapshots::{IntoSnapshot, SnapshotDomainsInterface, SnapshotValuesInterface},
+        snapshots::{
+            BuiltinFunctionsInterface, IntoSnapshot, SnapshotDomainsInterface,
+            SnapshotValuesInterface,
+        },
         types::TypesInterface,
     },
 };
@@ -408,6 +411,14 @@ pub(super) trait IntoSnapshotLowerer<'p, 'v: 'p, 'tcx: 'v> {
             vir_mid::BinaryOpKind::Or => vir_low::BinaryOpKind::Or,
             vir_mid::BinaryOpKind::Implies => vir_low::BinaryOpKind::Implies,
             vir_mid::BinaryOpKind::LifetimeIntersection => unreachable!(),
+            vir_mid::BinaryOpKind::BitAnd
+            | vir_mid::BinaryOpKind::BitOr
+            | vir_mid::BinaryOpKind::BitXor
+            | vir_mid::BinaryOpKind::Shl
+            | vir_mid::BinaryOpKind::LShr
+            | vir_mid::BinaryOpKind::AShr => {
+                unreachable!("rejected by construct_binary_op_snapshot: {}", op)
+            }
         })
     }
 
@@ -492,6 +503,24 @@ pub(super) trait IntoSnapshotLowerer<'p, 'v: 'p, 'tcx: 'v> {
             ))
         };
 
+        let set = |low_kind, args| {
+            Ok(vir_low::Expression::container_op(
+                low_kind,
+                vir_low::Type::set(ty_args[0].clone()),
+                args,
+                app.position,
+            ))
+        };
+
+        let multiset = |low_kind, args| {
+            Ok(vir_low::Expression::container_op(
+                low_kind,
+                vir_low::Type::multi_set(ty_args[0].clone()),
+                args,
+                app.position,
+            ))
+        };
+
         match app.function {
             BuiltinFunc::Size => {
                 let return_type = self.type_to_snapshot(lowerer, &app.return_type)?;
@@ -585,6 +614,165 @@ pub(super) trait IntoSnapshotLowerer<'p, 'v: 'p, 'tcx: 'v> {
                 let value = seq(ContainerOpKind::SeqLen)?;
                 lowerer.construct_constant_snapshot(app.get_type(), value, app.position)
             }
+            BuiltinFunc::UpdateSeq => {
+                assert_eq!(args.len(), 3);
+                let index = lowerer.obtain_constant_value(
+                    app.arguments[1].get_type(),
+                    args[1].clone(),
+                    app.position,
+                )?;
+                Ok(vir_low::Expression::container_op(
+                    ContainerOpKind::SeqUpdate,
+                    vir_low::Type::seq(ty_args[0].clone()),
+                    vec![args[0].clone(), index, args[2].clone()],
+                    app.position,
+                ))
+            }
+            BuiltinFunc::SeqTake | BuiltinFunc::SeqSkip => {
+                assert_eq!(args.len(), 2);
+                let count = lowerer.obtain_constant_value(
+                    app.arguments[1].get_type(),
+                    args[1].clone(),
+                    app.position,
+                )?;
+                let kind = if app.function == BuiltinFunc::SeqTake {
+                    ContainerOpKind::SeqTake
+                } else {
+                    ContainerOpKind::SeqDrop
+                };
+                Ok(vir_low::Expression::container_op(
+                    kind,
+                    vir_low::Type::seq(ty_args[0].clone()),
+                    vec![args[0].clone(), count],
+                    app.position,
+                ))
+            }
+            BuiltinFunc::SeqSubsequence => {
+                // `s[from..to]` is encoded as `s[from..][..to - from]`.
+                assert_eq!(args.len(), 3);
+                let from = lowerer.obtain_constant_value(
+                    app.arguments[1].get_type(),
+                    args[1].clone(),
+                    app.position,
+                )?;
+                let to = lowerer.obtain_constant_value(
+                    app.arguments[2].get_type(),
+                    args[2].clone(),
+                    app.position,
+                )?;
+                let seq_ty = vir_low::Type::seq(ty_args[0].clone());
+                let dropped = vir_low::Expression::container_op(
+                    ContainerOpKind::SeqDrop,
+                    seq_ty.clone(),
+                    vec![args[0].clone(), from.clone()],
+                    app.position,
+                );
+                let length = vir_low::Expression::binary_op(
+                    vir_low::BinaryOpKind::Sub,
+                    to,
+                    from,
+                    app.position,
+                );
+                Ok(vir_low::Expression::container_op(
+                    ContainerOpKind::SeqTake,
+                    seq_ty,
+                    vec![dropped, length],
+                    app.position,
+                ))
+            }
+            BuiltinFunc::SeqContains => {
+                assert_eq!(args.len(), 2);
+                // The element is passed by reference.
+                let element = lowerer.reference_target_current_snapshot(
+                    app.arguments[1].get_type(),
+                    args.pop().unwrap(),
+                    app.position,
+                )?;
+                let sequence = args.pop().unwrap();
+                // Viper expects the element first.
+                let value = vir_low::Expression::container_op(
+                    ContainerOpKind::SeqContains,
+                    vir_low::Type::seq(ty_args[0].clone()),
+                    vec![element, sequence],
+                    app.position,
+                );
+                let value =
+                    lowerer.construct_constant_snapshot(app.get_type(), value, app.position)?;
+                self.ensure_bool_expression(lowerer, app.get_type(), value, expect_math_bool)
+            }
+            BuiltinFunc::SeqIndexOf => {
+                assert_eq!(args.len(), 2);
+                let element = lowerer.reference_target_current_snapshot(
+                    app.arguments[1].get_type(),
+                    args.pop().unwrap(),
+                    app.position,
+                )?;
+                let sequence = args.pop().unwrap();
+                let value = lowerer.encode_sequence_index_of_call(
+                    app.arguments[0].get_type(),
+                    sequence,
+                    element,
+                )?;
+                lowerer.construct_constant_snapshot(app.get_type(), value, app.position)
+            }
+            BuiltinFunc::SeqFromSlice => {
+                // Slices already use Viper sequences as their snapshots and the
+                // reference to the slice was dereferenced above.
+                assert_eq!(args.len(), 1);
+                Ok(args.pop().unwrap())
+            }
+            BuiltinFunc::SeqToMultiSet => {
+                assert_eq!(args.len(), 1);
+                lowerer.encode_sequence_to_multiset_call(&app.return_type, args.pop().unwrap())
+            }
+            BuiltinFunc::EmptySet | BuiltinFunc::SingleSet => {
+                set(ContainerOpKind::SetConstructor, args)
+            }
+            BuiltinFunc::SetUnion => set(ContainerOpKind::SetUnion, args),
+            BuiltinFunc::SetIntersection => set(ContainerOpKind::SetIntersection, args),
+            BuiltinFunc::SetMinus => set(ContainerOpKind::SetMinus, args),
+            BuiltinFunc::SetSubset => {
+                let value = set(ContainerOpKind::SetSubset, args)?;
+                let value =
+                    lowerer.construct_constant_snapshot(app.get_type(), value, app.position)?;
+                self.ensure_bool_expression(lowerer, app.get_type(), value, expect_math_bool)
+            }
+            BuiltinFunc::SetContains => {
+                // Viper expects the element first.
+                args.swap(0, 1);
+                let value = set(ContainerOpKind::SetContains, args)?;
+                let value =
+                    lowerer.construct_constant_snapshot(app.get_type(), value, app.position)?;
+                self.ensure_bool_expression(lowerer, app.get_type(), value, expect_math_bool)
+            }
+            BuiltinFunc::SetLen => {
+                let value = set(ContainerOpKind::SetCardinality, args)?;
+                lowerer.construct_constant_snapshot(app.get_type(), value, app.position)
+            }
+            BuiltinFunc::EmptyMultiSet | BuiltinFunc::SingleMultiSet => {
+                multiset(ContainerOpKind::MultiSetConstructor, args)
+            }
+            BuiltinFunc::MultiSetUnion => multiset(ContainerOpKind::MultiSetUnion, args),
+            BuiltinFunc::MultiSetIntersection => {
+                multiset(ContainerOpKind::MultiSetIntersection, args)
+            }
+            BuiltinFunc::MultiSetMinus => multiset(ContainerOpKind::MultiSetMinus, args),
+            BuiltinFunc::MultiSetSubset => {
+                let value = multiset(ContainerOpKind::MultiSetSubset, args)?;
+                let value =
+                    lowerer.construct_constant_snapshot(app.get_type(), value, app.position)?;
+                self.ensure_bool_expression(lowerer, app.get_type(), value, expect_math_bool)
+            }
+            BuiltinFunc::MultiSetCount => {
+                // Viper expects the element first.
+                args.swap(0, 1);
+                let value = multiset(ContainerOpKind::MultiSetContains, args)?;
+                lowerer.construct_constant_snapshot(app.get_type(), value, app.position)
+            }
+            BuiltinFunc::MultiSetLen => {
+                let value = multiset(ContainerOpKind::MultiSetCardinality, args)?;
+                lowerer.construct_constant_snapshot(app.get_type(), value, app.position)
+            }
             BuiltinFunc::LifetimeIncluded => {
                 assert_eq!(args.len(), 2);
                 lowerer.encode_lifetime_included()?;
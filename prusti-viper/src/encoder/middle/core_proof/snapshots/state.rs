@@ -15,6 +15,10 @@ pub(in super::super) struct SnapshotsState {
     pub(super) encoded_to_bytes: FxHashSet<vir_mid::Type>,
     /// The list of types for which sequence_repeat_constructor was encoded.
     pub(super) encoded_sequence_repeat_constructor: FxHashSet<vir_mid::Type>,
+    /// The list of multiset types for which sequence_to_multiset was encoded.
+    pub(super) encoded_sequence_to_multiset: FxHashSet<vir_mid::Type>,
+    /// The list of sequence types for which sequence_index_of was encoded.
+    pub(super) encoded_sequence_index_of: FxHashSet<vir_mid::Type>,
     pub(super) all_variables: AllVariablesMap,
     pub(super) variables: BTreeMap<vir_mid::BasicBlockId, VariableVersionMap>,
     pub(super) variables_at_label: BTreeMap<String, VariableVersionMap>,
@@ -1,5 +1,5 @@
 use crate::encoder::{
-    errors::SpannedEncodingResult,
+    errors::{MultiSpan, SpannedEncodingError, SpannedEncodingResult},
     middle::core_proof::{
         addresses::AddressesInterface,
         lowerer::{DomainsLowererInterface, Lowerer},
@@ -248,6 +248,27 @@ impl<'p, 'v: 'p, 'tcx: 'v> SnapshotValuesInterface for Lowerer<'p, 'v, 'tcx> {
         right: vir_low::Expression,
         position: vir_mid::Position,
     ) -> SpannedEncodingResult<vir_low::Expression> {
+        if matches!(
+            op,
+            vir_mid::BinaryOpKind::BitAnd
+                | vir_mid::BinaryOpKind::BitOr
+                | vir_mid::BinaryOpKind::BitXor
+                | vir_mid::BinaryOpKind::Shl
+                | vir_mid::BinaryOpKind::LShr
+                | vir_mid::BinaryOpKind::AShr
+        ) {
+            let span = self
+                .encoder
+                .error_manager()
+                .position_manager()
+                .get_span(position.into())
+                .cloned()
+                .unwrap_or_else(MultiSpan::new);
+            return Err(SpannedEncodingError::unsupported(
+                format!("bitwise operation '{op}' is not supported in the core proof"),
+                span,
+            ));
+        }
         // TODO: Add MPerm special cases here.
         if ty == &vir_mid::Type::MBool {
             Ok(vir_low::Expression::binary_op(
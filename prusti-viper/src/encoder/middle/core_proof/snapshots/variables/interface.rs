@@ -1,5 +1,5 @@
 use crate::encoder::{
-    errors::{ErrorCtxt, SpannedEncodingResult},
+    errors::{ErrorCtxt, SpannedEncodingError, SpannedEncodingResult},
     high::types::HighTypeEncoderInterface,
     middle::core_proof::{
         lowerer::{Lowerer, VariablesLowererInterface},
@@ -13,6 +13,7 @@ use crate::encoder::{
     mir::errors::ErrorInterface,
 };
 
+use prusti_rustc_interface::errors::MultiSpan;
 use std::collections::BTreeMap;
 use vir_crate::{
     low::{self as vir_low},
@@ -233,6 +234,22 @@ impl<'p, 'v: 'p, 'tcx: 'v> Private for Lowerer<'p, 'v, 'tcx> {
                 }
                 vir_mid::TypeDecl::Sequence(_) => unimplemented!("ty: {}", type_decl),
                 vir_mid::TypeDecl::Map(_) => unimplemented!("ty: {}", type_decl),
+                vir_mid::TypeDecl::Set(_) | vir_mid::TypeDecl::MultiSet(_) => {
+                    let span = self
+                        .encoder
+                        .error_manager()
+                        .position_manager()
+                        .get_span(position.into())
+                        .cloned()
+                        .unwrap_or_else(MultiSpan::new);
+                    return Err(SpannedEncodingError::unsupported(
+                        format!(
+                            "updating a part of a value of the ghost type `{parent_type}` is not \
+                                supported in the core proof"
+                        ),
+                        span,
+                    ));
+                }
                 vir_mid::TypeDecl::Never => unimplemented!("ty: {}", type_decl),
                 vir_mid::TypeDecl::Closure(_) => unimplemented!("ty: {}", type_decl),
                 vir_mid::TypeDecl::Unsupported(_) => unimplemented!("ty: {}", type_decl),
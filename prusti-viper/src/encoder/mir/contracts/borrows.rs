@@ -222,6 +222,22 @@ impl<'tcx> TypeVisitor<'tcx> for BorrowInfoCollectingVisitor<'tcx> {
         Ok(())
     }
 
+    /// The upvars of a generator are visited like the fields of a tuple.
+    fn visit_generator(
+        &mut self,
+        _def_id: hir::def_id::DefId,
+        substs: ty::GenericArgsRef<'tcx>,
+    ) -> Result<(), Self::Error> {
+        let old_path = self.current_path.take().unwrap();
+        for (i, ty) in substs.as_generator().upvar_tys().iter().enumerate() {
+            let field = FieldIdx::from_usize(i);
+            self.current_path = Some(self.tcx().mk_place_field(old_path, field, ty));
+            self.visit_ty(ty)?;
+        }
+        self.current_path = Some(old_path);
+        Ok(())
+    }
+
     #[tracing::instrument(level = "trace", skip(self), fields(current_path = ?self.current_path))]
     fn visit_raw_ptr(
         &mut self,
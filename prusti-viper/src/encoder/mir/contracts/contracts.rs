@@ -6,7 +6,10 @@ use prusti_rustc_interface::{
         def_id::{DefId, LocalDefId},
         Mutability,
     },
-    middle::{mir, ty::GenericArgsRef},
+    middle::{
+        mir,
+        ty::{self, GenericArgsRef},
+    },
 };
 use rustc_hash::FxHashMap;
 use std::fmt;
@@ -44,6 +47,21 @@ where
 }
 
 impl<L: fmt::Debug, P: fmt::Debug> ProcedureContractGeneric<L, P> {
+    /// The substitutions of the specification items declared on the procedure.
+    /// The specifications of a closure are nested items that do not share the
+    /// generics of the closure.
+    fn inherent_spec_substs<'tcx>(
+        &self,
+        env: &Environment<'tcx>,
+        substs: GenericArgsRef<'tcx>,
+    ) -> GenericArgsRef<'tcx> {
+        if env.query.is_closure(self.def_id) {
+            ty::List::empty()
+        } else {
+            substs
+        }
+    }
+
     pub fn functional_precondition<'a, 'tcx>(
         &'a self,
         env: &'a Environment<'tcx>,
@@ -54,7 +72,7 @@ impl<L: fmt::Debug, P: fmt::Debug> ProcedureContractGeneric<L, P> {
             typed::SpecificationItem::Inherent(pres)
             | typed::SpecificationItem::Refined(_, pres) => pres
                 .iter()
-                .map(|inherent_def_id| (*inherent_def_id, substs))
+                .map(|inherent_def_id| (*inherent_def_id, self.inherent_spec_substs(env, substs)))
                 .collect(),
             typed::SpecificationItem::Inherited(pres) => pres
                 .iter()
@@ -87,7 +105,7 @@ impl<L: fmt::Debug, P: fmt::Debug> ProcedureContractGeneric<L, P> {
             typed::SpecificationItem::Inherent(posts)
             | typed::SpecificationItem::Refined(_, posts) => posts
                 .iter()
-                .map(|inherent_def_id| (*inherent_def_id, substs))
+                .map(|inherent_def_id| (*inherent_def_id, self.inherent_spec_substs(env, substs)))
                 .collect(),
             typed::SpecificationItem::Inherited(posts) => posts
                 .iter()
@@ -73,8 +73,15 @@ impl<'v, 'tcx: 'v> ContractsEncoderInterface<'tcx> for super::super::super::Enco
             .borrow_mut()
             .entry(proc_def_id)
             .or_insert_with(|| {
+                // The generator of an `async fn` body is verified against the
+                // specification of the function.
+                let spec_def_id = self
+                    .env()
+                    .query
+                    .get_async_fn_of_generator(proc_def_id)
+                    .unwrap_or(proc_def_id);
                 let specification = self
-                    .get_procedure_specs(proc_def_id, substs)
+                    .get_procedure_specs(spec_def_id, substs)
                     .unwrap_or_else(|| typed::ProcedureSpecification::empty(proc_def_id));
                 get_procedure_contract(self, specification, proc_def_id, substs)
             })
@@ -134,7 +141,7 @@ impl<'v, 'tcx: 'v> ContractsEncoderInterface<'tcx> for super::super::super::Enco
 #[tracing::instrument(level = "debug", skip(encoder, specification))]
 fn get_procedure_contract<'p, 'v: 'p, 'tcx: 'v>(
     encoder: &'p Encoder<'v, 'tcx>,
-    specification: typed::ProcedureSpecification,
+    mut specification: typed::ProcedureSpecification,
     proc_def_id: DefId,
     substs: GenericArgsRef<'tcx>,
 ) -> EncodingResult<ProcedureContractMirDef<'tcx>> {
@@ -173,6 +180,14 @@ fn get_procedure_contract<'p, 'v: 'p, 'tcx: 'v>(
         return_ty = mir.local_decls[mir::Local::from_usize(0)].ty;
     }
 
+    if env.query.is_async_fn(proc_def_id) {
+        // An `async fn` only returns the future that runs its body. Its
+        // postcondition describes the output of that future, and callers
+        // assume it when the future is polled to completion.
+        specification.posts = typed::SpecificationItem::Empty;
+        specification.pledges = typed::SpecificationItem::Empty;
+    }
+
     let mut fake_mir_args = Vec::new();
     let mut fake_mir_args_ty = Vec::new();
     for (local, arg_ty) in args_ty {
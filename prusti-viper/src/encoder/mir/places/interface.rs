@@ -9,7 +9,6 @@ use crate::{
     error_internal, error_unsupported,
 };
 use log::debug;
-use prusti_common::config;
 use prusti_rustc_interface::{
     hir::def_id::DefId,
     middle::{mir, ty},
@@ -225,18 +224,37 @@ impl<'v, 'tcx: 'v> PlacesEncoderInterface<'tcx> for super::super::super::Encoder
                     ..
                 } => {
                     debug!("constantindex: {:?}[len - {}]", expr, offset);
-                    unimplemented!("TODO: Unify encoding of slices and arrays.");
+                    let len = self
+                        .encode_len_call(expr.clone())
+                        .with_span(declaration_span)?;
+                    let encoded_index = vir_high::Expression::subtract(len, offset.into());
+                    self.encode_index_call(expr, encoded_index)
+                        .with_span(declaration_span)?
+                }
+                mir::ProjectionElem::Subslice { from, to, from_end } => {
+                    debug!("subslice: {:?}[{}..{}]", expr, from, to);
+                    let end = if from_end {
+                        let len = self
+                            .encode_len_call(expr.clone())
+                            .with_span(declaration_span)?;
+                        vir_high::Expression::subtract(len, to.into())
+                    } else {
+                        to.into()
+                    };
+                    let tcx = self.env().tcx();
+                    let range_did = tcx.lang_items().range_struct().unwrap();
+                    let range_ty = ty::Ty::new_adt(
+                        tcx,
+                        tcx.adt_def(range_did),
+                        tcx.mk_args(&[tcx.types.usize.into()]),
+                    );
+                    let range = vir_high::Expression::constructor_no_pos(
+                        self.encode_type_high(range_ty)?,
+                        vec![from.into(), end],
+                    );
+                    self.encode_subslice_call(expr, range)
+                        .with_span(declaration_span)?
                 }
-                // ConstantIndex {
-                //     offset: u64,
-                //     min_length: u64,
-                //     from_end: bool,
-                // },
-                // Subslice {
-                //     from: u64,
-                //     to: u64,
-                //     from_end: bool,
-                // },
                 mir::ProjectionElem::Downcast(Some(symbol), _variant) => {
                     let variant_index = symbol.as_str().to_string().into();
                     vir_high::Expression::variant_no_pos(expr, variant_index, ty)
@@ -308,8 +326,47 @@ impl<'v, 'tcx: 'v> PlacesEncoderInterface<'tcx> for super::super::super::Encoder
             mir::BinOp::BitAnd if is_bool => vir_high::Expression::and(left, right),
             mir::BinOp::BitOr if is_bool => vir_high::Expression::or(left, right),
             mir::BinOp::BitXor if is_bool => vir_high::Expression::xor(left, right),
-            mir::BinOp::BitAnd | mir::BinOp::BitOr | mir::BinOp::BitXor => {
-                error_unsupported!("bitwise operations on non-boolean types are not supported");
+            mir::BinOp::BitAnd
+            | mir::BinOp::BitOr
+            | mir::BinOp::BitXor
+            | mir::BinOp::Shl
+            | mir::BinOp::Shr => {
+                let is_signed = match ty {
+                    vir_high::Type::Int(
+                        vir_high::ty::Int::Usize
+                        | vir_high::ty::Int::Isize
+                        | vir_high::ty::Int::Char
+                        | vir_high::ty::Int::Unbounded,
+                    ) => {
+                        error_unsupported!(
+                            "bitwise operations on '{:?}' are not supported because its size is \
+                            unknown",
+                            ty
+                        );
+                    }
+                    vir_high::Type::Int(
+                        vir_high::ty::Int::I8
+                        | vir_high::ty::Int::I16
+                        | vir_high::ty::Int::I32
+                        | vir_high::ty::Int::I64
+                        | vir_high::ty::Int::I128,
+                    ) => true,
+                    vir_high::Type::Int(_) => false,
+                    _ => {
+                        error_unsupported!("bitwise operations on '{:?}' are not supported", ty);
+                    }
+                };
+                let op_kind = match op {
+                    mir::BinOp::BitAnd => vir_high::BinaryOpKind::BitAnd,
+                    mir::BinOp::BitOr => vir_high::BinaryOpKind::BitOr,
+                    mir::BinOp::BitXor => vir_high::BinaryOpKind::BitXor,
+                    mir::BinOp::Shl => vir_high::BinaryOpKind::Shl,
+                    // Arithmetic right shift on signed integer types, logical
+                    // right shift on unsigned integer types.
+                    _ if is_signed => vir_high::BinaryOpKind::AShr,
+                    _ => vir_high::BinaryOpKind::LShr,
+                };
+                vir_high::Expression::binary_op_no_pos(op_kind, left, right)
             }
             unsupported_op => {
                 error_unsupported!("operation '{:?}' is not supported", unsupported_op);
@@ -395,9 +452,6 @@ impl<'v, 'tcx: 'v> PlacesEncoderInterface<'tcx> for super::super::super::Encoder
                 },
 
                 mir::BinOp::Shl | mir::BinOp::Shr => {
-                    if !config::encode_bitvectors() {
-                        error_unsupported!("overflow checks on a shift operation are unsupported");
-                    }
                     let size: u32 = match ty {
                         vir_high::Type::Int(vir_high::ty::Int::U8) => 8,
                         vir_high::Type::Int(vir_high::ty::Int::U16) => 16,
@@ -236,6 +236,80 @@ impl<'p, 'v, 'tcx> BuiltinFuncAppEncoder<'p, 'v, 'tcx> for super::ProcedureEncod
             "prusti_contracts::Seq::<T>::lookup" => {
                 make_builtin_call(self, block_builder, vir_high::BuiltinFunc::LookupSeq)?
             }
+            "prusti_contracts::Seq::<T>::update" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::UpdateSeq)?
+            }
+            "prusti_contracts::Seq::<T>::take" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqTake)?
+            }
+            "prusti_contracts::Seq::<T>::skip" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqSkip)?
+            }
+            "prusti_contracts::Seq::<T>::subsequence" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqSubsequence)?
+            }
+            "prusti_contracts::Seq::<T>::contains" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqContains)?
+            }
+            "prusti_contracts::Seq::<T>::index_of" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqIndexOf)?
+            }
+            "prusti_contracts::Seq::<T>::from_slice" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqFromSlice)?
+            }
+            "prusti_contracts::Seq::<T>::to_multiset" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SeqToMultiSet)?
+            }
+            "prusti_contracts::Set::<T>::empty" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::EmptySet)?
+            }
+            "prusti_contracts::Set::<T>::single" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SingleSet)?
+            }
+            "prusti_contracts::Set::<T>::union" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SetUnion)?
+            }
+            "prusti_contracts::Set::<T>::intersection" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SetIntersection)?
+            }
+            "prusti_contracts::Set::<T>::difference" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SetMinus)?
+            }
+            "prusti_contracts::Set::<T>::is_subset" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SetSubset)?
+            }
+            "prusti_contracts::Set::<T>::contains" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SetContains)?
+            }
+            "prusti_contracts::Set::<T>::len" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SetLen)?
+            }
+            "prusti_contracts::Multiset::<T>::empty" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::EmptyMultiSet)?
+            }
+            "prusti_contracts::Multiset::<T>::single" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::SingleMultiSet)?
+            }
+            "prusti_contracts::Multiset::<T>::union" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MultiSetUnion)?
+            }
+            "prusti_contracts::Multiset::<T>::intersection" => make_builtin_call(
+                self,
+                block_builder,
+                vir_high::BuiltinFunc::MultiSetIntersection,
+            )?,
+            "prusti_contracts::Multiset::<T>::difference" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MultiSetMinus)?
+            }
+            "prusti_contracts::Multiset::<T>::is_subset" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MultiSetSubset)?
+            }
+            "prusti_contracts::Multiset::<T>::count" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MultiSetCount)?
+            }
+            "prusti_contracts::Multiset::<T>::len" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MultiSetLen)?
+            }
             "prusti_contracts::Ghost::<T>::new" => {
                 make_manual_assign(self, block_builder, &mut |_, args, _| args[0].clone())?
             }
@@ -272,7 +346,9 @@ impl<'p, 'v, 'tcx> BuiltinFuncAppEncoder<'p, 'v, 'tcx> for super::ProcedureEncod
                         target_type:
                             box vir_high::Type::Int(vir_high::ty::Int::Unbounded)
                             | box vir_high::Type::Sequence(..)
-                            | box vir_high::Type::Map(..),
+                            | box vir_high::Type::Map(..)
+                            | box vir_high::Type::Set(..)
+                            | box vir_high::Type::MultiSet(..),
                         ..
                     })
                 ) {
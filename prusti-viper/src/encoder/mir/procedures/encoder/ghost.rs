@@ -56,6 +56,8 @@ impl<'a, 'p, 'v, 'tcx> GhostChecker<'a, 'p, 'v, 'tcx> {
             _ if ty_str.starts_with("Adt(prusti_contracts::Int,") => return true,
             _ if ty_str.starts_with("Adt(prusti_contracts::Seq,") => return true,
             _ if ty_str.starts_with("Adt(prusti_contracts::Map,") => return true,
+            _ if ty_str.starts_with("Adt(prusti_contracts::Set,") => return true,
+            _ if ty_str.starts_with("Adt(prusti_contracts::Multiset,") => return true,
             _ => {}
         }
 
@@ -104,7 +106,13 @@ impl<'a, 'p, 'v, 'tcx> Visitor<'tcx> for GhostChecker<'a, 'p, 'v, 'tcx> {
                     ..
                 } => {
                     if let ty::TyKind::FnDef(def_id, _call_substs) = literal.ty().kind() {
-                        if !self.p.encoder.is_pure(*def_id, None) {
+                        let is_lemma = self
+                            .p
+                            .encoder
+                            .env()
+                            .query
+                            .has_prusti_attribute(*def_id, "lemma");
+                        if !self.p.encoder.is_pure(*def_id, None) && !is_lemma {
                             self.violations.push(SpannedEncodingError::incorrect(
                                 "Only pure function calls are allowed in ghost blocks.",
                                 term.source_info.span,
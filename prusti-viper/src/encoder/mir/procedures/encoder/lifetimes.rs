@@ -1,5 +1,5 @@
 use crate::encoder::{
-    errors::{ErrorCtxt, SpannedEncodingResult},
+    errors::{ErrorCtxt, SpannedEncodingError, SpannedEncodingResult},
     mir::{
         errors::ErrorInterface,
         procedures::encoder::{scc::*, ProcedureEncoder},
@@ -8,7 +8,11 @@ use crate::encoder::{
 use prusti_interface::environment::{
     debug_utils::to_text::ToText, mir_body::borrowck::facts::RichLocation,
 };
-use prusti_rustc_interface::middle::mir;
+use prusti_rustc_interface::{
+    hir::def_id::DefId,
+    middle::{mir, ty, ty::GenericArgsRef},
+    span::Span,
+};
 use std::collections::{BTreeMap, BTreeSet};
 use vir_crate::high::{self as vir_high, builders::procedure::BasicBlockBuilder};
 
@@ -182,6 +186,13 @@ pub(super) trait LifetimesEncoder<'tcx> {
         relations: BTreeSet<(String, String)>,
     ) -> BTreeMap<String, String>;
     fn opaque_lifetimes(&mut self) -> SpannedEncodingResult<Vec<vir_high::ty::LifetimeConst>>;
+    fn instantiate_late_bound_lifetimes(
+        &self,
+        called_def_id: DefId,
+        call_substs: GenericArgsRef<'tcx>,
+        args: &[mir::Operand<'tcx>],
+        span: Span,
+    ) -> SpannedEncodingResult<Vec<String>>;
     fn encode_inhale_lifetime_token(
         &mut self,
         lifetime_const: vir_high::ty::LifetimeConst,
@@ -1094,6 +1105,67 @@ impl<'p, 'v: 'p, 'tcx: 'v> LifetimesEncoder<'tcx> for ProcedureEncoder<'p, 'v, '
             .collect())
     }
 
+    /// The late-bound lifetimes of the called function, such as `'a` in
+    /// `fn f<'a>(x: &'a u32)` or the lifetime of `&self` in `Fn::call`, are
+    /// not part of `call_substs`: each call instantiates them with fresh
+    /// lifetimes. Returns the lifetimes of the arguments that instantiate them
+    /// at this call.
+    fn instantiate_late_bound_lifetimes(
+        &self,
+        called_def_id: DefId,
+        call_substs: GenericArgsRef<'tcx>,
+        args: &[mir::Operand<'tcx>],
+        span: Span,
+    ) -> SpannedEncodingResult<Vec<String>> {
+        let tcx = self.encoder.env().tcx();
+        if self.encoder.env().query.is_closure(called_def_id) {
+            // Closures do not have a signature of their own.
+            return Ok(Vec::new());
+        }
+        // Liberating the late-bound regions turns them into free regions
+        // scoped to the called function, which distinguishes them from the
+        // regions of `call_substs`.
+        let signature = tcx.liberate_late_bound_regions(
+            called_def_id,
+            tcx.fn_sig(called_def_id).instantiate(tcx, call_substs),
+        );
+        let is_late_bound = |region: ty::Region<'tcx>| {
+            matches!(
+                region.kind(),
+                ty::RegionKind::ReFree(free_region) if free_region.scope == called_def_id
+            )
+        };
+        let mut lifetimes = Vec::new();
+        for (parameter_ty, arg) in signature.inputs().iter().zip(args) {
+            let arg_ty = arg.ty(self.mir, tcx);
+            let has_late_bound_regions = parameter_ty.walk().any(|part| match part.unpack() {
+                ty::GenericArgKind::Lifetime(region) => is_late_bound(region),
+                _ => false,
+            });
+            let mut related_regions = Vec::new();
+            if !relate_regions((*parameter_ty).into(), arg_ty.into(), &mut related_regions)
+                && has_late_bound_regions
+            {
+                return Err(SpannedEncodingError::unsupported(
+                    format!(
+                        "the lifetimes of the parameter type `{parameter_ty}` cannot be \
+                        instantiated with the ones of the argument type `{arg_ty}`"
+                    ),
+                    span,
+                ));
+            }
+            for (parameter_region, arg_region) in related_regions {
+                if is_late_bound(parameter_region) && !arg_region.is_erased() {
+                    let lifetime = arg_region.to_text();
+                    if !lifetimes.contains(&lifetime) {
+                        lifetimes.push(lifetime);
+                    }
+                }
+            }
+        }
+        Ok(lifetimes)
+    }
+
     fn encode_inhale_lifetime_token(
         &mut self,
         lifetime_const: vir_high::ty::LifetimeConst,
@@ -1166,3 +1238,75 @@ impl<'p, 'v: 'p, 'tcx: 'v> LifetimesEncoder<'tcx> for ProcedureEncoder<'p, 'v, '
         Ok(())
     }
 }
+
+/// Relate the regions of `parameter` to the regions at the same positions in
+/// `arg`, which has the same structure up to its regions. Returns `false` if
+/// the structures differ, for example, because `parameter` contains an
+/// unnormalized projection.
+fn relate_regions<'tcx>(
+    parameter: ty::GenericArg<'tcx>,
+    arg: ty::GenericArg<'tcx>,
+    related_regions: &mut Vec<(ty::Region<'tcx>, ty::Region<'tcx>)>,
+) -> bool {
+    let mut relate_all = |parameters: &[ty::GenericArg<'tcx>], args: &[ty::GenericArg<'tcx>]| {
+        parameters.len() == args.len()
+            && parameters
+                .iter()
+                .zip(args)
+                .all(|(parameter, arg)| relate_regions(*parameter, *arg, related_regions))
+    };
+    match (parameter.unpack(), arg.unpack()) {
+        (ty::GenericArgKind::Lifetime(parameter), ty::GenericArgKind::Lifetime(arg)) => {
+            related_regions.push((parameter, arg));
+            true
+        }
+        (ty::GenericArgKind::Const(_), ty::GenericArgKind::Const(_)) => true,
+        (ty::GenericArgKind::Type(parameter), ty::GenericArgKind::Type(arg)) => {
+            match (parameter.kind(), arg.kind()) {
+                (
+                    ty::TyKind::Ref(parameter_region, parameter_ty, parameter_mutability),
+                    ty::TyKind::Ref(arg_region, arg_ty, arg_mutability),
+                ) if parameter_mutability == arg_mutability => relate_all(
+                    &[(*parameter_region).into(), (*parameter_ty).into()],
+                    &[(*arg_region).into(), (*arg_ty).into()],
+                ),
+                (ty::TyKind::RawPtr(parameter_pointee), ty::TyKind::RawPtr(arg_pointee))
+                    if parameter_pointee.mutbl == arg_pointee.mutbl =>
+                {
+                    relate_all(&[parameter_pointee.ty.into()], &[arg_pointee.ty.into()])
+                }
+                (ty::TyKind::Array(parameter_ty, _), ty::TyKind::Array(arg_ty, _))
+                | (ty::TyKind::Slice(parameter_ty), ty::TyKind::Slice(arg_ty)) => {
+                    relate_all(&[(*parameter_ty).into()], &[(*arg_ty).into()])
+                }
+                (ty::TyKind::Tuple(parameter_tys), ty::TyKind::Tuple(arg_tys)) => {
+                    let parameters: Vec<_> = parameter_tys.iter().map(Into::into).collect();
+                    let args: Vec<_> = arg_tys.iter().map(Into::into).collect();
+                    relate_all(&parameters, &args)
+                }
+                (
+                    ty::TyKind::Adt(parameter_def, parameter_substs),
+                    ty::TyKind::Adt(arg_def, arg_substs),
+                ) if parameter_def.did() == arg_def.did() => {
+                    relate_all(parameter_substs, arg_substs)
+                }
+                (
+                    ty::TyKind::Closure(parameter_def_id, parameter_substs),
+                    ty::TyKind::Closure(arg_def_id, arg_substs),
+                )
+                | (
+                    ty::TyKind::FnDef(parameter_def_id, parameter_substs),
+                    ty::TyKind::FnDef(arg_def_id, arg_substs),
+                ) if parameter_def_id == arg_def_id => relate_all(parameter_substs, arg_substs),
+                (
+                    ty::TyKind::Dynamic(parameter_predicates, parameter_region, _),
+                    ty::TyKind::Dynamic(arg_predicates, arg_region, _),
+                ) if parameter_predicates == arg_predicates => {
+                    relate_all(&[(*parameter_region).into()], &[(*arg_region).into()])
+                }
+                _ => parameter == arg,
+            }
+        }
+        _ => false,
+    }
+}
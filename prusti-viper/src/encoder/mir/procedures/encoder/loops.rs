@@ -1,3 +1,4 @@
+use super::termination::lexicographic_components;
 use crate::encoder::{
     errors::{ErrorCtxt, SpannedEncodingError, SpannedEncodingResult},
     mir::{
@@ -137,13 +138,20 @@ impl<'p, 'v: 'p, 'tcx: 'v> super::ProcedureEncoder<'p, 'v, 'tcx> {
         }
 
         // Construct the variant info.
-        let loop_variant = encoded_variant_specs.into_iter().next().map(|spec| {
-            let var = self.fresh_ghost_variable(
-                "loop_variant",
-                vir_high::Type::Int(vir_high::ty::Int::Unbounded),
-            );
-            vir_high::ast::statement::LoopVariant { var, expr: spec }
-        });
+        let loop_variant = encoded_variant_specs
+            .into_iter()
+            .next()
+            .map(lexicographic_components)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|spec| {
+                let var = self.fresh_ghost_variable(
+                    "loop_variant",
+                    vir_high::Type::Int(vir_high::ty::Int::Unbounded),
+                );
+                vir_high::ast::statement::LoopVariant { var, expr: spec }
+            })
+            .collect();
 
         // Construct the invariant info.
         let loop_invariant = vir_high::Statement::loop_invariant_no_pos(
@@ -66,7 +66,7 @@ mod lifetimes;
 mod loops;
 mod scc;
 pub mod specification_blocks;
-mod termination;
+pub(crate) mod termination;
 
 pub(super) fn encode_procedure<'v, 'tcx: 'v>(
     encoder: &mut Encoder<'v, 'tcx>,
@@ -121,7 +121,8 @@ pub(super) fn encode_procedure<'v, 'tcx: 'v>(
         points_to_reborrow,
         reborrow_lifetimes_to_remove_for_block,
         current_basic_block,
-        termination_variable: None,
+        termination_variables: None,
+        recursion_component: None,
     };
     procedure_encoder.encode()
 }
@@ -161,7 +162,12 @@ struct ProcedureEncoder<'p, 'v: 'p, 'tcx: 'v> {
     points_to_reborrow: BTreeSet<vir_high::Local>,
     reborrow_lifetimes_to_remove_for_block: BTreeMap<mir::BasicBlock, BTreeSet<String>>,
     current_basic_block: Option<mir::BasicBlock>,
-    termination_variable: Option<vir_high::VariableDecl>,
+    /// The ghost variables that store the components of the termination
+    /// measure of the encoded function.
+    termination_variables: Option<Vec<vir_high::VariableDecl>>,
+    /// The terminating local functions that are mutually recursive with the
+    /// encoded function. Computed lazily.
+    recursion_component: Option<FxHashSet<DefId>>,
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
@@ -571,7 +577,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         }
         if let Some(statement) = self.loop_invariant_encoding.remove(&bb) {
             if self.needs_termination(bb)
-                && statement.clone().unwrap_loop_invariant().variant.is_none()
+                && statement.clone().unwrap_loop_invariant().variant.is_empty()
             {
                 block_builder.add_statement(self.encoder.set_statement_error_ctxt(
                     vir_high::Statement::assert_no_pos(false.into()),
@@ -1528,6 +1534,17 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 }
             }
         }
+        // Late-bound lifetimes of the callee, for example, the one of `&self`
+        // in `Fn::call` of a `F: for<'a> Fn(&'a T)` bound, are instantiated
+        // at each call with the lifetimes of the arguments.
+        lifetimes_to_exhale_inhale.extend(self.instantiate_late_bound_lifetimes(
+            called_def_id,
+            call_substs,
+            args,
+            span,
+        )?);
+        let mut seen_lifetimes = FxHashSet::default();
+        lifetimes_to_exhale_inhale.retain(|lifetime| seen_lifetimes.insert(lifetime.clone()));
 
         // construct function lifetime
         self.function_call_ctr += 1;
@@ -1709,18 +1726,11 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     }
                 }
                 if self.encoder.is_pure(called_def_id, Some(call_substs))
-                    && !self.encoder.env().callee_reaches_caller(
-                        self.def_id,
-                        called_def_id,
-                        call_substs,
-                    )
+                    && !self.is_recursive_call(called_def_id, call_substs)
                 {
                     // If we are verifying a pure function, we always need
-                    // to encode it as a method
-                    //
-                    // FIXME: This is not enough to make termination checks sound
-                    // in the case of mutually recursive functions.
-                    // See: https://github.com/viperproject/prusti-dev/issues/1443
+                    // to encode it as a method. This includes calls to
+                    // functions that are mutually recursive with it.
                     let (function_name, return_type) = self
                         .encoder
                         .encode_pure_function_use_high(called_def_id, self.def_id, call_substs)
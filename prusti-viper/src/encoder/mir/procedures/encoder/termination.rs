@@ -1,3 +1,4 @@
+use super::scc::{Graph, Tarjan};
 use crate::encoder::{
     errors::{ErrorCtxt, SpannedEncodingError, SpannedEncodingResult, WithSpan},
     mir::{
@@ -6,23 +7,124 @@ use crate::encoder::{
         pure::SpecificationEncoderInterface,
         specifications::SpecificationsInterface,
     },
+    Encoder,
 };
 use prusti_rustc_interface::{
-    middle::{mir::BasicBlock, ty::GenericArgsRef},
+    hir::def_id::DefId,
+    middle::{
+        mir::{self, BasicBlock},
+        ty::GenericArgsRef,
+    },
     span::Span,
 };
+use rustc_hash::{FxHashMap, FxHashSet};
 use vir_crate::{
-    common::{check_mode::CheckMode, expression::BinaryOperationHelpers},
+    common::{
+        check_mode::CheckMode,
+        expression::{lexicographically_less, BinaryOperationHelpers},
+    },
     high::{self as vir_high, builders::procedure::BasicBlockBuilder},
 };
 
 pub(super) enum TerminationMeasure {
-    /// A termination measure of type Int.
-    Int(vir_high::Expression),
+    /// A lexicographic termination measure whose components are of type Int.
+    /// A measure that is not a tuple has a single component.
+    Lexicographic(Vec<vir_high::Expression>),
     /// The annotated item is trusted to always terminate.
     Trusted,
 }
 
+/// Splits a termination measure or a loop variant written as a tuple into its
+/// lexicographic components.
+pub(in super::super) fn lexicographic_components(
+    expression: vir_high::Expression,
+) -> Vec<vir_high::Expression> {
+    match expression {
+        vir_high::Expression::Constructor(vir_high::Constructor {
+            ty: vir_high::Type::Tuple(_),
+            arguments,
+            ..
+        }) => arguments,
+        expression => vec![expression],
+    }
+}
+
+/// The strongly connected component of the call graph of terminating local
+/// functions that contains the function `def_id` with the body `mir`. A call
+/// from `def_id` to a function of the component is (mutually) recursive.
+pub(crate) fn compute_recursion_component<'v, 'tcx: 'v>(
+    encoder: &Encoder<'v, 'tcx>,
+    def_id: DefId,
+    mir: &mir::Body<'tcx>,
+) -> FxHashSet<DefId> {
+    let mut functions = vec![def_id];
+    let mut indices: FxHashMap<DefId, usize> = FxHashMap::default();
+    indices.insert(def_id, 0);
+    let mut edges = Vec::new();
+    let mut index = 0;
+    while index < functions.len() {
+        for callee in called_terminating_functions(encoder, def_id, mir, functions[index]) {
+            let callee_index = *indices.entry(callee).or_insert_with(|| {
+                functions.push(callee);
+                functions.len() - 1
+            });
+            edges.push((index, callee_index));
+        }
+        index += 1;
+    }
+    let mut graph = Graph::new(functions.len());
+    for (from, to) in edges {
+        graph.add_edge(from, to);
+    }
+    Tarjan::walk(&graph)
+        .into_iter()
+        .find(|component| component.contains(&0))
+        .unwrap()
+        .into_iter()
+        .map(|index| functions[index])
+        .collect()
+}
+
+/// The local terminating functions that are called from the body of the
+/// given local function. `root_def_id` is the function being encoded, whose
+/// body is `root_mir`.
+fn called_terminating_functions<'v, 'tcx: 'v>(
+    encoder: &Encoder<'v, 'tcx>,
+    root_def_id: DefId,
+    root_mir: &mir::Body<'tcx>,
+    def_id: DefId,
+) -> Vec<DefId> {
+    let Some(local_def_id) = def_id.as_local() else {
+        return Vec::new();
+    };
+    if encoder.is_trusted(def_id, None) {
+        return Vec::new();
+    }
+    let env = encoder.env();
+    let body = if def_id == root_def_id {
+        None
+    } else if encoder.is_pure(def_id, None) {
+        Some(
+            env.body
+                .get_pure_fn_body(def_id, env.query.identity_substs(def_id), root_def_id),
+        )
+    } else {
+        Some(env.body.get_impure_fn_body_identity(local_def_id))
+    };
+    let body: &mir::Body<'tcx> = body.as_deref().unwrap_or(root_mir);
+    body.basic_blocks
+        .iter()
+        .filter_map(|data| match &data.terminator().kind {
+            mir::TerminatorKind::Call { func, .. } => func.const_fn_def(),
+            _ => None,
+        })
+        .map(|(called_def_id, _)| called_def_id)
+        .filter(|called_def_id| {
+            called_def_id.is_local() && encoder.terminates(*called_def_id, None)
+        })
+        .collect()
+}
+
 impl<'p, 'v: 'p, 'tcx: 'v> super::ProcedureEncoder<'p, 'v, 'tcx> {
     pub(super) fn needs_termination(&self, bb: BasicBlock) -> bool {
         let function_termination = self.encoder.terminates(self.def_id, None);
@@ -62,7 +164,38 @@ impl<'p, 'v: 'p, 'tcx: 'v> super::ProcedureEncoder<'p, 'v, 'tcx> {
                 return Ok(TerminationMeasure::Trusted);
             }
         }
-        Ok(TerminationMeasure::Int(expression))
+        Ok(TerminationMeasure::Lexicographic(lexicographic_components(
+            expression,
+        )))
+    }
+
+    /// Whether the called function is in the same strongly connected
+    /// component of the call graph of terminating local functions as the
+    /// function being encoded, that is, whether the call is (mutually)
+    /// recursive.
+    pub(super) fn is_recursive_call(
+        &mut self,
+        called_def_id: DefId,
+        call_substs: GenericArgsRef<'tcx>,
+    ) -> bool {
+        if self
+            .encoder
+            .env()
+            .callee_reaches_caller(self.def_id, called_def_id, call_substs)
+        {
+            return true;
+        }
+        if self.recursion_component.is_none() {
+            self.recursion_component = Some(compute_recursion_component(
+                self.encoder,
+                self.def_id,
+                self.mir,
+            ));
+        }
+        self.recursion_component
+            .as_ref()
+            .unwrap()
+            .contains(&called_def_id)
     }
 
     pub(super) fn encode_termination_initialization(
@@ -87,21 +220,26 @@ impl<'p, 'v: 'p, 'tcx: 'v> super::ProcedureEncoder<'p, 'v, 'tcx> {
                 substs,
                 &arguments,
             )?;
-            if let TerminationMeasure::Int(termination_expr) = termination_expr {
-                let term_var = self.fresh_ghost_variable(
-                    "termination_var",
-                    vir_high::Type::Int(vir_high::ty::Int::Unbounded),
-                );
-                self.termination_variable = Some(term_var.clone());
-                let assign_stmt =
-                    vir_high::Statement::ghost_assign_no_pos(term_var.into(), termination_expr);
-                let assign_stmt = self.encoder.set_statement_error_ctxt(
-                    assign_stmt,
-                    mir_span,
-                    ErrorCtxt::UnexpectedAssignMethodTerminationMeasure,
-                    self.def_id,
-                )?;
-                Ok(vec![assign_stmt])
+            if let TerminationMeasure::Lexicographic(components) = termination_expr {
+                let mut statements = Vec::new();
+                let mut term_vars = Vec::new();
+                for component in components {
+                    let term_var = self.fresh_ghost_variable(
+                        "termination_var",
+                        vir_high::Type::Int(vir_high::ty::Int::Unbounded),
+                    );
+                    term_vars.push(term_var.clone());
+                    let assign_stmt =
+                        vir_high::Statement::ghost_assign_no_pos(term_var.into(), component);
+                    statements.push(self.encoder.set_statement_error_ctxt(
+                        assign_stmt,
+                        mir_span,
+                        ErrorCtxt::UnexpectedAssignMethodTerminationMeasure,
+                        self.def_id,
+                    )?);
+                }
+                self.termination_variables = Some(term_vars);
+                Ok(statements)
             } else {
                 Ok(vec![])
             }
@@ -129,24 +267,22 @@ impl<'p, 'v: 'p, 'tcx: 'v> super::ProcedureEncoder<'p, 'v, 'tcx> {
             )?);
         }
 
-        if !self
-            .encoder
-            .env()
-            .callee_reaches_caller(self.def_id, called_fun, call_substs)
-        {
+        if !self.is_recursive_call(called_fun, call_substs) {
             return Ok(());
         }
 
-        let Some(term_var) = self.termination_variable.clone() else {
+        let Some(term_vars) = self.termination_variables.clone() else {
             return Ok(());
         };
         let term_ty = vir_high::Type::Int(vir_high::ty::Int::Unbounded);
+        let term_exprs: Vec<vir_high::Expression> =
+            term_vars.iter().cloned().map(Into::into).collect();
 
         // called termination measure is lower
         let call_expr =
             self.encode_termination_expression(procedure_contract, span, call_substs, arguments)?;
-        if let TerminationMeasure::Int(call_expr) = call_expr {
-            let cond = vir_high::Expression::greater_than(term_var.clone().into(), call_expr);
+        if let TerminationMeasure::Lexicographic(call_exprs) = call_expr {
+            let cond = lexicographically_less(&call_exprs, &term_exprs);
             let assert_statement = self.encoder.set_statement_error_ctxt(
                 vir_high::Statement::assert_no_pos(cond),
                 span,
@@ -157,15 +293,17 @@ impl<'p, 'v: 'p, 'tcx: 'v> super::ProcedureEncoder<'p, 'v, 'tcx> {
         }
 
         // called termination measure should be non-negative
-        let zero = vir_high::Expression::constant_no_pos(0.into(), term_ty);
-        let cond = vir_high::Expression::greater_equals(term_var.into(), zero);
-        let assert_statement = self.encoder.set_statement_error_ctxt(
-            vir_high::Statement::assert_no_pos(cond),
-            span,
-            ErrorCtxt::CallTerminationMeasureNonNegative,
-            self.def_id,
-        )?;
-        block_builder.add_statement(assert_statement);
+        for term_expr in term_exprs {
+            let zero = vir_high::Expression::constant_no_pos(0.into(), term_ty.clone());
+            let cond = vir_high::Expression::greater_equals(term_expr, zero);
+            let assert_statement = self.encoder.set_statement_error_ctxt(
+                vir_high::Statement::assert_no_pos(cond),
+                span,
+                ErrorCtxt::CallTerminationMeasureNonNegative,
+                self.def_id,
+            )?;
+            block_builder.add_statement(assert_statement);
+        }
 
         Ok(())
     }
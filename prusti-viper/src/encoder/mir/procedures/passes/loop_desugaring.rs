@@ -5,7 +5,10 @@ use crate::encoder::{
 };
 use std::collections::{BTreeMap, BTreeSet};
 use vir_crate::{
-    common::{cfg::Cfg, expression::BinaryOperationHelpers},
+    common::{
+        cfg::Cfg,
+        expression::{lexicographically_less, BinaryOperationHelpers},
+    },
     high::{
         self as vir_high,
         ast::{expression::visitors::ExpressionFolder, statement::visitors::StatementFolder},
@@ -52,7 +55,7 @@ pub(in super::super) fn desugar_loops<'v, 'tcx: 'v>(
             .unwrap()
             .unwrap_loop_invariant();
 
-        if let Some(variant) = &loop_invariant.variant {
+        for variant in &loop_invariant.variant {
             let stmt = encoder.set_surrounding_error_context_for_statement(
                 vir_high::Statement::ghost_assign_no_pos(
                     vir_high::Expression::local_no_pos(variant.var.clone()),
@@ -78,14 +81,21 @@ pub(in super::super) fn desugar_loops<'v, 'tcx: 'v>(
             invariant_block.statements.push(statement);
         }
 
-        if let Some(variant) = &loop_invariant.variant {
-            let stmt = encoder.set_surrounding_error_context_for_statement(
-                vir_high::Statement::assert_no_pos(vir_high::Expression::greater_than(
-                    vir_high::Expression::local_no_pos(variant.var.clone()),
+        if !loop_invariant.variant.is_empty() {
+            let zeros = loop_invariant
+                .variant
+                .iter()
+                .map(|variant| {
                     vir_high::Expression::constant_no_pos(
                         vir_high::expression::ConstantValue::Int(0),
                         variant.var.ty.clone(),
-                    ),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let stmt = encoder.set_surrounding_error_context_for_statement(
+                vir_high::Statement::assert_no_pos(lexicographically_less(
+                    &zeros,
+                    &variant_variables(&loop_invariant.variant),
                 )),
                 loop_invariant.position,
                 ErrorCtxt::LoopVariantOnEntry,
@@ -111,7 +121,7 @@ pub(in super::super) fn desugar_loops<'v, 'tcx: 'v>(
             invariant_block.statements.push(statement);
         }
 
-        if let Some(variant) = &loop_invariant.variant {
+        for variant in &loop_invariant.variant {
             let stmt = encoder.set_surrounding_error_context_for_statement(
                 vir_high::Statement::ghost_havoc_no_pos(variant.var.clone()),
                 loop_invariant.position,
@@ -129,7 +139,7 @@ pub(in super::super) fn desugar_loops<'v, 'tcx: 'v>(
             invariant_block.statements.push(statement);
         }
 
-        if let Some(variant) = &loop_invariant.variant {
+        for variant in &loop_invariant.variant {
             let stmt = encoder.set_surrounding_error_context_for_statement(
                 vir_high::Statement::assume_no_pos(vir_high::Expression::equals(
                     vir_high::Expression::local_no_pos(variant.var.clone()),
@@ -144,6 +154,15 @@ pub(in super::super) fn desugar_loops<'v, 'tcx: 'v>(
     Ok(procedure)
 }
 
+fn variant_variables(
+    variant: &[vir_high::ast::statement::LoopVariant],
+) -> Vec<vir_high::Expression> {
+    variant
+        .iter()
+        .map(|variant| vir_high::Expression::local_no_pos(variant.var.clone()))
+        .collect()
+}
+
 fn find_loop_invariant(
     procedure: &vir_high::ProcedureDecl,
 ) -> Option<(vir_high::BasicBlockId, &vir_high::LoopInvariant)> {
@@ -204,18 +223,24 @@ fn duplicate_blocks<'v, 'tcx: 'v>(
                 )?;
                 block.statements.push(statement);
             }
-            if let Some(variant) = &loop_invariant.variant {
+            if !loop_invariant.variant.is_empty() {
+                let variant_expressions = loop_invariant
+                    .variant
+                    .iter()
+                    .map(|variant| variant.expr.clone())
+                    .collect::<Vec<_>>();
                 block
                     .statements
                     .push(encoder.set_surrounding_error_context_for_statement(
-                        vir_high::Statement::assert_no_pos(vir_high::Expression::less_than(
-                            variant.expr.clone(),
-                            vir_high::Expression::local_no_pos(variant.var.clone()),
+                        vir_high::Statement::assert_no_pos(lexicographically_less(
+                            &variant_expressions,
+                            &variant_variables(&loop_invariant.variant),
                         )),
                         loop_invariant.position,
                         ErrorCtxt::LoopVariantNonDecreased,
                     )?);
-
+            }
+            for variant in &loop_invariant.variant {
                 block
                     .statements
                     .push(encoder.set_surrounding_error_context_for_statement(
@@ -249,12 +249,6 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
                 state.substitute_value(&encoded_lhs, expr);
             }
             &mir::Rvalue::Ref(_, kind, place) => {
-                if !matches!(kind, mir::BorrowKind::Mut { .. } | mir::BorrowKind::Shared) {
-                    return Err(SpannedEncodingError::unsupported(
-                        format!("unsupported kind of reference: {kind:?}"),
-                        span,
-                    ));
-                }
                 let encoded_place = self.encoder.encode_place_high(self.mir, place, None)?;
                 let ty = self
                     .encoder
@@ -578,7 +572,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
             assert_eq!(type_arguments.len(), 1);
 
             let elem_type = type_arguments[0].clone();
-            let seq_type = Type::sequence(elem_type.clone(), lifetimes);
+            let seq_type = Type::sequence(elem_type.clone(), lifetimes.clone());
+            let multiset_type = Type::multi_set(elem_type.clone(), lifetimes);
 
             return builtin(match proc_name {
                 "empty" => (EmptySeq, seq_type),
@@ -586,8 +581,51 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
                 "len" => (SeqLen, Type::Int(vir_high::ty::Int::Unbounded)),
                 "lookup" => (LookupSeq, elem_type),
                 "concat" => (ConcatSeq, seq_type),
+                "update" => (UpdateSeq, seq_type),
+                "take" => (SeqTake, seq_type),
+                "skip" => (SeqSkip, seq_type),
+                "subsequence" => (SeqSubsequence, seq_type),
+                "contains" => (SeqContains, Type::bool()),
+                "index_of" => (SeqIndexOf, Type::Int(Int::Unbounded)),
+                "from_slice" => (SeqFromSlice, seq_type),
+                "to_multiset" => (SeqToMultiSet, multiset_type),
                 _ => unreachable!("no further Seq functions"),
             });
+        } else if let Some(proc_name) = proc_name.strip_prefix("prusti_contracts::Set::<T>::") {
+            assert_eq!(type_arguments.len(), 1);
+
+            let elem_type = type_arguments[0].clone();
+            let set_type = Type::set(elem_type, lifetimes);
+
+            return builtin(match proc_name {
+                "empty" => (EmptySet, set_type),
+                "single" => (SingleSet, set_type),
+                "union" => (SetUnion, set_type),
+                "intersection" => (SetIntersection, set_type),
+                "difference" => (SetMinus, set_type),
+                "is_subset" => (SetSubset, Type::bool()),
+                "contains" => (SetContains, Type::bool()),
+                "len" => (SetLen, Type::Int(Int::Unbounded)),
+                _ => unreachable!("no further Set functions"),
+            });
+        } else if let Some(proc_name) = proc_name.strip_prefix("prusti_contracts::Multiset::<T>::")
+        {
+            assert_eq!(type_arguments.len(), 1);
+
+            let elem_type = type_arguments[0].clone();
+            let multiset_type = Type::multi_set(elem_type, lifetimes);
+
+            return builtin(match proc_name {
+                "empty" => (EmptyMultiSet, multiset_type),
+                "single" => (SingleMultiSet, multiset_type),
+                "union" => (MultiSetUnion, multiset_type),
+                "intersection" => (MultiSetIntersection, multiset_type),
+                "difference" => (MultiSetMinus, multiset_type),
+                "is_subset" => (MultiSetSubset, Type::bool()),
+                "count" => (MultiSetCount, Type::Int(Int::Unbounded)),
+                "len" => (MultiSetLen, Type::Int(Int::Unbounded)),
+                _ => unreachable!("no further Multiset functions"),
+            });
         } else if let Some(proc_name) = proc_name.strip_prefix("prusti_contracts::Int::") {
             assert!(type_arguments.is_empty());
             return match proc_name {
@@ -699,6 +737,14 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
                 );
                 subst_with(encoded_rhs)
             }
+            "std::ops::RangeInclusive::<Idx>::new" | "core::ops::RangeInclusive::<Idx>::new" => {
+                // The fields are `start`, `end`, and `exhausted`.
+                assert_eq!(encoded_args.len(), 2);
+                let ty = encoded_lhs.get_type().clone();
+                let mut arguments = encoded_args.to_vec();
+                arguments.push(false.into());
+                subst_with(vir_high::Expression::constructor_no_pos(ty, arguments))
+            }
             "core::slice::<impl [T]>::len" => {
                 assert_eq!(encoded_args.len(), 1);
                 self.encode_call_len(
@@ -17,10 +17,11 @@ use crate::{
             },
             sequences::MirSequencesEncoderInterface,
             specifications::SpecificationsInterface,
-            types::MirTypeEncoderInterface,
+            types::{is_mathematical_int_ty, string_bytes_ty, MirTypeEncoderInterface},
         },
         mir_encoder::{
-            MirEncoder, PlaceEncoder, PlaceEncoding, PRECONDITION_LABEL, WAND_LHS_LABEL,
+            MirEncoder, PlaceEncoder, PlaceEncoding, LOOP_ITERATOR_PLACEHOLDER, PRECONDITION_LABEL,
+            WAND_LHS_LABEL,
         },
         snapshot::interface::SnapshotEncoderInterface,
         Encoder,
@@ -28,11 +29,12 @@ use crate::{
     error_unsupported,
 };
 use log::{debug, trace};
-use prusti_common::vir_local;
+use prusti_common::{config, vir_local};
 use prusti_interface::environment::mir_utils::SliceOrArrayRef;
 use prusti_rustc_interface::{
     hir::def_id::DefId,
     middle::{mir, span_bug, ty},
+    span::Span,
 };
 use rustc_hash::FxHashMap;
 use std::{convert::TryInto, mem};
@@ -193,6 +195,259 @@ impl<'p, 'v: 'p, 'tcx: 'v> PureFunctionBackwardInterpreter<'p, 'v, 'tcx> {
             }
         })
     }
+
+    /// Encode a method of `Set` or `Multiset` with the built-in set and
+    /// multiset operations of Viper, which work on both kinds of containers.
+    fn encode_set_operation(
+        &self,
+        method_name: &str,
+        return_ty: ty::Ty<'tcx>,
+        encoded_args: &[vir::Expr],
+        span: Span,
+    ) -> SpannedEncodingResult<vir::Expr> {
+        let args: Vec<_> = encoded_args
+            .iter()
+            .cloned()
+            .map(vir::Expr::snap_app)
+            .collect();
+        let container_op = |op_kind, left: &vir::Expr, right: &vir::Expr| {
+            vir::Expr::ContainerOp(vir::ContainerOp {
+                op_kind,
+                left: Box::new(left.clone()),
+                right: Box::new(right.clone()),
+                position: vir::Position::default(),
+            })
+        };
+        let encoded_rhs = match method_name {
+            "empty" | "single" => vir::Expr::Seq(vir::Seq {
+                typ: self
+                    .encoder
+                    .encode_snapshot_type(return_ty)
+                    .with_span(span)?,
+                elements: args,
+                position: vir::Position::default(),
+            }),
+            "union" => container_op(vir::ContainerOpKind::SetUnion, &args[0], &args[1]),
+            "intersection" => {
+                container_op(vir::ContainerOpKind::SetIntersection, &args[0], &args[1])
+            }
+            "difference" => container_op(vir::ContainerOpKind::SetMinus, &args[0], &args[1]),
+            "is_subset" => container_op(vir::ContainerOpKind::SetSubset, &args[0], &args[1]),
+            // On multisets, `contains` of Viper is the multiplicity.
+            "contains" | "count" => {
+                container_op(vir::ContainerOpKind::SetContains, &args[0], &args[1])
+            }
+            "len" => container_op(
+                vir::ContainerOpKind::SetCardinality,
+                &args[0],
+                &true.into(), // unused
+            ),
+            _ => unreachable!("no further Set or Multiset methods"),
+        };
+        Ok(encoded_rhs)
+    }
+
+    /// Encode a method of `Seq` with the built-in sequence operations of
+    /// Viper.
+    fn encode_seq_operation(
+        &self,
+        method_name: &str,
+        return_ty: ty::Ty<'tcx>,
+        args: &[mir::Operand<'tcx>],
+        encoded_args: &[vir::Expr],
+        span: Span,
+    ) -> SpannedEncodingResult<vir::Expr> {
+        let snapshot_args: Vec<_> = encoded_args
+            .iter()
+            .cloned()
+            .map(vir::Expr::snap_app)
+            .collect();
+        let container_op = |op_kind, left: vir::Expr, right: vir::Expr| {
+            vir::Expr::ContainerOp(vir::ContainerOp {
+                op_kind,
+                left: Box::new(left),
+                right: Box::new(right),
+                position: vir::Position::default(),
+            })
+        };
+        let seq = || snapshot_args[0].clone();
+        let arg = |index: usize| snapshot_args[index].clone();
+        let encoded_rhs = match method_name {
+            "empty" | "single" => vir::Expr::Seq(vir::Seq {
+                typ: self
+                    .encoder
+                    .encode_snapshot_type(return_ty)
+                    .with_span(span)?,
+                elements: snapshot_args.clone(),
+                position: vir::Position::default(),
+            }),
+            "concat" => container_op(vir::ContainerOpKind::SeqConcat, seq(), arg(1)),
+            "lookup" => container_op(vir::ContainerOpKind::SeqIndex, seq(), arg(1)),
+            "len" => container_op(
+                vir::ContainerOpKind::SeqLen,
+                seq(),
+                true.into(), // unused
+            ),
+            // `s[i := v]` is encoded as `s[..i] ++ Seq(v) ++ s[i + 1..]`.
+            "update" => {
+                let updated = vir::Expr::Seq(vir::Seq {
+                    typ: self
+                        .encoder
+                        .encode_snapshot_type(return_ty)
+                        .with_span(span)?,
+                    elements: vec![arg(2)],
+                    position: vir::Position::default(),
+                });
+                let prefix = container_op(vir::ContainerOpKind::SeqTake, seq(), arg(1));
+                let suffix = container_op(
+                    vir::ContainerOpKind::SeqDrop,
+                    seq(),
+                    vir::Expr::add(arg(1), 1.into()),
+                );
+                container_op(
+                    vir::ContainerOpKind::SeqConcat,
+                    container_op(vir::ContainerOpKind::SeqConcat, prefix, updated),
+                    suffix,
+                )
+            }
+            "take" => container_op(vir::ContainerOpKind::SeqTake, seq(), arg(1)),
+            "skip" => container_op(vir::ContainerOpKind::SeqDrop, seq(), arg(1)),
+            // `s[from..to]` is encoded as `s[from..][..to - from]`.
+            "subsequence" => container_op(
+                vir::ContainerOpKind::SeqTake,
+                container_op(vir::ContainerOpKind::SeqDrop, seq(), arg(1)),
+                vir::Expr::sub(arg(2), arg(1)),
+            ),
+            // The element is passed by reference, which the snapshot strips.
+            "contains" => container_op(vir::ContainerOpKind::SeqContains, seq(), arg(1)),
+            "index_of" => {
+                let seq_snap_type = self
+                    .encoder
+                    .encode_snapshot_type(self.mir_encoder.get_operand_ty(&args[0]))
+                    .with_span(span)?;
+                let vir::Type::Seq(vir::SeqType {
+                    typ: box elem_snap_type,
+                }) = seq_snap_type.clone()
+                else {
+                    unreachable!("the snapshot of a Seq is not a sequence: {}", seq_snap_type);
+                };
+                let (function_name, type_arguments) =
+                    self.encoder
+                        .encode_builtin_function_use(BuiltinFunctionKind::SeqIndexOf {
+                            elem_snap_type: elem_snap_type.clone(),
+                        });
+                vir::Expr::func_app(
+                    function_name,
+                    type_arguments,
+                    vec![seq(), arg(1)],
+                    vec![
+                        vir::LocalVar::new("seq", seq_snap_type),
+                        vir::LocalVar::new("elem", elem_snap_type),
+                    ],
+                    vir::Type::Int,
+                    vir::Position::default(),
+                )
+            }
+            // The snapshot of a slice wraps the sequence of its elements.
+            "from_slice" => self
+                .encoder
+                .encode_snapshot_destructor(self.mir_encoder.get_operand_ty(&args[0]), vec![seq()])
+                .with_span(span)?,
+            "to_multiset" => {
+                return Err(SpannedEncodingError::unsupported(
+                    "`Seq::to_multiset` is supported only by the core proof encoding",
+                    span,
+                ));
+            }
+            _ => unreachable!("no further Seq methods"),
+        };
+        Ok(encoded_rhs)
+    }
+
+    /// Encode a method of `Map` with the built-in map operations of Viper.
+    /// Inserting and deleting entries are encoded with built-in functions.
+    fn encode_map_operation(
+        &self,
+        method_name: &str,
+        return_ty: ty::Ty<'tcx>,
+        args: &[mir::Operand<'tcx>],
+        encoded_args: &[vir::Expr],
+        span: Span,
+    ) -> SpannedEncodingResult<vir::Expr> {
+        let snapshot_args: Vec<_> = encoded_args
+            .iter()
+            .cloned()
+            .map(vir::Expr::snap_app)
+            .collect();
+        let container_op = |op_kind, left: vir::Expr, right: vir::Expr| {
+            vir::Expr::ContainerOp(vir::ContainerOp {
+                op_kind,
+                left: Box::new(left),
+                right: Box::new(right),
+                position: vir::Position::default(),
+            })
+        };
+        let map = || snapshot_args[0].clone();
+        let arg = |index: usize| snapshot_args[index].clone();
+        let encoded_rhs = match method_name {
+            "empty" => vir::Expr::Map(vir::Map {
+                typ: self
+                    .encoder
+                    .encode_snapshot_type(return_ty)
+                    .with_span(span)?,
+                elements: vec![],
+                position: vir::Position::default(),
+            }),
+            "len" => container_op(
+                vir::ContainerOpKind::MapLen,
+                map(),
+                true.into(), // unused
+            ),
+            "contains" => container_op(vir::ContainerOpKind::MapContains, map(), arg(1)),
+            "lookup" => container_op(vir::ContainerOpKind::MapLookup, map(), arg(1)),
+            "insert" | "delete" => {
+                let map_snap_type = self
+                    .encoder
+                    .encode_snapshot_type(self.mir_encoder.get_operand_ty(&args[0]))
+                    .with_span(span)?;
+                let vir::Type::Map(vir::MapType {
+                    key_type: box key_snap_type,
+                    val_type: box val_snap_type,
+                }) = map_snap_type.clone()
+                else {
+                    unreachable!("the snapshot of a Map is not a map: {}", map_snap_type);
+                };
+                let mut formal_args = vec![
+                    vir::LocalVar::new("map", map_snap_type.clone()),
+                    vir::LocalVar::new("key", key_snap_type.clone()),
+                ];
+                let kind = if method_name == "insert" {
+                    formal_args.push(vir::LocalVar::new("val", val_snap_type.clone()));
+                    BuiltinFunctionKind::MapInsert {
+                        key_snap_type,
+                        val_snap_type,
+                    }
+                } else {
+                    BuiltinFunctionKind::MapDelete {
+                        key_snap_type,
+                        val_snap_type,
+                    }
+                };
+                let (function_name, type_arguments) =
+                    self.encoder.encode_builtin_function_use(kind);
+                vir::Expr::func_app(
+                    function_name,
+                    type_arguments,
+                    snapshot_args.clone(),
+                    formal_args,
+                    map_snap_type,
+                    vir::Position::default(),
+                )
+            }
+            _ => unreachable!("no further Map methods"),
+        };
+        Ok(encoded_rhs)
+    }
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
@@ -332,7 +587,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                 let mut cfg_targets: Vec<(vir::Expr, mir::BasicBlock)> = vec![];
                 let discr_val = self
                     .mir_encoder
-                    .encode_operand_expr(discr)
+                    .encode_operand_expr(discr, span)
                     .with_span(span)?;
                 for (value, target) in targets.iter() {
                     // Convert int to bool, if required
@@ -423,7 +678,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                             self.encode_place(destination).with_span(span)?;
                         let encoded_args: Vec<vir::Expr> = args
                             .iter()
-                            .map(|arg| self.mir_encoder.encode_operand_expr(arg))
+                            .map(|arg| self.mir_encoder.encode_operand_expr(arg, span))
                             .collect::<Result<_, _>>()
                             .with_span(span)?;
 
@@ -454,6 +709,130 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            "prusti_contracts::prusti_is_active_union_field" => {
+                                // args[0]: the address of a union field
+                                assert_eq!(args.len(), 1);
+                                let Some((union_place, field)) = self
+                                    .mir_encoder
+                                    .get_address_of_source(bb, &args[0])
+                                    .and_then(|place| self.mir_encoder.try_pop_union_field(place))
+                                else {
+                                    return Err(SpannedEncodingError::incorrect(
+                                        "the argument of `is_active_union_field!` must be a union field",
+                                        span,
+                                    ));
+                                };
+                                let (encoded_union, union_ty, _) =
+                                    self.encode_place(union_place).with_span(span)?;
+                                let adt_def = union_ty.ty_adt_def().unwrap();
+                                let encoded_rhs = if adt_def.non_enum_variant().fields.len() == 1 {
+                                    // The only field is always the active one.
+                                    true.into()
+                                } else {
+                                    let discriminant = self
+                                        .encoder
+                                        .encode_discriminant_func_app(encoded_union, adt_def)?;
+                                    vir::Expr::eq_cmp(discriminant, field.index().into())
+                                };
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "prusti_contracts::prusti_loop_iterator" => {
+                                assert_eq!(args.len(), 0);
+                                // The iterator is replaced when the loop invariant is encoded.
+                                let encoded_rhs = vir::Expr::local(vir::LocalVar::new(
+                                    LOOP_ITERATOR_PLACEHOLDER,
+                                    encoded_lhs.get_type().clone(),
+                                ));
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "prusti_contracts::Int::new" | "prusti_contracts::Int::new_usize" => {
+                                assert_eq!(args.len(), 1);
+                                // The snapshot of a mathematical integer is a Viper `Int`.
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_args[0].clone());
+                                state
+                            }
+
+                            name if name.starts_with("prusti_contracts::Seq::<T>::") => {
+                                let encoded_rhs = self.encode_seq_operation(
+                                    tcx.item_name(def_id).as_str(),
+                                    ty,
+                                    args,
+                                    &encoded_args,
+                                    span,
+                                )?;
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            name if name.starts_with("prusti_contracts::Map::<K, V>::") => {
+                                let encoded_rhs = self.encode_map_operation(
+                                    tcx.item_name(def_id).as_str(),
+                                    ty,
+                                    args,
+                                    &encoded_args,
+                                    span,
+                                )?;
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            name if name.starts_with("prusti_contracts::Set::<T>::")
+                                || name.starts_with("prusti_contracts::Multiset::<T>::") =>
+                            {
+                                let encoded_rhs = self.encode_set_operation(
+                                    tcx.item_name(def_id).as_str(),
+                                    ty,
+                                    &encoded_args,
+                                    span,
+                                )?;
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "std::ops::Add::add"
+                            | "core::ops::Add::add"
+                            | "std::ops::Sub::sub"
+                            | "core::ops::Sub::sub"
+                            | "std::ops::Mul::mul"
+                            | "core::ops::Mul::mul"
+                            | "std::ops::Div::div"
+                            | "core::ops::Div::div"
+                            | "std::ops::Rem::rem"
+                            | "core::ops::Rem::rem"
+                                if is_mathematical_int_ty(
+                                    tcx,
+                                    self.mir_encoder.get_operand_ty(&args[0]),
+                                ) =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                let op_kind = match tcx.item_name(def_id).as_str() {
+                                    "add" => vir::BinaryOpKind::Add,
+                                    "sub" => vir::BinaryOpKind::Sub,
+                                    "mul" => vir::BinaryOpKind::Mul,
+                                    "div" => vir::BinaryOpKind::Div,
+                                    "rem" => vir::BinaryOpKind::Mod,
+                                    _ => unreachable!(),
+                                };
+                                let encoded_rhs = vir::Expr::bin_op(
+                                    op_kind,
+                                    encoded_args[0].clone(),
+                                    encoded_args[1].clone(),
+                                );
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
                             "std::cmp::PartialEq::eq" | "core::cmp::PartialEq::eq"
                                 if self.encoder.has_structural_eq_impl(
                                     self.mir_encoder.get_operand_ty(&args[0]),
@@ -484,7 +863,29 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
-                            "core::slice::<impl [T]>::len" => {
+                            "core::f32::<impl f32>::is_nan"
+                            | "core::f32::<impl f32>::is_infinite"
+                            | "core::f32::<impl f32>::is_finite"
+                            | "core::f64::<impl f64>::is_nan"
+                            | "core::f64::<impl f64>::is_infinite"
+                            | "core::f64::<impl f64>::is_finite"
+                                if config::encode_ieee_floats() =>
+                            {
+                                assert_eq!(args.len(), 1);
+                                let method = full_func_proc_name.rsplit("::").next().unwrap();
+                                let encoded_rhs = self
+                                    .mir_encoder
+                                    .encode_float_classification(method, encoded_args[0].clone())
+                                    .unwrap();
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "core::slice::<impl [T]>::len"
+                            | "core::str::<impl str>::len"
+                            | "std::string::String::len"
+                            | "alloc::string::String::len" => {
                                 assert_eq!(args.len(), 1);
                                 let slice_ty = self.mir_encoder.get_operand_ty(&args[0]);
                                 let len = self
@@ -497,6 +898,110 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            // Strings are modelled as the sequence of their bytes, so
+                            // these views have the same snapshot as the string.
+                            "core::str::<impl str>::as_bytes"
+                            | "std::string::String::as_str"
+                            | "alloc::string::String::as_str"
+                            | "std::string::String::as_bytes"
+                            | "alloc::string::String::as_bytes" => {
+                                assert_eq!(args.len(), 1);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(
+                                    &encoded_lhs,
+                                    vir::Expr::snap_app(encoded_args[0].clone()),
+                                );
+                                state
+                            }
+
+                            "std::ops::Deref::deref" | "core::ops::Deref::deref"
+                                if string_bytes_ty(
+                                    self.encoder.env().tcx(),
+                                    call_substs.type_at(0),
+                                )
+                                .is_some() =>
+                            {
+                                assert_eq!(args.len(), 1);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(
+                                    &encoded_lhs,
+                                    vir::Expr::snap_app(encoded_args[0].clone()),
+                                );
+                                state
+                            }
+
+                            "core::str::<impl str>::starts_with"
+                                if call_substs.type_at(0).peel_refs().is_str() =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                let str_ty = self.mir_encoder.get_operand_ty(&args[0]);
+                                let starts_with = self
+                                    .mir_encoder
+                                    .encode_str_starts_with(
+                                        str_ty,
+                                        vir::Expr::snap_app(encoded_args[0].clone()),
+                                        vir::Expr::snap_app(encoded_args[1].clone()),
+                                    )
+                                    .with_span(span)?;
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, starts_with);
+                                state
+                            }
+
+                            // See the corresponding case in procedure_encoder.rs
+                            "std::ops::RangeInclusive::<Idx>::new"
+                            | "core::ops::RangeInclusive::<Idx>::new" => {
+                                assert_eq!(args.len(), 2);
+                                let idx_ty = call_substs.type_at(0);
+                                let mut state = states[&target_block].clone();
+                                let mut field_exprs = vec![];
+                                for (field_name, operand) in
+                                    ["start", "end"].iter().zip(args.iter())
+                                {
+                                    let encoded_field = self
+                                        .encoder
+                                        .encode_struct_field(field_name, idx_ty)
+                                        .with_span(span)?;
+                                    let field_place = encoded_lhs.clone().field(encoded_field);
+                                    let (encoded_rhs, is_value) =
+                                        self.encode_operand(operand).with_span(span)?;
+                                    if is_value {
+                                        state.substitute_value(
+                                            &self
+                                                .encoder
+                                                .encode_value_expr(field_place, idx_ty)
+                                                .with_span(span)?,
+                                            encoded_rhs.clone(),
+                                        );
+                                    } else {
+                                        state.substitute_value(&field_place, encoded_rhs.clone());
+                                    }
+                                    field_exprs.push(encoded_rhs);
+                                }
+                                let bool_ty = tcx.types.bool;
+                                let exhausted_field = self
+                                    .encoder
+                                    .encode_struct_field("exhausted", bool_ty)
+                                    .with_span(span)?;
+                                state.substitute_value(
+                                    &self
+                                        .encoder
+                                        .encode_value_expr(
+                                            encoded_lhs.clone().field(exhausted_field),
+                                            bool_ty,
+                                        )
+                                        .with_span(span)?,
+                                    false.into(),
+                                );
+                                field_exprs.push(false.into());
+                                let snapshot = self
+                                    .encoder
+                                    .encode_snapshot(ty, Some(0), field_exprs)
+                                    .with_span(span)?;
+                                state.substitute_value(&encoded_lhs, snapshot);
+                                state
+                            }
+
                             "std::ops::Index::index" | "core::ops::Index::index" => {
                                 assert_eq!(args.len(), 2);
                                 trace!("slice::index(args={:?}, encoded_args={:?}, ty={:?}, encoded_lhs={:?})", args, encoded_args, ty, encoded_lhs);
@@ -522,42 +1027,90 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 let usize_ty =
                                     tcx.mk_ty_from_kind(ty::TyKind::Uint(ty::UintTy::Usize));
                                 let start = match &*idx_ident {
-                                    "std::ops::Range" | "core::ops::Range" |
-                                    "std::ops::RangeFrom" | "core::ops::RangeFrom" =>
-                                        self.encoder.encode_struct_field_value(encoded_idx.clone(), "start", usize_ty).with_span(span)?,
+                                    "std::ops::Range"
+                                    | "core::ops::Range"
+                                    | "std::ops::RangeFrom"
+                                    | "core::ops::RangeFrom" => self
+                                        .encoder
+                                        .encode_struct_field_value(
+                                            encoded_idx.clone(),
+                                            "start",
+                                            usize_ty,
+                                        )
+                                        .with_span(span)?,
                                     // See procedure_encoder.rs
-                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => return Err(
-                                        SpannedEncodingError::unsupported("slicing with RangeInclusive (e.g. [x..=y]) currently not supported".to_string(), span)
-                                    ),
-                                    "std::ops::RangeTo" | "core::ops::RangeTo" |
-                                    "std::ops::RangeFull" | "core::ops::RangeFull" |
-                                    "std::ops::RangeToInclusive" | "core::ops::RangeToInclusive" => vir::Expr::from(0u32),
-                                    _ => unreachable!("{}", idx_ident)
+                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => {
+                                        self.encoder
+                                            .encode_range_inclusive_slice_start(
+                                                encoded_idx.clone(),
+                                                usize_ty,
+                                            )
+                                            .with_span(span)?
+                                    }
+                                    "std::ops::RangeTo"
+                                    | "core::ops::RangeTo"
+                                    | "std::ops::RangeFull"
+                                    | "core::ops::RangeFull"
+                                    | "std::ops::RangeToInclusive"
+                                    | "core::ops::RangeToInclusive" => vir::Expr::from(0u32),
+                                    _ => unreachable!("{}", idx_ident),
                                 };
                                 let end = match &*idx_ident {
-                                    "std::ops::Range" | "core::ops::Range" |
-                                    "std::ops::RangeTo" | "core::ops::RangeTo" =>
-                                        self.encoder.encode_struct_field_value(encoded_idx.clone(), "end", usize_ty).with_span(span)?,
-                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => return Err(
-                                        SpannedEncodingError::unsupported("slicing with RangeInclusive (e.g. [x..=y]) currently not supported".to_string(), span)
-                                    ),
-                                    "std::ops::RangeToInclusive" | "core::ops::RangeToInclusive" => {
-                                        let end_expr = self.encoder.encode_struct_field_value(encoded_idx.clone(), "end", usize_ty).with_span(span)?;
+                                    "std::ops::Range" | "core::ops::Range"
+                                    | "std::ops::RangeTo" | "core::ops::RangeTo" => self
+                                        .encoder
+                                        .encode_struct_field_value(
+                                            encoded_idx.clone(),
+                                            "end",
+                                            usize_ty,
+                                        )
+                                        .with_span(span)?,
+                                    "std::ops::RangeInclusive"
+                                    | "core::ops::RangeInclusive"
+                                    | "std::ops::RangeToInclusive"
+                                    | "core::ops::RangeToInclusive" => {
+                                        let end_expr = self
+                                            .encoder
+                                            .encode_struct_field_value(
+                                                encoded_idx.clone(),
+                                                "end",
+                                                usize_ty,
+                                            )
+                                            .with_span(span)?;
                                         vir::Expr::add(end_expr, vir::Expr::from(1u32))
                                     }
-                                    "std::ops::RangeFrom" | "core::ops::RangeFrom" |
-                                    "std::ops::RangeFull" | "core::ops::RangeFull" => {
+                                    "std::ops::RangeFrom"
+                                    | "core::ops::RangeFrom"
+                                    | "std::ops::RangeFull"
+                                    | "core::ops::RangeFull" => {
                                         if base_ty.peel_refs().is_array() {
-                                            let array_len = self.encoder.encode_sequence_types(base_ty.peel_refs()).with_span(span)?.sequence_len.unwrap();
+                                            let array_len = self
+                                                .encoder
+                                                .encode_sequence_types(base_ty.peel_refs())
+                                                .with_span(span)?
+                                                .sequence_len
+                                                .unwrap();
                                             vir::Expr::from(array_len)
                                         } else if base_ty.is_slice() {
-                                            let base = self.mir_encoder.encode_operand_place(&args[0]).with_span(span)?.unwrap();
-                                            let base_expr = self.encoder.encode_value_expr(base, base_ty).with_span(span)?;
-                                            let slice_types_base = self.encoder.encode_sequence_types(base_ty.peel_refs()).with_span(span)?;
+                                            let base = self
+                                                .mir_encoder
+                                                .encode_operand_place(&args[0])
+                                                .with_span(span)?
+                                                .unwrap();
+                                            let base_expr = self
+                                                .encoder
+                                                .encode_value_expr(base, base_ty)
+                                                .with_span(span)?;
+                                            let slice_types_base = self
+                                                .encoder
+                                                .encode_sequence_types(base_ty.peel_refs())
+                                                .with_span(span)?;
                                             slice_types_base.len(self.encoder, base_expr)
-                                        } else { todo!("Get last idx for {}", base_ty) }
+                                        } else {
+                                            todo!("Get last idx for {}", base_ty)
+                                        }
                                     }
-                                    _ => unreachable!("{}", idx_ident)
+                                    _ => unreachable!("{}", idx_ident),
                                 };
 
                                 let slice_expr = self
@@ -596,6 +1149,20 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            // The contract of a specification entailment is
+                            // passed through a helper that only fixes its type.
+                            _ if self
+                                .encoder
+                                .env()
+                                .query
+                                .has_prusti_attribute(def_id, "spec_entailment_contract") =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_args[1].clone());
+                                state
+                            }
+
                             // simple function call
                             _ => {
                                 let (called_def_id, call_substs) = self
@@ -727,7 +1294,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                 ref msg,
                 ..
             } => {
-                let cond_val = self.mir_encoder.encode_operand_expr(cond).with_span(span)?;
+                let cond_val = self
+                    .mir_encoder
+                    .encode_operand_expr(cond, span)
+                    .with_span(span)?;
                 let viper_guard = if expected {
                     cond_val
                 } else {
@@ -928,6 +1498,45 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state.substitute_value(&encoded_lhs, snapshot);
                             }
 
+                            &mir::AggregateKind::Adt(adt_did, _, subst, _, Some(active_field_index)) => {
+                                // A union, whose only operand initializes the active field.
+                                let tcx = self.encoder.env().tcx();
+                                let adt_def = tcx.adt_def(adt_did);
+                                if adt_def.non_enum_variant().fields.len() > 1 {
+                                    let discr_field = self.encoder.encode_discriminant_field();
+                                    state.substitute_value(
+                                        &encoded_lhs.clone().field(discr_field),
+                                        active_field_index.index().into(),
+                                    );
+                                }
+                                let field = &adt_def.non_enum_variant().fields[active_field_index];
+                                let field_name = field.ident(tcx).to_string();
+                                let field_ty = field.ty(tcx, subst);
+                                let encoded_field = self.encoder
+                                    .encode_struct_field(&field_name, field_ty)
+                                    .with_span(span)?;
+                                let field_place = encoded_lhs.clone()
+                                    .variant(&field_name)
+                                    .field(encoded_field);
+                                let (encoded_rhs, is_value) = self.encode_operand(&operands[0usize.into()])
+                                    .with_span(span)?;
+                                if is_value {
+                                    state.substitute_value(
+                                        &self.encoder.encode_value_expr(field_place, field_ty)
+                                            .with_span(span)?,
+                                        encoded_rhs.clone(),
+                                    );
+                                } else {
+                                    state.substitute_value(&field_place, encoded_rhs.clone());
+                                }
+                                let snapshot = self.encoder.encode_snapshot(
+                                    ty,
+                                    Some(active_field_index.index()),
+                                    vec![encoded_rhs],
+                                ).with_span(span)?;
+                                state.substitute_value(&encoded_lhs, snapshot);
+                            }
+
                             &mir::AggregateKind::Adt(adt_did, variant_index, subst, _, _) => {
                                 let tcx = self.encoder.env().tcx();
                                 let adt_def = tcx.adt_def(adt_did);
@@ -1047,9 +1656,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                     }
 
                     &mir::Rvalue::BinaryOp(op, box(ref left, ref right)) => {
-                        let encoded_left = self.mir_encoder.encode_operand_expr(left)
+                        let encoded_left = self.mir_encoder.encode_operand_expr(left, span)
                             .with_span(span)?;
-                        let encoded_right = self.mir_encoder.encode_operand_expr(right)
+                        let encoded_right = self.mir_encoder.encode_operand_expr(right, span)
                             .with_span(span)?;
                         let encoded_value = self.mir_encoder.encode_bin_op_expr(
                             op,
@@ -1069,9 +1678,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                             unreachable!()
                         };
 
-                        let encoded_left = self.mir_encoder.encode_operand_expr(left)
+                        let encoded_left = self.mir_encoder.encode_operand_expr(left, span)
                             .with_span(span)?;
-                        let encoded_right = self.mir_encoder.encode_operand_expr(right)
+                        let encoded_right = self.mir_encoder.encode_operand_expr(right, span)
                             .with_span(span)?;
 
                         let encoded_value = self.mir_encoder.encode_bin_op_expr(
@@ -1111,7 +1720,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                     }
 
                     &mir::Rvalue::UnaryOp(op, ref operand) => {
-                        let encoded_val = self.mir_encoder.encode_operand_expr(operand)
+                        let encoded_val = self.mir_encoder.encode_operand_expr(operand, span)
                             .with_span(span)?;
                         let encoded_value = self.mir_encoder.encode_unary_op_expr(op, encoded_val);
 
@@ -1160,8 +1769,30 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                         }
                     }
 
+                    &mir::Rvalue::Ref(_, mir::BorrowKind::Shared, place)
+                        if matches!(place.projection.last(), Some(mir::ProjectionElem::Subslice { .. })) =>
+                    {
+                        let (base, base_ty, start, end) = self.mir_encoder
+                            .encode_subslice(place)
+                            .with_span(span)?
+                            .unwrap();
+                        let slice_ty = place.ty(self.mir, self.encoder.env().tcx()).ty;
+                        if !slice_ty.is_slice() {
+                            return Err(SpannedEncodingError::unsupported(
+                                format!("binding the rest of an array of type {base_ty} in a slice pattern is not supported in pure functions"),
+                                span,
+                            ));
+                        }
+                        let slice_expr = self
+                            .encoder
+                            .encode_snapshot_slicing(base_ty, base, slice_ty, start, end)
+                            .with_span(span)?;
+                        state.substitute_value(&encoded_lhs, slice_expr);
+                    }
+
                     &mir::Rvalue::Ref(_, mir::BorrowKind::Mut { .. }, place)
-                    | &mir::Rvalue::Ref(_, mir::BorrowKind::Shared, place) => {
+                    | &mir::Rvalue::Ref(_, mir::BorrowKind::Shared, place)
+                    | &mir::Rvalue::Ref(_, mir::BorrowKind::Shallow, place) => {
                         let (encoded_place, _, _) = self.encode_place(place).with_span(span)?;
                         // TODO: Instead of generating an `AddrOf(..)` expression, here we could
                         // generate a shapshot representing a reference. If we do so, we should
@@ -1183,7 +1814,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                         state.substitute_value(&encoded_lhs, encoded_ref);
                     }
 
-                    mir::Rvalue::Cast(mir::CastKind::IntToInt, ref operand, dst_ty) => {
+                    mir::Rvalue::Cast(mir::CastKind::IntToInt, ref operand, dst_ty)
+                    | mir::Rvalue::Cast(mir::CastKind::FloatToInt, ref operand, dst_ty)
+                    | mir::Rvalue::Cast(mir::CastKind::FloatToFloat, ref operand, dst_ty)
+                    | mir::Rvalue::Cast(mir::CastKind::IntToFloat, ref operand, dst_ty) => {
                         let encoded_val = self.mir_encoder
                             .encode_cast_expr(operand, *dst_ty, span)?;
 
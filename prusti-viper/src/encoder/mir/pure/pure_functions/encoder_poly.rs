@@ -150,11 +150,12 @@ impl<'p, 'v: 'p, 'tcx: 'v> PureFunctionFormalArgsEncoderInterface<'p, 'v, 'tcx>
         var_span: Span,
         ty: ty::Binder<'tcx, ty::Ty<'tcx>>,
     ) -> SpannedEncodingResult<()> {
-        if !self
-            .encoder
-            .env()
-            .query
-            .type_is_copy(ty, self.parent_def_id)
+        if !self.encoder.is_ghost_type_method(self.proc_def_id)
+            && !self
+                .encoder
+                .env()
+                .query
+                .type_is_copy(ty, self.parent_def_id)
         {
             Err(SpannedEncodingError::incorrect(
                 "pure function parameters must be Copy",
@@ -637,11 +638,12 @@ impl<'p, 'v: 'p, 'tcx: 'v> PureFunctionEncoder<'p, 'v, 'tcx> {
         let ty = self.sig.output();
 
         // Return an error for unsupported return types
-        if !self
-            .encoder
-            .env()
-            .query
-            .type_is_copy(ty, self.parent_def_id)
+        if !self.encoder.is_ghost_type_method(self.proc_def_id)
+            && !self
+                .encoder
+                .env()
+                .query
+                .type_is_copy(ty, self.parent_def_id)
         {
             return Err(SpannedEncodingError::incorrect(
                 "return type of pure function does not implement Copy",
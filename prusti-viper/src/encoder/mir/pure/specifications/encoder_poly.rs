@@ -291,6 +291,204 @@ pub(super) fn encode_quantifier<'tcx>(
     }
 }
 
+pub(super) fn encode_spec_entailment<'tcx>(
+    encoder: &Encoder<'_, 'tcx>,
+    span: Span,
+    encoded_args: Vec<vir_crate::polymorphic::Expr>,
+    parent_def_id: DefId,
+    substs: ty::GenericArgsRef<'tcx>,
+) -> SpannedEncodingResult<vir_crate::polymorphic::Expr> {
+    // Specification entailments are encoded as:
+    //   specification_entailment(
+    //     <calls>,
+    //     &<closure>,
+    //     |args...| -> bool { <precondition> },
+    //     |args..., result| -> bool { <postcondition> },
+    //   )
+    //
+    // A call of the closure in the state `cl` satisfies the entailment if the
+    // precondition implies `closure$pre(cl, args...)`, and if the precondition
+    // and `closure$post(cl, cl', args..., result)` imply the postcondition. If
+    // `<calls>` is zero, this has to hold for all states of the closure, and
+    // therefore for any number of calls. Otherwise, it has to hold for the
+    // current state of the closure and for the states `cl'` that are reached
+    // by the following calls, up to `<calls>` calls in total.
+
+    let vir_crate::polymorphic::Expr::Const(vir_crate::polymorphic::ConstExpr {
+        value: vir_crate::polymorphic::Const::BigInt(calls),
+        ..
+    }) = &encoded_args[0]
+    else {
+        return Err(EncodingError::internal(format!(
+            "the number of calls of a specification entailment is not a constant: {}",
+            encoded_args[0]
+        )))
+        .with_span(span);
+    };
+    let calls: usize = calls.parse().unwrap();
+
+    let closure_ty = substs.type_at(0);
+    let (pre_def_id, pre_substs, pre_span, arg_tys, _) =
+        extract_closure_from_ty(encoder.env().query, substs.type_at(1));
+    let (post_def_id, post_substs, post_span, mut post_arg_tys, _) =
+        extract_closure_from_ty(encoder.env().query, substs.type_at(2));
+    let result_ty = post_arg_tys.pop().unwrap();
+    assert_eq!(arg_tys, post_arg_tys);
+
+    let closure_snap_ty = encoder.encode_snapshot_type(closure_ty).with_span(span)?;
+    let item_name = encoder.encode_item_name(pre_def_id);
+    let next_closure = |depth: usize| {
+        vir_crate::polymorphic::LocalVar::new(
+            format!("_next_cl_quant_{depth}_{item_name}"),
+            closure_snap_ty.clone(),
+        )
+    };
+    let pos = encoder.error_manager().register_span(parent_def_id, span);
+
+    // Encodes a call of the closure in the state `closure`, which depends on
+    // the quantified variables `closure_qvars`. `then` describes the later
+    // calls in the state `next_closure(depth)` after the call.
+    let encode_call = |depth: usize,
+                       closure: vir_crate::polymorphic::Expr,
+                       closure_qvars: Vec<vir_crate::polymorphic::LocalVar>,
+                       then: vir_crate::polymorphic::Expr|
+     -> SpannedEncodingResult<vir_crate::polymorphic::Expr> {
+        let mut encoded_qvars = vec![];
+        let mut bounds = vec![];
+        for (arg_idx, arg_ty) in arg_tys.iter().chain([&result_ty]).enumerate() {
+            let qvar_ty = encoder.encode_snapshot_type(*arg_ty).with_span(span)?;
+            let encoded_qvar = vir_crate::polymorphic::LocalVar::new(
+                format!("_{arg_idx}_quant_{depth}_{item_name}"),
+                qvar_ty,
+            );
+            if config::check_overflows() {
+                bounds.push(
+                    encoder
+                        .encode_type_bounds(&encoded_qvar.clone().into(), *arg_ty)
+                        .into_iter()
+                        .conjoin(),
+                );
+            }
+            encoded_qvars.push(encoded_qvar);
+        }
+        let encoded_result = encoded_qvars.pop().unwrap();
+        let result_bounds = if config::check_overflows() {
+            bounds.pop().unwrap()
+        } else {
+            true.into()
+        };
+        let arg_exprs: Vec<vir_crate::polymorphic::Expr> = encoded_qvars
+            .iter()
+            .cloned()
+            .map(vir_crate::polymorphic::Expr::local)
+            .collect();
+
+        let encoded_pre = inline_closure(
+            encoder,
+            pre_def_id,
+            encoded_args[2].clone(),
+            encoded_qvars.clone(),
+            parent_def_id,
+            pre_substs,
+        )?;
+        let mut post_qvars = encoded_qvars.clone();
+        post_qvars.push(encoded_result.clone());
+        let encoded_post = inline_closure(
+            encoder,
+            post_def_id,
+            encoded_args[3].clone(),
+            post_qvars,
+            parent_def_id,
+            post_substs,
+        )?;
+
+        let encoded_next_closure = next_closure(depth);
+        let closure_pre = encoder.encode_closure_spec_func_app(
+            closure_ty,
+            &arg_tys,
+            result_ty,
+            false,
+            std::iter::once(closure.clone())
+                .chain(arg_exprs.iter().cloned())
+                .collect(),
+            pre_span,
+            pos,
+        )?;
+        let closure_post = encoder.encode_closure_spec_func_app(
+            closure_ty,
+            &arg_tys,
+            result_ty,
+            true,
+            [closure, encoded_next_closure.clone().into()]
+                .into_iter()
+                .chain(arg_exprs.iter().cloned())
+                .chain(std::iter::once(encoded_result.clone().into()))
+                .collect(),
+            post_span,
+            pos,
+        )?;
+
+        let args_bounds = bounds.into_iter().conjoin();
+        let mut pre_qvars = closure_qvars.clone();
+        pre_qvars.extend(encoded_qvars.iter().cloned());
+        let pre_entailment = vir_crate::polymorphic::Expr::forall(
+            pre_qvars,
+            vec![vir_crate::polymorphic::Trigger::new(vec![
+                closure_pre.clone()
+            ])],
+            vir_crate::polymorphic::Expr::implies(
+                vir_crate::polymorphic::Expr::and(args_bounds.clone(), encoded_pre.clone()),
+                closure_pre,
+            ),
+        );
+        let mut post_qvars = closure_qvars;
+        post_qvars.push(encoded_next_closure);
+        post_qvars.extend(encoded_qvars);
+        post_qvars.push(encoded_result);
+        let post_entailment = vir_crate::polymorphic::Expr::forall(
+            post_qvars,
+            vec![vir_crate::polymorphic::Trigger::new(vec![
+                closure_post.clone()
+            ])],
+            vir_crate::polymorphic::Expr::implies(
+                vec![args_bounds, result_bounds, encoded_pre, closure_post]
+                    .into_iter()
+                    .conjoin(),
+                vir_crate::polymorphic::Expr::and(encoded_post, then),
+            ),
+        );
+        Ok(vir_crate::polymorphic::Expr::and(
+            pre_entailment,
+            post_entailment,
+        ))
+    };
+
+    if calls == 0 {
+        let encoded_closure = vir_crate::polymorphic::LocalVar::new(
+            format!("_cl_quant_{item_name}"),
+            closure_snap_ty.clone(),
+        );
+        return encode_call(
+            0,
+            encoded_closure.clone().into(),
+            vec![encoded_closure],
+            true.into(),
+        );
+    }
+    // The calls are encoded from the last one to the first one, which is made
+    // in the current state of the closure.
+    let mut entailment: vir_crate::polymorphic::Expr = true.into();
+    for depth in (0..calls).rev() {
+        let closure = if depth == 0 {
+            encoded_args[1].clone()
+        } else {
+            next_closure(depth - 1).into()
+        };
+        entailment = encode_call(depth, closure, vec![], entailment)?;
+    }
+    Ok(entailment)
+}
+
 fn find_quantifier_depth(expr: &vir_crate::polymorphic::Expr) -> usize {
     use vir_crate::polymorphic::ExprWalker;
     struct DepthChecker {
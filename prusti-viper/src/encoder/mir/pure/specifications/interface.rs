@@ -19,7 +19,9 @@ use crate::encoder::{
                 encoder_high::{
                     encode_quantifier_high, inline_closure_high, inline_spec_item_high,
                 },
-                encoder_poly::{encode_quantifier, inline_closure, inline_spec_item},
+                encoder_poly::{
+                    encode_quantifier, encode_spec_entailment, inline_closure, inline_spec_item,
+                },
             },
             PureEncodingContext,
         },
@@ -252,6 +254,9 @@ impl<'v, 'tcx: 'v> SpecificationEncoderInterface<'tcx> for crate::encoder::Encod
                 parent_def_id,
                 substs,
             ),
+            "prusti_contracts::specification_entailment" => {
+                encode_spec_entailment(self, span, encoded_args, parent_def_id, substs)
+            }
             "prusti_contracts::snap" => Ok(vir_poly::Expr::snap_app(encoded_args[0].clone())),
             "prusti_contracts::snapshot_equality" => Ok(vir_poly::Expr::eq_cmp(
                 vir_poly::Expr::snap_app(encoded_args[0].clone()),
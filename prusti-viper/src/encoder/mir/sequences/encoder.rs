@@ -1,7 +1,7 @@
 use super::interface::EncodedSequenceTypes;
-use crate::{
-    encoder::{errors::EncodingResult, high::types::HighTypeEncoderInterface, Encoder},
-    error_unsupported,
+use crate::encoder::{
+    errors::EncodingResult, high::types::HighTypeEncoderInterface, mir::types::string_bytes_ty,
+    Encoder,
 };
 use prusti_rustc_interface::middle::ty;
 
@@ -9,6 +9,8 @@ pub(super) fn encode_sequence_types<'p, 'v: 'p, 'tcx: 'v>(
     encoder: &'p Encoder<'v, 'tcx>,
     sequence_ty_rs: ty::Ty<'tcx>,
 ) -> EncodingResult<EncodedSequenceTypes<'tcx>> {
+    let sequence_ty_rs =
+        string_bytes_ty(encoder.env().tcx(), sequence_ty_rs).unwrap_or(sequence_ty_rs);
     let (elem_ty_rs, sequence_len) = match sequence_ty_rs.kind() {
         ty::TyKind::Array(elem_ty, array_len) => {
             let len = encoder
@@ -22,9 +24,6 @@ pub(super) fn encode_sequence_types<'p, 'v: 'p, 'tcx: 'v>(
             (*elem_ty, Some(len))
         }
         ty::TyKind::Slice(elem_ty) => (*elem_ty, None),
-        ty::TyKind::Str => {
-            error_unsupported!("Encoding of Str slice type");
-        }
         _ => unreachable!(),
     };
 
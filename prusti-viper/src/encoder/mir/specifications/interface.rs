@@ -72,6 +72,11 @@ pub(crate) trait SpecificationsInterface<'tcx> {
     // TODO abstract-predicates: Maybe this should be deleted (and ProcedureSpecificationKind::is_pure)
     fn is_pure(&self, def_id: DefId, substs: Option<GenericArgsRef<'tcx>>) -> bool;
 
+    /// Is the function a method of one of the ghost types of `prusti_contracts`,
+    /// such as `Seq` or `Map`? These methods work on snapshots, so their
+    /// arguments and results do not need to be `Copy`.
+    fn is_ghost_type_method(&self, def_id: DefId) -> bool;
+
     fn get_proc_kind(
         &self,
         def_id: DefId,
@@ -133,20 +138,20 @@ impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encode
     #[tracing::instrument(level = "trace", skip(self), ret)]
     fn is_pure(&self, def_id: DefId, substs: Option<GenericArgsRef<'tcx>>) -> bool {
         let kind = self.get_proc_kind(def_id, substs);
-        let mut pure = matches!(
+        matches!(
             kind,
             ProcedureSpecificationKind::Pure | ProcedureSpecificationKind::Predicate(_)
-        );
+        ) || self.is_ghost_type_method(def_id)
+    }
 
+    fn is_ghost_type_method(&self, def_id: DefId) -> bool {
         let func_name = self.env().name.get_unique_item_name(def_id);
-        if func_name.starts_with("prusti_contracts::prusti_contracts::Map")
+        func_name.starts_with("prusti_contracts::prusti_contracts::Map")
             || func_name.starts_with("prusti_contracts::prusti_contracts::Seq")
+            || func_name.starts_with("prusti_contracts::prusti_contracts::Set")
+            || func_name.starts_with("prusti_contracts::prusti_contracts::Multiset")
             || func_name.starts_with("prusti_contracts::prusti_contracts::Ghost")
             || func_name.starts_with("prusti_contracts::prusti_contracts::Int")
-        {
-            pure = true;
-        }
-        pure
     }
 
     fn get_proc_kind(
@@ -167,6 +172,12 @@ impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encode
 
     #[tracing::instrument(level = "trace", skip(self), ret)]
     fn is_trusted(&self, def_id: DefId, substs: Option<GenericArgsRef<'tcx>>) -> bool {
+        // The body of a trusted `async fn` is trusted as well.
+        let def_id = self
+            .env()
+            .query
+            .get_async_fn_of_generator(def_id)
+            .unwrap_or(def_id);
         let substs = substs.unwrap_or_else(|| self.env().query.identity_substs(def_id));
         let query = SpecQuery::GetProcKind(def_id, substs);
         self.specifications_state
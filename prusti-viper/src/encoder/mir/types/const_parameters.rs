@@ -79,10 +79,14 @@ pub(super) fn extract_const_parameters_from_type<'tcx>(
         ty::TyKind::Param(_param_ty) => {
             // FIXME: extract const_parameters from TyKind::Param()
         }
+        ty::TyKind::Generator(_, args, _) => {
+            for upvar_ty in args.as_generator().upvar_tys() {
+                extract_const_parameters_from_type(type_encoder, upvar_ty, const_parameters)?;
+            }
+        }
         ty::TyKind::Bound(_, _)
         | ty::TyKind::Placeholder(_)
         | ty::TyKind::Infer(_)
-        | ty::TyKind::Generator(..)
         | ty::TyKind::GeneratorWitness(_)
         | ty::TyKind::GeneratorWitnessMIR(..) => {
             return Err(SpannedEncodingError::unsupported(
@@ -4,7 +4,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::{helpers::compute_discriminant_values, interface::MirTypeEncoderInterface};
+use super::{
+    helpers::{compute_discriminant_values, is_task_context_ty, string_bytes_ty, transparent_ty},
+    interface::MirTypeEncoderInterface,
+};
 use crate::encoder::{
     errors::{EncodingResult, SpannedEncodingError, SpannedEncodingResult, WithSpan},
     mir::{
@@ -48,6 +51,8 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
             type_name,
             "prusti_contracts::Seq"
                 | "prusti_contracts::Map"
+                | "prusti_contracts::Set"
+                | "prusti_contracts::Multiset"
                 | "prusti_contracts::Int"
                 | "prusti_contracts::Ghost"
         )
@@ -71,6 +76,12 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
         const_arguments: &[vir::Expression],
     ) -> SpannedEncodingResult<vir::Type> {
         // self.encode_polymorphic_predicate_use()
+        if let Some(bytes_ty) = string_bytes_ty(self.encoder.env().tcx(), self.ty) {
+            return self.encoder.encode_type_high(bytes_ty);
+        }
+        if let Some(inner_ty) = transparent_ty(self.encoder.env().tcx(), self.ty) {
+            return self.encoder.encode_type_high(inner_ty);
+        }
         let lifetimes = self.encoder.get_lifetimes_from_type_high(self.ty)?;
         let result = match self.ty.kind() {
             ty::TyKind::Bool => vir::Type::Bool,
@@ -137,6 +148,16 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                         val_type: enc_substs[1].clone(),
                         lifetimes,
                     })
+                } else if type_name == "prusti_contracts::Set" {
+                    vir::Type::Set(vir::ty::Set {
+                        element_type: enc_substs[0].clone(),
+                        lifetimes,
+                    })
+                } else if type_name == "prusti_contracts::Multiset" {
+                    vir::Type::MultiSet(vir::ty::MultiSet {
+                        element_type: enc_substs[0].clone(),
+                        lifetimes,
+                    })
                 } else if type_name == "prusti_contracts::Int" {
                     vir::Type::Int(vir::ty::Int::Unbounded)
                 } else if type_name == "prusti_contracts::Ghost" {
@@ -224,6 +245,11 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                 // lifetimes,
             ),
 
+            ty::TyKind::Generator(def_id, _substs, _movability) => vir::Type::closure(
+                encode_generator_name(self.encoder, *def_id),
+                // FIXME: We are currently ignoring type arguments and lifetimes.
+            ),
+
             ty::TyKind::FnDef(def_id, _substs) => vir::Type::function_def(
                 encode_function_def_name(self.encoder, *def_id),
                 // FIXME: We are currently ignoring type arguments and lifetimes.
@@ -243,7 +269,9 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                 )
             }
 
-            ty::TyKind::Dynamic(..) => vir::Type::unsupported("dynamic".to_string()),
+            ty::TyKind::Dynamic(predicates, ..) => {
+                vir::Type::unsupported(encode_dynamic_name(self.encoder, predicates))
+            }
 
             ty::TyKind::FnPtr(..) => vir::Type::unsupported("fnptr".to_string()),
 
@@ -344,6 +372,12 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
     /// Encodes a type predicate for the given type.
     #[tracing::instrument(level = "debug", skip(self), fields(ty = ?self.ty))]
     pub fn encode_type_def_high(self) -> SpannedEncodingResult<vir::TypeDecl> {
+        if let Some(bytes_ty) = string_bytes_ty(self.encoder.env().tcx(), self.ty) {
+            return TypeEncoder::new(self.encoder, bytes_ty).encode_type_def_high();
+        }
+        if let Some(inner_ty) = transparent_ty(self.encoder.env().tcx(), self.ty) {
+            return TypeEncoder::new(self.encoder, inner_ty).encode_type_def_high();
+        }
         let type_decl = match self.ty.kind() {
             ty::TyKind::Bool => vir::TypeDecl::bool(),
             ty::TyKind::Int(_) | ty::TyKind::Uint(_) | ty::TyKind::Char => {
@@ -367,7 +401,7 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
             ty::TyKind::Float(_) => {
                 let mut lower_bound = None;
                 let mut upper_bound = None;
-                if config::check_overflows() {
+                if config::check_overflows() && !config::encode_ieee_floats() {
                     if let Some((lower, upper)) = self.get_float_bounds() {
                         lower_bound = Some(Box::new(lower));
                         upper_bound = Some(Box::new(upper));
@@ -438,6 +472,12 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                         enc_substs[0].clone(),
                         enc_substs[1].clone(),
                     ),
+                    "prusti_contracts::Set" => {
+                        vir::TypeDecl::set(lifetimes, const_parameters, enc_substs[0].clone())
+                    }
+                    "prusti_contracts::Multiset" => {
+                        vir::TypeDecl::multi_set(lifetimes, const_parameters, enc_substs[0].clone())
+                    }
                     "prusti_contracts::Int" => vir::TypeDecl::Int(vir::type_decl::Int {
                         lower_bound: None,
                         upper_bound: None,
@@ -463,6 +503,11 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                     const_parameters,
                 )
             }
+            ty::TyKind::Adt(..) if is_task_context_ty(self.encoder.env().tcx(), self.ty) => {
+                // The context in which futures are polled is opaque.
+                let ty = self.encoder.encode_type_high(self.ty)?;
+                vir::TypeDecl::unsupported(ty)
+            }
             ty::TyKind::Adt(adt_def, substs) => {
                 encode_adt_def(self.encoder, *adt_def, substs, None)?
             }
@@ -480,6 +525,24 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                 let name = encode_closure_name(self.encoder, *def_id);
                 vir::TypeDecl::closure(name, arguments)
             }
+            ty::TyKind::Generator(def_id, internal_substs, _movability) => {
+                // Like a closure, a generator is encoded as the tuple of its
+                // upvars; its suspended state is not modelled.
+                let arguments = internal_substs
+                    .as_generator()
+                    .upvar_tys()
+                    .iter()
+                    .filter_map(|ty| self.encoder.encode_type_high(ty).ok())
+                    .collect();
+                let name = encode_generator_name(self.encoder, *def_id);
+                vir::TypeDecl::closure(name, arguments)
+            }
+            ty::TyKind::Dynamic(..) => {
+                // Trait objects are opaque: everything that is known about
+                // them comes from the specifications of the trait.
+                let ty = self.encoder.encode_type_high(self.ty)?;
+                vir::TypeDecl::unsupported(ty)
+            }
             ty::TyKind::Array(elem_ty, _size) => {
                 let lifetimes = self.encoder.get_lifetimes_from_type_high(self.ty)?;
                 let const_parameters = self.encoder.get_const_parameters_from_type_high(self.ty)?;
@@ -678,10 +741,24 @@ fn encode_closure_name<'v, 'tcx: 'v>(encoder: &Encoder<'v, 'tcx>, did: DefId) ->
     format!("closure${}", encoder.encode_item_name(did))
 }
 
+fn encode_generator_name<'v, 'tcx: 'v>(encoder: &Encoder<'v, 'tcx>, did: DefId) -> String {
+    format!("generator${}", encoder.encode_item_name(did))
+}
+
 fn encode_function_def_name<'v, 'tcx: 'v>(encoder: &Encoder<'v, 'tcx>, did: DefId) -> String {
     format!("fndef${}", encoder.encode_item_name(did))
 }
 
+fn encode_dynamic_name<'v, 'tcx: 'v>(
+    encoder: &Encoder<'v, 'tcx>,
+    predicates: &ty::List<ty::PolyExistentialPredicate<'tcx>>,
+) -> String {
+    match predicates.principal_def_id() {
+        Some(did) => format!("dyn${}", encoder.encode_item_name(did)),
+        None => "dyn$".to_string(),
+    }
+}
+
 fn encode_box_name() -> String {
     "box$".to_string()
 }
@@ -752,12 +829,6 @@ pub(super) fn encode_adt_def<'v, 'tcx>(
         )?))
     } else if adt_def.is_union() {
         debug!("ADT {:?} is a union", adt_def);
-        if !config::unsafe_core_proof() {
-            return Err(SpannedEncodingError::unsupported(
-                "unions are not supported",
-                encoder.env().query.get_def_span(adt_def.did()),
-            ));
-        }
         assert!(variant_index.is_none());
         let name = encode_union_name(encoder, adt_def.did());
         // We treat union fields as variants.
@@ -1,6 +1,6 @@
 use crate::encoder::utils::{range_extract, PlusOne};
 
-use prusti_rustc_interface::{middle::ty, target::abi::Integer};
+use prusti_rustc_interface::{hir::LangItem, middle::ty, span::symbol::sym, target::abi::Integer};
 
 use vir_crate::{
     high as vir_high,
@@ -12,6 +12,11 @@ pub(crate) fn compute_discriminant_values<'tcx>(
     adt_def: ty::AdtDef<'tcx>,
     tcx: ty::TyCtxt<'tcx>,
 ) -> Vec<i128> {
+    if adt_def.is_union() {
+        // The discriminant of a union is the index of its active field.
+        let num_fields: i128 = adt_def.non_enum_variant().fields.len().try_into().unwrap();
+        return (0..num_fields).collect();
+    }
     let mut discr_values: Vec<i128> = vec![];
     let size = ty::tls::with(|tcx| Integer::from_attr(&tcx, adt_def.repr().discr_type()).size());
     for (_variant_idx, discr) in adt_def.discriminants(tcx) {
@@ -22,6 +27,64 @@ pub(crate) fn compute_discriminant_values<'tcx>(
     discr_values
 }
 
+/// Prusti models `str` and `String` as the sequence of their UTF-8 bytes, so
+/// both are encoded exactly like the slice type `[u8]`. Returns that slice type
+/// if `ty` is one of the two string types.
+pub(crate) fn string_bytes_ty<'tcx>(
+    tcx: ty::TyCtxt<'tcx>,
+    ty: ty::Ty<'tcx>,
+) -> Option<ty::Ty<'tcx>> {
+    let is_string = match ty.kind() {
+        ty::TyKind::Str => true,
+        ty::TyKind::Adt(adt_def, _) => tcx.is_diagnostic_item(sym::String, adt_def.did()),
+        _ => false,
+    };
+    is_string.then(|| ty::Ty::new_slice(tcx, tcx.types.u8))
+}
+
+/// Prusti sees through the wrappers in which futures and closures are passed
+/// around: the opaque type of a future or of a closure is encoded as the
+/// generator or closure that implements it, and `Pin<P>` is encoded exactly
+/// like the pointer `P`. Returns the type that `ty` is encoded as if it is one
+/// of these wrappers.
+pub(crate) fn transparent_ty<'tcx>(
+    tcx: ty::TyCtxt<'tcx>,
+    ty: ty::Ty<'tcx>,
+) -> Option<ty::Ty<'tcx>> {
+    match ty.kind() {
+        ty::TyKind::Alias(ty::AliasKind::Opaque, alias_ty) => {
+            let hidden_ty = tcx.type_of(alias_ty.def_id).instantiate(tcx, alias_ty.args);
+            (hidden_ty.is_generator() || hidden_ty.is_closure()).then_some(hidden_ty)
+        }
+        ty::TyKind::Adt(adt_def, substs)
+            if tcx.lang_items().get(LangItem::Pin) == Some(adt_def.did()) =>
+        {
+            Some(substs.type_at(0))
+        }
+        _ => None,
+    }
+}
+
+/// Is `ty` the context in which a future is polled? Prusti treats it as an
+/// opaque type, because its contents cannot be described by specifications.
+pub(crate) fn is_task_context_ty<'tcx>(tcx: ty::TyCtxt<'tcx>, ty: ty::Ty<'tcx>) -> bool {
+    match ty.kind() {
+        ty::TyKind::Adt(adt_def, _) => [LangItem::Context, LangItem::ResumeTy]
+            .into_iter()
+            .any(|item| tcx.lang_items().get(item) == Some(adt_def.did())),
+        _ => false,
+    }
+}
+
+/// Is `ty` the unbounded mathematical integer `prusti_contracts::Int`? Its
+/// snapshot is a Viper `Int`, like the snapshots of the bounded integer types.
+pub(crate) fn is_mathematical_int_ty<'tcx>(tcx: ty::TyCtxt<'tcx>, ty: ty::Ty<'tcx>) -> bool {
+    match ty.kind() {
+        ty::TyKind::Adt(adt_def, _) => tcx.def_path_str(adt_def.did()) == "prusti_contracts::Int",
+        _ => false,
+    }
+}
+
 /// Encode a disjunction that lists all possible discrimintant values.
 pub(super) fn compute_discriminant_ranges<'tcx>(
     adt_def: ty::AdtDef<'tcx>,
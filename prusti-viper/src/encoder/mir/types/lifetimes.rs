@@ -12,9 +12,7 @@ pub(super) fn extract_lifetimes_from_substs<'tcx>(
 ) -> SpannedEncodingResult<()> {
     for kind in substs.iter() {
         if let ty::GenericArgKind::Lifetime(region) = kind.unpack() {
-            lifetimes.push(vir_high::ty::LifetimeConst {
-                name: region.to_text(),
-            });
+            extract_lifetime_from_region(region, lifetimes);
         }
     }
     for kind in substs.iter() {
@@ -25,6 +23,20 @@ pub(super) fn extract_lifetimes_from_substs<'tcx>(
     Ok(())
 }
 
+/// Late-bound regions, such as `'a` in `for<'a> fn(&'a u32)`, are not
+/// lifetime parameters of a type: a higher-ranked signature is instantiated
+/// with fresh lifetimes at each call.
+fn extract_lifetime_from_region(
+    region: ty::Region<'_>,
+    lifetimes: &mut Vec<vir_high::ty::LifetimeConst>,
+) {
+    if !region.is_late_bound() {
+        lifetimes.push(vir_high::ty::LifetimeConst {
+            name: region.to_text(),
+        });
+    }
+}
+
 pub(super) fn extract_lifetimes_from_types<'tcx>(
     type_encoder: &impl super::MirTypeEncoderInterface<'tcx>,
     types: impl IntoIterator<Item = ty::Ty<'tcx>>,
@@ -60,13 +72,9 @@ pub(super) fn extract_lifetimes_from_type<'tcx>(
         ty::TyKind::Array(ty, _) | ty::TyKind::Slice(ty) => {
             extract_lifetimes_from_type(type_encoder, *ty, lifetimes)?
         }
-        ty::TyKind::Dynamic(_, region, _) => lifetimes.push(vir_high::ty::LifetimeConst {
-            name: region.to_text(),
-        }),
+        ty::TyKind::Dynamic(_, region, _) => extract_lifetime_from_region(*region, lifetimes),
         ty::TyKind::Ref(region, target_ty, _) => {
-            lifetimes.push(vir_high::ty::LifetimeConst {
-                name: region.to_text(),
-            });
+            extract_lifetime_from_region(*region, lifetimes);
             extract_lifetimes_from_type(type_encoder, *target_ty, lifetimes)?;
         }
         ty::TyKind::Tuple(ty_list) => {
@@ -78,22 +86,23 @@ pub(super) fn extract_lifetimes_from_type<'tcx>(
             extract_lifetimes_from_type(type_encoder, type_and_mut.ty, lifetimes)?
         }
         ty::TyKind::FnPtr(poly_fn_sig) => {
-            let ty_list = poly_fn_sig.inputs_and_output().bound_vars();
-            for bound_variable_kind in ty_list.iter() {
-                if let ty::BoundVariableKind::Region(bound_region_kind) = bound_variable_kind {
-                    lifetimes.push(vir_high::ty::LifetimeConst {
-                        name: bound_region_kind.to_text(),
-                    });
-                }
+            // The bound lifetimes of the signature are skipped: only the free
+            // ones are lifetime parameters of the type.
+            for item_ty in poly_fn_sig.inputs_and_output().skip_binder() {
+                extract_lifetimes_from_type(type_encoder, item_ty, lifetimes)?;
             }
         }
         ty::TyKind::Param(_param_ty) => {
             // FIXME: extract lifetimes from TyKind::Param()
         }
+        ty::TyKind::Generator(_, args, _) => {
+            for upvar_ty in args.as_generator().upvar_tys() {
+                extract_lifetimes_from_type(type_encoder, upvar_ty, lifetimes)?;
+            }
+        }
         ty::TyKind::Bound(_, _)
         | ty::TyKind::Placeholder(_)
         | ty::TyKind::Infer(_)
-        | ty::TyKind::Generator(..)
         | ty::TyKind::GeneratorWitness(_)
         | ty::TyKind::GeneratorWitnessMIR(..) => {
             return Err(SpannedEncodingError::unsupported(
@@ -7,7 +7,10 @@ mod interface;
 mod lifetimes;
 
 pub(crate) use self::{
-    helpers::compute_discriminant_bounds,
+    helpers::{
+        compute_discriminant_bounds, is_mathematical_int_ty, is_task_context_ty, string_bytes_ty,
+        transparent_ty,
+    },
     interface::{MirTypeEncoderInterface, MirTypeEncoderState},
 };
 
@@ -6,21 +6,27 @@
 
 use std::fmt::Debug;
 
-use super::high::types::HighTypeEncoderInterface;
+use super::high::{
+    builtin_functions::HighBuiltinFunctionEncoderInterface, types::HighTypeEncoderInterface,
+};
 use crate::{
     encoder::{
+        builtin_encoder::BuiltinFunctionKind,
         errors::{
             EncodingError, EncodingResult, ErrorCtxt, PanicCause, SpannedEncodingError,
             SpannedEncodingResult, WithSpan,
         },
-        mir::{sequences::MirSequencesEncoderInterface, types::MirTypeEncoderInterface},
+        mir::{
+            sequences::MirSequencesEncoderInterface,
+            types::{transparent_ty, MirTypeEncoderInterface},
+        },
         snapshot::interface::SnapshotEncoderInterface,
         Encoder,
     },
     error_internal, error_unsupported, utils,
 };
 use log::{debug, trace};
-use prusti_common::{config, vir_expr};
+use prusti_common::{config, vir_expr, vir_local};
 use prusti_interface::environment::mir_utils::MirPlace;
 use prusti_rustc_interface::{
     errors::MultiSpan,
@@ -34,11 +40,15 @@ use vir_crate::polymorphic as vir;
 
 mod downcast_detector;
 mod place_encoding;
+mod union_field_detector;
 
 pub use place_encoding::{ExprOrArrayBase, PlaceEncoding};
 
 pub static PRECONDITION_LABEL: &str = "pre";
 pub static WAND_LHS_LABEL: &str = "lhs";
+/// The name of the local that stands for the iterator of a `for` loop in its
+/// body invariant, until the iterator is known.
+pub static LOOP_ITERATOR_PLACEHOLDER: &str = "_loop_iterator";
 
 pub trait PlaceEncoder<'v, 'tcx: 'v> {
     fn encoder(&self) -> &Encoder<'v, 'tcx>;
@@ -110,6 +120,13 @@ pub trait PlaceEncoder<'v, 'tcx: 'v> {
         Ok(match elem {
             mir::ProjectionElem::Field(ref field, proj_field_ty) => {
                 match base_ty.kind() {
+                    ty::TyKind::Adt(..)
+                        if transparent_ty(self.encoder().env().tcx(), base_ty).is_some() =>
+                    {
+                        // `Pin<P>` is encoded as `P`, which is its only field.
+                        (encoded_base, *proj_field_ty, None)
+                    }
+
                     ty::TyKind::Tuple(elems) => {
                         let field_name = format!("tuple_{}", field.index());
                         let field_ty = elems[field.index()];
@@ -139,12 +156,15 @@ pub trait PlaceEncoder<'v, 'tcx: 'v> {
                         };
                         let tcx = self.encoder().env().tcx();
                         let variant_def = &adt_def.variants()[variant_index.into()];
-                        let encoded_variant = if num_variants != 1 {
+                        let field = &variant_def.fields[*field];
+                        let encoded_variant = if adt_def.is_union() {
+                            // The fields of a union are encoded as variants.
+                            encoded_base.variant(field.ident(tcx).as_str())
+                        } else if num_variants != 1 {
                             encoded_base.variant(variant_def.ident(tcx).as_str())
                         } else {
                             encoded_base
                         };
-                        let field = &variant_def.fields[*field];
                         let field_ty = *proj_field_ty;
                         if utils::is_reference(field_ty) {
                             error_unsupported!("access to reference-typed fields is not supported");
@@ -173,7 +193,13 @@ pub trait PlaceEncoder<'v, 'tcx: 'v> {
                     }
 
                     ty::TyKind::Generator(..) => {
-                        error_unsupported!("generator fields are not supported");
+                        // The fields of a generator are its upvars, which are
+                        // encoded like the ones of a closure.
+                        let field_ty = *proj_field_ty;
+                        let field_name = format!("closure_{}", field.index());
+                        let encoded_field =
+                            self.encoder().encode_raw_ref_field(field_name, field_ty)?;
+                        (encoded_base.field(encoded_field), field_ty, None)
                     }
 
                     x => {
@@ -270,7 +296,10 @@ pub trait PlaceEncoder<'v, 'tcx: 'v> {
             }
 
             mir::ProjectionElem::Subslice { .. } => {
-                error_unsupported!("slice patterns are not supported",)
+                // Shared borrows of subslices are handled by `encode_subslice`.
+                error_unsupported!(
+                    "slice patterns can bind the rest of an array or slice only by shared reference"
+                )
             }
         })
     }
@@ -390,14 +419,27 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         }
     }
 
-    /// Returns an `vir::Expr` that corresponds to the value of the operand
+    /// Returns an `vir::Expr` that corresponds to the value of the operand.
+    /// `span` is the span of the statement or terminator that reads the
+    /// operand; it is used to report invalid reads through raw pointers.
     #[tracing::instrument(level = "trace", skip(self))]
-    pub fn encode_operand_expr(&self, operand: &mir::Operand<'tcx>) -> EncodingResult<vir::Expr> {
+    pub fn encode_operand_expr(
+        &self,
+        operand: &mir::Operand<'tcx>,
+        span: Span,
+    ) -> EncodingResult<vir::Expr> {
         Ok(match operand {
+            // String literals are not stored in a place, so we use their snapshot.
+            mir::Operand::Constant(expr) if expr.ty().peel_refs().is_str() => {
+                self.encoder.encode_snapshot_constant(expr)?
+            }
             mir::Operand::Constant(expr) => {
                 self.encoder.encode_const_expr(expr.ty(), expr.literal)?
             }
             &mir::Operand::Copy(place) | &mir::Operand::Move(place) => {
+                if let Some(read) = self.encode_raw_pointer_read(place, span)? {
+                    return Ok(read);
+                }
                 // let val_place = self.eval_place(&place)?;
                 // inlined to do try_into_expr
                 let (encoded_place, place_ty, _) = self.encode_place(place)?;
@@ -434,6 +476,107 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         })
     }
 
+    /// Is `place` a dereference of a raw pointer?
+    pub fn is_raw_pointer_deref(&self, place: mir::Place<'tcx>) -> bool {
+        let tcx = self.encoder.env().tcx();
+        prusti_interface::utils::try_pop_deref(tcx, place).map_or(false, |pointer| {
+            pointer.ty(self.mir, tcx).ty.is_unsafe_ptr()
+        })
+    }
+
+    /// Returns the address stored in the raw pointer that `place`
+    /// dereferences, together with the type of the pointee, or `None` if
+    /// `place` is not a dereference of a raw pointer.
+    pub fn encode_raw_pointer_target(
+        &self,
+        place: mir::Place<'tcx>,
+    ) -> EncodingResult<Option<(vir::Expr, ty::Ty<'tcx>)>> {
+        if !self.is_raw_pointer_deref(place) {
+            return Ok(None);
+        }
+        let tcx = self.encoder.env().tcx();
+        let pointee_ty = place.ty(self.mir, tcx).ty;
+        if !pointee_ty.is_primitive() {
+            return Err(EncodingError::unsupported(format!(
+                "accessing values of type {pointee_ty} through raw pointers is not supported"
+            )));
+        }
+        let (encoded_place, _, _) = self.encode_place(place)?;
+        let address = encoded_place.try_into_expr().map_err(|_| {
+            EncodingError::unsupported(
+                "raw pointers stored in arrays or slices are not supported".to_string(),
+            )
+        })?;
+        Ok(Some((address, pointee_ty)))
+    }
+
+    /// Returns the encoding of a read of a primitive value through a raw
+    /// pointer, or `None` if `place` is not a dereference of a raw pointer.
+    ///
+    /// The memory pointed to by a raw pointer is not tracked by the
+    /// fold-unfold algorithm, so the read is encoded as a call of a builtin
+    /// function whose precondition requires a permission to the memory.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub fn encode_raw_pointer_read(
+        &self,
+        place: mir::Place<'tcx>,
+        span: Span,
+    ) -> EncodingResult<Option<vir::Expr>> {
+        let Some((address, pointee_ty)) = self.encode_raw_pointer_target(place)? else {
+            return Ok(None);
+        };
+        let pointee_pred_type = self.encoder.encode_type(pointee_ty)?;
+        let value_field = self.encoder.encode_value_field(pointee_ty)?;
+        let return_type = value_field.typ.clone();
+        let (function_name, type_arguments) =
+            self.encoder
+                .encode_builtin_function_use(BuiltinFunctionKind::ReadRawPointer {
+                    pointee_pred_type: pointee_pred_type.clone(),
+                    value_field,
+                });
+        let pos = self.register_error(span, ErrorCtxt::RawPointerRead);
+        Ok(Some(vir::Expr::func_app(
+            function_name,
+            type_arguments,
+            vec![address],
+            vec![vir::LocalVar::new("address", pointee_pred_type)],
+            return_type,
+            pos,
+        )))
+    }
+
+    /// Returns the encoded array or slice that `place` is a subslice of,
+    /// together with its type and the bounds `start..end` of the subslice,
+    /// or `None` if `place` is not a subslice.
+    ///
+    /// Subslices are created by slice patterns like `[first, rest @ ..]`.
+    #[allow(clippy::type_complexity)]
+    pub fn encode_subslice(
+        &self,
+        place: mir::Place<'tcx>,
+    ) -> EncodingResult<Option<(vir::Expr, ty::Ty<'tcx>, vir::Expr, vir::Expr)>> {
+        let tcx = self.encoder.env().tcx();
+        let Some((mir::ProjectionElem::Subslice { from, to, from_end }, base)) =
+            prusti_interface::utils::try_pop_one_level(tcx, place)
+        else {
+            return Ok(None);
+        };
+        let (encoded_base, base_ty, _) = self.encode_place(base)?;
+        let encoded_base = encoded_base.try_into_expr().map_err(|_| {
+            EncodingError::unsupported(
+                "slice patterns on elements of arrays or slices are not supported".to_string(),
+            )
+        })?;
+        let end = if from_end {
+            let sequence_types = self.encoder.encode_sequence_types(base_ty)?;
+            let len = sequence_types.len(self.encoder, encoded_base.clone());
+            vir_expr! { [len] - [vir::Expr::from(to)] }
+        } else {
+            to.into()
+        };
+        Ok(Some((encoded_base, base_ty, from.into(), end)))
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub fn get_operand_ty(&self, operand: &mir::Operand<'tcx>) -> ty::Ty<'tcx> {
         // match operand {
@@ -487,40 +630,40 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
             mir::BinOp::Le => vir::Expr::le_cmp(left, right),
             mir::BinOp::AddUnchecked | mir::BinOp::Add => vir::Expr::add(left, right),
             mir::BinOp::SubUnchecked | mir::BinOp::Sub => vir::Expr::sub(left, right),
+            mir::BinOp::Rem if ty.is_floating_point() => {
+                error_unsupported!("the remainder of floating-point numbers is not supported");
+            }
             mir::BinOp::Rem => vir::Expr::rem(left, right),
             mir::BinOp::Div => vir::Expr::div(left, right),
             mir::BinOp::MulUnchecked | mir::BinOp::Mul => vir::Expr::mul(left, right),
             mir::BinOp::BitAnd if is_bool => vir::Expr::and(left, right),
             mir::BinOp::BitOr if is_bool => vir::Expr::or(left, right),
             mir::BinOp::BitXor if is_bool => vir::Expr::xor(left, right),
-            mir::BinOp::BitAnd | mir::BinOp::BitOr | mir::BinOp::BitXor
-                if !config::encode_bitvectors() =>
-            {
-                error_unsupported!(
-                    "bitwise operations on non-boolean types are experimental and disabled by \
-                    default; use `encode_bitvectors` to enable"
-                );
-            }
-            unsupported_op if !config::encode_bitvectors() => {
-                error_unsupported!(
-                    "support for operation '{:?}' is experimental and disabled by default; use \
-                    `encode_bitvectors` to enable it",
-                    unsupported_op
-                );
-            }
-            mir::BinOp::BitAnd => vir::Expr::bin_op(vir::BinaryOpKind::BitAnd, left, right),
-            mir::BinOp::BitOr => vir::Expr::bin_op(vir::BinaryOpKind::BitOr, left, right),
-            mir::BinOp::BitXor => vir::Expr::bin_op(vir::BinaryOpKind::BitXor, left, right),
-            mir::BinOp::ShlUnchecked | mir::BinOp::Shl => {
-                vir::Expr::bin_op(vir::BinaryOpKind::Shl, left, right)
-            }
-            // https://doc.rust-lang.org/reference/expressions/operator-expr.html#arithmetic-and-logical-binary-operators
-            // Arithmetic right shift on signed integer types, logical right shift on unsigned integer types.
-            mir::BinOp::ShrUnchecked | mir::BinOp::Shr if is_signed => {
-                vir::Expr::bin_op(vir::BinaryOpKind::AShr, left, right)
-            }
-            mir::BinOp::ShrUnchecked | mir::BinOp::Shr => {
-                vir::Expr::bin_op(vir::BinaryOpKind::LShr, left, right)
+            mir::BinOp::BitAnd
+            | mir::BinOp::BitOr
+            | mir::BinOp::BitXor
+            | mir::BinOp::ShlUnchecked
+            | mir::BinOp::Shl
+            | mir::BinOp::ShrUnchecked
+            | mir::BinOp::Shr => {
+                let op_kind = match op {
+                    mir::BinOp::BitAnd => vir::BinaryOpKind::BitAnd,
+                    mir::BinOp::BitOr => vir::BinaryOpKind::BitOr,
+                    mir::BinOp::BitXor => vir::BinaryOpKind::BitXor,
+                    mir::BinOp::ShlUnchecked | mir::BinOp::Shl => vir::BinaryOpKind::Shl,
+                    // https://doc.rust-lang.org/reference/expressions/operator-expr.html#arithmetic-and-logical-binary-operators
+                    // Arithmetic right shift on signed integer types, logical right shift on unsigned integer types.
+                    _ if is_signed => vir::BinaryOpKind::AShr,
+                    _ => vir::BinaryOpKind::LShr,
+                };
+                if config::encode_bitvectors() {
+                    // All integers of the program are turned into bitvectors
+                    // by the `encode_bitvectors` optimization.
+                    vir::Expr::bin_op(op_kind, left, right)
+                } else {
+                    let bv = self.encode_bitvector_type(ty)?;
+                    vir::Expr::int_bit_op(op_kind, bv, left, right)
+                }
             }
             mir::BinOp::Offset => {
                 error_unsupported!("operation '{:?}' is not supported", op);
@@ -535,6 +678,34 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         }
     }
 
+    /// Returns the bitvector that has the same width and signedness as the
+    /// given integer type.
+    pub fn encode_bitvector_type(&self, ty: ty::Ty<'tcx>) -> EncodingResult<vir::BitVector> {
+        Ok(match ty.kind() {
+            ty::TyKind::Uint(ty::UintTy::U8) => vir::BitVector::Unsigned(vir::BitVectorSize::BV8),
+            ty::TyKind::Uint(ty::UintTy::U16) => vir::BitVector::Unsigned(vir::BitVectorSize::BV16),
+            ty::TyKind::Uint(ty::UintTy::U32) => vir::BitVector::Unsigned(vir::BitVectorSize::BV32),
+            ty::TyKind::Uint(ty::UintTy::U64) => vir::BitVector::Unsigned(vir::BitVectorSize::BV64),
+            ty::TyKind::Uint(ty::UintTy::U128) => {
+                vir::BitVector::Unsigned(vir::BitVectorSize::BV128)
+            }
+            ty::TyKind::Int(ty::IntTy::I8) => vir::BitVector::Signed(vir::BitVectorSize::BV8),
+            ty::TyKind::Int(ty::IntTy::I16) => vir::BitVector::Signed(vir::BitVectorSize::BV16),
+            ty::TyKind::Int(ty::IntTy::I32) => vir::BitVector::Signed(vir::BitVectorSize::BV32),
+            ty::TyKind::Int(ty::IntTy::I64) => vir::BitVector::Signed(vir::BitVectorSize::BV64),
+            ty::TyKind::Int(ty::IntTy::I128) => vir::BitVector::Signed(vir::BitVectorSize::BV128),
+            ty::TyKind::Uint(ty::UintTy::Usize) | ty::TyKind::Int(ty::IntTy::Isize) => {
+                error_unsupported!(
+                    "bitwise operations on '{:?}' are not supported because its size is unknown",
+                    ty
+                );
+            }
+            _ => {
+                error_unsupported!("bitwise operations on '{:?}' are not supported", ty);
+            }
+        })
+    }
+
     /// Returns `true` is an overflow happened
     pub fn encode_bin_op_check(
         &self,
@@ -547,7 +718,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
             op,
             mir::BinOp::Add | mir::BinOp::Sub | mir::BinOp::Mul | mir::BinOp::Shl | mir::BinOp::Shr
         ) || !config::check_overflows()
+            || (ty.is_floating_point() && config::encode_ieee_floats())
         {
+            // With IEEE-754 semantics, float operations do not overflow but
+            // produce infinities instead.
             Ok(false.into())
         } else {
             let result = self.encode_bin_op_expr(op, left, right.clone(), ty)?;
@@ -707,7 +881,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
             | (ty::TyKind::Uint(ty::UintTy::U64), ty::TyKind::Uint(ty::UintTy::U128))
             | (ty::TyKind::Uint(ty::UintTy::U128), ty::TyKind::Uint(ty::UintTy::U128))
             | (ty::TyKind::Uint(ty::UintTy::Usize), ty::TyKind::Uint(ty::UintTy::Usize)) => {
-                self.encode_operand_expr(operand).with_span(span)?
+                self.encode_operand_expr(operand, span).with_span(span)?
             }
 
             // Numeric casts where the source value might not fit into the target type
@@ -719,7 +893,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
             | (ty::TyKind::Uint(_), ty::TyKind::Char)
             | (ty::TyKind::Uint(_), ty::TyKind::Int(_))
             | (ty::TyKind::Uint(_), ty::TyKind::Uint(_)) => {
-                let encoded_operand = self.encode_operand_expr(operand).with_span(span)?;
+                let encoded_operand = self.encode_operand_expr(operand, span).with_span(span)?;
                 if config::check_overflows() {
                     // Check the cast
                     let function_name = self
@@ -747,6 +921,66 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
                 }
             }
 
+            (ty::TyKind::Int(_) | ty::TyKind::Uint(_), ty::TyKind::Float(float_ty))
+                if config::encode_ieee_floats() =>
+            {
+                let encoded_operand = self.encode_operand_expr(operand, span).with_span(span)?;
+                let bitvector = self.encode_bitvector_type(src_ty).with_span(span)?;
+                vir::Expr::cast(
+                    vir::CastKind::IntIntoFloat(bitvector, encode_float(*float_ty)),
+                    encoded_operand,
+                )
+            }
+
+            (ty::TyKind::Float(src_float_ty), ty::TyKind::Float(dst_float_ty))
+                if config::encode_ieee_floats() =>
+            {
+                let encoded_operand = self.encode_operand_expr(operand, span).with_span(span)?;
+                if src_float_ty == dst_float_ty {
+                    encoded_operand
+                } else {
+                    vir::Expr::cast(
+                        vir::CastKind::FloatIntoFloat(encode_float(*dst_float_ty)),
+                        encoded_operand,
+                    )
+                }
+            }
+
+            (ty::TyKind::Float(float_ty), ty::TyKind::Int(_) | ty::TyKind::Uint(_))
+                if config::encode_ieee_floats() =>
+            {
+                let encoded_operand = self.encode_operand_expr(operand, span).with_span(span)?;
+                let bitvector = self.encode_bitvector_type(dst_ty).with_span(span)?;
+                let (min, max) = int_bounds(dst_ty).unwrap();
+                let (float_min, float_max) = int_bounds_as_float(dst_ty, *float_ty).unwrap();
+                // Casts from floats to integers saturate, and NaN is cast to zero.
+                vir::Expr::ite(
+                    vir::Expr::is_nan(encoded_operand.clone()),
+                    0.into(),
+                    vir::Expr::ite(
+                        vir::Expr::ge_cmp(encoded_operand.clone(), float_max),
+                        max,
+                        vir::Expr::ite(
+                            vir::Expr::le_cmp(encoded_operand.clone(), float_min),
+                            min,
+                            vir::Expr::cast(
+                                vir::CastKind::FloatIntoInt(bitvector),
+                                encoded_operand,
+                            ),
+                        ),
+                    ),
+                )
+            }
+
+            (ty::TyKind::Float(_), _) | (_, ty::TyKind::Float(_)) => {
+                let mut error = SpannedEncodingError::unsupported(
+                    format!("unsupported cast from type '{src_ty:?}' to type '{dst_ty:?}'"),
+                    span,
+                );
+                error.set_help("casts involving floats require the `encode_ieee_floats` option");
+                return Err(error);
+            }
+
             _ => {
                 return Err(SpannedEncodingError::unsupported(
                     format!("unsupported cast from type '{src_ty:?}' to type '{dst_ty:?}'"),
@@ -758,6 +992,61 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         Ok(encoded_val)
     }
 
+    /// Encodes a call of `is_nan`, `is_infinite` or `is_finite` on the float
+    /// `argument`. Returns `None` if `method` is not one of these methods.
+    pub fn encode_float_classification(
+        &self,
+        method: &str,
+        argument: vir::Expr,
+    ) -> Option<vir::Expr> {
+        Some(match method {
+            "is_nan" => vir::Expr::is_nan(argument),
+            "is_infinite" => vir::Expr::is_infinite(argument),
+            "is_finite" => vir::Expr::not(vir::Expr::or(
+                vir::Expr::is_nan(argument.clone()),
+                vir::Expr::is_infinite(argument),
+            )),
+            _ => return None,
+        })
+    }
+
+    /// Encodes `string.starts_with(prefix)`, where both arguments are
+    /// snapshots of strings of type `str_ty`.
+    pub fn encode_str_starts_with(
+        &self,
+        str_ty: ty::Ty<'tcx>,
+        string: vir::Expr,
+        prefix: vir::Expr,
+    ) -> EncodingResult<vir::Expr> {
+        let string_len = self
+            .encoder
+            .encode_snapshot_slice_len(str_ty, string.clone())?;
+        let prefix_len = self
+            .encoder
+            .encode_snapshot_slice_len(str_ty, prefix.clone())?;
+        let i = vir_local! { i: Int };
+        let i_var: vir::Expr = i.clone().into();
+        let string_byte = self
+            .encoder
+            .encode_snapshot_slice_idx(str_ty, string, i_var.clone())?;
+        let prefix_byte = self
+            .encoder
+            .encode_snapshot_slice_idx(str_ty, prefix, i_var.clone())?;
+        // prefix_len <= string_len && forall i: Int :: { prefix[i] }
+        //     0 <= i && i < prefix_len ==> string[i] == prefix[i]
+        Ok(vir_expr! {
+            [vir_expr!{ [prefix_len] <= [string_len] }] &&
+            [vir::Expr::forall(
+                vec![i],
+                vec![vir::Trigger::new(vec![prefix_byte.clone()])],
+                vir_expr!{
+                    (([vir::Expr::from(0usize)] <= [i_var]) && ([i_var] < [prefix_len]))
+                    ==> ([string_byte] == [prefix_byte])
+                }
+            )]
+        })
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub fn encode_operand_place(
         &self,
@@ -797,6 +1086,55 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         downcast_detector::detect_downcasts(self.mir, location)
     }
 
+    /// Returns the union places whose fields are read at `location`, together
+    /// with the indices of the read fields.
+    pub fn get_union_field_accesses_at_location(
+        &self,
+        location: mir::Location,
+    ) -> Vec<(mir::Place<'tcx>, abi::FieldIdx)> {
+        let tcx = self.encoder.env().tcx();
+        union_field_detector::detect_union_field_accesses(self.mir, tcx, location)
+    }
+
+    /// If `place` is a field of a union, returns the union and the index of
+    /// the field.
+    pub fn try_pop_union_field(
+        &self,
+        place: mir::Place<'tcx>,
+    ) -> Option<(mir::Place<'tcx>, abi::FieldIdx)> {
+        let tcx = self.encoder.env().tcx();
+        match prusti_interface::utils::try_pop_one_level(tcx, place)? {
+            (mir::ProjectionElem::Field(field, _), base)
+                if base.ty(self.mir, tcx).ty.is_union() =>
+            {
+                Some((base, field))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the place whose address is stored in `operand` by a statement
+    /// of the block `bb`.
+    pub fn get_address_of_source(
+        &self,
+        bb: mir::BasicBlock,
+        operand: &mir::Operand<'tcx>,
+    ) -> Option<mir::Place<'tcx>> {
+        let pointer = operand.place()?;
+        self.mir[bb]
+            .statements
+            .iter()
+            .rev()
+            .find_map(|statement| match &statement.kind {
+                mir::StatementKind::Assign(box (lhs, mir::Rvalue::AddressOf(_, place)))
+                    if *lhs == pointer =>
+                {
+                    Some(*place)
+                }
+                _ => None,
+            })
+    }
+
     pub fn get_span_of_basic_block(&self, bbi: mir::BasicBlock) -> Span {
         let bb_data = &self.mir.basic_blocks[bbi];
         bb_data.terminator().source_info.span
@@ -854,3 +1192,54 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         }
     }
 }
+
+fn encode_float(float_ty: ty::FloatTy) -> vir::Float {
+    match float_ty {
+        ty::FloatTy::F32 => vir::Float::F32,
+        ty::FloatTy::F64 => vir::Float::F64,
+    }
+}
+
+/// The bounds of an integer type.
+fn int_bounds(ty: ty::Ty) -> Option<(vir::Expr, vir::Expr)> {
+    Some(match ty.kind() {
+        ty::TyKind::Int(ty::IntTy::I8) => (i8::MIN.into(), i8::MAX.into()),
+        ty::TyKind::Int(ty::IntTy::I16) => (i16::MIN.into(), i16::MAX.into()),
+        ty::TyKind::Int(ty::IntTy::I32) => (i32::MIN.into(), i32::MAX.into()),
+        ty::TyKind::Int(ty::IntTy::I64) => (i64::MIN.into(), i64::MAX.into()),
+        ty::TyKind::Int(ty::IntTy::I128) => (i128::MIN.into(), i128::MAX.into()),
+        ty::TyKind::Uint(ty::UintTy::U8) => (0.into(), u8::MAX.into()),
+        ty::TyKind::Uint(ty::UintTy::U16) => (0.into(), u16::MAX.into()),
+        ty::TyKind::Uint(ty::UintTy::U32) => (0.into(), u32::MAX.into()),
+        ty::TyKind::Uint(ty::UintTy::U64) => (0.into(), u64::MAX.into()),
+        ty::TyKind::Uint(ty::UintTy::U128) => (0.into(), u128::MAX.into()),
+        _ => return None,
+    })
+}
+
+/// The bounds of an integer type, rounded to the nearest value of a float
+/// type. A float that is at least (at most) the rounded upper (lower) bound
+/// saturates to the upper (lower) bound when cast to the integer type.
+fn int_bounds_as_float(ty: ty::Ty, float_ty: ty::FloatTy) -> Option<(vir::Expr, vir::Expr)> {
+    macro_rules! bounds {
+        ($int:ty) => {
+            match float_ty {
+                ty::FloatTy::F32 => ((<$int>::MIN as f32).into(), (<$int>::MAX as f32).into()),
+                ty::FloatTy::F64 => ((<$int>::MIN as f64).into(), (<$int>::MAX as f64).into()),
+            }
+        };
+    }
+    Some(match ty.kind() {
+        ty::TyKind::Int(ty::IntTy::I8) => bounds!(i8),
+        ty::TyKind::Int(ty::IntTy::I16) => bounds!(i16),
+        ty::TyKind::Int(ty::IntTy::I32) => bounds!(i32),
+        ty::TyKind::Int(ty::IntTy::I64) => bounds!(i64),
+        ty::TyKind::Int(ty::IntTy::I128) => bounds!(i128),
+        ty::TyKind::Uint(ty::UintTy::U8) => bounds!(u8),
+        ty::TyKind::Uint(ty::UintTy::U16) => bounds!(u16),
+        ty::TyKind::Uint(ty::UintTy::U32) => bounds!(u32),
+        ty::TyKind::Uint(ty::UintTy::U64) => bounds!(u64),
+        ty::TyKind::Uint(ty::UintTy::U128) => bounds!(u128),
+        _ => return None,
+    })
+}
@@ -0,0 +1,64 @@
+use prusti_rustc_interface::{
+    middle::{
+        mir,
+        mir::visit::{MutatingUseContext, PlaceContext, Visitor},
+        ty::TyCtxt,
+    },
+    target::abi::FieldIdx,
+};
+
+/// Collect the union fields that are read at `location`, as pairs of a union
+/// place and the index of the accessed field. Assigning to a union field
+/// changes the active field instead of reading it, so it is not reported.
+pub fn detect_union_field_accesses<'tcx>(
+    body: &mir::Body<'tcx>,
+    tcx: TyCtxt<'tcx>,
+    location: mir::Location,
+) -> Vec<(mir::Place<'tcx>, FieldIdx)> {
+    let mut collector = UnionFieldCollector {
+        body,
+        tcx,
+        accesses: Vec::new(),
+    };
+    collector.visit_location(body, location);
+    collector.accesses
+}
+
+struct UnionFieldCollector<'a, 'tcx> {
+    body: &'a mir::Body<'tcx>,
+    tcx: TyCtxt<'tcx>,
+    /// The accesses, ordered starting from the outermost union.
+    accesses: Vec<(mir::Place<'tcx>, FieldIdx)>,
+}
+
+impl<'tcx> Visitor<'tcx> for UnionFieldCollector<'_, 'tcx> {
+    fn visit_place(
+        &mut self,
+        place: &mir::Place<'tcx>,
+        context: PlaceContext,
+        location: mir::Location,
+    ) {
+        self.super_place(place, context, location);
+
+        let is_store = matches!(
+            context,
+            PlaceContext::MutatingUse(MutatingUseContext::Store | MutatingUseContext::Call)
+        );
+        for (index, (place_ref, elem)) in place.iter_projections().enumerate() {
+            if is_store && index + 1 == place.projection.len() {
+                break;
+            }
+            if let mir::ProjectionElem::Field(field, _) = elem {
+                if place_ref.ty(self.body, self.tcx).ty.is_union() {
+                    let union_place = mir::Place {
+                        local: place_ref.local,
+                        projection: self.tcx.mk_place_elems(place_ref.projection),
+                    };
+                    if !self.accesses.contains(&(union_place, field)) {
+                        self.accesses.push((union_place, field));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -9,28 +9,32 @@ use super::{
 };
 use crate::{
     encoder::{
-        builtin_encoder::BuiltinMethodKind,
+        builtin_encoder::{BuiltinFunctionKind, BuiltinMethodKind},
         errors::{
             error_manager::PanicCause, EncodingError, EncodingErrorKind, EncodingResult, ErrorCtxt,
             SpannedEncodingError, SpannedEncodingResult, WithSpan,
         },
         foldunfold,
-        high::types::HighTypeEncoderInterface,
+        high::{
+            builtin_functions::HighBuiltinFunctionEncoderInterface, types::HighTypeEncoderInterface,
+        },
         initialisation::InitInfo,
         loop_encoder::{LoopEncoder, LoopEncoderError},
         mir::{
             contracts::{ContractsEncoderInterface, ProcedureContract},
-            procedures::encoder::specification_blocks::SpecificationBlocks,
+            procedures::encoder::{
+                specification_blocks::SpecificationBlocks, termination::compute_recursion_component,
+            },
             pure::{PureFunctionEncoderInterface, SpecificationEncoderInterface},
             sequences::MirSequencesEncoderInterface,
             spans::interface::SpanInterface,
             specifications::SpecificationsInterface,
             type_invariants::TypeInvariantEncoderInterface,
-            types::MirTypeEncoderInterface,
+            types::{string_bytes_ty, transparent_ty, MirTypeEncoderInterface},
         },
         mir_encoder::{
             ExprOrArrayBase, FakeMirEncoder, MirEncoder, PlaceEncoder, PlaceEncoding,
-            PRECONDITION_LABEL,
+            LOOP_ITERATOR_PLACEHOLDER, PRECONDITION_LABEL,
         },
         mir_successor::MirSuccessor,
         places::{Local, LocalVariableManager, Place},
@@ -81,9 +85,12 @@ use std::{
     convert::TryInto,
     fmt::{Debug, Write},
 };
-use vir_crate::polymorphic::{
-    self as vir, borrows::Borrow, collect_assigned_vars, compute_identifier, CfgBlockIndex,
-    ExprIterator, Float, Successor, Type,
+use vir_crate::{
+    common::expression::lexicographically_less,
+    polymorphic::{
+        self as vir, borrows::Borrow, collect_assigned_vars, compute_identifier, CfgBlockIndex,
+        ExprIterator, Float, Successor, Type,
+    },
 };
 
 pub struct ProcedureEncoder<'p, 'v: 'p, 'tcx: 'v> {
@@ -118,6 +125,9 @@ pub struct ProcedureEncoder<'p, 'v: 'p, 'tcx: 'v> {
     array_loop_old_label: FxHashMap<BasicBlockIndex, String>,
     /// Slices created at certain locations
     slice_created_at: FxHashMap<mir::Location, vir::Expr>,
+    /// The reference and the borrowed place of the two-phase borrows reserved
+    /// at certain locations
+    two_phase_borrow_at: FxHashMap<mir::Location, (vir::Expr, vir::Expr)>,
     // /// Contracts of functions called at given locations with map for replacing fake expressions.
     procedure_contracts:
         FxHashMap<mir::Location, (ProcedureContract<'tcx>, FxHashMap<vir::Expr, vir::Expr>)>,
@@ -136,6 +146,12 @@ pub struct ProcedureEncoder<'p, 'v: 'p, 'tcx: 'v> {
     /// Type substitutions inside this procedure. Most likely identity for the
     /// given proc_def_id.
     substs: GenericArgsRef<'tcx>,
+    /// Ghost variables that store the components of the termination measure
+    /// of a terminating procedure in its pre-state.
+    termination_variables: Option<Vec<vir::LocalVar>>,
+    /// The terminating functions whose calls from this procedure are
+    /// (mutually) recursive. Computed the first time it is needed.
+    recursion_component: Option<FxHashSet<ProcedureDefId>>,
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
@@ -189,6 +205,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             array_magic_wand_at: FxHashMap::default(),
             array_loop_old_label: FxHashMap::default(),
             slice_created_at: FxHashMap::default(),
+            two_phase_borrow_at: FxHashMap::default(),
             procedure_contracts: FxHashMap::default(),
             pure_var_for_preserving_value_map: FxHashMap::default(),
             init_info,
@@ -196,6 +213,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             old_ghost_vars: FxHashMap::default(),
             cached_loop_invariant_block: FxHashMap::default(),
             substs,
+            termination_variables: None,
+            recursion_component: None,
         })
     }
 
@@ -227,10 +246,58 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         let block = &self.mir[bb];
         let _ = self.try_encode_assert(bb, block, encoded_statements)?
             || self.try_encode_assume(bb, block, encoded_statements)?
-            || self.try_encode_refute(bb, block, encoded_statements)?;
+            || self.try_encode_refute(bb, block, encoded_statements)?
+            || self.try_encode_set_union_active_field(bb, block, encoded_statements)?;
         Ok(())
     }
 
+    fn try_encode_set_union_active_field(
+        &mut self,
+        bb: mir::BasicBlock,
+        block: &mir::BasicBlockData<'tcx>,
+        encoded_statements: &mut Vec<vir::Stmt>,
+    ) -> SpannedEncodingResult<bool> {
+        let mir::TerminatorKind::Call {
+            func: mir::Operand::Constant(box mir::Constant { literal, .. }),
+            args,
+            ..
+        } = &block.terminator().kind
+        else {
+            return Ok(false);
+        };
+        let ty::TyKind::FnDef(def_id, _) = literal.ty().kind() else {
+            return Ok(false);
+        };
+        if self.encoder.env().name.get_absolute_item_name(*def_id)
+            != "prusti_contracts::prusti_set_union_active_field"
+        {
+            return Ok(false);
+        }
+        let span = block.terminator().source_info.span;
+        // args[0]: the address of the field that becomes active
+        let Some((union_place, field)) = self
+            .mir_encoder
+            .get_address_of_source(bb, &args[0])
+            .and_then(|place| self.mir_encoder.try_pop_union_field(place))
+        else {
+            return Err(SpannedEncodingError::incorrect(
+                "the argument of `prusti_set_union_active_field` must be the address of a union field",
+                span,
+            ));
+        };
+        let (encoded_union, union_ty, _) =
+            self.mir_encoder.encode_place(union_place).with_span(span)?;
+        let encoded_union = encoded_union.try_into_expr().with_span(span)?;
+        encoded_statements.extend(self.encode_set_union_active_field(
+            encoded_union,
+            union_ty.ty_adt_def().unwrap(),
+            field,
+            true,
+            span,
+        )?);
+        Ok(true)
+    }
+
     fn try_encode_assume(
         &mut self,
         bb: mir::BasicBlock,
@@ -354,11 +421,20 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 loop_head,
                 variable,
             } => {
-                let msg = if self.mir.local_decls[variable].is_user_variable() {
-                    "creation of loan 'FIXME: extract variable name' in loop is unsupported"
-                        .to_string()
-                } else {
-                    "creation of temporary loan in loop is unsupported".to_string()
+                let variable_name = self
+                    .mir
+                    .var_debug_info
+                    .iter()
+                    .find(|info| {
+                        matches!(info.value, mir::VarDebugInfoContents::Place(place)
+                            if place.as_local() == Some(variable))
+                    })
+                    .map(|info| info.name);
+                let msg = match variable_name {
+                    Some(name) if self.mir.local_decls[variable].is_user_variable() => {
+                        format!("creation of loan '{name}' in loop is unsupported")
+                    }
+                    _ => "creation of temporary loan in loop is unsupported".to_string(),
                 };
                 SpannedEncodingError::unsupported(
                     msg,
@@ -368,7 +444,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
             PoloniusInfoError::LoansInNestedLoops(location1, _loop1, _location2, _loop2) => {
                 SpannedEncodingError::unsupported(
-                    "creation of loans in nested loops is not supported".to_string(),
+                    "creation of loans that are kept alive across the iterations of \
+                    different loops is not supported"
+                        .to_string(),
                     self.mir.source_info(location1).span,
                 )
             }
@@ -427,6 +505,18 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         assert_one_magic_wand(procedure_contract.borrow_infos.len()).with_span(mir_span)?;
         self.procedure_contract = Some(procedure_contract);
 
+        if config::check_pure_function_termination()
+            && self.encoder.is_pure(self.proc_def_id, None)
+            && !self.encoder.terminates(self.proc_def_id, None)
+        {
+            let mut err = SpannedEncodingError::incorrect(
+                "Pure functions need to terminate",
+                self.encoder.get_mir_body_span(self.mir),
+            );
+            err.set_help("Consider adding the `#[terminates]` attribute.");
+            return Err(err);
+        }
+
         // Declare the formal return
         for local in self.mir.local_decls.indices().take(1) {
             let name = self.mir_encoder.encode_local_var_name(local);
@@ -505,6 +595,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
         self.encode_specification_blocks()?;
 
+        // Store the termination measure, which calls and loops compare against
+        let termination_initialization = self.encode_termination_initialization()?;
+
         // Encode all blocks
         let (opt_body_head, unresolved_edges) = self.encode_blocks_group(
             "",
@@ -531,6 +624,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
         // Encode preconditions
         self.encode_preconditions(start_cfg_block, precondition_weakening)?;
+        self.cfg_method
+            .add_stmts(start_cfg_block, termination_initialization);
 
         // Encode postcondition
         self.encode_postconditions(return_cfg_block, postcondition_strengthening)?;
@@ -954,6 +1049,15 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         let loop_body_before_inv = &loop_body[after_guard_block_pos..after_inv_block_pos];
         let loop_body_after_inv = &loop_body[after_inv_block_pos..];
 
+        // In a terminating procedure, every loop needs a variant that decreases
+        // with each iteration.
+        let check_termination = self.encoder.terminates(self.proc_def_id, None);
+        let loop_variant = if check_termination {
+            self.encode_loop_variant(loop_head)?
+        } else {
+            None
+        };
+
         // The main path in the encoding is: start -> G -> B1 -> invariant -> B2 -> G -> B1 -> end
         // We are going to build the encoding left to right.
         let mut heads = vec![];
@@ -1019,6 +1123,22 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         heads.push(Some(inv_pre_block));
         self.cfg_method
             .set_successor(inv_pre_block, vir::Successor::Goto(inv_post_block_perms));
+        if check_termination {
+            let stmt = if let Some((variant, variant_span)) = &loop_variant {
+                let zeros = vec![vir::Expr::from(0usize); variant.len()];
+                vir::Stmt::Assert(vir::Assert {
+                    expr: lexicographically_less(&zeros, variant),
+                    position: self.register_error(*variant_span, ErrorCtxt::LoopVariantOnEntry),
+                })
+            } else {
+                vir::Stmt::Assert(vir::Assert {
+                    expr: false.into(),
+                    position: self
+                        .register_error(loop_head_span, ErrorCtxt::UnexpectedReachableLoop),
+                })
+            };
+            self.cfg_method.add_stmt(inv_pre_block, stmt);
+        }
         {
             let stmts =
                 self.encode_loop_invariant_exhale_stmts(loop_head, before_invariant_block, false)?;
@@ -1034,6 +1154,23 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             self.cfg_method.add_stmts(inv_post_block_fnspc, stmts);
             fnspec_span
         };
+        // Store the value of the loop variant at the beginning of the iteration
+        let mut variant_variables = vec![];
+        if let Some((variant, _)) = &loop_variant {
+            let mut stmts = vec![vir::Stmt::comment(format!(
+                "Store the loop variant of block {loop_head:?}"
+            ))];
+            for component in variant {
+                let variant_var = self.cfg_method.add_fresh_local_var(vir::Type::Int);
+                stmts.push(vir::Stmt::Assign(vir::Assign {
+                    target: variant_var.clone().into(),
+                    source: component.clone(),
+                    kind: vir::AssignKind::Ghost,
+                }));
+                variant_variables.push(vir::Expr::local(variant_var));
+            }
+            self.cfg_method.add_stmts(inv_post_block_fnspc, stmts);
+        }
         {
             let stmts = self
                 .encode_loop_invariant_inhale_perm_stmts(loop_head, before_invariant_block, false)
@@ -1152,6 +1289,20 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 "========== {loop_label_prefix}_end_body =========="
             ))],
         );
+        if let Some((variant, variant_span)) = loop_variant {
+            let mut stmts = vec![vir::Stmt::Assert(vir::Assert {
+                expr: lexicographically_less(&variant, &variant_variables),
+                position: self.register_error(variant_span, ErrorCtxt::LoopVariantNonDecreased),
+            })];
+            let position = self.register_error(variant_span, ErrorCtxt::LoopVariantAfterIteration);
+            for component in variant {
+                stmts.push(vir::Stmt::Assert(vir::Assert {
+                    expr: vir::Expr::ge_cmp(component, vir::Expr::from(0usize)),
+                    position,
+                }));
+            }
+            self.cfg_method.add_stmts(end_body_block, stmts);
+        }
         {
             let stmts =
                 self.encode_loop_invariant_exhale_stmts(loop_head, before_invariant_block, true)?;
@@ -1302,6 +1453,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 vir::Type::Domain(_) => BuiltinMethodKind::HavocRef,
                 vir::Type::Snapshot(_) => BuiltinMethodKind::HavocRef,
                 vir::Type::Seq(_) => BuiltinMethodKind::HavocRef,
+                vir::Type::Set(_) => BuiltinMethodKind::HavocRef,
+                vir::Type::MultiSet(_) => BuiltinMethodKind::HavocRef,
                 vir::Type::Map(_) => BuiltinMethodKind::HavocRef,
                 vir::Type::Ref => {
                     return Err(SpannedEncodingError::internal(
@@ -1487,15 +1640,21 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         let span = self.mir_encoder.get_span_of_location(location);
         let bb_data = &self.mir[location.block];
         let index = location.statement_index;
-        let stmts_succ_res = if index < bb_data.statements.len() {
-            let mir_stmt = &bb_data.statements[index];
-            self.encode_statement(mir_stmt, location)
-                .map(|stmts| (stmts, None))
-        } else {
-            let mir_term = bb_data.terminator();
-            self.encode_terminator(mir_term, location)
-                .map(|(stmts, succ)| (stmts, Some(succ)))
-        };
+        let stmts_succ_res =
+            self.encode_two_phase_borrow_activations(location)
+                .and_then(|mut stmts| {
+                    let successor = if index < bb_data.statements.len() {
+                        let mir_stmt = &bb_data.statements[index];
+                        stmts.extend(self.encode_statement(mir_stmt, location)?);
+                        None
+                    } else {
+                        let mir_term = bb_data.terminator();
+                        let (term_stmts, successor) = self.encode_terminator(mir_term, location)?;
+                        stmts.extend(term_stmts);
+                        Some(successor)
+                    };
+                    Ok((stmts, successor))
+                });
 
         // Intercept encoding error caused by an unsupported feature
         let (stmts, successor) = match stmts_succ_res {
@@ -1534,6 +1693,40 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok((self.set_stmts_default_pos(stmts, span), successor))
     }
 
+    /// Encode the activation of the two-phase borrows that are activated at
+    /// `location`. The borrows are created where they are reserved, but until
+    /// their activation the borrowed places can still be read, so only here
+    /// the permissions are transferred to the references.
+    fn encode_two_phase_borrow_activations(
+        &self,
+        location: mir::Location,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let mut stmts = vec![];
+        for loan in self
+            .polonius_info()
+            .get_two_phase_borrows_activated_at(location)
+        {
+            let reservation = self.polonius_info().get_loan_location(&loan);
+            let Some((reference, borrowed_place)) =
+                self.two_phase_borrow_at.get(&reservation).cloned()
+            else {
+                return Err(SpannedEncodingError::internal(
+                    format!("the two-phase borrow reserved at {reservation:?} was not encoded"),
+                    self.mir_encoder.get_span_of_location(location),
+                ));
+            };
+            stmts.push(vir::Stmt::comment(format!(
+                "[mir] activation of the two-phase borrow reserved at {reservation:?}"
+            )));
+            stmts.push(vir::Stmt::TransferPerm(vir::TransferPerm {
+                left: borrowed_place,
+                right: reference,
+                unchecked: false,
+            }));
+        }
+        Ok(stmts)
+    }
+
     /// Note: it's better to call `encode_statement_at` instead of this method.
     #[tracing::instrument(level = "debug", skip(self), fields(statement = ?stmt.kind, span = ?stmt.source_info.span))]
     fn encode_statement(
@@ -1553,7 +1746,34 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             | mir::StatementKind::PlaceMention(..)
             | mir::StatementKind::Nop => vec![],
 
+            mir::StatementKind::Assign(box (lhs, ref rhs))
+                if self.mir_encoder.is_raw_pointer_deref(lhs) =>
+            {
+                stmts.extend(self.encode_union_field_checks(location)?);
+                self.encode_raw_pointer_write(lhs, rhs, location)?
+            }
+
             mir::StatementKind::Assign(box (lhs, ref rhs)) => {
+                stmts.extend(self.encode_union_field_checks(location)?);
+                if let Some((union_place, field)) = self.mir_encoder.try_pop_union_field(lhs) {
+                    // Assigning to a field of a union makes it the active field.
+                    let (encoded_union, pre_stmts, union_ty, _) = self.encode_place(
+                        union_place,
+                        ArrayAccessKind::Mutable(None, location),
+                        location,
+                    )?;
+                    stmts.extend(pre_stmts);
+                    let is_initialized = self
+                        .init_info
+                        .is_vir_place_accessible(&encoded_union, location);
+                    stmts.extend(self.encode_set_union_active_field(
+                        encoded_union,
+                        union_ty.ty_adt_def().unwrap(),
+                        field,
+                        is_initialized,
+                        span,
+                    )?);
+                }
                 // Array access on the LHS should always be mutable (idx is always calculated
                 // before, and just a separate local variable here)
                 let (lhs_place_encoding, ty, _) =
@@ -1647,7 +1867,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             }
             mir::Rvalue::Cast(mir::CastKind::PointerExposeAddress, ref operand, dst_ty)
             | mir::Rvalue::Cast(mir::CastKind::PointerFromExposedAddress, ref operand, dst_ty)
-            | mir::Rvalue::Cast(mir::CastKind::IntToInt, ref operand, dst_ty) => {
+            | mir::Rvalue::Cast(mir::CastKind::IntToInt, ref operand, dst_ty)
+            | mir::Rvalue::Cast(mir::CastKind::FloatToInt, ref operand, dst_ty)
+            | mir::Rvalue::Cast(mir::CastKind::FloatToFloat, ref operand, dst_ty)
+            | mir::Rvalue::Cast(mir::CastKind::IntToFloat, ref operand, dst_ty) => {
                 self.encode_cast(operand, dst_ty, encoded_lhs, ty, location)?
             }
             mir::Rvalue::Len(place) => {
@@ -1670,6 +1893,11 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 if rhs_ty.is_array_ref() && cast_ty.is_slice_ref() {
                     trace!("slice: operand={:?}, ty={:?}", operand, cast_ty);
                     self.encode_assign_slice(encoded_lhs, operand, cast_ty, location)?
+                } else if cast_ty
+                    .builtin_deref(true)
+                    .map_or(false, |pointee| pointee.ty.is_trait())
+                {
+                    self.encode_assign_trait_object(encoded_lhs, operand, cast_ty, location)?
                 } else {
                     return Err(SpannedEncodingError::unsupported(
                         format!("unsizing a {rhs_ty} into a {cast_ty} is not supported"),
@@ -1677,24 +1905,39 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     ));
                 }
             }
-            mir::Rvalue::Cast(mir::CastKind::PointerCoercion(_), _, _)
-            | mir::Rvalue::Cast(mir::CastKind::DynStar, _, _) => {
+            mir::Rvalue::Cast(
+                mir::CastKind::PointerCoercion(ty::adjustment::PointerCoercion::MutToConstPointer),
+                ref operand,
+                _,
+            )
+            | mir::Rvalue::Cast(mir::CastKind::PtrToPtr, ref operand, _) => {
+                let address = self
+                    .mir_encoder
+                    .encode_operand_expr(operand, span)
+                    .with_span(span)?;
+                self.encode_assign_address(encoded_lhs, address, ty, location)?
+            }
+            mir::Rvalue::Cast(mir::CastKind::PointerCoercion(coercion), _, _) => {
                 return Err(SpannedEncodingError::unsupported(
-                    "raw pointers are not supported",
+                    format!("pointer coercions {coercion:?} are not supported"),
                     span,
                 ));
             }
-            mir::Rvalue::Cast(cast_kind, _, _) => {
+            mir::Rvalue::Cast(mir::CastKind::DynStar, _, _) => {
                 return Err(SpannedEncodingError::unsupported(
-                    format!("casts {cast_kind:?} are not supported"),
+                    "dyn* casts are not supported",
                     span,
                 ));
             }
-            mir::Rvalue::AddressOf(_, _) => {
+            mir::Rvalue::Cast(cast_kind, _, _) => {
                 return Err(SpannedEncodingError::unsupported(
-                    "raw addresses of expressions and casts from references to raw pointers are not supported", span
+                    format!("casts {cast_kind:?} are not supported"),
+                    span,
                 ));
             }
+            mir::Rvalue::AddressOf(mutability, place) => {
+                self.encode_assign_address_of(encoded_lhs, mutability, place, ty, location)?
+            }
             mir::Rvalue::ThreadLocalRef(_) => {
                 return Err(SpannedEncodingError::unsupported(
                     "references to thread-local storage are not supported",
@@ -1710,18 +1953,6 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         })
     }
 
-    /// Generate an unsupported encoding error for unhandled borrow kinds.
-    fn unsupported_borrow_kind(kind: mir::BorrowKind) -> EncodingError {
-        match kind {
-            mir::BorrowKind::Shallow => {
-                EncodingError::unsupported(
-                    "unsupported creation of shallow borrows (implicitly created when lowering matches)",
-                )
-            }
-            _ => unreachable!(),
-        }
-    }
-
     /// Encode the lhs and the rhs of the assignment that create the loan
     #[tracing::instrument(level = "debug", skip(self))]
     fn encode_loan_places(
@@ -1824,11 +2055,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         };
         Ok(match loan_places.source {
             mir::Rvalue::Ref(_, mir_borrow_kind, rhs_place) => {
-                let is_mut = match mir_borrow_kind {
-                    mir::BorrowKind::Shared => false,
-                    mir::BorrowKind::Mut { .. } => true,
-                    _ => return Err(Self::unsupported_borrow_kind(mir_borrow_kind).with_span(span)),
-                };
+                let is_mut = matches!(mir_borrow_kind, mir::BorrowKind::Mut { .. });
                 let array_encode_kind = if is_mut {
                     ArrayAccessKind::Mutable(None, location)
                 } else {
@@ -1872,8 +2099,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             }
 
             mir::Rvalue::Use(mir::Operand::Constant(ref expr)) => {
-                // TODO: Encoding of string literals is not yet supported, so
-                // do not return an expression in restored here.
+                // String literals are not stored in a place, so there is
+                // nothing to restore.
                 let restored: Option<vir::Expr> = if is_str(expr.ty()) {
                     None
                 } else {
@@ -1950,7 +2177,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         if let Some(stmt) = self.polonius_info().get_assignment_for_loan(loan)? {
             Ok(match stmt.kind {
                 mir::StatementKind::Assign(box (_, ref rhs)) => match rhs {
-                    &mir::Rvalue::Ref(_, mir::BorrowKind::Shared, _)
+                    &mir::Rvalue::Ref(_, mir::BorrowKind::Shared | mir::BorrowKind::Shallow, _)
                     | &mir::Rvalue::Use(mir::Operand::Copy(_)) => false,
                     &mir::Rvalue::Ref(_, mir::BorrowKind::Mut { .. }, _)
                     | &mir::Rvalue::Use(mir::Operand::Move(_)) => true,
@@ -1990,15 +2217,24 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         let mut builder = vir::borrows::DAGBuilder::new();
         for node in mir_dag.iter() {
             let node = match node.kind {
-                ReborrowingKind::Assignment { loan } => self
-                    .construct_vir_reborrowing_node_for_assignment(
-                        &mir_dag,
-                        loan,
-                        node,
-                        location,
-                        end_location,
-                        is_in_package_stmt,
-                    )?,
+                ReborrowingKind::Assignment { loan } => {
+                    // Borrowing the rest of a slice in a slice pattern creates
+                    // a slice like a call to `index` does.
+                    if let Some(slice_expiry_node) =
+                        self.construct_vir_reborrowing_node_for_slice(loan, node, location)?
+                    {
+                        slice_expiry_node
+                    } else {
+                        self.construct_vir_reborrowing_node_for_assignment(
+                            &mir_dag,
+                            loan,
+                            node,
+                            location,
+                            end_location,
+                            is_in_package_stmt,
+                        )?
+                    }
+                }
                 ReborrowingKind::Call { loan, .. } => {
                     if let Some(slice_expiry_node) =
                         self.construct_vir_reborrowing_node_for_slice(loan, node, location)?
@@ -2380,6 +2616,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
     ) -> SpannedEncodingResult<(Vec<vir::Stmt>, MirSuccessor)> {
         let mut stmts: Vec<vir::Stmt> = vec![vir::Stmt::comment(format!("[mir] {:?}", term.kind))];
         let span = self.mir_encoder.get_span_of_location(location);
+        stmts.extend(self.encode_union_field_checks(location)?);
 
         let result = match term.kind {
             TerminatorKind::Return => {
@@ -2457,7 +2694,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 };
                 let encoded_discr = self
                     .mir_encoder
-                    .encode_operand_expr(discr)
+                    .encode_operand_expr(discr, span)
                     .with_span(span)?;
                 stmts.push(vir::Stmt::Assign(vir::Assign {
                     target: discr_var.clone().into(),
@@ -2661,6 +2898,47 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             )?);
                         }
 
+                        "std::ops::RangeInclusive::<Idx>::new"
+                        | "core::ops::RangeInclusive::<Idx>::new" => {
+                            // The fields of `RangeInclusive` are private, so the range is
+                            // built with a call instead of an aggregate.
+                            // args[0]: start
+                            // args[1]: end
+                            assert_eq!(args.len(), 2);
+
+                            let (dst, pre_stmts, _, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            stmts.extend(
+                                self.encode_havoc_and_initialization(&dst).with_span(span)?,
+                            );
+
+                            let idx_ty = call_substs.type_at(0);
+                            for (field_name, operand) in ["start", "end"].iter().zip(args.iter()) {
+                                let encoded_field = self
+                                    .encoder
+                                    .encode_struct_field(field_name, idx_ty)
+                                    .with_span(span)?;
+                                stmts.extend(self.encode_assign_operand(
+                                    &dst.clone().field(encoded_field),
+                                    operand,
+                                    location,
+                                )?);
+                            }
+
+                            let bool_ty = self.encoder.env().tcx().types.bool;
+                            let exhausted_field = self
+                                .encoder
+                                .encode_struct_field("exhausted", bool_ty)
+                                .with_span(span)?;
+                            stmts.extend(self.encode_copy_value_assign(
+                                dst.field(exhausted_field),
+                                false.into(),
+                                bool_ty,
+                                location,
+                            )?);
+                        }
+
                         "std::cmp::PartialEq::eq" | "core::cmp::PartialEq::eq"
                             if args.len() == 2
                                 && self.encoder.has_structural_eq_impl(
@@ -2699,10 +2977,29 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             )?);
                         }
 
-                        "std::ops::Fn::call" | "core::ops::Fn::call" => {
+                        "std::ops::Fn::call"
+                        | "core::ops::Fn::call"
+                        | "std::ops::FnMut::call_mut"
+                        | "core::ops::FnMut::call_mut"
+                        | "std::ops::FnOnce::call_once"
+                        | "core::ops::FnOnce::call_once" => {
+                            let tcx = self.encoder.env().tcx();
                             let cl_type: ty::Ty = call_substs[0].expect_ty();
+                            // Closures returned from functions have an opaque type.
+                            let cl_type = transparent_ty(tcx, cl_type).unwrap_or(cl_type);
                             match cl_type.kind() {
-                                ty::TyKind::Closure(cl_def_id, _) => {
+                                ty::TyKind::Closure(cl_def_id, cl_substs) => {
+                                    let trait_kind =
+                                        tcx.trait_of_item(called_def_id).and_then(|trait_id| {
+                                            tcx.fn_trait_kind_from_def_id(trait_id)
+                                        });
+                                    if trait_kind != Some(cl_substs.as_closure().kind()) {
+                                        return Err(SpannedEncodingError::unsupported(
+                                            "calling a closure through a different `Fn*` trait \
+                                            than the one it implements is not supported",
+                                            term.source_info.span,
+                                        ));
+                                    }
                                     debug!(
                                         "Encoding call to closure {:?} with func {:?}",
                                         cl_def_id, func_const_val
@@ -2719,14 +3016,86 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                                 }
 
                                 _ => {
-                                    return Err(SpannedEncodingError::unsupported(
-                                        format!("only calls to closures are supported. The term is a {:?}, not a closure.", cl_type.kind()),
+                                    // The specification of the call is given by the
+                                    // specification entailments of the closure.
+                                    stmts.extend(self.encode_impure_function_call(
+                                        location,
                                         term.source_info.span,
-                                    ));
+                                        args,
+                                        destination,
+                                        target,
+                                        called_def_id,
+                                        call_substs,
+                                    )?);
                                 }
                             }
                         }
 
+                        "std::ptr::null"
+                        | "core::ptr::null"
+                        | "std::ptr::null_mut"
+                        | "core::ptr::null_mut" => {
+                            let (dst, pre_stmts, dest_ty, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            let null = vir::Expr::Const(vir::ConstExpr {
+                                value: vir::Const::NullPtr,
+                                position: vir::Position::default(),
+                            });
+                            stmts.extend(self.encode_assign_address(dst, null, dest_ty, location)?);
+                        }
+
+                        "core::ptr::const_ptr::<impl *const T>::is_null"
+                        | "core::ptr::mut_ptr::<impl *mut T>::is_null" => {
+                            assert_eq!(args.len(), 1);
+                            let address = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[0], span)
+                                .with_span(span)?;
+                            let null = vir::Expr::Const(vir::ConstExpr {
+                                value: vir::Const::NullPtr,
+                                position: vir::Position::default(),
+                            });
+                            let (dst, pre_stmts, dest_ty, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            stmts.extend(self.encode_copy_value_assign(
+                                dst,
+                                vir::Expr::eq_cmp(address, null),
+                                dest_ty,
+                                location,
+                            )?);
+                        }
+
+                        "core::f32::<impl f32>::is_nan"
+                        | "core::f32::<impl f32>::is_infinite"
+                        | "core::f32::<impl f32>::is_finite"
+                        | "core::f64::<impl f64>::is_nan"
+                        | "core::f64::<impl f64>::is_infinite"
+                        | "core::f64::<impl f64>::is_finite"
+                            if config::encode_ieee_floats() =>
+                        {
+                            assert_eq!(args.len(), 1);
+                            let value = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[0], span)
+                                .with_span(span)?;
+                            let method = full_func_proc_name.rsplit("::").next().unwrap();
+                            let classification = self
+                                .mir_encoder
+                                .encode_float_classification(method, value)
+                                .unwrap();
+                            let (dst, pre_stmts, dest_ty, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            stmts.extend(self.encode_copy_value_assign(
+                                dst,
+                                classification,
+                                dest_ty,
+                                location,
+                            )?);
+                        }
+
                         "core::slice::<impl [T]>::len" => {
                             stmts.extend(self.encode_slice_len_call(
                                 destination,
@@ -2736,6 +3105,109 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             )?);
                         }
 
+                        "core::str::<impl str>::len"
+                        | "std::string::String::len"
+                        | "alloc::string::String::len" => {
+                            assert_eq!(args.len(), 1);
+                            let str_ty = self.mir_encoder.get_operand_ty(&args[0]);
+                            let string = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[0], span)
+                                .with_span(span)?;
+                            let len = self
+                                .encoder
+                                .encode_snapshot_slice_len(str_ty, vir::Expr::snap_app(string))
+                                .with_span(span)?;
+                            stmts.extend(self.encode_snapshot_builtin_call(
+                                location,
+                                args,
+                                destination,
+                                target,
+                                len,
+                            )?);
+                        }
+
+                        "core::str::<impl str>::as_bytes"
+                        | "std::string::String::as_str"
+                        | "alloc::string::String::as_str"
+                        | "std::string::String::as_bytes"
+                        | "alloc::string::String::as_bytes" => {
+                            // Strings are modelled as the sequence of their bytes, so
+                            // these views have the same snapshot as the string.
+                            assert_eq!(args.len(), 1);
+                            let string = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[0], span)
+                                .with_span(span)?;
+                            stmts.extend(self.encode_snapshot_builtin_call(
+                                location,
+                                args,
+                                destination,
+                                target,
+                                vir::Expr::snap_app(string),
+                            )?);
+                        }
+
+                        "std::ops::Deref::deref" | "core::ops::Deref::deref"
+                            if string_bytes_ty(
+                                self.encoder.env().tcx(),
+                                call_substs.type_at(0),
+                            )
+                            .is_some() =>
+                        {
+                            assert_eq!(args.len(), 1);
+                            let string = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[0], span)
+                                .with_span(span)?;
+                            stmts.extend(self.encode_snapshot_builtin_call(
+                                location,
+                                args,
+                                destination,
+                                target,
+                                vir::Expr::snap_app(string),
+                            )?);
+                        }
+
+                        "core::str::<impl str>::starts_with" if is_str(call_substs.type_at(0)) => {
+                            assert_eq!(args.len(), 2);
+                            let str_ty = self.mir_encoder.get_operand_ty(&args[0]);
+                            let string = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[0], span)
+                                .with_span(span)?;
+                            let prefix = self
+                                .mir_encoder
+                                .encode_operand_expr(&args[1], span)
+                                .with_span(span)?;
+                            let starts_with = self
+                                .mir_encoder
+                                .encode_str_starts_with(
+                                    str_ty,
+                                    vir::Expr::snap_app(string),
+                                    vir::Expr::snap_app(prefix),
+                                )
+                                .with_span(span)?;
+                            stmts.extend(self.encode_snapshot_builtin_call(
+                                location,
+                                args,
+                                destination,
+                                target,
+                                starts_with,
+                            )?);
+                        }
+
+                        "std::iter::Iterator::next" | "core::iter::Iterator::next"
+                            if self.is_integer_range(call_substs.type_at(0)) =>
+                        {
+                            stmts.extend(self.encode_range_next_call(
+                                destination,
+                                args,
+                                location,
+                                span,
+                            )?);
+                        }
+
                         "std::iter::Iterator::next" | "core::iter::Iterator::next" => {
                             return Err(SpannedEncodingError::unsupported(
                                 "iterators are not fully supported yet",
@@ -2743,6 +3215,66 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             ));
                         }
 
+                        "std::iter::IntoIterator::into_iter"
+                        | "core::iter::IntoIterator::into_iter"
+                            if self.is_integer_range(call_substs.type_at(0))
+                                && self.is_identity_into_iter(
+                                    called_def_id,
+                                    call_substs,
+                                    &args[0],
+                                    destination,
+                                ) =>
+                        {
+                            // Ranges are turned into themselves in the
+                            // desugaring of `for` loops.
+                            let (dst, pre_stmts, _, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            stmts.extend(self.encode_assign_operand(&dst, &args[0], location)?);
+                        }
+
+                        "std::future::IntoFuture::into_future"
+                        | "core::future::IntoFuture::into_future"
+                            if self.is_identity_into_iter(
+                                called_def_id,
+                                call_substs,
+                                &args[0],
+                                destination,
+                            ) =>
+                        {
+                            // Futures are turned into themselves, e.g. in the
+                            // desugaring of `.await`.
+                            let (dst, pre_stmts, _, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            stmts.extend(self.encode_assign_operand(&dst, &args[0], location)?);
+                        }
+
+                        "std::pin::Pin::<P>::new_unchecked"
+                        | "core::pin::Pin::<P>::new_unchecked" => {
+                            // `Pin` is encoded as the pointer that it wraps.
+                            let (dst, pre_stmts, _, _) =
+                                self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+                            stmts.extend(pre_stmts);
+                            stmts.extend(self.encode_assign_operand(&dst, &args[0], location)?);
+                        }
+
+                        "std::future::Future::poll" | "core::future::Future::poll"
+                            if self.mir.generator_kind().is_some()
+                                && self
+                                    .get_awaited_async_fn(
+                                        called_def_id,
+                                        self.mir_encoder.get_operand_ty(&args[0]),
+                                    )
+                                    .is_none() =>
+                        {
+                            return Err(SpannedEncodingError::unsupported(
+                                "awaiting futures that are not returned by an `async fn` is not \
+                                supported",
+                                term.source_info.span,
+                            ));
+                        }
+
                         // TODO: use extern_spec
                         "core::ops::IndexMut::index_mut" | "std::ops::IndexMut::index_mut" => {
                             return Err(SpannedEncodingError::unsupported(
@@ -2788,7 +3320,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                                 assert!(target.is_some());
                                 for operand in args.iter() {
                                     // Check that the operand's type is Copy
-                                    let _ = self.mir_encoder.encode_operand_expr(operand);
+                                    let _ = self
+                                        .mir_encoder
+                                        .encode_operand_expr(operand, term.source_info.span);
                                 }
 
                                 stmts.extend(self.encode_pure_function_call(
@@ -2846,12 +3380,13 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 // Use local variables in the switch/if.
                 // See Silicon issue https://github.com/viperproject/silicon/issues/356
                 let cond_var = self.cfg_method.add_fresh_local_var(vir::Type::Bool);
+                let cond_span = self.mir_encoder.get_span_of_location(location);
                 stmts.push(vir::Stmt::Assign(vir::Assign {
                     target: cond_var.clone().into(),
                     source: self
                         .mir_encoder
-                        .encode_operand_expr(cond)
-                        .with_span(self.mir_encoder.get_span_of_location(location))?,
+                        .encode_operand_expr(cond, cond_span)
+                        .with_span(cond_span)?,
                     kind: vir::AssignKind::Copy,
                 }));
 
@@ -2886,8 +3421,15 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 (stmts, MirSuccessor::Goto(target))
             }
 
+            TerminatorKind::Yield { .. } => {
+                // The resumption of a suspended generator is not explored. See
+                // `RealEdges` for why this is sound.
+                stmts.push(vir::Stmt::comment("Suspend the generator"));
+                stmts.push(vir::Stmt::Inhale(vir::Inhale { expr: false.into() }));
+                (stmts, MirSuccessor::Kill)
+            }
+
             TerminatorKind::UnwindResume
-            | TerminatorKind::Yield { .. }
             | TerminatorKind::GeneratorDrop
             | TerminatorKind::InlineAsm { .. } => unimplemented!("{:?}", term.kind),
         };
@@ -2905,7 +3447,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         assert!(args.len() == 1, "unexpected args to slice::len(): {args:?}");
         let slice_operand = self
             .mir_encoder
-            .encode_operand_expr(&args[0])
+            .encode_operand_expr(&args[0], span)
             .with_span(span)?;
 
         let mut stmts = vec![];
@@ -2950,6 +3492,182 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok(stmts)
     }
 
+    /// Encodes a call of a built-in pure function by inhaling that the
+    /// snapshot of its result is `result`.
+    fn encode_snapshot_builtin_call(
+        &mut self,
+        location: mir::Location,
+        args: &[mir::Operand<'tcx>],
+        destination: mir::Place<'tcx>,
+        target: Option<BasicBlockIndex>,
+        result: vir::Expr,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let (target_value, mut stmts) =
+            self.encode_pure_function_call_lhs_value(destination, target, location)?;
+        let inhaled_expr = vir::Expr::eq_cmp(vir::Expr::snap_app(target_value), result);
+
+        let (call_stmts, label) =
+            self.encode_pure_function_call_site(location, destination, target, inhaled_expr)?;
+        stmts.extend(call_stmts);
+
+        self.encode_transfer_args_permissions(location, args, &mut stmts, &label, false)?;
+
+        Ok(stmts)
+    }
+
+    /// Whether `ty` is a `Range` over an integer type, whose `next` method is
+    /// encoded without a call.
+    fn is_integer_range(&self, ty: ty::Ty<'tcx>) -> bool {
+        let tcx = self.encoder.env().tcx();
+        match ty.kind() {
+            ty::TyKind::Adt(adt_def, substs) => {
+                Some(adt_def.did()) == tcx.lang_items().range_struct()
+                    && substs.type_at(0).is_integral()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the call is to the blanket implementation of `IntoIterator`
+    /// for iterators, which returns its argument unchanged.
+    fn is_identity_into_iter(
+        &self,
+        called_def_id: ProcedureDefId,
+        call_substs: GenericArgsRef<'tcx>,
+        arg: &mir::Operand<'tcx>,
+        destination: mir::Place<'tcx>,
+    ) -> bool {
+        let (impl_def_id, _) = self.encoder.env().query.resolve_method_call(
+            self.proc_def_id,
+            called_def_id,
+            call_substs,
+        );
+        let tcx = self.encoder.env().tcx();
+        let arg_ty = tcx.erase_regions(self.mir_encoder.get_operand_ty(arg));
+        let dest_ty = tcx.erase_regions(destination.ty(self.mir, tcx).ty);
+        !impl_def_id.is_local() && arg_ty == dest_ty
+    }
+
+    /// Encode a call of `next` on a `Range` over integers: the result is
+    /// `Some(start)` and `start` is incremented if the range is not empty,
+    /// otherwise the result is `None`.
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn encode_range_next_call(
+        &mut self,
+        destination: mir::Place<'tcx>,
+        args: &[mir::Operand<'tcx>],
+        location: mir::Location,
+        span: Span,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        assert!(
+            args.len() == 1,
+            "unexpected args to Range::next(): {args:?}"
+        );
+        let tcx = self.encoder.env().tcx();
+        let mut stmts = vec![];
+
+        // we need to put a label before, it seems..
+        let label = self.cfg_method.get_fresh_label_name();
+        stmts.push(vir::Stmt::label(label.clone()));
+
+        let range_ty = match self.mir_encoder.get_operand_ty(&args[0]).kind() {
+            ty::TyKind::Ref(_, range_ty, _) => *range_ty,
+            _ => unreachable!(),
+        };
+        let idx_ty = match range_ty.kind() {
+            ty::TyKind::Adt(_, substs) => substs.type_at(0),
+            _ => unreachable!(),
+        };
+        let range_ref = self
+            .mir_encoder
+            .encode_operand_place(&args[0])
+            .with_span(span)?
+            .unwrap();
+        let range = range_ref.field(
+            self.encoder
+                .encode_dereference_field(range_ty)
+                .with_span(span)?,
+        );
+        let start = self
+            .encoder
+            .encode_struct_field_value(range.clone(), "start", idx_ty)
+            .with_span(span)?;
+        let end = self
+            .encoder
+            .encode_struct_field_value(range.clone(), "end", idx_ty)
+            .with_span(span)?;
+        let is_not_empty = vir_expr! { [start.clone()] < [end] };
+
+        let (dst, pre_stmts, option_ty, _) =
+            self.encode_place(destination, ArrayAccessKind::Shared, location)?;
+        stmts.extend(pre_stmts);
+        stmts.extend(self.encode_havoc_and_initialization(&dst).with_span(span)?);
+
+        let option_adt_def = match option_ty.kind() {
+            ty::TyKind::Adt(adt_def, _) => *adt_def,
+            _ => unreachable!(),
+        };
+        let some_variant_did = tcx.lang_items().option_some_variant().unwrap();
+        let some_variant = option_adt_def.variant_index_with_id(some_variant_did);
+        let none_variant = option_adt_def
+            .variants()
+            .indices()
+            .find(|&variant| variant != some_variant)
+            .unwrap();
+        let discriminant_of = |variant| -> vir::Expr {
+            option_adt_def
+                .discriminant_for_variant(tcx, variant)
+                .val
+                .into()
+        };
+        // dst was havocked, so it is safe to assume its value here.
+        let discriminant = self
+            .encoder
+            .encode_discriminant_func_app(dst.clone(), option_adt_def)?;
+        stmts.push(vir::Stmt::Inhale(vir::Inhale {
+            expr: vir::Expr::eq_cmp(
+                discriminant,
+                vir::Expr::ite(
+                    is_not_empty.clone(),
+                    discriminant_of(some_variant),
+                    discriminant_of(none_variant),
+                ),
+            ),
+        }));
+        let some_start = self
+            .encoder
+            .encode_snapshot(option_ty, Some(some_variant.index()), vec![start.clone()])
+            .with_span(span)?;
+        stmts.push(vir::Stmt::Inhale(vir::Inhale {
+            expr: vir::Expr::implies(
+                is_not_empty.clone(),
+                vir::Expr::eq_cmp(vir::Expr::snap_app(dst), some_start),
+            ),
+        }));
+
+        let start_field = self
+            .encoder
+            .encode_struct_field("start", idx_ty)
+            .with_span(span)?;
+        stmts.extend(self.encode_copy_value_assign(
+            range.field(start_field),
+            vir::Expr::ite(
+                is_not_empty,
+                vir::Expr::add(start.clone(), 1usize.into()),
+                start,
+            ),
+            idx_ty,
+            location,
+        )?);
+
+        self.encode_transfer_args_permissions(location, args, &mut stmts, &label, false)?;
+
+        // Store a label for permissions got back from the call
+        self.label_after_location.insert(location, label);
+
+        Ok(stmts)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     fn encode_sequence_index_call(
         &mut self,
@@ -3018,17 +3736,6 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             .encoder
             .encode_sequence_types(base_seq_ty.peel_refs())?;
 
-        let j = vir_local! { j: Int };
-        let elem_snap_ty = self
-            .encoder
-            .encode_snapshot_type(enc_sequence_types.elem_ty_rs)?;
-        let rhs_lookup_j = enc_sequence_types.encode_lookup_pure_call(
-            self.encoder,
-            base_seq_expr.clone(),
-            j.clone().into(),
-            elem_snap_ty,
-        );
-
         let encoded_idx = self.mir_encoder.encode_operand_place(&args[1])?.unwrap();
         trace!("idx: {:?}", encoded_idx);
         let idx_ty = self.mir_encoder.get_operand_ty(&args[1]);
@@ -3041,7 +3748,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
         self.slice_created_at.insert(location, encoded_lhs);
 
-        let original_len = enc_sequence_types.len(self.encoder, base_seq_expr);
+        let original_len = enc_sequence_types.len(self.encoder, base_seq_expr.clone());
 
         // TODO: there's fields like _5.f$start.val_int on `encoded_idx`, it just feels hacky to
         // manually re-do and hardcode them here when we probably just encoded the type
@@ -3079,12 +3786,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             // RangeInclusive is wierdly differnet to all of the other Range*s in that the struct fields are private
             // and it is created with a new() fn and start/end are accessed with getter fns
             // See https://github.com/rust-lang/rust/issues/67371 for why this is the case...
-            "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => {
-                return Err(EncodingError::unsupported(
-                    "slicing with RangeInclusive (e.g. [x..=y]) currently not supported"
-                        .to_string(),
-                ))
-            }
+            "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => self
+                .encoder
+                .encode_range_inclusive_slice_start(encoded_idx.clone(), usize_ty)?,
             "std::ops::RangeTo"
             | "core::ops::RangeTo"
             | "std::ops::RangeFull"
@@ -3112,13 +3816,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 }
                 end_expr
             }
-            "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => {
-                return Err(EncodingError::unsupported(
-                    "slicing with RangeInclusive (e.g. [x..=y]) currently not supported"
-                        .to_string(),
-                ))
-            }
-            "std::ops::RangeToInclusive" | "core::ops::RangeToInclusive" => {
+            "std::ops::RangeInclusive"
+            | "core::ops::RangeInclusive"
+            | "std::ops::RangeToInclusive"
+            | "core::ops::RangeToInclusive" => {
                 let end_expr =
                     self.encoder
                         .encode_struct_field_value(encoded_idx, "end", usize_ty)?;
@@ -3146,13 +3847,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
         trace!("start: {}, end: {}", start, end);
 
-        let slice_types_lhs = self.encoder.encode_sequence_types(lhs_slice_ty)?;
-        let elem_snap_ty = self
-            .encoder
-            .encode_snapshot_type(slice_types_lhs.elem_ty_rs)?;
-
         // length
-        let length = vir_expr! { [end] - [start] };
         if self.check_panics {
             // start must be leq than end
             if idx_ident != "std::ops::RangeFull" && idx_ident != "core::ops::RangeFull" {
@@ -3168,6 +3863,61 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             }
         }
 
+        stmts.extend(self.encode_slice_contents(
+            lhs_slice_expr,
+            lhs_slice_ty,
+            base_seq_expr,
+            base_seq_ty.peel_refs(),
+            start,
+            end,
+        )?);
+
+        self.encode_transfer_args_permissions(location, args, &mut stmts, &label, false)?;
+        // Store a label for permissions got back from the call
+        debug!(
+            "Pure function call location {:?} has label {}",
+            location, label
+        );
+        self.label_after_location.insert(location, label);
+
+        // plan
+        //
+        // [x] inhale Array$lookup_pure == Slice$lookup_pure with quantifier from start to end
+        // [?] label, encode_transfer_permissions?
+        // [ ] what if the index is not a range? should support Index<usize> for arrays and slices maybe, mostly implemented anyway i guess
+
+        Ok(stmts)
+    }
+
+    /// Inhales the length and the elements of the slice `lhs_slice_expr`,
+    /// which is the range `start..end` of the array or slice `base_seq_expr`.
+    fn encode_slice_contents(
+        &self,
+        lhs_slice_expr: vir::Expr,
+        lhs_slice_ty: ty::Ty<'tcx>,
+        base_seq_expr: vir::Expr,
+        base_seq_ty: ty::Ty<'tcx>,
+        start: vir::Expr,
+        end: vir::Expr,
+    ) -> EncodingResult<Vec<vir::Stmt>> {
+        let mut stmts = vec![];
+        let enc_sequence_types = self.encoder.encode_sequence_types(base_seq_ty)?;
+        let slice_types_lhs = self.encoder.encode_sequence_types(lhs_slice_ty)?;
+        let elem_snap_ty = self
+            .encoder
+            .encode_snapshot_type(slice_types_lhs.elem_ty_rs)?;
+
+        let j = vir_local! { j: Int };
+        let rhs_lookup_j = enc_sequence_types.encode_lookup_pure_call(
+            self.encoder,
+            base_seq_expr,
+            j.clone().into(),
+            elem_snap_ty.clone(),
+        );
+
+        // length
+        let length = vir_expr! { [end] - [start] };
+
         let slice_len_call = slice_types_lhs.len(self.encoder, lhs_slice_expr.clone());
         stmts.push(vir_stmt! {
             inhale [vir_expr!{ [slice_len_call] == [length] }]
@@ -3216,20 +3966,6 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             ]
         });
 
-        self.encode_transfer_args_permissions(location, args, &mut stmts, &label, false)?;
-        // Store a label for permissions got back from the call
-        debug!(
-            "Pure function call location {:?} has label {}",
-            location, label
-        );
-        self.label_after_location.insert(location, label);
-
-        // plan
-        //
-        // [x] inhale Array$lookup_pure == Slice$lookup_pure with quantifier from start to end
-        // [?] label, encode_transfer_permissions?
-        // [ ] what if the index is not a range? should support Index<usize> for arrays and slices maybe, mostly implemented anyway i guess
-
         Ok(stmts)
     }
 
@@ -3254,11 +3990,11 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         {
             let lhs = self
                 .mir_encoder
-                .encode_operand_expr(&args[0])
+                .encode_operand_expr(&args[0], call_site_span)
                 .with_span(call_site_span)?;
             let rhs = self
                 .mir_encoder
-                .encode_operand_expr(&args[1])
+                .encode_operand_expr(&args[1], call_site_span)
                 .with_span(call_site_span)?;
 
             let expr = match bin_op {
@@ -3480,23 +4216,37 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                         .encode_value_field(arg_ty)
                         .with_span(call_site_span)?;
 
-                    // TODO: String constants are not being encoded currently,
-                    //       instead just inhale the permission to the value.
+                    // String literals are not stored in a local place, so the
+                    // argument points to a fresh read-only slice of their bytes.
                     if !is_str(arg_ty) {
                         let arg_val_expr = self
                             .mir_encoder
-                            .encode_operand_expr(mir_arg)
+                            .encode_operand_expr(mir_arg, call_site_span)
                             .with_span(call_site_span)?;
                         debug!("arg_val_expr: {} {}", arg_place, arg_val_expr);
                         fake_exprs.insert(arg_place.clone().field(val_field), arg_val_expr);
                     } else {
                         fake_expr_spans.insert(arg, call_site_span);
+                        let arg_val = arg_place.clone().field(val_field);
+                        stmts.push(vir::Stmt::Inhale(vir::Inhale {
+                            expr: vir::Expr::acc_permission(arg_val.clone(), vir::PermAmount::Read),
+                        }));
                         stmts.push(vir::Stmt::Inhale(vir::Inhale {
-                            expr: vir::Expr::acc_permission(
-                                arg_place.clone().field(val_field),
+                            expr: vir::Expr::predicate_access_predicate(
+                                arg_val.get_type().clone(),
+                                arg_val.clone(),
                                 vir::PermAmount::Read,
                             ),
                         }));
+                        if let mir::Operand::Constant(constant) = mir_arg {
+                            let const_val = self
+                                .encoder
+                                .encode_snapshot_constant(constant)
+                                .with_span(call_site_span)?;
+                            stmts.push(vir::Stmt::Inhale(vir::Inhale {
+                                expr: vir::Expr::eq_cmp(vir::Expr::snap_app(arg_val), const_val),
+                            }));
+                        }
                     }
                     let in_loop = self.loop_encoder.get_loop_depth(location.block) > 0;
                     if in_loop {
@@ -3617,6 +4367,34 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             expr: replace_fake_exprs(pre_invs_spec),
             position: pos,
         }));
+
+        // Check the termination of the call, before the arguments are moved.
+        if self.encoder.terminates(self.proc_def_id, None) {
+            let is_recursive = self.is_recursive_call(called_def_id, substs);
+            let call_measure = if is_recursive {
+                let encoded_args: Vec<vir::Expr> = self
+                    .encode_spec_args(&procedure_contract)?
+                    .into_iter()
+                    .map(|(arg, _)| arg)
+                    .collect();
+                self.encode_termination_measure(
+                    procedure_contract.functional_termination_measure(self.encoder.env(), substs),
+                    &encoded_args,
+                    false,
+                    call_site_span,
+                )?
+                .map(|components| components.into_iter().map(&replace_fake_exprs).collect())
+            } else {
+                None
+            };
+            stmts.extend(self.encode_call_termination_checks(
+                call_site_span,
+                called_def_id,
+                substs,
+                is_recursive,
+                call_measure,
+            ));
+        }
         let pre_perm_spec = replace_fake_exprs(pre_type_spec);
         assert!(!pos.is_default());
         stmts.push(vir::Stmt::Exhale(vir::Exhale {
@@ -3754,12 +4532,44 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         for operand in args.iter() {
             let arg_expr = self
                 .mir_encoder
-                .encode_operand_expr(operand)
+                .encode_operand_expr(operand, call_site_span)
                 .with_span(call_site_span)?;
             arg_exprs.push(arg_expr);
         }
 
-        self.encode_specified_pure_function_call(
+        // Check the termination of the call
+        let mut stmts = vec![];
+        if self.encoder.terminates(self.proc_def_id, None) {
+            let is_recursive = self.is_recursive_call(called_def_id, call_substs);
+            let call_measure = if is_recursive {
+                let procedure_contract = self
+                    .encoder
+                    .get_mir_procedure_contract_for_call(
+                        self.proc_def_id,
+                        called_def_id,
+                        call_substs,
+                    )
+                    .with_span(call_site_span)?;
+                self.encode_termination_measure(
+                    procedure_contract
+                        .functional_termination_measure(self.encoder.env(), call_substs),
+                    &arg_exprs,
+                    true,
+                    call_site_span,
+                )?
+            } else {
+                None
+            };
+            stmts.extend(self.encode_call_termination_checks(
+                call_site_span,
+                called_def_id,
+                call_substs,
+                is_recursive,
+                call_measure,
+            ));
+        }
+
+        stmts.extend(self.encode_specified_pure_function_call(
             location,
             call_site_span,
             args,
@@ -3770,7 +4580,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             return_type,
             called_def_id,
             call_substs,
-        )
+        )?);
+        Ok(stmts)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -3922,8 +4733,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 .encode_operand_place(operand)
                 .with_span(span)?;
             match (operand_place, &operand_ty.kind()) {
-                (Some(ref place), ty::TyKind::RawPtr(ty::TypeAndMut { ty: inner_ty, .. }))
-                | (Some(ref place), ty::TyKind::Ref(_, inner_ty, _)) => {
+                // Raw pointers do not own the memory they point to, so there
+                // are no permissions to transfer.
+                (Some(ref place), ty::TyKind::Ref(_, inner_ty, _)) => {
                     let ref_field = self
                         .encoder
                         .encode_dereference_field(*inner_ty)
@@ -4081,13 +4893,14 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         }
 
         // Encode functional specification
-        let encoded_args: Vec<vir::Expr> = contract
-            .args
-            .iter()
-            .map(|local| self.encode_prusti_local(*local).into())
+        let mut encoded_args: Vec<vir::Expr> = self
+            .encode_spec_args(contract)?
+            .into_iter()
+            .map(|(arg, _)| arg)
             .collect();
+        encoded_args.extend(self.encode_closure_views(contract, None)?);
 
-        let func_spec: Vec<vir::Expr> = contract
+        let mut func_spec: Vec<vir::Expr> = contract
             .functional_precondition(self.encoder.env(), substs)
             .iter()
             .map(|(assertion, assertion_substs)| {
@@ -4102,6 +4915,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 )
             })
             .collect::<Result<Vec<_>, _>>()?;
+        if let Some(assertion) = self.encode_fn_trait_call_spec(contract, None, self.mir.span)? {
+            func_spec.push(assertion);
+        }
 
         // TODO(tymap): do this with the previous step ...
         let precondition_spans = MultiSpan::from_spans(
@@ -4142,11 +4958,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Option<PostconditionStrengthening>,
     )> {
         // Encode arguments and return
-        let encoded_args = self
-            .procedure_contract()
-            .args
+        let spec_args = self.encode_spec_args(self.procedure_contract())?;
+        let encoded_args = spec_args
             .iter()
-            .map(|local| self.encode_prusti_local(*local).into())
+            .map(|(arg, _)| arg.clone())
             .collect::<Vec<_>>();
         let encoded_return = self
             .encode_prusti_local(self.procedure_contract().returned_value)
@@ -4272,8 +5087,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     [to_post] ==> [from_post]
                 },
                 pre_label,
-                self.procedure_contract(),
-                &encoded_args,
+                &spec_args,
             )?;
 
             strengthening = Some(PostconditionStrengthening {
@@ -4364,11 +5178,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         };
 
         // Encode args and return.
-        let encoded_args: Vec<vir::Expr> = contract
-            .args
-            .iter()
-            .map(|local| self.encode_prusti_local(*local).into())
-            .collect();
+        let spec_args = self.encode_spec_args(contract)?;
+        let encoded_args: Vec<vir::Expr> = spec_args.iter().map(|(arg, _)| arg.clone()).collect();
         let encoded_return: vir::Expr = self.encode_prusti_local(contract.returned_value).into();
 
         // Encode magic wands
@@ -4452,18 +5263,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     self.proc_def_id,
                     substs,
                 )?;
-                assertion_lhs = self.wrap_arguments_into_old(
-                    assertion_lhs,
-                    pre_label,
-                    contract,
-                    &encoded_args,
-                )?;
-                assertion_rhs = self.wrap_arguments_into_old(
-                    assertion_rhs,
-                    pre_label,
-                    contract,
-                    &encoded_args,
-                )?;
+                assertion_lhs =
+                    self.wrap_arguments_into_old(assertion_lhs, pre_label, &spec_args)?;
+                assertion_rhs =
+                    self.wrap_arguments_into_old(assertion_rhs, pre_label, &spec_args)?;
                 let ty = self.locals.get_type(contract.returned_value);
                 let return_span = self
                     .mir_encoder
@@ -4491,52 +5294,357 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         }
     }
 
-    /// Wrap function arguments used in the postcondition into ``old``:
-    ///
-    /// +   For references wrap the base ``_1.var_ref``.
-    /// +   For non-references wrap the entire place into old.
-    fn wrap_arguments_into_old(
+    /// Encode the expressions that the arguments of a specification refer to,
+    /// together with their types. These are the arguments of the procedure,
+    /// except for closures, whose specification does not refer to the closure
+    /// itself, and for the generator of an `async fn`, whose specification
+    /// refers to the arguments captured by the generator.
+    fn encode_spec_args(
         &self,
-        mut assertion: vir::Expr,
-        pre_label: &str,
         contract: &ProcedureContract<'tcx>,
-        encoded_args: &[vir::Expr],
-    ) -> SpannedEncodingResult<vir::Expr> {
-        for (encoded_arg, &arg) in encoded_args.iter().zip(&contract.args) {
-            let ty = self.locals.get_type(arg);
-            if is_reference(ty) {
-                // If the argument is a reference, we wrap _1.val_ref into old.
-                let arg_span = self.mir_encoder.get_local_span(arg.into());
-                let (encoded_deref, ..) = self
-                    .mir_encoder
-                    .encode_deref(encoded_arg.clone(), ty)
-                    .with_span(arg_span)?;
-                let original_expr = encoded_deref;
-                let old_expr = original_expr.clone().old(pre_label);
-                assertion = assertion.replace_place(&original_expr, &old_expr);
-            } else {
-                // If the argument is not a reference, we wrap entire path into old.
-                assertion = assertion.fold_expr(|e| {
-                    if let vir::Expr::FuncApp(vir::FuncApp {
-                        function_name,
-                        arguments,
-                        ..
-                    }) = &e
-                    {
-                        // If `assertion` is e.g. a `foo(snap(_1))` with an `_1: T` and `fn foo(x: &T)` we cannot
-                        // wrap `foo(snap(old[pre](_1)))`, but should instead wrap as `foo(old[pre](snap(_1)))`
-                        // TODO: this could all be fixed by making all arguments into fields (e.g. `_1.local`).
-                        // This would also make mutating of arguments sound.
-                        if function_name == "snap$" && &arguments[0] == encoded_arg {
-                            return e.old(pre_label);
-                        }
-                    } else if e.is_place() {
-                        // Check the base of the path matches the current arg:
-                        let base: vir::Expr = e.get_base().into();
-                        if encoded_arg == &base {
-                            return e.old(pre_label);
-                        }
-                    }
+    ) -> SpannedEncodingResult<Vec<(vir::Expr, ty::Ty<'tcx>)>> {
+        let query = &self.encoder.env().query;
+        let Some(async_fn_def_id) = query.get_async_fn_of_generator(contract.def_id) else {
+            let skipped_args = usize::from(query.is_closure(contract.def_id));
+            return Ok(contract
+                .args
+                .iter()
+                .skip(skipped_args)
+                .map(|&local| {
+                    (
+                        self.encode_prusti_local(local).into(),
+                        self.locals.get_type(local),
+                    )
+                })
+                .collect());
+        };
+        let generator = contract.args[0];
+        let ty::TyKind::Generator(_, substs, _) = self.locals.get_type(generator).kind() else {
+            unreachable!("the first argument of a generator is the generator itself");
+        };
+        let upvar_tys = substs.as_generator().upvar_tys();
+        let tcx = self.encoder.env().tcx();
+        let arity = tcx
+            .fn_sig(async_fn_def_id)
+            .skip_binder()
+            .inputs()
+            .skip_binder()
+            .len();
+        if upvar_tys.len() != arity {
+            return Err(SpannedEncodingError::unsupported(
+                "the arguments of this `async fn` are not captured one by one by its body",
+                self.mir.span,
+            ));
+        }
+        let encoded_generator: vir::Expr = self.encode_prusti_local(generator).into();
+        upvar_tys
+            .iter()
+            .enumerate()
+            .map(|(index, upvar_ty)| {
+                let field = self
+                    .encoder
+                    .encode_raw_ref_field(format!("closure_{index}"), upvar_ty)
+                    .with_span(self.mir.span)?;
+                Ok((encoded_generator.clone().field(field), upvar_ty))
+            })
+            .collect()
+    }
+
+    /// Encode the places of the captured variables that the specification of a
+    /// closure refers to as views. They are passed to the specification items
+    /// of the closure after its arguments. In a postcondition, which is given
+    /// by `pre_label`, the closure is found through the reference to it before
+    /// the call, while the views refer to the captured state after the call.
+    fn encode_closure_views(
+        &self,
+        contract: &ProcedureContract<'tcx>,
+        pre_label: Option<&str>,
+    ) -> SpannedEncodingResult<Vec<vir::Expr>> {
+        let query = &self.encoder.env().query;
+        let views = query.get_closure_views(contract.def_id);
+        if views.is_empty() {
+            return Ok(vec![]);
+        }
+        let span = query.get_def_span(contract.def_id);
+        let closure_arg = contract.args[0];
+        let closure_ref_ty = self.locals.get_type(closure_arg);
+        if !closure_ref_ty.is_ref() {
+            return Err(SpannedEncodingError::unsupported(
+                "views of closures that can only be called once are not supported",
+                span,
+            ));
+        }
+        let (mut closure, closure_ty, _) = self
+            .mir_encoder
+            .encode_deref(self.encode_prusti_local(closure_arg).into(), closure_ref_ty)
+            .with_span(span)?;
+        if let Some(pre_label) = pre_label {
+            closure = closure.old(pre_label);
+        }
+        let ty::TyKind::Closure(_, substs) = closure_ty.kind() else {
+            unreachable!("the first argument of a closure is a reference to the closure");
+        };
+        let upvar_tys = substs.as_closure().upvar_tys();
+        let captures = self
+            .encoder
+            .env()
+            .tcx()
+            .closure_captures(contract.def_id.expect_local());
+        views
+            .iter()
+            .map(|view| {
+                let Some(index) = captures.iter().position(|capture| {
+                    capture.place.projections.is_empty() && capture.to_symbol().as_str() == view
+                }) else {
+                    return Err(SpannedEncodingError::unsupported(
+                        format!("the view `{view}` is not a variable captured as a whole"),
+                        span,
+                    ));
+                };
+                let upvar_ty = upvar_tys[index];
+                let field = self
+                    .encoder
+                    .encode_raw_ref_field(format!("closure_{index}"), upvar_ty)
+                    .with_span(span)?;
+                let upvar = closure.clone().field(field);
+                // A variable captured by reference is viewed through it.
+                if upvar_ty.is_ref() {
+                    let (target, ..) = self
+                        .mir_encoder
+                        .encode_deref(upvar, upvar_ty)
+                        .with_span(span)?;
+                    Ok(target)
+                } else {
+                    Ok(upvar)
+                }
+            })
+            .collect()
+    }
+
+    /// Encodes the assignment of the termination measure of a terminating
+    /// procedure to fresh ghost variables, against which the measures of
+    /// recursive calls are compared.
+    fn encode_termination_initialization(&mut self) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        if !self.encoder.terminates(self.proc_def_id, None) {
+            return Ok(vec![]);
+        }
+        let contract = self.procedure_contract();
+        let encoded_args: Vec<vir::Expr> = self
+            .encode_spec_args(contract)?
+            .into_iter()
+            .map(|(arg, _)| arg)
+            .collect();
+        let Some(components) = self.encode_termination_measure(
+            contract.functional_termination_measure(self.encoder.env(), self.substs),
+            &encoded_args,
+            false,
+            self.mir.span,
+        )?
+        else {
+            return Ok(vec![]);
+        };
+        let mut stmts = vec![vir::Stmt::comment("Store the termination measure")];
+        let mut termination_variables = vec![];
+        for component in components {
+            let termination_var = self.cfg_method.add_fresh_local_var(vir::Type::Int);
+            stmts.push(vir::Stmt::Assign(vir::Assign {
+                target: termination_var.clone().into(),
+                source: component,
+                kind: vir::AssignKind::Ghost,
+            }));
+            termination_variables.push(termination_var);
+        }
+        self.termination_variables = Some(termination_variables);
+        Ok(stmts)
+    }
+
+    /// Encodes the termination measure `measure` of a procedure, applied to
+    /// `encoded_args`, as its lexicographic components. Returns `None` if the
+    /// procedure is trusted to terminate.
+    fn encode_termination_measure(
+        &self,
+        measure: Option<(
+            prusti_rustc_interface::hir::def_id::LocalDefId,
+            GenericArgsRef<'tcx>,
+        )>,
+        encoded_args: &[vir::Expr],
+        targets_are_values: bool,
+        span: Span,
+    ) -> SpannedEncodingResult<Option<Vec<vir::Expr>>> {
+        let (measure, measure_substs) = measure.ok_or_else(|| {
+            SpannedEncodingError::incorrect(
+                "Terminating function calls nonterminating function",
+                span,
+            )
+        })?;
+        if self.is_trusted_termination_measure(measure.to_def_id(), measure_substs) {
+            return Ok(None);
+        }
+        let encoded_measure = self.encoder.encode_assertion(
+            &measure.to_def_id(),
+            None,
+            encoded_args,
+            None,
+            targets_are_values,
+            self.proc_def_id,
+            measure_substs,
+        )?;
+        let measure_ty = self
+            .encoder
+            .env()
+            .query
+            .get_fn_sig(measure.to_def_id(), measure_substs)
+            .skip_binder()
+            .output();
+        Ok(Some(lexicographic_components(
+            encoded_measure,
+            measure_ty,
+            span,
+        )?))
+    }
+
+    /// Whether `measure_def_id` is the measure generated for
+    /// `#[terminates(trusted)]`, whose body is a call to
+    /// `prusti_terminates_trusted`.
+    fn is_trusted_termination_measure(
+        &self,
+        measure_def_id: ProcedureDefId,
+        substs: GenericArgsRef<'tcx>,
+    ) -> bool {
+        let env = self.encoder.env();
+        let body = env
+            .body
+            .get_expression_body(measure_def_id, substs, self.proc_def_id);
+        body.basic_blocks.iter().any(|data| {
+            matches!(
+                &data.terminator().kind,
+                mir::TerminatorKind::Call { func, .. }
+                    if func.const_fn_def().map_or(false, |(called_def_id, _)| {
+                        env.name.get_absolute_item_name(called_def_id)
+                            == "prusti_contracts::prusti_terminates_trusted"
+                    })
+            )
+        })
+    }
+
+    /// Whether the called function is in the same strongly connected
+    /// component of the call graph of terminating local functions as the
+    /// procedure being encoded, that is, whether the call is (mutually)
+    /// recursive.
+    fn is_recursive_call(
+        &mut self,
+        called_def_id: ProcedureDefId,
+        call_substs: GenericArgsRef<'tcx>,
+    ) -> bool {
+        if self
+            .encoder
+            .env()
+            .callee_reaches_caller(self.proc_def_id, called_def_id, call_substs)
+        {
+            return true;
+        }
+        if self.recursion_component.is_none() {
+            self.recursion_component = Some(compute_recursion_component(
+                self.encoder,
+                self.proc_def_id,
+                self.mir,
+            ));
+        }
+        self.recursion_component
+            .as_ref()
+            .unwrap()
+            .contains(&called_def_id)
+    }
+
+    /// Encodes the termination checks of a call from a terminating procedure:
+    /// the called function has to terminate and, if the call is recursive,
+    /// its termination measure `call_measure` has to be lower than the one of
+    /// the caller, which has to be non-negative.
+    fn encode_call_termination_checks(
+        &self,
+        span: Span,
+        called_def_id: ProcedureDefId,
+        call_substs: GenericArgsRef<'tcx>,
+        is_recursive: bool,
+        call_measure: Option<Vec<vir::Expr>>,
+    ) -> Vec<vir::Stmt> {
+        let mut stmts = vec![];
+        if !self.encoder.terminates(called_def_id, Some(call_substs)) {
+            stmts.push(vir::Stmt::Assert(vir::Assert {
+                expr: false.into(),
+                position: self.register_error(span, ErrorCtxt::UnexpectedReachableCall),
+            }));
+        }
+        if !is_recursive {
+            return stmts;
+        }
+        let Some(termination_variables) = self.termination_variables.clone() else {
+            return stmts;
+        };
+        let caller_measure: Vec<vir::Expr> = termination_variables
+            .into_iter()
+            .map(vir::Expr::local)
+            .collect();
+        if let Some(call_measure) = call_measure {
+            stmts.push(vir::Stmt::Assert(vir::Assert {
+                expr: lexicographically_less(&call_measure, &caller_measure),
+                position: self.register_error(span, ErrorCtxt::CallTerminationMeasureLower),
+            }));
+        }
+        let position = self.register_error(span, ErrorCtxt::CallTerminationMeasureNonNegative);
+        for component in caller_measure {
+            stmts.push(vir::Stmt::Assert(vir::Assert {
+                expr: vir::Expr::ge_cmp(component, vir::Expr::from(0usize)),
+                position,
+            }));
+        }
+        stmts
+    }
+
+    /// Wrap function arguments used in the postcondition into ``old``:
+    ///
+    /// +   For references wrap the base ``_1.var_ref``.
+    /// +   For non-references wrap the entire place into old.
+    fn wrap_arguments_into_old(
+        &self,
+        mut assertion: vir::Expr,
+        pre_label: &str,
+        spec_args: &[(vir::Expr, ty::Ty<'tcx>)],
+    ) -> SpannedEncodingResult<vir::Expr> {
+        for (encoded_arg, ty) in spec_args {
+            let ty = *ty;
+            if is_reference(ty) {
+                // If the argument is a reference, we wrap _1.val_ref into old.
+                let (encoded_deref, ..) = self
+                    .mir_encoder
+                    .encode_deref(encoded_arg.clone(), ty)
+                    .with_span(self.mir.span)?;
+                let original_expr = encoded_deref;
+                let old_expr = original_expr.clone().old(pre_label);
+                assertion = assertion.replace_place(&original_expr, &old_expr);
+            } else {
+                // If the argument is not a reference, we wrap entire path into old.
+                assertion = assertion.fold_expr(|e| {
+                    if let vir::Expr::FuncApp(vir::FuncApp {
+                        function_name,
+                        arguments,
+                        ..
+                    }) = &e
+                    {
+                        // If `assertion` is e.g. a `foo(snap(_1))` with an `_1: T` and `fn foo(x: &T)` we cannot
+                        // wrap `foo(snap(old[pre](_1)))`, but should instead wrap as `foo(old[pre](snap(_1)))`
+                        // TODO: this could all be fixed by making all arguments into fields (e.g. `_1.local`).
+                        // This would also make mutating of arguments sound.
+                        if function_name == "snap$" && &arguments[0] == encoded_arg {
+                            return e.old(pre_label);
+                        }
+                    } else if e.is_place() {
+                        // Check the base of the path matches the current arg:
+                        let base: vir::Expr = e.get_base().into();
+                        if encoded_arg == &base || e.has_prefix(encoded_arg) {
+                            return e.old(pre_label);
+                        }
+                    }
                     e
                 });
             }
@@ -4544,6 +5652,277 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok(assertion.remove_redundant_old())
     }
 
+    /// Encode that the future returned by an `async fn` captures the values
+    /// of its arguments, for which the postcondition holds once it is awaited.
+    fn encode_future_captures_args(
+        &self,
+        contract: &ProcedureContract<'tcx>,
+        encoded_return: &vir::Expr,
+        spec_args: &[(vir::Expr, ty::Ty<'tcx>)],
+        pre_label: &str,
+    ) -> SpannedEncodingResult<vir::Expr> {
+        let tcx = self.encoder.env().tcx();
+        let return_ty = self.locals.get_type(contract.returned_value);
+        let Some(ty::TyKind::Generator(_, substs, _)) =
+            transparent_ty(tcx, return_ty).map(|ty| ty.kind())
+        else {
+            unreachable!("an `async fn` returns a generator, got {:?}", return_ty);
+        };
+        let upvar_tys = substs.as_generator().upvar_tys();
+        if upvar_tys.len() != spec_args.len() {
+            return Err(SpannedEncodingError::unsupported(
+                "the arguments of this `async fn` are not captured one by one by its body",
+                self.encoder.env().query.get_def_span(contract.def_id),
+            ));
+        }
+        let mut conjuncts = vec![];
+        for (index, ((encoded_arg, arg_ty), upvar_ty)) in
+            spec_args.iter().zip(upvar_tys.iter()).enumerate()
+        {
+            // A shared reference keeps its target frozen while the future is
+            // alive. The changes made through a mutable one would only be
+            // visible to the caller when the future is awaited, which is not
+            // encoded.
+            if matches!(
+                arg_ty.kind(),
+                ty::TyKind::RawPtr(..) | ty::TyKind::Ref(_, _, Mutability::Mut)
+            ) {
+                return Err(SpannedEncodingError::unsupported(
+                    "`async fn` with mutable reference or raw pointer arguments is not supported",
+                    self.encoder.env().query.get_def_span(contract.def_id),
+                ));
+            }
+            let field = self
+                .encoder
+                .encode_raw_ref_field(format!("closure_{index}"), upvar_ty)
+                .with_span(self.mir.span)?;
+            conjuncts.push(vir::Expr::eq_cmp(
+                vir::Expr::snap_app(encoded_return.clone().field(field)),
+                vir::Expr::snap_app(encoded_arg.clone()),
+            ));
+        }
+        self.wrap_arguments_into_old(conjuncts.into_iter().conjoin(), pre_label, spec_args)
+    }
+
+    /// If `called_def_id` is `Future::poll` and the polled future is returned
+    /// by an `async fn`, returns the `async fn` and the type of its generator.
+    fn get_awaited_async_fn(
+        &self,
+        called_def_id: ProcedureDefId,
+        pinned_future_ty: ty::Ty<'tcx>,
+    ) -> Option<(ProcedureDefId, ty::Ty<'tcx>)> {
+        let full_func_proc_name = self
+            .encoder
+            .env()
+            .name
+            .get_absolute_item_name(called_def_id);
+        if full_func_proc_name != "core::future::Future::poll"
+            && full_func_proc_name != "std::future::Future::poll"
+        {
+            return None;
+        }
+        let tcx = self.encoder.env().tcx();
+        let ty::TyKind::Ref(_, future_ty, _) = transparent_ty(tcx, pinned_future_ty)?.kind() else {
+            return None;
+        };
+        let generator_ty = transparent_ty(tcx, *future_ty)?;
+        let ty::TyKind::Generator(generator_def_id, ..) = generator_ty.kind() else {
+            return None;
+        };
+        let async_fn_def_id = self
+            .encoder
+            .env()
+            .query
+            .get_async_fn_of_generator(*generator_def_id)?;
+        Some((async_fn_def_id, generator_ty))
+    }
+
+    /// Encode the postcondition of a call of `Future::poll` on the future of
+    /// an `async fn`: if the poll is ready, its output satisfies the
+    /// postcondition of the `async fn` for the arguments captured by the future.
+    fn encode_awaited_postcondition(
+        &self,
+        contract: &ProcedureContract<'tcx>,
+        encoded_args: &[vir::Expr],
+        encoded_return: &vir::Expr,
+        pre_label: &str,
+        span: Span,
+    ) -> SpannedEncodingResult<Option<vir::Expr>> {
+        let pinned_future_ty = self.locals.get_type(contract.args[0]);
+        let Some((async_fn_def_id, generator_ty)) =
+            self.get_awaited_async_fn(contract.def_id, pinned_future_ty)
+        else {
+            return Ok(None);
+        };
+        let tcx = self.encoder.env().tcx();
+        let ty::TyKind::Generator(_, generator_substs, _) = generator_ty.kind() else {
+            unreachable!();
+        };
+        let generator_substs = generator_substs.as_generator();
+        let future_ref_ty = transparent_ty(tcx, pinned_future_ty).unwrap();
+        let (awaitee, ..) = self
+            .mir_encoder
+            .encode_deref(encoded_args[0].clone(), future_ref_ty)
+            .with_span(span)?;
+
+        // The arguments of the `async fn` are the ones captured by its future.
+        let mut upvar_args = vec![];
+        for (index, upvar_ty) in generator_substs.upvar_tys().iter().enumerate() {
+            let field = self
+                .encoder
+                .encode_raw_ref_field(format!("closure_{index}"), upvar_ty)
+                .with_span(span)?;
+            upvar_args.push((awaitee.clone().field(field), upvar_ty));
+        }
+        let encoded_upvars: Vec<vir::Expr> =
+            upvar_args.iter().map(|(arg, _)| arg.clone()).collect();
+
+        let async_fn_substs = generator_substs.parent_args();
+        let posts = self
+            .encoder
+            .get_procedure_specs_for_call(async_fn_def_id, self.proc_def_id, async_fn_substs)
+            .and_then(|spec| spec.posts.extract_with_selective_replacement().cloned())
+            .unwrap_or_default();
+        if posts.is_empty() {
+            return Ok(None);
+        }
+
+        // The output of the `async fn` is the value in `Poll::Ready`.
+        let poll_ty = self.locals.get_type(contract.returned_value);
+        let ty::TyKind::Adt(poll_adt_def, poll_substs) = poll_ty.kind() else {
+            unreachable!("`Future::poll` returns `Poll`, got {:?}", poll_ty);
+        };
+        let ready_variant_did = tcx.lang_items().poll_ready_variant().unwrap();
+        let ready_variant = poll_adt_def.variant_index_with_id(ready_variant_did);
+        let ready_field = self
+            .encoder
+            .encode_struct_field("0", poll_substs.type_at(0))
+            .with_span(span)?;
+        let ready_value = encoded_return
+            .clone()
+            .variant(poll_adt_def.variant(ready_variant).ident(tcx).as_str())
+            .field(ready_field);
+
+        let mut conjuncts = vec![];
+        for post in posts {
+            let assertion = self.encoder.encode_assertion(
+                &post,
+                Some(pre_label),
+                &encoded_upvars,
+                Some(&ready_value),
+                false,
+                self.proc_def_id,
+                async_fn_substs,
+            )?;
+            conjuncts.push(self.wrap_arguments_into_old(assertion, pre_label, &upvar_args)?);
+        }
+        let discriminant = self
+            .encoder
+            .encode_discriminant_func_app(encoded_return.clone(), *poll_adt_def)?;
+        let ready_discriminant: vir::Expr = poll_adt_def
+            .discriminant_for_variant(tcx, ready_variant)
+            .val
+            .into();
+        Ok(Some(vir::Expr::implies(
+            vir::Expr::eq_cmp(discriminant, ready_discriminant),
+            conjuncts.into_iter().conjoin(),
+        )))
+    }
+
+    /// Encode the specification of a call of a closure of a generic or opaque
+    /// type through one of the `Fn*` traits. The specification of such a
+    /// closure is only known through specification entailments, which
+    /// describe the functions `closure$pre` and `closure$post`. The
+    /// precondition of the call is `closure$pre(closure, args...)` and, if
+    /// `pre_label` is given, the postcondition is
+    /// `closure$post(old(closure), closure, old(args)..., result)`.
+    fn encode_fn_trait_call_spec(
+        &self,
+        contract: &ProcedureContract<'tcx>,
+        pre_label: Option<&str>,
+        span: Span,
+    ) -> SpannedEncodingResult<Option<vir::Expr>> {
+        let tcx = self.encoder.env().tcx();
+        let Some(trait_kind) = tcx
+            .trait_of_item(contract.def_id)
+            .and_then(|trait_def_id| tcx.fn_trait_kind_from_def_id(trait_def_id))
+        else {
+            return Ok(None);
+        };
+        let self_arg = contract.args[0];
+        let self_ty = self.locals.get_type(self_arg);
+        let encoded_self: vir::Expr = self.encode_prusti_local(self_arg).into();
+        let (closure, closure_ty) = if trait_kind == ty::ClosureKind::FnOnce {
+            (encoded_self, self_ty)
+        } else {
+            let (closure, closure_ty, _) = self
+                .mir_encoder
+                .encode_deref(encoded_self, self_ty)
+                .with_span(span)?;
+            (closure, closure_ty)
+        };
+        if transparent_ty(tcx, closure_ty)
+            .unwrap_or(closure_ty)
+            .is_closure()
+        {
+            // Calls of closures are encoded with the contract of the closure.
+            return Ok(None);
+        }
+
+        let args_arg = contract.args[1];
+        let args_tuple_ty = self.locals.get_type(args_arg);
+        let encoded_args_tuple: vir::Expr = self.encode_prusti_local(args_arg).into();
+        let arg_tys = args_tuple_ty.tuple_fields();
+        let mut encoded_args = vec![];
+        for (index, arg_ty) in arg_tys.iter().enumerate() {
+            let field = self
+                .encoder
+                .encode_raw_ref_field(format!("tuple_{index}"), arg_ty)
+                .with_span(span)?;
+            encoded_args.push(vir::Expr::snap_app(encoded_args_tuple.clone().field(field)));
+        }
+        let result_ty = self.locals.get_type(contract.returned_value);
+        let encoded_closure = vir::Expr::snap_app(closure);
+
+        let Some(pre_label) = pre_label else {
+            let mut args = vec![encoded_closure];
+            args.extend(encoded_args);
+            return self
+                .encoder
+                .encode_closure_spec_func_app(
+                    closure_ty,
+                    arg_tys,
+                    result_ty,
+                    false,
+                    args,
+                    span,
+                    vir::Position::default(),
+                )
+                .map(Some);
+        };
+        let encoded_return: vir::Expr = self.encode_prusti_local(contract.returned_value).into();
+        let mut args = vec![encoded_closure.clone().old(pre_label), encoded_closure];
+        args.extend(encoded_args);
+        args.push(vir::Expr::snap_app(encoded_return));
+        let closure_post = self.encoder.encode_closure_spec_func_app(
+            closure_ty,
+            arg_tys,
+            result_ty,
+            true,
+            args,
+            span,
+            vir::Position::default(),
+        )?;
+        // As in other postconditions, the arguments are read in the state
+        // before the call, while the target of a mutable reference to the
+        // closure is read in the state after the call. The closure arguments
+        // therefore relate its states before and after the call.
+        let spec_args = [(self_arg, self_ty), (args_arg, args_tuple_ty)]
+            .map(|(arg, ty)| (self.encode_prusti_local(arg).into(), ty));
+        self.wrap_arguments_into_old(closure_post, pre_label, &spec_args)
+            .map(Some)
+    }
+
     /// Encode the postcondition with three expressions:
     /// - one for the type encoding
     /// - one for the type invariants
@@ -4617,11 +5996,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         }
 
         // Encode args and return.
-        let encoded_args: Vec<vir::Expr> = contract
-            .args
-            .iter()
-            .map(|local| self.encode_prusti_local(*local).into())
-            .collect();
+        let spec_args = self.encode_spec_args(contract)?;
+        let mut encoded_args: Vec<vir::Expr> =
+            spec_args.iter().map(|(arg, _)| arg.clone()).collect();
+        encoded_args.extend(self.encode_closure_views(contract, Some(pre_label))?);
         trace!(
             "encode_postcondition_expr: encoded_args {:?} ({:?}) as {:?}",
             contract.args,
@@ -4689,10 +6067,29 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             let assertion_span = self.encoder.env().query.get_def_span(typed_assertion);
             func_spec_spans.push(assertion_span);
             let assertion_pos = self.mir_encoder.register_span(assertion_span);
-            assertion =
-                self.wrap_arguments_into_old(assertion, pre_label, contract, &encoded_args)?;
+            assertion = self.wrap_arguments_into_old(assertion, pre_label, &spec_args)?;
+            func_spec.push(assertion.set_default_pos(assertion_pos));
+        }
+        if self.encoder.env().query.is_async_fn(contract.def_id) {
+            let assertion =
+                self.encode_future_captures_args(contract, &encoded_return, &spec_args, pre_label)?;
+            let assertion_span = self.encoder.env().query.get_def_span(contract.def_id);
+            func_spec_spans.push(assertion_span);
+            let assertion_pos = self.mir_encoder.register_span(assertion_span);
             func_spec.push(assertion.set_default_pos(assertion_pos));
         }
+        if let Some(assertion) = self.encode_awaited_postcondition(
+            contract,
+            &encoded_args,
+            &encoded_return,
+            pre_label,
+            span,
+        )? {
+            func_spec.push(assertion);
+        }
+        if let Some(assertion) = self.encode_fn_trait_call_spec(contract, Some(pre_label), span)? {
+            func_spec.push(assertion);
+        }
         let postcondition_span = MultiSpan::from_spans(func_spec_spans);
         let func_spec_pos = self.mir_encoder.register_span(postcondition_span.clone());
 
@@ -5572,7 +6969,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     ),
                 )) = stmt.kind
                 {
-                    if let Some(spec) = self.encoder.get_loop_specs(cl_def_id) {
+                    if let Some(typed::LoopSpecification::Invariant(invariant)) =
+                        self.encoder.get_loop_specs(cl_def_id)
+                    {
                         encoded_specs.push(self.encoder.encode_invariant(
                             self.mir,
                             bbi,
@@ -5580,20 +6979,111 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             cl_substs,
                             true,
                         )?);
-                        let invariant = match spec {
-                            prusti_interface::specs::typed::LoopSpecification::Invariant(inv) => {
-                                inv
-                            }
-                            _ => continue,
-                        };
                         encoded_spec_spans.push(self.encoder.env().tcx().def_span(invariant));
                     }
                 }
             }
         }
+        let encoded_specs = self
+            .replace_loop_iterator(loop_head, encoded_specs)
+            .with_span(MultiSpan::from_spans(encoded_spec_spans.clone()))?;
         Ok((encoded_specs, MultiSpan::from_spans(encoded_spec_spans)))
     }
 
+    /// Encode the `body_variant!(..)` of a loop as its lexicographic
+    /// components, together with its span.
+    fn encode_loop_variant(
+        &self,
+        loop_head: BasicBlockIndex,
+    ) -> SpannedEncodingResult<Option<(Vec<vir::Expr>, Span)>> {
+        for bbi in self.get_loop_spec_blocks(loop_head) {
+            for stmt in &self.mir.basic_blocks[bbi].statements {
+                if let mir::StatementKind::Assign(box (
+                    _,
+                    mir::Rvalue::Aggregate(
+                        box mir::AggregateKind::Closure(cl_def_id, cl_substs),
+                        _,
+                    ),
+                )) = stmt.kind
+                {
+                    if let Some(typed::LoopSpecification::Variant(variant)) =
+                        self.encoder.get_loop_specs(cl_def_id)
+                    {
+                        let span = self.encoder.env().tcx().def_span(variant);
+                        let encoded_variant = self.encoder.encode_invariant(
+                            self.mir,
+                            bbi,
+                            self.proc_def_id,
+                            cl_substs,
+                            true,
+                        )?;
+                        let encoded_variant = self
+                            .replace_loop_iterator(loop_head, vec![encoded_variant])
+                            .with_span(span)?
+                            .pop()
+                            .unwrap();
+                        let variant_ty = self
+                            .encoder
+                            .env()
+                            .query
+                            .get_fn_sig(cl_def_id, cl_substs)
+                            .skip_binder()
+                            .output();
+                        let components =
+                            lexicographic_components(encoded_variant, variant_ty, span)?;
+                        return Ok(Some((components, span)));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Replace the `loop_iterator!` placeholders in the encoded body
+    /// invariants with the iterator of the `for` loop.
+    fn replace_loop_iterator(
+        &self,
+        loop_head: BasicBlockIndex,
+        encoded_specs: Vec<vir::Expr>,
+    ) -> EncodingResult<Vec<vir::Expr>> {
+        struct PlaceholderFinder {
+            found: bool,
+        }
+        impl vir::ExprWalker for PlaceholderFinder {
+            fn walk_local(&mut self, vir::Local { variable, .. }: &vir::Local) {
+                if variable.name == LOOP_ITERATOR_PLACEHOLDER {
+                    self.found = true;
+                }
+            }
+        }
+
+        let encoded_specs =
+            if let Some(iterator) = self.loop_encoder.get_for_loop_iterator(loop_head) {
+                let (encoded_iterator, _, _) = self.mir_encoder.encode_place(iterator)?;
+                let encoded_iterator = encoded_iterator.try_into_expr()?;
+                let placeholder = vir::Expr::local(vir::LocalVar::new(
+                    LOOP_ITERATOR_PLACEHOLDER,
+                    encoded_iterator.get_type().clone(),
+                ));
+                encoded_specs
+                    .into_iter()
+                    .map(|spec| spec.replace_place(&placeholder, &encoded_iterator))
+                    .collect()
+            } else {
+                encoded_specs
+            };
+        let mut finder = PlaceholderFinder { found: false };
+        for spec in &encoded_specs {
+            vir::ExprWalker::walk(&mut finder, spec);
+        }
+        if finder.found {
+            return Err(EncodingError::incorrect(
+                "`loop_iterator!` must be used in a `for` loop and with the type of its iterator",
+            ));
+        }
+        Ok(encoded_specs)
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     fn encode_loop_invariant_exhale_stmts(
         &mut self,
@@ -6000,12 +7490,26 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         location: mir::Location,
     ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
         let span = self.mir_encoder.get_span_of_location(location);
+        if let mir::Operand::Copy(place) | mir::Operand::Move(place) = operand {
+            if let Some(read) = self
+                .mir_encoder
+                .encode_raw_pointer_read(*place, span)
+                .with_span(span)?
+            {
+                let ty = self.mir_encoder.get_operand_ty(operand);
+                return self.encode_copy_value_assign(lhs.clone(), read, ty, location);
+            }
+        }
         let stmts = match operand {
             mir::Operand::Move(place) => {
                 let (src, mut stmts, ty, _) =
                     self.encode_place(*place, ArrayAccessKind::Shared, location)?;
                 let encode_stmts = match ty.kind() {
-                    ty::TyKind::RawPtr(..) | ty::TyKind::Ref(..) => {
+                    ty::TyKind::RawPtr(..) => {
+                        let field = self.encoder.encode_value_field(ty).with_span(span)?;
+                        self.encode_assign_address(lhs.clone(), src.field(field), ty, location)?
+                    }
+                    ty::TyKind::Ref(..) => {
                         // Reborrow.
                         let field = self.encoder.encode_value_field(ty).with_span(span)?;
                         let mut alloc_stmts = self.prepare_assign_target(
@@ -6056,10 +7560,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     self.encode_place(*place, ArrayAccessKind::Shared, location)?;
                 let encode_stmts = match ty.kind() {
                     ty::TyKind::RawPtr(..) => {
-                        return Err(SpannedEncodingError::unsupported(
-                            "raw pointers are not supported",
-                            span,
-                        ));
+                        let field = self.encoder.encode_value_field(ty).with_span(span)?;
+                        self.encode_assign_address(lhs.clone(), src.field(field), ty, location)?
                     }
                     ty::TyKind::Ref(..) => {
                         let loan = self.polonius_info().get_loan_at_location(location);
@@ -6098,6 +7600,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     ty::TyKind::Tuple(elements) if elements.is_empty() => Vec::new(),
                     _ => {
                         let field = self.encoder.encode_value_field(ty).with_span(span)?;
+                        let is_fresh_target =
+                            !self.init_info.is_vir_place_accessible(lhs, location);
                         let mut stmts = self.prepare_assign_target(
                             lhs.clone(),
                             field.clone(),
@@ -6105,9 +7609,29 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             vir::AssignKind::Copy,
                             true,
                         )?;
-                        // TODO Encoding of string literals is not yet supported,
-                        // so do not encode an assignment if the RHS is a string
-                        if !is_str(ty) {
+                        if is_str(ty) {
+                            // String literals are not stored in a local place, so the
+                            // target points to a fresh read-only slice of the literal's
+                            // bytes.
+                            let target = lhs.clone().field(field);
+                            if !is_fresh_target {
+                                stmts.extend(self.encode_havoc(&target).with_span(span)?);
+                                stmts.push(vir::Stmt::Inhale(vir::Inhale {
+                                    expr: vir::Expr::predicate_access_predicate(
+                                        target.get_type().clone(),
+                                        target.clone(),
+                                        vir::PermAmount::Read,
+                                    ),
+                                }));
+                            }
+                            let const_val = self
+                                .encoder
+                                .encode_snapshot_constant(expr)
+                                .with_span(span)?;
+                            stmts.push(vir::Stmt::Inhale(vir::Inhale {
+                                expr: vir::Expr::eq_cmp(vir::Expr::snap_app(target), const_val),
+                            }));
+                        } else {
                             // Initialize the constant
                             let const_val = self
                                 .encoder
@@ -6145,10 +7669,13 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         location: mir::Location,
     ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
         let span = self.mir_encoder.get_span_of_location(location);
-        let encoded_left = self.mir_encoder.encode_operand_expr(left).with_span(span)?;
+        let encoded_left = self
+            .mir_encoder
+            .encode_operand_expr(left, span)
+            .with_span(span)?;
         let encoded_right = self
             .mir_encoder
-            .encode_operand_expr(right)
+            .encode_operand_expr(right, span)
             .with_span(span)?;
         let encoded_value = self
             .mir_encoder
@@ -6169,6 +7696,130 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         self.encode_copy_value_assign2(encoded_lhs, encoded_rhs, field, location)
     }
 
+    /// Store `address` in the raw pointer `encoded_lhs` of type `ty`. Raw
+    /// pointers own only their address, so no permissions are transferred.
+    fn encode_assign_address(
+        &mut self,
+        encoded_lhs: vir::Expr,
+        address: vir::Expr,
+        ty: ty::Ty<'tcx>,
+        location: mir::Location,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
+        let field = self.encoder.encode_value_field(ty).with_span(span)?;
+        let mut stmts = self.prepare_assign_target(
+            encoded_lhs.clone(),
+            field.clone(),
+            location,
+            vir::AssignKind::Ghost,
+            false,
+        )?;
+        stmts.push(vir::Stmt::Assign(vir::Assign {
+            target: encoded_lhs.field(field),
+            source: address,
+            kind: vir::AssignKind::Ghost,
+        }));
+        Ok(stmts)
+    }
+
+    /// Encode `&raw const place` and `&raw mut place`.
+    fn encode_assign_address_of(
+        &mut self,
+        encoded_lhs: vir::Expr,
+        mutability: mir::Mutability,
+        place: mir::Place<'tcx>,
+        ty: ty::Ty<'tcx>,
+        location: mir::Location,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
+        let (encoded_place, mut stmts, place_ty, _) =
+            self.encode_place(place, ArrayAccessKind::Shared, location)?;
+        if !place_ty.is_primitive() {
+            return Err(SpannedEncodingError::unsupported(
+                format!("raw addresses of values of type {place_ty} are not supported"),
+                span,
+            ));
+        }
+        // Accesses through the pointer require the permission of the whole
+        // pointed value, so we make sure that it is folded.
+        let perm_amount = match mutability {
+            mir::Mutability::Mut => vir::PermAmount::Write,
+            mir::Mutability::Not => vir::PermAmount::Read,
+        };
+        let predicate = vir::Expr::pred_permission(encoded_place.clone(), perm_amount).unwrap();
+        let pos = self.mir_encoder.register_span(span);
+        stmts.extend(self.encode_obtain(predicate, pos));
+        stmts.extend(self.encode_assign_address(encoded_lhs, encoded_place, ty, location)?);
+        Ok(stmts)
+    }
+
+    /// Encode `*p = rhs`, where `p` is a raw pointer.
+    ///
+    /// The memory pointed to by `p` is not tracked by the fold-unfold
+    /// algorithm, so the write is encoded as a call of a builtin method. The
+    /// written value is passed through a builtin function whose precondition
+    /// checks that the memory is writable, so that a failure can be reported
+    /// at the right position.
+    fn encode_raw_pointer_write(
+        &mut self,
+        lhs: mir::Place<'tcx>,
+        rhs: &mir::Rvalue<'tcx>,
+        location: mir::Location,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
+        let (address, pointee_ty) = self
+            .mir_encoder
+            .encode_raw_pointer_target(lhs)
+            .with_span(span)?
+            .unwrap();
+        let value = match rhs {
+            mir::Rvalue::Use(operand) => self
+                .mir_encoder
+                .encode_operand_expr(operand, span)
+                .with_span(span)?,
+            _ => {
+                return Err(SpannedEncodingError::unsupported(
+                    "only plain values can be written through raw pointers",
+                    span,
+                ));
+            }
+        };
+        let pointee_pred_type = self.encoder.encode_type(pointee_ty).with_span(span)?;
+        let value_field = self
+            .encoder
+            .encode_value_field(pointee_ty)
+            .with_span(span)?;
+        let (check_name, check_type_arguments) =
+            self.encoder
+                .encode_builtin_function_use(BuiltinFunctionKind::CheckRawPointerWrite {
+                    pointee_pred_type: pointee_pred_type.clone(),
+                    value_field: value_field.clone(),
+                });
+        let checked_value = vir::Expr::func_app(
+            check_name,
+            check_type_arguments,
+            vec![address.clone(), value],
+            vec![
+                vir::LocalVar::new("address", pointee_pred_type.clone()),
+                vir::LocalVar::new("value", value_field.typ.clone()),
+            ],
+            value_field.typ.clone(),
+            self.register_error(span, ErrorCtxt::RawPointerWrite),
+        );
+        let method_name = self
+            .encoder
+            .encode_builtin_method_use(BuiltinMethodKind::WriteRawPointer {
+                pointee_pred_type,
+                value_field,
+            })
+            .with_span(span)?;
+        Ok(vec![vir::Stmt::MethodCall(vir::MethodCall {
+            method_name,
+            arguments: vec![address, checked_value],
+            targets: vec![],
+        })])
+    }
+
     /// Assignment with a(n overflow-)checked binary operation on the RHS.
     /// [encoded_lhs] = [left] [op] [right]
     #[tracing::instrument(level = "trace", skip(self))]
@@ -6187,10 +7838,13 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         } else {
             unreachable!()
         };
-        let encoded_left = self.mir_encoder.encode_operand_expr(left).with_span(span)?;
+        let encoded_left = self
+            .mir_encoder
+            .encode_operand_expr(left, span)
+            .with_span(span)?;
         let encoded_right = self
             .mir_encoder
-            .encode_operand_expr(right)
+            .encode_operand_expr(right, span)
             .with_span(span)?;
         let encoded_value = self
             .mir_encoder
@@ -6278,10 +7932,11 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         ty: ty::Ty<'tcx>,
         location: mir::Location,
     ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
         let encoded_val = self
             .mir_encoder
-            .encode_operand_expr(operand)
-            .with_span(self.mir_encoder.get_span_of_location(location))?;
+            .encode_operand_expr(operand, span)
+            .with_span(span)?;
         let encoded_value = self.mir_encoder.encode_unary_op_expr(op, encoded_val);
         // Initialize `lhs.field`
         self.encode_copy_value_assign(encoded_lhs, encoded_value, ty, location)
@@ -6428,16 +8083,46 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         let span = self.mir_encoder.get_span_of_location(location);
         let loan = self.polonius_info().get_loan_at_location(location);
         let (vir_assign_kind, array_encode_kind) = match mir_borrow_kind {
-            mir::BorrowKind::Shared => (
+            // The shallow borrows that are created when lowering the guards of
+            // a `match` are never read through, so they are encoded like
+            // shared borrows: the borrowed place becomes read-only.
+            mir::BorrowKind::Shared | mir::BorrowKind::Shallow => (
                 vir::AssignKind::SharedBorrow(loan.index().into()),
                 ArrayAccessKind::Shared,
             ),
-            mir::BorrowKind::Mut { .. } => (
-                vir::AssignKind::MutableBorrow(loan.index().into()),
-                ArrayAccessKind::Mutable(Some(loan.index().into()), location),
-            ),
-            _ => return Err(Self::unsupported_borrow_kind(mir_borrow_kind).with_span(span)),
+            // Unique immutable borrows of captured variables are mutable
+            // borrows with `MutBorrowKind::ClosureCapture`.
+            mir::BorrowKind::Mut { .. } => {
+                let assign_kind = if self.polonius_info().is_two_phase_reservation(location) {
+                    vir::AssignKind::TwoPhaseBorrow(loan.index().into())
+                } else {
+                    vir::AssignKind::MutableBorrow(loan.index().into())
+                };
+                (
+                    assign_kind,
+                    ArrayAccessKind::Mutable(Some(loan.index().into()), location),
+                )
+            }
         };
+        if let Some((encoded_base, base_ty, start, end)) =
+            self.mir_encoder.encode_subslice(place).with_span(span)?
+        {
+            if !matches!(mir_borrow_kind, mir::BorrowKind::Shared) {
+                return Err(SpannedEncodingError::unsupported(
+                    "slice patterns can bind the rest of an array or slice only by shared reference",
+                    span,
+                ));
+            }
+            return self.encode_assign_subslice_ref(
+                location,
+                encoded_lhs,
+                ty,
+                encoded_base,
+                base_ty,
+                start,
+                end,
+            );
+        }
         let (encoded_value, mut stmts, _, _) =
             self.encode_place(place, array_encode_kind, location)?;
         // Initialize ref_var.ref_field
@@ -6449,6 +8134,15 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             vir_assign_kind,
             false,
         )?);
+        if let vir::AssignKind::TwoPhaseBorrow(_) = vir_assign_kind {
+            self.two_phase_borrow_at.insert(
+                location,
+                (
+                    encoded_lhs.clone().field(field.clone()),
+                    encoded_value.clone(),
+                ),
+            );
+        }
         stmts.push(vir::Stmt::Assign(vir::Assign {
             target: encoded_lhs.field(field),
             source: encoded_value,
@@ -6462,6 +8156,47 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok(stmts)
     }
 
+    /// Shared borrow of the rest of an array or slice in a slice pattern
+    /// [encoded_lhs] = &[encoded_base][start..end]
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn encode_assign_subslice_ref(
+        &mut self,
+        location: mir::Location,
+        encoded_lhs: vir::Expr,
+        ty: ty::Ty<'tcx>,
+        encoded_base: vir::Expr,
+        base_ty: ty::Ty<'tcx>,
+        start: vir::Expr,
+        end: vir::Expr,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
+        let mut stmts = self.encode_havoc(&encoded_lhs).with_span(span)?;
+        stmts.push(vir_stmt!{ inhale [vir::Expr::pred_permission(encoded_lhs.clone(), vir::PermAmount::Read).unwrap()] });
+        let lhs_slice_expr = self
+            .encoder
+            .encode_value_expr(encoded_lhs.clone(), ty)
+            .with_span(span)?;
+        stmts.extend(
+            self.encode_slice_contents(
+                lhs_slice_expr,
+                ty.peel_refs(),
+                encoded_base,
+                base_ty,
+                start,
+                end,
+            )
+            .with_span(span)?,
+        );
+        self.slice_created_at.insert(location, encoded_lhs);
+        // Store a label for this state
+        let label = self.cfg_method.get_fresh_label_name();
+        debug!("Current loc {:?} has label {}", location, label);
+        self.label_after_location.insert(location, label.clone());
+        stmts.push(vir::Stmt::label(label));
+        Ok(stmts)
+    }
+
     /// Assignment where the RHS is a cast operation
     /// [encoded_lhs] = [operand] as [dst_ty]
     #[tracing::instrument(level = "trace", skip(self))]
@@ -6590,6 +8325,127 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok(stmts)
     }
 
+    /// Encode the coercion of a pointer to a value of a concrete type into a
+    /// pointer to a trait object. Trait objects are opaque, so afterwards only
+    /// the specifications of the trait are known about the pointed-to value.
+    /// Only shared coercions are supported, because the permissions inhaled
+    /// for the trait object are not linked to the borrowed value.
+    fn encode_assign_trait_object(
+        &mut self,
+        encoded_lhs: vir::Expr,
+        operand: &mir::Operand<'tcx>,
+        ty: ty::Ty<'tcx>,
+        location: mir::Location,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
+        let concrete_ty = self
+            .mir_encoder
+            .get_operand_ty(operand)
+            .builtin_deref(true)
+            .unwrap()
+            .ty;
+        let ty::TypeAndMut {
+            ty: trait_object_ty,
+            mutbl,
+        } = ty.builtin_deref(true).unwrap();
+        if mutbl == mir::Mutability::Mut {
+            // The permissions of the trait object would have to be transferred
+            // back to the borrowed value when the loan expires.
+            return Err(SpannedEncodingError::unsupported(
+                format!("coercing a mutable reference into a {ty} is not supported"),
+                span,
+            ));
+        }
+        self.check_behavioural_subtyping(concrete_ty, trait_object_ty, span)?;
+
+        let mut stmts = vec![];
+        let label = self.cfg_method.get_fresh_label_name();
+        stmts.push(vir::Stmt::label(label.clone()));
+        stmts.extend(self.encode_havoc(&encoded_lhs).with_span(span)?);
+        let val_ref_field = self.encoder.encode_value_field(ty).with_span(span)?;
+        let trait_object_expr = encoded_lhs.field(val_ref_field);
+        stmts.push(
+            vir_stmt! { inhale [vir::Expr::FieldAccessPredicate( vir::FieldAccessPredicate {
+                base: Box::new(trait_object_expr.clone()),
+                permission: vir::PermAmount::Write,
+                position: vir::Position::default(),
+            })]},
+        );
+        // A box owns the value behind the trait object, which can therefore be
+        // mutated through it. The value was moved into the box, so there is
+        // nothing to restore afterwards.
+        let permission = if ty.is_box() {
+            vir::PermAmount::Write
+        } else {
+            vir::PermAmount::Read
+        };
+        let trait_object_perm = self
+            .mir_encoder
+            .encode_place_predicate_permission(trait_object_expr, permission)
+            .unwrap();
+        stmts.push(vir_stmt! { inhale [trait_object_perm] });
+        self.label_after_location.insert(location, label);
+        Ok(stmts)
+    }
+
+    /// Check that the implementation of the trait of `trait_object_ty` for
+    /// `concrete_ty` can be used through a trait object, i.e. that calls
+    /// verified against the specifications of the trait are also correct for
+    /// the implementation. Refined specifications are checked against the
+    /// trait when the implementation is verified, so this is only a problem
+    /// when the implementation is not verified.
+    fn check_behavioural_subtyping(
+        &self,
+        concrete_ty: ty::Ty<'tcx>,
+        trait_object_ty: ty::Ty<'tcx>,
+        span: Span,
+    ) -> SpannedEncodingResult<()> {
+        let tcx = self.encoder.env().tcx();
+        let ty::TyKind::Dynamic(predicates, ..) = trait_object_ty.kind() else {
+            unreachable!("not a trait object: {:?}", trait_object_ty);
+        };
+        let Some(principal) = predicates.principal() else {
+            // Only auto traits, which have no methods.
+            return Ok(());
+        };
+        let trait_ref = principal.with_self_ty(tcx, concrete_ty).skip_binder();
+        for item in tcx.associated_items(trait_ref.def_id).in_definition_order() {
+            // Methods with generic parameters cannot be called through a trait object.
+            if item.kind != ty::AssocKind::Fn
+                || tcx.generics_of(item.def_id).count() != trait_ref.args.len()
+            {
+                continue;
+            }
+            let (impl_def_id, impl_substs) = self.encoder.env().query.resolve_method_call(
+                self.proc_def_id,
+                item.def_id,
+                trait_ref.args,
+            );
+            if impl_def_id == item.def_id {
+                // The default implementation, which has the trait's specification.
+                continue;
+            }
+            let Some(specification) = self.encoder.get_procedure_specs(impl_def_id, impl_substs)
+            else {
+                continue;
+            };
+            let is_refined = matches!(specification.pres, SpecificationItem::Refined(..))
+                || matches!(specification.posts, SpecificationItem::Refined(..));
+            if is_refined && (!impl_def_id.is_local() || self.encoder.is_trusted(impl_def_id, None))
+            {
+                return Err(SpannedEncodingError::unsupported(
+                    format!(
+                        "the refined specification of `{}` cannot be checked against the \
+                        specification of the trait, because the implementation is not verified",
+                        self.encoder.env().name.get_absolute_item_name(impl_def_id),
+                    ),
+                    span,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     fn encode_assign_sequence_len(
         &mut self,
@@ -6645,7 +8501,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
         let encoded_operand = self
             .mir_encoder
-            .encode_operand_expr(operand)
+            .encode_operand_expr(operand, span)
             .with_span(span)?;
         let len: usize = self
             .encoder
@@ -6747,6 +8603,74 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok(stmts)
     }
 
+    /// Check that the union fields read at `location` are the active ones.
+    fn encode_union_field_checks(
+        &mut self,
+        location: mir::Location,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let span = self.mir_encoder.get_span_of_location(location);
+        let mut stmts = vec![];
+        for (union_place, field) in self
+            .mir_encoder
+            .get_union_field_accesses_at_location(location)
+        {
+            let (encoded_union, pre_stmts, union_ty, _) =
+                self.encode_place(union_place, ArrayAccessKind::Shared, location)?;
+            stmts.extend(pre_stmts);
+            let adt_def = union_ty.ty_adt_def().unwrap();
+            if adt_def.non_enum_variant().fields.len() == 1 {
+                // The only field is always the active one.
+                continue;
+            }
+            let discriminant = self
+                .encoder
+                .encode_discriminant_func_app(encoded_union, adt_def)?;
+            stmts.push(vir::Stmt::Assert(vir::Assert {
+                expr: vir::Expr::eq_cmp(discriminant, field.index().into()),
+                position: self.register_error(span, ErrorCtxt::UnionFieldRead),
+            }));
+        }
+        Ok(stmts)
+    }
+
+    /// Make `field` the active field of the union `encoded_union`. The value
+    /// of the union is forgotten, because the new active field is going to
+    /// be overwritten.
+    fn encode_set_union_active_field(
+        &mut self,
+        encoded_union: vir::Expr,
+        adt_def: ty::AdtDef<'tcx>,
+        field: FieldIdx,
+        is_initialized: bool,
+        span: Span,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let permission = self
+            .mir_encoder
+            .encode_place_predicate_permission(encoded_union.clone(), vir::PermAmount::Write)
+            .unwrap();
+        let mut stmts = if is_initialized {
+            vec![
+                vir::Stmt::Exhale(vir::Exhale {
+                    expr: permission.clone(),
+                    position: self.mir_encoder.register_span(span),
+                }),
+                vir::Stmt::Inhale(vir::Inhale { expr: permission }),
+            ]
+        } else {
+            self.encode_havoc_and_initialization(&encoded_union)
+                .with_span(span)?
+        };
+        if adt_def.non_enum_variant().fields.len() != 1 {
+            let discriminant = self
+                .encoder
+                .encode_discriminant_func_app(encoded_union, adt_def)?;
+            stmts.push(vir::Stmt::Inhale(vir::Inhale {
+                expr: vir::Expr::eq_cmp(discriminant, field.index().into()),
+            }));
+        }
+        Ok(stmts)
+    }
+
     /// Prepare the ``dst`` to be copy target:
     ///
     /// 1.  Havoc and allocate if it is not yet allocated.
@@ -6787,8 +8711,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 }
                 vir::AssignKind::Move
                 | vir::AssignKind::MutableBorrow(_)
-                | vir::AssignKind::SharedBorrow(_) => {}
-                vir::AssignKind::Ghost => unreachable!(),
+                | vir::AssignKind::TwoPhaseBorrow(_)
+                | vir::AssignKind::SharedBorrow(_)
+                // Raw pointers are assigned with ghost assignments.
+                | vir::AssignKind::Ghost => {}
             }
             debug!("alloc_stmts = {}", alloc_stmts.iter().to_string());
             Ok(alloc_stmts)
@@ -6920,15 +8846,35 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 }
             }
 
-            mir::AggregateKind::Adt(adt_did, variant_index, subst, _, _) => {
+            mir::AggregateKind::Adt(adt_did, _, subst, _, Some(active_field_index)) => {
+                // A union, whose only operand initializes the active field.
                 let tcx = self.encoder.env().tcx();
                 let adt_def = tcx.adt_def(adt_did);
-                if adt_def.is_union() {
-                    return Err(SpannedEncodingError::unsupported(
-                        "unions are not supported",
-                        span,
-                    ));
+                if adt_def.non_enum_variant().fields.len() != 1 {
+                    // dst was havocked, so it is safe to assume the active field here.
+                    let discriminant = self
+                        .encoder
+                        .encode_discriminant_func_app(dst.clone(), adt_def)?;
+                    stmts.push(vir::Stmt::Inhale(vir::Inhale {
+                        expr: vir::Expr::eq_cmp(discriminant, active_field_index.index().into()),
+                    }));
                 }
+                let field = &adt_def.non_enum_variant().fields[active_field_index];
+                let field_name = field.ident(tcx).to_string();
+                let encoded_field = self
+                    .encoder
+                    .encode_struct_field(&field_name, field.ty(tcx, subst))
+                    .with_span(span)?;
+                stmts.extend(self.encode_assign_operand(
+                    &dst.clone().variant(&field_name).field(encoded_field),
+                    &operands[0usize.into()],
+                    location,
+                )?);
+            }
+
+            mir::AggregateKind::Adt(adt_did, variant_index, subst, _, _) => {
+                let tcx = self.encoder.env().tcx();
+                let adt_def = tcx.adt_def(adt_did);
                 let num_variants = adt_def.variants().len();
                 let variant_def = &adt_def.variants()[variant_index];
                 let mut dst_base = dst.clone();
@@ -7034,7 +8980,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
 
                     let encoded_operand = self
                         .mir_encoder
-                        .encode_operand_expr(operand)
+                        .encode_operand_expr(operand, span)
                         .with_span(span)?;
 
                     stmts.push(
@@ -7045,11 +8991,22 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 }
             }
 
-            mir::AggregateKind::Generator(..) => {
-                return Err(SpannedEncodingError::unsupported(
-                    "construction of generators is not supported",
-                    span,
-                ));
+            mir::AggregateKind::Generator(_, substs, _) => {
+                // Like a closure, a generator is constructed from its upvars.
+                let gen_substs = substs.as_generator();
+                for (field_index, field_ty) in gen_substs.upvar_tys().iter().enumerate() {
+                    let operand = &operands[field_index.into()];
+                    let field_name = format!("closure_{field_index}");
+                    let encoded_field = self
+                        .encoder
+                        .encode_raw_ref_field(field_name, field_ty)
+                        .with_span(span)?;
+                    stmts.extend(self.encode_assign_operand(
+                        &dst.clone().field(encoded_field),
+                        operand,
+                        location,
+                    )?);
+                }
             }
         }
 
@@ -7366,6 +9323,32 @@ fn is_str(ty: ty::Ty<'_>) -> bool {
     }
 }
 
+/// Splits a termination measure or a loop variant of type `ty` into its
+/// lexicographic components. The snapshot of a tuple is the application of its
+/// constructor to the components. Every component must be an integer.
+fn lexicographic_components(
+    expression: vir::Expr,
+    ty: ty::Ty<'_>,
+    span: Span,
+) -> SpannedEncodingResult<Vec<vir::Expr>> {
+    let components = match (ty.kind(), expression) {
+        (ty::TyKind::Tuple(_), vir::Expr::DomainFuncApp(vir::DomainFuncApp { arguments, .. })) => {
+            arguments
+        }
+        (_, expression) => vec![expression],
+    };
+    if components
+        .iter()
+        .any(|component| component.get_type() != &vir::Type::Int)
+    {
+        return Err(SpannedEncodingError::unsupported(
+            "termination measures must be integers or tuples of integers",
+            span,
+        ));
+    }
+    Ok(components)
+}
+
 type PreconditionWeakening = RefinementCheckExpr;
 type PostconditionStrengthening = RefinementCheckExpr;
 struct RefinementCheckExpr {
@@ -175,7 +175,9 @@ impl StmtWalker for VarDependencyCollector {
             entry.extend(dependents.iter().cloned());
         }
         match kind {
-            vir::AssignKind::SharedBorrow(_) | vir::AssignKind::MutableBorrow(_) => {
+            vir::AssignKind::SharedBorrow(_)
+            | vir::AssignKind::MutableBorrow(_)
+            | vir::AssignKind::TwoPhaseBorrow(_) => {
                 if let vir::Expr::Field(vir::FieldExpr {
                     base:
                         box vir::Expr::Local(vir::Local {
@@ -238,7 +240,11 @@ fn translate_type<'tcx>(encoder: &Encoder<'_, 'tcx>, typ: vir::Type) -> vir::Typ
             let mir_typ = encoder.decode_type_predicate_type(&typ).unwrap(); // FIXME: unwrap
             encoder.encode_snapshot_type(mir_typ).unwrap() // FIXME: unwrap
         }
-        vir::Type::Ref | vir::Type::Seq(_) | vir::Type::Map(_) => unreachable!(),
+        vir::Type::Ref
+        | vir::Type::Seq(_)
+        | vir::Type::Set(_)
+        | vir::Type::MultiSet(_)
+        | vir::Type::Map(_) => unreachable!(),
     }
 }
 
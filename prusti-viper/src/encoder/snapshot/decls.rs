@@ -53,7 +53,7 @@ pub(super) enum Snapshot {
         /// result Seq[elem_ty]
         slice_helper: vir::FunctionIdentifier,
         cons: vir::DomainFunc,
-        _uncons: vir::DomainFunc,
+        uncons: vir::DomainFunc,
         read: vir::DomainFunc,
         len: vir::DomainFunc,
     },
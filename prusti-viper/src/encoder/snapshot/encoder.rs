@@ -10,7 +10,13 @@ use crate::{
         errors::{EncodingError, EncodingResult},
         foldunfold,
         high::types::HighTypeEncoderInterface,
-        mir::{sequences::MirSequencesEncoderInterface, types::MirTypeEncoderInterface},
+        mir::{
+            sequences::MirSequencesEncoderInterface,
+            types::{
+                is_mathematical_int_ty, is_task_context_ty, string_bytes_ty, transparent_ty,
+                MirTypeEncoderInterface,
+            },
+        },
         snapshot::{decls::Snapshot, patcher::SnapshotPatcher},
         utils::range_extract,
         Encoder,
@@ -213,6 +219,9 @@ impl SnapshotEncoder {
                         expr,
                         vir::Field::new("val_int", Type::Int),
                     ),
+                    ty::TyKind::Adt(..) if is_mathematical_int_ty(encoder.env().tcx(), ty) => {
+                        Expr::field(expr, vir::Field::new("val_int", Type::Int))
+                    }
                     ty::TyKind::Float(ty::FloatTy::F32) => Expr::field(
                         expr,
                         vir::Field::new("val_float32", Type::Float(F32)),
@@ -240,6 +249,8 @@ impl SnapshotEncoder {
             | Type::Int
             | Type::Map(..)
             | Type::Seq(..)
+            | Type::Set(..)
+            | Type::MultiSet(..)
             | Type::Float(_) => Ok(expr),
 
             _ => Err(EncodingError::internal(
@@ -397,7 +408,8 @@ impl SnapshotEncoder {
         }
     }
 
-    /// Constructs a snapshot. The `variant` is needed only if `ty` is an enum.
+    /// Constructs a snapshot. The `variant` is needed only if `ty` is an enum
+    /// or a union.
     /// The result is not necessarily a domain; it could be a primitive type.
     pub fn encode_constructor<'p, 'v: 'p, 'tcx: 'v>(
         &mut self,
@@ -462,9 +474,8 @@ impl SnapshotEncoder {
             Snapshot::Complex { .. } => {
                 unimplemented!();
             }
-            Snapshot::Array { uncons, .. } => Ok(uncons.apply(args)),
-            Snapshot::Slice { .. } => {
-                unimplemented!()
+            Snapshot::Array { uncons, .. } | Snapshot::Slice { uncons, .. } => {
+                Ok(uncons.apply(args))
             }
             _ => Err(EncodingError::internal(format!(
                 "invalid constructor (not Complex): {ty}"
@@ -575,7 +586,11 @@ impl SnapshotEncoder {
         encoder: &'p Encoder<'v, 'tcx>,
         ty: ty::Ty<'tcx>,
     ) -> EncodingResult<Snapshot> {
-        let ty = strip_refs_and_boxes(ty);
+        let mut ty = strip_refs_and_boxes(ty);
+        while let Some(inner_ty) = transparent_ty(encoder.env().tcx(), ty) {
+            ty = strip_refs_and_boxes(inner_ty);
+        }
+        let ty = string_bytes_ty(encoder.env().tcx(), ty).unwrap_or(ty);
         let predicate_type = encoder.encode_type(ty)?;
 
         // was the snapshot for the type already encoded?
@@ -595,11 +610,18 @@ impl SnapshotEncoder {
             ty::TyKind::Int(_) => Type::Int,
             ty::TyKind::Uint(_) => Type::Int,
             ty::TyKind::Char => Type::Int,
+            ty::TyKind::Adt(..) if is_mathematical_int_ty(encoder.env().tcx(), ty) => Type::Int,
             ty::TyKind::Float(ty::FloatTy::F32) => Type::Float(vir::Float::F32),
             ty::TyKind::Float(ty::FloatTy::F64) => Type::Float(vir::Float::F64),
             ty::TyKind::Bool => Type::Bool,
 
-            _ if predicate_type.is_map() || predicate_type.is_seq() => predicate_type.clone(),
+            _ if predicate_type.is_map()
+                || predicate_type.is_seq()
+                || predicate_type.is_set()
+                || predicate_type.is_multiset() =>
+            {
+                predicate_type.clone()
+            }
             // Param(_) | Adt(_) | Tuple(_), arrays and slices and unsupported types
             _ => predicate_type.convert_to_snapshot(),
         };
@@ -641,12 +663,20 @@ impl SnapshotEncoder {
             // since all encoding goes through [encode_type] first, we should
             // never get a box or reference here
             _ if ty.is_box() => unreachable!(),
-            _ if predicate_type.is_map() => Ok(Snapshot::Primitive(predicate_type.clone())),
+            _ if predicate_type.is_map()
+                || predicate_type.is_set()
+                || predicate_type.is_multiset() =>
+            {
+                Ok(Snapshot::Primitive(predicate_type.clone()))
+            }
             ty::TyKind::Ref(_, _, _) => unreachable!(),
 
             ty::TyKind::Int(_) | ty::TyKind::Uint(_) | ty::TyKind::Char => {
                 Ok(Snapshot::Primitive(Type::Int))
             }
+            ty::TyKind::Adt(..) if is_mathematical_int_ty(tcx, ty) => {
+                Ok(Snapshot::Primitive(Type::Int))
+            }
             ty::TyKind::Float(ty::FloatTy::F32) => {
                 Ok(Snapshot::Primitive(Type::Float(vir::Float::F32)))
             }
@@ -711,6 +741,36 @@ impl SnapshotEncoder {
                     predicate_type,
                 )
             }
+            ty::TyKind::Generator(_def_id, substs, _movability) => {
+                let mut fields = vec![];
+                for (field_num, field_ty) in substs.as_generator().upvar_tys().iter().enumerate() {
+                    let field_name = format!("closure_{field_num}");
+                    fields.push(SnapshotField {
+                        name: field_name.to_string(),
+                        access: self.snap_app(
+                            encoder,
+                            Expr::field(
+                                arg_expr.clone(),
+                                encoder.encode_raw_ref_field(field_name.to_string(), field_ty)?,
+                            ),
+                        )?,
+                        mir_type: field_ty,
+                        typ: self.encode_type(encoder, field_ty)?,
+                    });
+                }
+                self.encode_complex(
+                    encoder,
+                    vec![SnapshotVariant {
+                        discriminant: -1,
+                        fields,
+                        name: None,
+                    }],
+                    predicate_type,
+                )
+            }
+            ty::TyKind::Adt(..) if is_task_context_ty(tcx, ty) => {
+                self.encode_abstract(predicate_type)
+            }
             ty::TyKind::Adt(adt_def, substs) if adt_def.is_struct() => {
                 let mut fields = vec![];
                 for field in adt_def.all_fields() {
@@ -804,6 +864,33 @@ impl SnapshotEncoder {
                 }
                 self.encode_complex(encoder, variants, predicate_type)
             }
+            ty::TyKind::Adt(adt_def, substs) if adt_def.is_union() => {
+                // Each field of a union is a variant whose discriminant is
+                // the index of the field.
+                let mut variants = vec![];
+                for (field_idx, field) in adt_def.non_enum_variant().fields.iter().enumerate() {
+                    let field_name = field.ident(tcx).to_string();
+                    let field_ty = field.ty(tcx, substs);
+                    let field_ty = tcx
+                        .try_normalize_erasing_regions(ty::ParamEnv::reveal_all(), field_ty)
+                        .unwrap_or(field_ty);
+                    let field_access = Expr::field(
+                        arg_expr.clone().variant(&field_name),
+                        encoder.encode_struct_field(&field_name, field_ty)?,
+                    );
+                    variants.push(SnapshotVariant {
+                        discriminant: field_idx as i128,
+                        fields: vec![SnapshotField {
+                            name: encode_field_name(&field_name),
+                            access: self.snap_app(encoder, field_access)?,
+                            mir_type: field_ty,
+                            typ: self.encode_type(encoder, field_ty)?,
+                        }],
+                        name: Some(field_name),
+                    });
+                }
+                self.encode_complex(encoder, variants, predicate_type)
+            }
 
             ty::TyKind::Array(elem_ty, ..) => {
                 let elem_snap_ty = self.encode_type(encoder, *elem_ty)?;
@@ -1343,7 +1430,7 @@ impl SnapshotEncoder {
                     _slice_collect_func: self.insert_function(slice_collect_func),
                     slice_helper: self.insert_function(slice_helper),
                     cons,
-                    _uncons: uncons,
+                    uncons,
                     read,
                     len,
                 })
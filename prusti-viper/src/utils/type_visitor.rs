@@ -40,7 +40,9 @@ pub trait TypeVisitor<'tcx>: Sized {
             TyKind::Never => self.visit_never(),
             TyKind::Param(param) => self.visit_param(param),
             TyKind::Alias(AliasKind::Projection, alias_ty) => self.visit_projection(alias_ty),
+            TyKind::Alias(AliasKind::Opaque, alias_ty) => self.visit_opaque(alias_ty),
             TyKind::Closure(def_id, substs) => self.visit_closure(def_id, substs),
+            TyKind::Generator(def_id, substs, _) => self.visit_generator(def_id, substs),
             TyKind::FnDef(def_id, substs) => self.visit_fndef(def_id, substs),
             TyKind::Array(ty, len) => self.visit_array(ty, len),
             ref x => self.visit_unsupported_sty(x),
@@ -85,6 +87,11 @@ pub trait TypeVisitor<'tcx>: Sized {
         Ok(())
     }
 
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn visit_opaque(&mut self, alias_ty: AliasTy<'tcx>) -> Result<(), Self::Error> {
+        walk_opaque(self, alias_ty)
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     fn visit_adt(
         &mut self,
@@ -150,6 +157,15 @@ pub trait TypeVisitor<'tcx>: Sized {
         walk_closure(self, def_id, substs)
     }
 
+    #[tracing::instrument(level = "trace", skip(self))]
+    fn visit_generator(
+        &mut self,
+        def_id: DefId,
+        substs: GenericArgsRef<'tcx>,
+    ) -> Result<(), Self::Error> {
+        walk_generator(self, def_id, substs)
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     fn visit_fndef(
         &mut self,
@@ -237,16 +253,43 @@ pub fn walk_closure<'tcx, E, V: TypeVisitor<'tcx, Error = E>>(
     substs: GenericArgsRef<'tcx>,
 ) -> Result<(), E> {
     let cl_substs = substs.as_closure();
-    // TODO: when are there bound typevars? can type visitor deal with generics?
-    let fn_sig = cl_substs.sig().no_bound_vars().ok_or_else(|| {
-        visitor.unsupported("higher-ranked lifetimes and types are not supported")
-    })?;
+    // The signature of a closure like `|x: &i32| ..` is higher-ranked. Its
+    // late-bound regions are kept bound, so that visitors can tell them apart
+    // and relate the ones of the inputs to the ones of the output; each call
+    // instantiates them with fresh regions.
+    let fn_sig = cl_substs.sig().skip_binder();
     for ty in fn_sig.inputs() {
         visitor.visit_ty(*ty)?;
     }
     visitor.visit_ty(fn_sig.output())
 }
 
+/// Only the opaque types of futures are supported: they are visited as the
+/// generators that implement them.
+pub fn walk_opaque<'tcx, E, V: TypeVisitor<'tcx, Error = E>>(
+    visitor: &mut V,
+    alias_ty: AliasTy<'tcx>,
+) -> Result<(), E> {
+    let tcx = visitor.tcx();
+    let hidden_ty = tcx.type_of(alias_ty.def_id).instantiate(tcx, alias_ty.args);
+    if hidden_ty.is_generator() {
+        visitor.visit_ty(hidden_ty)
+    } else {
+        visitor.visit_unsupported_sty(&TyKind::Alias(AliasKind::Opaque, alias_ty))
+    }
+}
+
+pub fn walk_generator<'tcx, E, V: TypeVisitor<'tcx, Error = E>>(
+    visitor: &mut V,
+    _def_id: DefId,
+    substs: GenericArgsRef<'tcx>,
+) -> Result<(), E> {
+    for ty in substs.as_generator().upvar_tys() {
+        visitor.visit_ty(ty)?;
+    }
+    Ok(())
+}
+
 pub fn walk_fndef<'tcx, E, V: TypeVisitor<'tcx, Error = E>>(
     visitor: &mut V,
     _def_id: DefId,
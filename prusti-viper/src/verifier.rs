@@ -9,10 +9,19 @@ use crate::encoder::{
     Encoder,
 };
 use ::log::{debug, error, info};
+use futures::{
+    channel::mpsc,
+    executor::block_on_stream,
+    future,
+    stream::{self, Stream, StreamExt},
+};
 use once_cell::sync::Lazy;
 use prusti_common::{
     config,
-    report::log,
+    report::{
+        log,
+        verification_report::{self, ErrorReport, ItemReport, ItemResult, ReportSpan},
+    },
     vir::{optimizations::optimize_program, program::Program},
     Stopwatch,
 };
@@ -22,12 +31,21 @@ use prusti_interface::{
     specs::typed,
     PrustiError,
 };
-use prusti_rustc_interface::span::DUMMY_SP;
+use prusti_rustc_interface::span::{def_id::LOCAL_CRATE, Span, DUMMY_SP};
 use prusti_server::{
     process_verification_request, spawn_server_thread, tokio::runtime::Builder, PrustiClient,
     VerificationRequest, ViperBackendConfig,
 };
-use viper::{self, PersistentCache, Viper};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{mpsc as std_mpsc, Arc, Mutex},
+    task::Poll,
+    thread,
+    time::{Duration, Instant},
+};
+use viper::{self, Cache, CacheMiss, Fingerprint, PersistentCache, VerificationBackend, Viper};
 use vir_crate::common::check_mode::CheckMode;
 
 /// A verifier is an object for verifying a single crate, potentially
@@ -38,6 +56,9 @@ where
 {
     env: &'v Environment<'tcx>,
     encoder: Encoder<'v, 'tcx>,
+    /// The outcome of each verified item, collected when a verification
+    /// report has been requested.
+    item_reports: Vec<ItemReport>,
 }
 
 impl<'v, 'tcx> Verifier<'v, 'tcx> {
@@ -45,9 +66,15 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
         Verifier {
             env,
             encoder: Encoder::new(env, def_spec),
+            item_reports: vec![],
         }
     }
 
+    /// The outcome of each item verified so far, for the verification report.
+    pub fn item_reports(&self) -> &[ItemReport] {
+        &self.item_reports
+    }
+
     #[tracing::instrument(name = "prusti_viper::verify", level = "info", skip(self))]
     pub fn verify(&mut self, task: &VerificationTask<'tcx>) -> VerificationResult {
         let mut stopwatch = Stopwatch::start("prusti-viper", "encoding to Viper");
@@ -93,58 +120,126 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
         };
         programs.extend(self.encoder.get_core_proof_programs());
 
+        let is_report_enabled = verification_report::is_report_enabled();
+        let mut program_def_ids = FxHashMap::default();
+        if is_report_enabled {
+            for &proc_id in &task.procedures {
+                program_def_ids.insert(self.env.name.get_unique_item_name(proc_id), proc_id);
+                program_def_ids.insert(self.env.name.get_absolute_item_name(proc_id), proc_id);
+            }
+        }
+
         stopwatch.start_next("verifying Viper program");
-        let verification_results = verify_programs(self.env, programs);
+        let mut result = VerificationResult::Success;
+        let mut verified_def_ids = FxHashSet::default();
+        // Report the errors of each program as soon as its verification finishes.
+        for program_result in block_on_stream(verify_programs(self.env, programs)) {
+            let (method_result, error_reports) =
+                self.report_result(&program_result.program_name, program_result.result);
+            if let VerificationResult::Failure = method_result {
+                result = VerificationResult::Failure;
+            }
+            if is_report_enabled {
+                let def_id = program_def_ids.get(&program_result.program_name).copied();
+                verified_def_ids.extend(def_id);
+                let item_report = ItemReport {
+                    def_path: def_id.map_or_else(
+                        || program_result.program_name.clone(),
+                        |def_id| self.env.name.get_item_def_path(def_id),
+                    ),
+                    span: def_id
+                        .and_then(|def_id| self.report_span(self.env.query.get_def_span(def_id))),
+                    program: Some(program_result.program_name),
+                    backend: Some(program_result.backend.to_string()),
+                    duration_ms: Some(program_result.duration.as_millis() as u64),
+                    cache_hit: program_result.cache_hit,
+                    cache_miss_reason: program_result
+                        .cache_miss
+                        .map(|cache_miss| cache_miss.to_string()),
+                    result: match method_result {
+                        VerificationResult::Success => ItemResult::Verified,
+                        VerificationResult::Failure => ItemResult::Failed,
+                    },
+                    errors: error_reports,
+                };
+                self.item_reports.push(item_report);
+            }
+        }
         stopwatch.finish();
 
-        // Group verification results
-        let mut verification_errors: Vec<_> = vec![];
-        let mut consistency_errors: Vec<_> = vec![];
-        let mut java_exceptions: Vec<_> = vec![];
-        for (method_name, result) in verification_results.into_iter() {
-            match result {
-                viper::VerificationResult::Success => {}
-                viper::VerificationResult::ConsistencyErrors(errors) => {
-                    for error in errors.into_iter() {
-                        consistency_errors.push((method_name.clone(), error));
-                    }
-                }
-                viper::VerificationResult::Failure(errors) => {
-                    for error in errors.into_iter() {
-                        verification_errors.push((method_name.clone(), error));
-                    }
-                }
-                viper::VerificationResult::JavaException(exception) => {
-                    java_exceptions.push((method_name, exception));
+        if is_report_enabled {
+            // Trusted items and items whose encoding failed have no program.
+            for &proc_id in &task.procedures {
+                if !verified_def_ids.contains(&proc_id) {
+                    let item_report = ItemReport {
+                        def_path: self.env.name.get_item_def_path(proc_id),
+                        span: self.report_span(self.env.query.get_def_span(proc_id)),
+                        program: None,
+                        backend: None,
+                        duration_ms: None,
+                        cache_hit: None,
+                        cache_miss_reason: None,
+                        result: ItemResult::NotVerified,
+                        errors: vec![],
+                    };
+                    self.item_reports.push(item_report);
                 }
             }
         }
 
-        // Convert verification results to Prusti errors
-        let error_manager = self.encoder.error_manager();
-        let mut result = VerificationResult::Success;
-
-        for (method, error) in consistency_errors.into_iter() {
-            PrustiError::internal(
-                format!("consistency error in {method}: {error}"),
-                DUMMY_SP.into(),
-            )
-            .emit(&self.env.diagnostic);
+        if encoding_errors_count != 0 {
             result = VerificationResult::Failure;
         }
 
-        for (method, exception) in java_exceptions.into_iter() {
-            error!("Java exception: {}", exception.get_stack_trace());
-            PrustiError::internal(format!("in {method}: {exception}"), DUMMY_SP.into())
-                .emit(&self.env.diagnostic);
-            result = VerificationResult::Failure;
-        }
+        result
+    }
+
+    /// Convert the verification result of a single program to Prusti errors
+    /// and emit them. Returns the errors in the form of the verification
+    /// report.
+    fn report_result(
+        &self,
+        method: &str,
+        result: viper::VerificationResult,
+    ) -> (VerificationResult, Vec<ErrorReport>) {
+        let verification_errors = match result {
+            viper::VerificationResult::Success => return (VerificationResult::Success, vec![]),
+            viper::VerificationResult::ConsistencyErrors(errors) => {
+                let mut error_reports = vec![];
+                for error in errors.into_iter() {
+                    let message = format!("consistency error in {method}: {error}");
+                    error_reports.push(ErrorReport {
+                        kind: "consistency_error".to_string(),
+                        message: message.clone(),
+                        span: None,
+                        counterexample: None,
+                    });
+                    PrustiError::internal(message, DUMMY_SP.into()).emit(&self.env.diagnostic);
+                }
+                return (VerificationResult::Failure, error_reports);
+            }
+            viper::VerificationResult::JavaException(exception) => {
+                error!("Java exception: {}", exception.get_stack_trace());
+                let message = format!("in {method}: {exception}");
+                let error_report = ErrorReport {
+                    kind: "java_exception".to_string(),
+                    message: message.clone(),
+                    span: None,
+                    counterexample: None,
+                };
+                PrustiError::internal(message, DUMMY_SP.into()).emit(&self.env.diagnostic);
+                return (VerificationResult::Failure, vec![error_report]);
+            }
+            viper::VerificationResult::Failure(errors) => errors,
+        };
 
         // Report verification errors
+        let error_manager = self.encoder.error_manager();
         let mut prusti_errors: Vec<_> = vec![];
-        for (method, verification_error) in verification_errors.into_iter() {
+        for verification_error in verification_errors.into_iter() {
             debug!("Verification error in {}: {:?}", method, verification_error);
             let mut prusti_error = error_manager.translate_verification_error(&verification_error);
+            let notes_without_counterexample = prusti_error.notes().len();
 
             // annotate with counterexample, if requested
             if config::counterexample() {
@@ -187,34 +282,106 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
                 }
             }
 
-            prusti_errors.push(prusti_error);
+            let counterexample = prusti_error.notes()[notes_without_counterexample..]
+                .iter()
+                .map(|(note, _)| note.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let counterexample = (!counterexample.is_empty()).then_some(counterexample);
+            prusti_errors.push((verification_error.full_id, counterexample, prusti_error));
         }
-        prusti_errors.sort();
+        prusti_errors.sort_by(|(_, _, a), (_, _, b)| a.cmp(b));
 
-        for prusti_error in prusti_errors {
+        let mut result = VerificationResult::Success;
+        let mut error_reports = vec![];
+        for (kind, counterexample, prusti_error) in prusti_errors {
             debug!("Prusti error: {:?}", prusti_error);
             if prusti_error.is_disabled() {
                 prusti_error.cancel();
             } else {
+                error_reports.push(ErrorReport {
+                    kind,
+                    message: prusti_error.message().to_string(),
+                    span: prusti_error
+                        .span()
+                        .primary_span()
+                        .and_then(|span| self.report_span(span)),
+                    counterexample,
+                });
                 prusti_error.emit(&self.env.diagnostic);
             }
             result = VerificationResult::Failure;
         }
+        (result, error_reports)
+    }
 
-        if encoding_errors_count != 0 {
-            result = VerificationResult::Failure;
+    /// Convert a span to a source location of the verification report.
+    fn report_span(&self, span: Span) -> Option<ReportSpan> {
+        if span.is_dummy() {
+            return None;
         }
+        let source_map = self.env.tcx().sess.source_map();
+        let span = span.source_callsite();
+        let start = source_map.lookup_char_pos(span.lo());
+        let end = source_map.lookup_char_pos(span.hi());
+        Some(ReportSpan {
+            file: start.file.name.prefer_local().to_string(),
+            start_line: start.line,
+            start_column: start.col_display + 1,
+            end_line: end.line,
+            end_column: end.col_display + 1,
+        })
+    }
+}
 
+/// The result of the verification of a single Viper program.
+struct ProgramVerificationResult {
+    program_name: String,
+    backend: VerificationBackend,
+    result: viper::VerificationResult,
+    /// The time spent to obtain the result, including the lookup in the cache.
+    duration: Duration,
+    /// Whether the result was taken from the cache. `None` if unknown, e.g.
+    /// when the program has been verified by a server or the cache is disabled.
+    cache_hit: Option<bool>,
+    /// Why the result could not be taken from the cache.
+    cache_miss: Option<CacheMiss>,
+}
+
+/// A cache that records the outcome of the last lookup.
+struct CacheLookupRecorder<'a, C> {
+    cache: C,
+    lookup: &'a RefCell<Option<Result<(), CacheMiss>>>,
+}
+
+impl<C: Cache> Cache for CacheLookupRecorder<'_, C> {
+    fn get(
+        &mut self,
+        program: &str,
+        fingerprint: &Fingerprint,
+    ) -> Result<viper::VerificationResult, CacheMiss> {
+        let result = self.cache.get(program, fingerprint);
+        *self.lookup.borrow_mut() = Some(result.as_ref().map(|_| ()).map_err(Clone::clone));
         result
     }
+    fn insert(
+        self,
+        program: String,
+        fingerprint: Fingerprint,
+        result: viper::VerificationResult,
+    ) -> Option<viper::VerificationResult> {
+        self.cache.insert(program, fingerprint, result)
+    }
 }
 
 /// Verify a list of programs.
-/// Returns a list of (program_name, verification_result) tuples.
+/// Returns a stream of verification results, in the same order as `programs`.
+/// Each result is yielded as soon as the verification of the corresponding
+/// program finishes.
 fn verify_programs(
     env: &Environment,
     programs: Vec<Program>,
-) -> Vec<(String, viper::VerificationResult)> {
+) -> impl Stream<Item = ProgramVerificationResult> + Unpin {
     let source_path = env.name.source_path();
     let rust_program_name = source_path
         .file_name()
@@ -222,64 +389,249 @@ fn verify_programs(
         .to_str()
         .unwrap()
         .to_owned();
-    let verification_requests = programs.into_iter().map(|mut program| {
-        let program_name = program.get_name().to_string();
-        let check_mode = program.get_check_mode();
-        // Prepend the Rust file name to the program.
-        program.set_name(format!("{rust_program_name}_{program_name}"));
-        let backend = if check_mode == CheckMode::Specifications {
-            config::verify_specifications_backend()
-        } else {
-            config::viper_backend()
+    // The stable crate id distinguishes crates with the same name, e.g. the
+    // library and the binary of a package.
+    let crate_id = format!(
+        "{}-{:016x}",
+        env.name.local_crate_name(),
+        env.tcx().stable_crate_id(LOCAL_CRATE).as_u64()
+    );
+    let (program_names, verification_requests): (Vec<_>, Vec<_>) = programs
+        .into_iter()
+        .map(|mut program| {
+            let program_name = program.get_name().to_string();
+            let check_mode = program.get_check_mode();
+            // Prepend the Rust file name to the program.
+            program.set_name(format!("{rust_program_name}_{program_name}"));
+            let backend = if check_mode == CheckMode::Specifications {
+                config::verify_specifications_backend()
+            } else {
+                config::viper_backend()
+            }
+            .parse()
+            .unwrap();
+            let request = VerificationRequest {
+                program,
+                backend_config: ViperBackendConfig::new(backend),
+                crate_id: crate_id.clone(),
+            };
+            (program_name, request)
+        })
+        .unzip();
+
+    // The verification runs on a separate thread, which sends each result
+    // through the channel as soon as it is available. This way, the caller
+    // can report errors while the remaining programs are still being verified.
+    let (sender, receiver) = mpsc::unbounded();
+    let worker = thread::Builder::new()
+        .name("prusti-viper-verifier".to_string())
+        .spawn(move || {
+            let program_names = program_names.into_iter();
+            if let Some(server_address) = config::server_address() {
+                verify_requests_on_server(
+                    server_address,
+                    program_names,
+                    verification_requests,
+                    sender,
+                )
+            } else {
+                verify_requests_locally(program_names, verification_requests, sender)
+            }
+        })
+        .expect("failed to spawn the verification thread");
+
+    // Once the channel is closed, join the worker so that a panic during the
+    // verification is not mistaken for the end of the stream.
+    let mut worker = Some(worker);
+    receiver.chain(stream::poll_fn(move |_| {
+        if let Some(worker) = worker.take() {
+            if let Err(panic) = worker.join() {
+                panic::resume_unwind(panic);
+            }
         }
-        .parse()
-        .unwrap();
-        let request = VerificationRequest {
-            program,
-            backend_config: ViperBackendConfig::new(backend),
-        };
-        (program_name, request)
+        Poll::Ready(None)
+    }))
+}
+
+fn verify_requests_on_server(
+    server_address: String,
+    program_names: impl Iterator<Item = String>,
+    verification_requests: Vec<VerificationRequest>,
+    sender: mpsc::UnboundedSender<ProgramVerificationResult>,
+) {
+    let server_address = if server_address == "MOCK" {
+        spawn_server_thread().to_string()
+    } else {
+        server_address
+    };
+    info!("Connecting to Prusti server at {}", server_address);
+    let client = PrustiClient::new(&server_address).unwrap_or_else(|error| {
+        panic!("Could not parse server address ({server_address}) due to {error:?}")
     });
-    if let Some(server_address) = config::server_address() {
-        let server_address = if server_address == "MOCK" {
-            spawn_server_thread().to_string()
-        } else {
-            server_address
-        };
-        info!("Connecting to Prusti server at {}", server_address);
-        let client = PrustiClient::new(&server_address).unwrap_or_else(|error| {
-            panic!("Could not parse server address ({server_address}) due to {error:?}")
-        });
-        // Here we construct a Tokio runtime to block until completion of the futures returned by
-        // `client.verify`. However, to report verification errors as early as possible,
-        // `verify_programs` should return an asynchronous stream of verification results.
-        let runtime = Builder::new_current_thread()
-            .thread_name("prusti-viper")
-            .enable_all()
-            .build()
-            .expect("failed to construct Tokio runtime");
-        verification_requests
-            .map(|(program_name, request)| {
-                let remote_result = runtime.block_on(client.verify(request));
+    let runtime = Builder::new_current_thread()
+        .thread_name("prusti-viper")
+        .enable_all()
+        .build()
+        .expect("failed to construct Tokio runtime");
+    let backends: Vec<_> = verification_requests
+        .iter()
+        .map(|request| request.backend_config.backend)
+        .collect();
+    let mut start = Instant::now();
+    runtime.block_on(
+        stream::iter(program_names.zip(backends))
+            .zip(client.verify(verification_requests))
+            .for_each(|((program_name, backend), remote_result)| {
                 let result = remote_result.unwrap_or_else(|error| {
                     panic!("Verification request of program {program_name} failed: {error:?}")
                 });
-                (program_name, result)
-            })
-            .collect()
-    } else {
-        let mut stopwatch = Stopwatch::start("prusti-viper", "JVM startup");
-        stopwatch.start_next("attach current thread to the JVM");
-        let viper =
-            Lazy::new(|| Viper::new_with_args(&config::viper_home(), config::extra_jvm_args()));
-        let viper_thread = Lazy::new(|| viper.attach_current_thread());
-        stopwatch.finish();
-        let mut cache = PersistentCache::load_cache(config::cache_path());
-        verification_requests
-            .map(|(program_name, request)| {
-                let result = process_verification_request(&viper_thread, request, &mut cache);
-                (program_name, result)
-            })
-            .collect()
+                // The requests are sent one after the other, so the time since
+                // the previous result is the time spent on this request.
+                let duration = start.elapsed();
+                start = Instant::now();
+                // The receiver might have been dropped if the compilation was aborted.
+                let _ = sender.unbounded_send(ProgramVerificationResult {
+                    program_name,
+                    backend,
+                    result,
+                    duration,
+                    cache_hit: None,
+                    cache_miss: None,
+                });
+                future::ready(())
+            }),
+    );
+}
+
+/// Verify the requests on a pool of threads attached to the same JVM. The
+/// results are forwarded in the order of the requests, independently of the
+/// order in which the threads finish, so that the emitted diagnostics are
+/// deterministic.
+fn verify_requests_locally(
+    program_names: impl Iterator<Item = String> + Send,
+    verification_requests: Vec<VerificationRequest>,
+    sender: mpsc::UnboundedSender<ProgramVerificationResult>,
+) {
+    let number_of_threads = config::local_verifier_threads()
+        .min(verification_requests.len())
+        .max(1);
+    let stopwatch = Stopwatch::start("prusti-viper", "JVM startup");
+    let viper = Lazy::new(|| Viper::new_with_args(&config::viper_home(), config::extra_jvm_args()));
+    stopwatch.finish();
+    let cache = Arc::new(Mutex::new(
+        PersistentCache::load_cache(config::cache_path()),
+    ));
+    let pending_requests = Mutex::new(program_names.zip(verification_requests).enumerate());
+    let (result_sender, result_receiver) = std_mpsc::channel();
+    thread::scope(|scope| {
+        for thread_index in 0..number_of_threads {
+            let result_sender = result_sender.clone();
+            let (viper, cache, pending_requests) = (&viper, &cache, &pending_requests);
+            thread::Builder::new()
+                .name(format!("prusti-viper-verifier-{thread_index}"))
+                .spawn_scoped(scope, move || {
+                    let stopwatch =
+                        Stopwatch::start("prusti-viper", "attach current thread to the JVM");
+                    let viper_thread = Lazy::new(|| viper.attach_current_thread());
+                    stopwatch.finish();
+                    loop {
+                        // Release the lock before starting the verification.
+                        let next_request = pending_requests.lock().unwrap().next();
+                        let Some((index, (program_name, request))) = next_request else {
+                            break;
+                        };
+                        let backend = request.backend_config.backend;
+                        let cache_lookup = RefCell::new(None);
+                        let start = Instant::now();
+                        let result = process_verification_request(
+                            &viper_thread,
+                            request,
+                            CacheLookupRecorder {
+                                cache,
+                                lookup: &cache_lookup,
+                            },
+                        );
+                        let cache_lookup = cache_lookup.into_inner();
+                        let program_result = ProgramVerificationResult {
+                            program_name,
+                            backend,
+                            result,
+                            duration: start.elapsed(),
+                            cache_hit: cache_lookup.as_ref().map(Result::is_ok),
+                            cache_miss: cache_lookup.and_then(Result::err),
+                        };
+                        if result_sender.send((index, program_result)).is_err() {
+                            break;
+                        }
+                    }
+                })
+                .expect("failed to spawn a verifier thread");
+        }
+        drop(result_sender);
+
+        forward_in_request_order(result_receiver, |program_result| {
+            // The receiver might have been dropped if the compilation was aborted.
+            let _ = sender.unbounded_send(program_result);
+        });
+    });
+}
+
+/// Forward the results, which are tagged with the index of their request and
+/// might arrive in any order, in the order of the requests.
+fn forward_in_request_order<T>(
+    results: impl IntoIterator<Item = (usize, T)>,
+    mut forward: impl FnMut(T),
+) {
+    let mut next_index = 0;
+    let mut finished_results = FxHashMap::default();
+    for (index, result) in results {
+        finished_results.insert(index, result);
+        while let Some(finished_result) = finished_results.remove(&next_index) {
+            forward(finished_result);
+            next_index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_in_request_order() {
+        let mut forwarded = Vec::new();
+        forward_in_request_order(
+            vec![(2, "c"), (0, "a"), (3, "d"), (1, "b"), (4, "e")],
+            |result| forwarded.push(result),
+        );
+        assert_eq!(forwarded, vec!["a", "b", "c", "d", "e"]);
+    }
+
+    #[test]
+    fn test_forward_in_request_order_from_workers() {
+        let number_of_requests = 8;
+        let (result_sender, result_receiver) = std_mpsc::channel();
+        let mut forwarded = Vec::new();
+        thread::scope(|scope| {
+            // Each worker waits for the worker of the next request to finish,
+            // so the results are sent in the reverse order of the requests.
+            let mut next_finished = None;
+            for index in (0..number_of_requests).rev() {
+                let result_sender = result_sender.clone();
+                let (finished_sender, finished_receiver) = std_mpsc::channel::<()>();
+                let wait_for = next_finished.replace(finished_receiver);
+                scope.spawn(move || {
+                    if let Some(wait_for) = wait_for {
+                        // Returns once the next worker dropped its sender.
+                        let _ = wait_for.recv();
+                    }
+                    result_sender.send((index, index)).unwrap();
+                    drop(finished_sender);
+                });
+            }
+            drop(result_sender);
+            forward_in_request_order(result_receiver, |result| forwarded.push(result));
+        });
+        assert_eq!(forwarded, (0..number_of_requests).collect::<Vec<_>>());
     }
 }
@@ -1,12 +1,20 @@
 //! A module that invokes the verifier `prusti-viper`
 
 use log::{debug, warn};
-use prusti_common::{config, report::user};
+use prusti_common::{
+    config,
+    report::{
+        user,
+        verification_report::{self, CrateReport},
+    },
+};
 use prusti_interface::{
     data::{VerificationResult, VerificationTask},
     environment::Environment,
     specs::typed,
+    PrustiError,
 };
+use prusti_rustc_interface::errors::MultiSpan;
 use prusti_viper::verifier::Verifier;
 
 #[tracing::instrument(name = "prusti::verify", level = "debug", skip(env))]
@@ -43,9 +51,9 @@ pub fn verify(env: Environment<'_>, def_spec: typed::DefSpecificationMap) {
             }
         }
 
-        let verification_result =
+        let (verification_result, item_reports) =
             if verification_task.procedures.is_empty() && verification_task.types.is_empty() {
-                VerificationResult::Success
+                (VerificationResult::Success, vec![])
             } else {
                 debug!("Dump borrow checker info...");
                 env.dump_borrowck_info(&verification_task.procedures);
@@ -54,9 +62,22 @@ pub fn verify(env: Environment<'_>, def_spec: typed::DefSpecificationMap) {
                 let verification_result = verifier.verify(&verification_task);
                 debug!("Verifier returned {:?}", verification_result);
 
-                verification_result
+                (verification_result, verifier.item_reports().to_vec())
             };
 
+        match verification_report::report_format() {
+            Ok(Some(format)) => {
+                let report = CrateReport {
+                    crate_name: env.name.local_crate_name(),
+                    crate_target: env.name.local_crate_target(),
+                    items: item_reports,
+                };
+                write_verification_report(&report, format);
+            }
+            Ok(None) => {}
+            Err(error) => PrustiError::incorrect(error, MultiSpan::new()).emit(&env.diagnostic),
+        }
+
         match verification_result {
             VerificationResult::Success => {
                 if env.diagnostic.has_errors() {
@@ -83,3 +104,26 @@ pub fn verify(env: Environment<'_>, def_spec: typed::DefSpecificationMap) {
         };
     }
 }
+
+/// Write the verification report of the crate. When running under
+/// `cargo-prusti`, the final report is written by `cargo-prusti` once all the
+/// crates have been verified.
+fn write_verification_report(report: &CrateReport, format: verification_report::ReportFormat) {
+    let report_dir = verification_report::report_dir();
+    let result = verification_report::write_crate_report(&report_dir, report).and_then(|()| {
+        if std::env::var("PRUSTI_CARGO").is_err() {
+            verification_report::write_final_report(
+                &report_dir,
+                format,
+                std::slice::from_ref(report),
+            )?;
+        }
+        Ok(())
+    });
+    if let Err(error) = result {
+        user::message(format!(
+            "Failed to write the verification report to {}: {error}",
+            report_dir.display()
+        ));
+    }
+}
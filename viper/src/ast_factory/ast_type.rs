@@ -93,12 +93,15 @@ impl<'a> AstFactory<'a> {
         Type::new(obj)
     }
 
-    pub fn backend_f64_type(&self) -> Type<'a> {
+    pub fn backend_f64_type(&self, ieee: bool) -> Type<'a> {
         let rm = ast::utility::RoundingMode::with(self.env)
             .call_RNE()
             .unwrap();
         let float_factory_ = ast::utility::FloatFactory::with(self.env);
-        let float_factory = ast::utility::FloatFactory::new(&float_factory_, 52, 12, rm).unwrap();
+        let (significand_width, exponent_width) = super::expression::f64_widths(ieee);
+        let float_factory =
+            ast::utility::FloatFactory::new(&float_factory_, significand_width, exponent_width, rm)
+                .unwrap();
 
         let obj = self.jni.unwrap_result(ast::utility::FloatFactory::call_typ(
             &float_factory_,
@@ -46,6 +46,17 @@ pub enum FloatSizeViper {
     F64,
 }
 
+/// The significand width (including the hidden bit) and the exponent width of
+/// `f64`. With `ieee`, these are the widths of the IEEE-754 `binary64` format;
+/// otherwise the widths of the default encoding are kept.
+pub(super) fn f64_widths(ieee: bool) -> (i32, i32) {
+    if ieee {
+        (53, 11)
+    } else {
+        (52, 12)
+    }
+}
+
 // Bitwise Operations on Backend Bitvectors
 pub enum UnOpBv {
     Not,
@@ -512,6 +523,7 @@ impl<'a> AstFactory<'a> {
         &self,
         op_kind: BinOpFloat,
         f_size: FloatSizeViper,
+        ieee: bool,
         left: Expr,
         right: Expr,
     ) -> Expr<'a> {
@@ -522,7 +534,10 @@ impl<'a> AstFactory<'a> {
         let factory = match f_size {
             //
             FloatSizeViper::F32 => ast::utility::FloatFactory::new(&factory_, 24, 8, rm),
-            FloatSizeViper::F64 => ast::utility::FloatFactory::new(&factory_, 52, 12, rm),
+            FloatSizeViper::F64 => {
+                let (significand_width, exponent_width) = f64_widths(ieee);
+                ast::utility::FloatFactory::new(&factory_, significand_width, exponent_width, rm)
+            }
         }
         .unwrap();
         let size = match f_size {
@@ -591,7 +606,13 @@ impl<'a> AstFactory<'a> {
         self.backend_func_app(op, &[left, right], self.no_position())
     }
 
-    pub fn float_unop(&self, op_kind: UnOpFloat, f_size: FloatSizeViper, arg: Expr) -> Expr<'a> {
+    pub fn float_unop(
+        &self,
+        op_kind: UnOpFloat,
+        f_size: FloatSizeViper,
+        ieee: bool,
+        arg: Expr,
+    ) -> Expr<'a> {
         let rm = ast::utility::RoundingMode::with(self.env)
             .call_RNE()
             .unwrap(); // Rounding mode
@@ -599,7 +620,10 @@ impl<'a> AstFactory<'a> {
         let factory = match f_size {
             // FloatFactory JObject
             FloatSizeViper::F32 => ast::utility::FloatFactory::new(&factory_, 24, 8, rm),
-            FloatSizeViper::F64 => ast::utility::FloatFactory::new(&factory_, 52, 12, rm),
+            FloatSizeViper::F64 => {
+                let (significand_width, exponent_width) = f64_widths(ieee);
+                ast::utility::FloatFactory::new(&factory_, significand_width, exponent_width, rm)
+            }
         }
         .unwrap();
         let size = match f_size {
@@ -667,13 +691,16 @@ impl<'a> AstFactory<'a> {
         self.backend_func_app(from_bv, &[bv], self.no_position())
     }
 
-    pub fn backend_f64_lit(&self, bits: u64) -> Expr<'a> {
+    pub fn backend_f64_lit(&self, bits: u64, ieee: bool) -> Expr<'a> {
         let bv = self.backend_bv64_lit(bits);
         let rm = ast::utility::RoundingMode::with(self.env)
             .call_RNE()
             .unwrap(); // Rounding mode
         let float_factory_ = ast::utility::FloatFactory::with(self.env); // FloatFactory
-        let float_factory = ast::utility::FloatFactory::new(&float_factory_, 52, 12, rm).unwrap(); // FloatFactory JObject
+        let (significand_width, exponent_width) = f64_widths(ieee);
+        let float_factory =
+            ast::utility::FloatFactory::new(&float_factory_, significand_width, exponent_width, rm)
+                .unwrap(); // FloatFactory JObject
         let from_bv = ast::utility::FloatFactory::call_from__bv(
             &float_factory_,
             float_factory,
@@ -1040,6 +1067,16 @@ impl<'a> AstFactory<'a> {
         Expr::new(obj)
     }
 
+    /// Applies a backend function that was created with `backend_func`.
+    pub fn backend_func_decl_app(
+        &self,
+        backend_function: DomainFunc,
+        args: &[Expr],
+        pos: Position,
+    ) -> Expr<'a> {
+        self.backend_func_app(backend_function.to_jobject(), args, pos)
+    }
+
     pub fn field_access_with_pos(&self, rcv: Expr, field: Field, pos: Position) -> Expr<'a> {
         build_ast_node_with_pos!(
             self,
@@ -7,99 +7,295 @@
 use log::{error, info, warn};
 
 use crate::verification_result::VerificationResult;
-use rustc_hash::FxHashMap;
+use bincode::Options;
+use rustc_hash::{FxHashMap, FxHasher};
 use std::{
-    fs, io,
+    collections::BTreeMap,
+    fmt, fs,
+    hash::{Hash, Hasher},
+    io,
     ops::DerefMut,
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 pub trait Cache {
-    fn get(&self, request: u64) -> Option<VerificationResult>;
-    fn insert(self, request: u64, result: VerificationResult) -> Option<VerificationResult>;
+    /// Look up the result of the program named `program`. The result is
+    /// returned only if none of the components of the program changed since
+    /// the result was stored; otherwise, the error explains why the program
+    /// needs to be verified again.
+    fn get(
+        &mut self,
+        program: &str,
+        fingerprint: &Fingerprint,
+    ) -> Result<VerificationResult, CacheMiss>;
+    fn insert(
+        self,
+        program: String,
+        fingerprint: Fingerprint,
+        result: VerificationResult,
+    ) -> Option<VerificationResult>;
 }
+
+/// The hashes of the components of a program that can influence its
+/// verification result, such as the body of the verified method, the
+/// contracts of the methods it calls and the pure functions it uses.
+/// Components are identified by a human-readable description, which is used
+/// to explain why a program has to be verified again.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Fingerprint(BTreeMap<String, u64>);
+
+impl Fingerprint {
+    pub fn add<S: ToString, T: Hash + ?Sized>(&mut self, component: S, value: &T) {
+        let mut hasher = FxHasher::default();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        // Components with the same description are combined, independently of
+        // their order.
+        let entry = self.0.entry(component.to_string()).or_insert(0);
+        *entry = entry.wrapping_add(hash);
+    }
+
+    /// Describe how the components changed since the `old` fingerprint.
+    pub fn changes_since(&self, old: &Fingerprint) -> Vec<String> {
+        let mut changes = vec![];
+        for (component, hash) in &self.0 {
+            match old.0.get(component) {
+                None => changes.push(format!("{component} was added")),
+                Some(old_hash) if old_hash != hash => changes.push(format!("{component} changed")),
+                _ => {}
+            }
+        }
+        for component in old.0.keys() {
+            if !self.0.contains_key(component) {
+                changes.push(format!("{component} was removed"));
+            }
+        }
+        changes
+    }
+}
+
+/// The reason why a program is not in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheMiss {
+    /// The program has never been verified.
+    NotCached,
+    /// Some components of the program changed since the last verification.
+    Changed(Vec<String>),
+}
+
+impl fmt::Display for CacheMiss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheMiss::NotCached => write!(f, "the program has not been verified before"),
+            CacheMiss::Changed(changes) => write!(f, "{}", changes.join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CacheEntry {
+    fingerprint: Fingerprint,
+    result: VerificationResult,
+    /// When the entry was last inserted or used, in seconds since the Unix
+    /// epoch. Used to evict stale entries.
+    last_used: u64,
+}
+
 // We can change the representation here (e.g. adding fields):
 #[derive(Debug, Clone)]
 pub struct PersistentCache {
     updated: bool,
     load_loc: PathBuf,
-    data: FxHashMap<u64, VerificationResult>,
+    /// The cached entries, indexed by the name of the program.
+    data: FxHashMap<String, CacheEntry>,
 }
 
-const RESULT_CACHE_VERSION: u64 = 4;
+/// The version of the on-disk format. Bump it whenever the serialized
+/// representation of `CacheEntry` or `VerificationResult` changes, and add a
+/// migration to `load_cache` if the old entries can still be used.
+const RESULT_CACHE_VERSION: u64 = 5;
 
+/// Since version 5 the version is serialized before the data, so that
+/// incompatible caches can be recognized without deserializing the data.
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
-struct ResultCache {
-    data: FxHashMap<u64, VerificationResult>,
+struct ResultCacheHeader {
+    /// Distinguishes the header from the data of older versions.
+    magic: [u8; 8],
     version: u64,
 }
 
-impl From<(PathBuf, ResultCache)> for PersistentCache {
-    /// Used when loading cache from disk
-    fn from((load_loc, rc): (PathBuf, ResultCache)) -> Self {
-        assert_eq!(rc.version, RESULT_CACHE_VERSION);
-        let data = rc.data;
-        PersistentCache {
-            updated: false,
-            load_loc,
-            data,
-        }
+const RESULT_CACHE_MAGIC: [u8; 8] = *b"PRUSTICH";
+
+/// The options with which the cache files are read. They are the same as the
+/// ones of `bincode::deserialize_from`, but reading more than `limit` bytes
+/// fails. This avoids huge allocations when a cache file with an unknown
+/// format is interpreted as one of the known formats.
+fn bincode_options(limit: u64) -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(limit)
+}
+
+/// The on-disk format of version 4, in which the version was serialized
+/// after the data. Its results are indexed by the hash of the whole request
+/// instead of the name of the program, and have no fingerprint, so they
+/// cannot be migrated.
+mod v4 {
+    use crate::verification_result::VerificationResult;
+    use rustc_hash::FxHashMap;
+
+    pub(super) const VERSION: u64 = 4;
+
+    #[derive(Debug, serde::Deserialize)]
+    pub(super) struct ResultCache {
+        #[allow(dead_code)]
+        pub(super) data: FxHashMap<u64, VerificationResult>,
+        pub(super) version: u64,
     }
 }
-impl From<&PersistentCache> for ResultCache {
-    /// Used when saving cache to disk
-    fn from(cache: &PersistentCache) -> Self {
-        ResultCache {
-            data: cache.data.clone(),
-            version: RESULT_CACHE_VERSION,
-        }
+
+/// Statistics about the content of a cache, as shown by the `cache stats`
+/// command of `prusti-server`.
+#[derive(Debug, Clone)]
+pub struct CacheStatistics {
+    pub version: u64,
+    pub entries: usize,
+    pub successes: usize,
+    pub failures: usize,
+    /// The size of the serialized entries, in bytes.
+    pub size: u64,
+    /// The time at which the least recently used entry was used, in seconds
+    /// since the Unix epoch.
+    pub oldest_use: Option<u64>,
+    /// The time at which the most recently used entry was used, in seconds
+    /// since the Unix epoch.
+    pub newest_use: Option<u64>,
+}
+
+impl fmt::Display for CacheStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let now = now();
+        let age = |time: Option<u64>| match time {
+            Some(time) => format!("{} days ago", now.saturating_sub(time) / SECONDS_PER_DAY),
+            None => "-".to_string(),
+        };
+        writeln!(f, "version:       {}", self.version)?;
+        writeln!(f, "entries:       {}", self.entries)?;
+        writeln!(f, "  successes:   {}", self.successes)?;
+        writeln!(f, "  failures:    {}", self.failures)?;
+        writeln!(f, "size:          {} bytes", self.size)?;
+        writeln!(f, "oldest use:    {}", age(self.oldest_use))?;
+        write!(f, "newest use:    {}", age(self.newest_use))
     }
 }
 
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// The current time in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
 impl PersistentCache {
     #[tracing::instrument(level = "debug")]
     pub fn load_cache(cache_loc: PathBuf) -> Self {
-        let mut data_res: Option<ResultCache> = None;
+        let mut data = None;
         if !cache_loc.as_os_str().is_empty() {
             if let Ok(f) = fs::File::open(&cache_loc) {
-                match bincode::deserialize_from::<_, ResultCache>(&mut io::BufReader::new(f)) {
-                    Ok(data) => {
-                        if data.version == RESULT_CACHE_VERSION {
-                            info!("Loaded cache from \"{}\"", cache_loc.display());
-                            data_res = Some(data);
-                        } else {
-                            error!(
-                                "Cache version mismatch when reading from \"{}\": \
-                                    expected={} actual={}",
-                                cache_loc.display(),
-                                RESULT_CACHE_VERSION,
-                                data.version
-                            )
-                        }
-                    }
-                    Err(e) => error!("Failed to read cache from \"{}\": {e}", cache_loc.display()),
-                }
+                data = Self::read_data(&cache_loc, f);
             }
         }
-        PersistentCache::from((
-            cache_loc,
-            data_res.unwrap_or_else(|| {
+        let updated = data.is_none() && cache_loc.is_file();
+        PersistentCache {
+            // Overwrite unusable cache files on the next save, even if no
+            // entry is added.
+            updated,
+            load_loc: cache_loc,
+            data: data.unwrap_or_else(|| {
                 info!("Cache file doesn't exist or is invalid. Using fresh cache.");
-                ResultCache {
-                    data: FxHashMap::default(),
-                    version: RESULT_CACHE_VERSION,
-                }
+                FxHashMap::default()
             }),
-        ))
+        }
     }
+
+    /// Load the cache only to inspect it. Unlike `load_cache`, an unusable
+    /// cache file is not overwritten when the cache is dropped.
+    pub fn load_cache_read_only(cache_loc: PathBuf) -> Self {
+        let mut cache = Self::load_cache(cache_loc);
+        cache.updated = false;
+        cache
+    }
+
+    /// Read the entries of a cache file. Returns `None` if the cache file
+    /// cannot be used, e.g. because it was written by an incompatible version.
+    fn read_data(cache_loc: &Path, f: fs::File) -> Option<FxHashMap<String, CacheEntry>> {
+        let file_len = f.metadata().ok()?.len();
+        let mut reader = io::BufReader::new(f);
+        let header =
+            bincode_options(file_len).deserialize_from::<_, ResultCacheHeader>(&mut reader);
+        match header {
+            Ok(ResultCacheHeader { magic, version }) if magic == RESULT_CACHE_MAGIC => {
+                if version != RESULT_CACHE_VERSION {
+                    warn!(
+                        "Dropping the cache \"{}\", because its version {} is not compatible \
+                            with the supported version {}",
+                        cache_loc.display(),
+                        version,
+                        RESULT_CACHE_VERSION
+                    );
+                    return None;
+                }
+                return match bincode_options(file_len).deserialize_from(&mut reader) {
+                    Ok(data) => {
+                        info!("Loaded cache from \"{}\"", cache_loc.display());
+                        Some(data)
+                    }
+                    Err(e) => {
+                        error!("Failed to read cache from \"{}\": {e}", cache_loc.display());
+                        None
+                    }
+                };
+            }
+            _ => {}
+        }
+        // Caches before version 5 serialized the version after the data.
+        let reader = io::BufReader::new(fs::File::open(cache_loc).ok()?);
+        match bincode_options(file_len).deserialize_from::<_, v4::ResultCache>(reader) {
+            Ok(rc) if rc.version == v4::VERSION => warn!(
+                "Dropping the cache \"{}\", because its version {} indexes the results by \
+                    request and cannot be migrated to the supported version {}",
+                cache_loc.display(),
+                rc.version,
+                RESULT_CACHE_VERSION
+            ),
+            _ => warn!(
+                "Dropping the cache \"{}\", because it has an unknown format \
+                    (the supported version is {})",
+                cache_loc.display(),
+                RESULT_CACHE_VERSION
+            ),
+        }
+        None
+    }
+
     #[tracing::instrument(level = "debug")]
     pub fn save_cache(&self, cache_loc: &Path) {
         match fs::File::create(cache_loc) {
             Ok(f) => {
                 info!("Saving cache to \"{}\"", cache_loc.display());
                 let mut cache_buffer = io::BufWriter::new(f);
-                bincode::serialize_into(&mut cache_buffer, &ResultCache::from(self))
+                let header = ResultCacheHeader {
+                    magic: RESULT_CACHE_MAGIC,
+                    version: RESULT_CACHE_VERSION,
+                };
+                bincode::serialize_into(&mut cache_buffer, &header)
+                    .and_then(|()| bincode::serialize_into(&mut cache_buffer, &self.data))
                     .unwrap_or_else(|e| error!("Failed to serialize the cache: {e}"));
                 match cache_buffer.into_inner() {
                     Err(e) => error!("Failed to flush the cache file: {e}"),
@@ -120,12 +316,81 @@ impl PersistentCache {
                 let mut save_dir = self.load_loc.clone();
                 save_dir.pop();
                 match fs::create_dir_all(&save_dir) {
-                    Ok(()) => self.save_cache(&self.load_loc),
+                    Ok(()) => {
+                        self.save_cache(&self.load_loc);
+                        self.updated = false;
+                    }
                     Err(e) => error!("Failed to create cache dir: {e}"),
                 }
             }
         }
     }
+
+    pub fn statistics(&self) -> CacheStatistics {
+        let successes = self
+            .data
+            .values()
+            .filter(|entry| matches!(entry.result, VerificationResult::Success))
+            .count();
+        CacheStatistics {
+            version: RESULT_CACHE_VERSION,
+            entries: self.data.len(),
+            successes,
+            failures: self.data.len() - successes,
+            size: self.data.values().map(Self::entry_size).sum(),
+            oldest_use: self.data.values().map(|entry| entry.last_used).min(),
+            newest_use: self.data.values().map(|entry| entry.last_used).max(),
+        }
+    }
+
+    fn entry_size(entry: &CacheEntry) -> u64 {
+        bincode::serialized_size(entry).unwrap_or(0)
+    }
+
+    /// Remove the entries that have not been used in the last `days` days.
+    /// Returns the number of removed entries.
+    pub fn evict_older_than(&mut self, days: u64) -> usize {
+        let threshold = now().saturating_sub(days.saturating_mul(SECONDS_PER_DAY));
+        let old_len = self.data.len();
+        self.data.retain(|_, entry| entry.last_used >= threshold);
+        self.mark_evicted(old_len)
+    }
+
+    /// Remove the least recently used entries until the serialized entries
+    /// take at most `max_size` bytes. Returns the number of removed entries.
+    pub fn evict_to_size(&mut self, max_size: u64) -> usize {
+        let mut entries: Vec<_> = self
+            .data
+            .iter()
+            .map(|(program, entry)| (entry.last_used, Self::entry_size(entry), program.clone()))
+            .collect();
+        // Keep the most recently used entries first.
+        entries.sort_unstable_by(|a, b| b.cmp(a));
+        let mut size = 0;
+        let old_len = self.data.len();
+        for (_, entry_size, program) in entries {
+            size += entry_size;
+            if size > max_size {
+                self.data.remove(&program);
+            }
+        }
+        self.mark_evicted(old_len)
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) -> usize {
+        let old_len = self.data.len();
+        self.data.clear();
+        self.mark_evicted(old_len)
+    }
+
+    fn mark_evicted(&mut self, old_len: usize) -> usize {
+        let evicted = old_len - self.data.len();
+        if evicted > 0 {
+            self.updated = true;
+        }
+        evicted
+    }
 }
 
 impl Drop for PersistentCache {
@@ -135,22 +400,235 @@ impl Drop for PersistentCache {
 }
 
 impl Cache for &mut PersistentCache {
-    fn get(&self, request: u64) -> Option<VerificationResult> {
-        self.data.get(&request).cloned()
+    fn get(
+        &mut self,
+        program: &str,
+        fingerprint: &Fingerprint,
+    ) -> Result<VerificationResult, CacheMiss> {
+        let entry = self.data.get_mut(program).ok_or(CacheMiss::NotCached)?;
+        let changes = fingerprint.changes_since(&entry.fingerprint);
+        if changes.is_empty() {
+            // Avoid rewriting the cache file only to refresh the timestamps
+            // of entries that have been used recently.
+            let now = now();
+            if now.saturating_sub(entry.last_used) >= SECONDS_PER_DAY {
+                entry.last_used = now;
+                self.updated = true;
+            }
+            Ok(entry.result.clone())
+        } else {
+            Err(CacheMiss::Changed(changes))
+        }
     }
-    fn insert(self, request: u64, result: VerificationResult) -> Option<VerificationResult> {
+    fn insert(
+        self,
+        program: String,
+        fingerprint: Fingerprint,
+        result: VerificationResult,
+    ) -> Option<VerificationResult> {
         self.updated = true;
-        self.data.insert(request, result)
+        self.data
+            .insert(
+                program,
+                CacheEntry {
+                    fingerprint,
+                    result,
+                    last_used: now(),
+                },
+            )
+            .map(|entry| entry.result)
     }
 }
 
 impl Cache for &Arc<Mutex<PersistentCache>> {
-    fn get(&self, request: u64) -> Option<VerificationResult> {
+    fn get(
+        &mut self,
+        program: &str,
+        fingerprint: &Fingerprint,
+    ) -> Result<VerificationResult, CacheMiss> {
         let mut cache = self.lock().unwrap();
-        cache.deref_mut().get(request)
+        cache.deref_mut().get(program, fingerprint)
     }
-    fn insert(self, request: u64, result: VerificationResult) -> Option<VerificationResult> {
+    fn insert(
+        self,
+        program: String,
+        fingerprint: Fingerprint,
+        result: VerificationResult,
+    ) -> Option<VerificationResult> {
         let mut cache = self.lock().unwrap();
-        cache.insert(request, result)
+        cache.insert(program, fingerprint, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(components: &[(&str, u32)]) -> Fingerprint {
+        let mut fingerprint = Fingerprint::default();
+        for (component, value) in components {
+            fingerprint.add(component, value);
+        }
+        fingerprint
+    }
+
+    #[test]
+    fn test_fingerprint_changes() {
+        let old = fingerprint(&[("method `m`", 1), ("function `f`", 2), ("domain `d`", 3)]);
+        let new = fingerprint(&[("method `m`", 1), ("function `f`", 4), ("field `x`", 5)]);
+        assert_eq!(
+            new.changes_since(&old),
+            vec![
+                "field `x` was added".to_string(),
+                "function `f` changed".to_string(),
+                "domain `d` was removed".to_string(),
+            ]
+        );
+        assert!(old.changes_since(&old).is_empty());
+    }
+
+    #[test]
+    fn test_fingerprint_is_independent_of_order() {
+        let a = fingerprint(&[("domain `d`", 1), ("domain `d`", 2), ("method `m`", 3)]);
+        let b = fingerprint(&[("method `m`", 3), ("domain `d`", 2), ("domain `d`", 1)]);
+        assert_eq!(a, b);
+        let c = fingerprint(&[("method `m`", 3), ("domain `d`", 2), ("domain `d`", 7)]);
+        assert_eq!(c.changes_since(&a), vec!["domain `d` changed".to_string()]);
+    }
+
+    /// A path in the temporary directory that is removed when dropped.
+    struct TempCachePath(PathBuf);
+
+    impl TempCachePath {
+        fn new(name: &str) -> Self {
+            let file_name = format!("prusti-cache-test-{name}-{}.bin", std::process::id());
+            let path = std::env::temp_dir().join(file_name);
+            let _ = fs::remove_file(&path);
+            TempCachePath(path)
+        }
+    }
+
+    impl Drop for TempCachePath {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    /// An in-memory cache with one successful entry per program, last used
+    /// the given number of days ago.
+    fn cache_with_entries(entries: &[(&str, u64)]) -> PersistentCache {
+        let mut cache = PersistentCache::load_cache(PathBuf::new());
+        let now = now();
+        for (program, days_ago) in entries {
+            let entry = CacheEntry {
+                fingerprint: fingerprint(&[("method `m`", 1)]),
+                result: VerificationResult::Success,
+                last_used: now - days_ago * SECONDS_PER_DAY,
+            };
+            cache.data.insert(program.to_string(), entry);
+        }
+        cache
+    }
+
+    fn programs(cache: &PersistentCache) -> Vec<&str> {
+        let mut programs: Vec<_> = cache.data.keys().map(String::as_str).collect();
+        programs.sort_unstable();
+        programs
+    }
+
+    #[test]
+    fn test_evict_older_than() {
+        let mut cache = cache_with_entries(&[("a", 0), ("b", 5), ("c", 10), ("d", 20)]);
+        assert_eq!(cache.evict_older_than(30), 0);
+        assert!(!cache.updated);
+        assert_eq!(cache.evict_older_than(7), 2);
+        assert!(cache.updated);
+        assert_eq!(programs(&cache), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_evict_to_size() {
+        let mut cache = cache_with_entries(&[("a", 0), ("b", 5), ("c", 10)]);
+        let entry_size = PersistentCache::entry_size(&cache.data["a"]);
+        assert!(entry_size > 0);
+        assert_eq!(cache.evict_to_size(3 * entry_size), 0);
+        assert!(!cache.updated);
+        // The least recently used entries are evicted first.
+        assert_eq!(cache.evict_to_size(2 * entry_size + 1), 1);
+        assert!(cache.updated);
+        assert_eq!(programs(&cache), vec!["a", "b"]);
+        assert_eq!(cache.evict_to_size(0), 2);
+        assert!(cache.data.is_empty());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cache = cache_with_entries(&[("a", 0), ("b", 5)]);
+        assert_eq!(cache.clear(), 2);
+        assert!(cache.updated);
+        assert!(cache.data.is_empty());
+        assert_eq!(cache.clear(), 0);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = TempCachePath::new("save-and-load");
+        let mut cache = cache_with_entries(&[("a", 0), ("b", 5)]);
+        cache.load_loc = path.0.clone();
+        cache.updated = true;
+        drop(cache);
+        let cache = PersistentCache::load_cache(path.0.clone());
+        assert!(!cache.updated);
+        assert_eq!(programs(&cache), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_discard_version_4() {
+        let path = TempCachePath::new("discard-v4");
+        // Version 4 indexed the results by the hash of the request and
+        // serialized the version after the data.
+        let mut data = FxHashMap::default();
+        data.insert(0xdead_beef_dead_beef_u64, VerificationResult::Success);
+        let old_content = bincode::serialize(&(data, v4::VERSION)).unwrap();
+        fs::write(&path.0, &old_content).unwrap();
+
+        let cache = PersistentCache::load_cache_read_only(path.0.clone());
+        assert!(cache.data.is_empty());
+        assert!(!cache.updated);
+        drop(cache);
+        // Inspecting the cache does not overwrite the file.
+        assert_eq!(fs::read(&path.0).unwrap(), old_content);
+
+        let cache = PersistentCache::load_cache(path.0.clone());
+        assert!(cache.data.is_empty());
+        assert!(cache.updated);
+        drop(cache);
+        // The unusable file is overwritten by an empty cache.
+        assert!(PersistentCache::load_cache(path.0.clone()).data.is_empty());
+        assert_ne!(fs::read(&path.0).unwrap(), old_content);
+    }
+
+    #[test]
+    fn test_cache_lookup_reports_changes() {
+        let mut cache = PersistentCache::load_cache(PathBuf::new());
+        let old = fingerprint(&[("method `m`", 1)]);
+        assert_eq!(
+            (&mut cache).get("program", &old).unwrap_err(),
+            CacheMiss::NotCached
+        );
+        (&mut cache).insert(
+            "program".to_string(),
+            old.clone(),
+            VerificationResult::Success,
+        );
+        assert!(matches!(
+            (&mut cache).get("program", &old),
+            Ok(VerificationResult::Success)
+        ));
+        let new = fingerprint(&[("method `m`", 2)]);
+        assert_eq!(
+            (&mut cache).get("program", &new).unwrap_err(),
+            CacheMiss::Changed(vec!["method `m` changed".to_string()])
+        );
     }
 }
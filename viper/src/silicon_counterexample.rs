@@ -357,7 +357,11 @@ fn unwrap_model_entry<'a>(
             let other_wrapper = silicon::reporting::OtherEntry::with(env);
             let value = jni.to_string(jni.unwrap_result(other_wrapper.call_value(entry)));
             let problem = jni.to_string(jni.unwrap_result(other_wrapper.call_problem(entry)));
-            Some(ModelEntry::Other(value, problem))
+            if let Some(float) = parse_smt_float(&value) {
+                Some(ModelEntry::LitFloat(float))
+            } else {
+                Some(ModelEntry::Other(value, problem))
+            }
         }
         "viper.silicon.reporting.SeqEntry" => {
             let seq_wrapper = silicon::reporting::SeqEntry::with(env);
@@ -382,6 +386,55 @@ fn unwrap_model_entry<'a>(
     }
 }
 
+/// Converts an SMT-LIB floating-point literal such as `(fp #b0 #b10000010
+/// #b01000000000000000000000)` or `(_ NaN 8 24)` into the way Rust prints
+/// the corresponding `f32` or `f64` value.
+fn parse_smt_float(value: &str) -> Option<String> {
+    let value = value.trim().strip_prefix('(')?.strip_suffix(')')?;
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    match parts.as_slice() {
+        ["_", special, _, _] => match *special {
+            "NaN" => Some("NaN".to_string()),
+            "+oo" => Some("inf".to_string()),
+            "-oo" => Some("-inf".to_string()),
+            "+zero" => Some("0.0".to_string()),
+            "-zero" => Some("-0.0".to_string()),
+            _ => None,
+        },
+        ["fp", sign, exponent, significand] => {
+            let (sign, sign_width) = parse_smt_bits(sign)?;
+            let (exponent, exponent_width) = parse_smt_bits(exponent)?;
+            let (significand, significand_width) = parse_smt_bits(significand)?;
+            if sign_width != 1 {
+                return None;
+            }
+            let bits = (sign << (exponent_width + significand_width))
+                | (exponent << significand_width)
+                | significand;
+            match (exponent_width, significand_width) {
+                (8, 23) => Some(format!("{:?}", f32::from_bits(bits as u32))),
+                (11, 52) => Some(format!("{:?}", f64::from_bits(bits))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parses an SMT-LIB bitvector literal in binary (`#b...`) or hexadecimal
+/// (`#x...`) notation into its value and width.
+fn parse_smt_bits(literal: &str) -> Option<(u64, u32)> {
+    if let Some(digits) = literal.strip_prefix("#b") {
+        let value = u64::from_str_radix(digits, 2).ok()?;
+        Some((value, digits.len() as u32))
+    } else if let Some(digits) = literal.strip_prefix("#x") {
+        let value = u64::from_str_radix(digits, 16).ok()?;
+        Some((value, 4 * digits.len() as u32))
+    } else {
+        None
+    }
+}
+
 fn unwrap_functions<'a>(
     env: &'a JNIEnv<'a>,
     jni: JniUtils<'a>,
@@ -481,3 +534,73 @@ fn unwrap_domains<'a>(
     }
     Domains { entries }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_smt_bits() {
+        assert_eq!(parse_smt_bits("#b101"), Some((5, 3)));
+        assert_eq!(parse_smt_bits("#b0"), Some((0, 1)));
+        assert_eq!(parse_smt_bits("#x1f"), Some((31, 8)));
+        assert_eq!(parse_smt_bits("#x8000000000000"), Some((1 << 51, 52)));
+        assert_eq!(parse_smt_bits("#b"), None);
+        assert_eq!(parse_smt_bits("#b102"), None);
+        assert_eq!(parse_smt_bits("#xg"), None);
+        assert_eq!(parse_smt_bits("42"), None);
+    }
+
+    #[test]
+    fn test_parse_smt_float_normal_values() {
+        assert_eq!(
+            parse_smt_float("(fp #b0 #b10000010 #b01000000000000000000000)"),
+            Some("10.0".to_string())
+        );
+        assert_eq!(
+            parse_smt_float("(fp #b0 #x7f #b00000000000000000000000)"),
+            Some("1.0".to_string())
+        );
+        assert_eq!(
+            parse_smt_float("(fp #b1 #b10000000000 #x8000000000000)"),
+            Some("-3.0".to_string())
+        );
+        assert_eq!(
+            parse_smt_float(" (fp #b0\n  #b01111111110 #x0000000000000) "),
+            Some("0.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_smt_float_special_values() {
+        assert_eq!(parse_smt_float("(_ NaN 8 24)"), Some("NaN".to_string()));
+        assert_eq!(parse_smt_float("(_ +oo 11 53)"), Some("inf".to_string()));
+        assert_eq!(parse_smt_float("(_ -oo 8 24)"), Some("-inf".to_string()));
+        assert_eq!(parse_smt_float("(_ +zero 8 24)"), Some("0.0".to_string()));
+        assert_eq!(parse_smt_float("(_ -zero 11 53)"), Some("-0.0".to_string()));
+        assert_eq!(
+            parse_smt_float("(fp #b0 #b11111111 #b10000000000000000000000)"),
+            Some("NaN".to_string())
+        );
+        assert_eq!(
+            parse_smt_float("(fp #b1 #b11111111111 #x0000000000000)"),
+            Some("-inf".to_string())
+        );
+        assert_eq!(
+            parse_smt_float("(fp #b1 #b00000000 #b00000000000000000000000)"),
+            Some("-0.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_smt_float_invalid_values() {
+        assert_eq!(parse_smt_float("10.0"), None);
+        assert_eq!(parse_smt_float("(_ foo 8 24)"), None);
+        assert_eq!(
+            parse_smt_float("(fp #b00 #b10000010 #b01000000000000000000000)"),
+            None
+        );
+        assert_eq!(parse_smt_float("(fp #b0 #b01111 #b0000000000)"), None);
+        assert_eq!(parse_smt_float("(fp #b0 #b10000010)"), None);
+    }
+}
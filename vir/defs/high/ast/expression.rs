@@ -146,6 +146,14 @@ pub enum BinaryOpKind {
     Or,
     Implies,
     LifetimeIntersection,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    /// Logical right shift.
+    LShr,
+    /// Arithmetic right shift.
+    AShr,
 }
 
 #[display(fmt = "({}) {} ({})", left, op_kind, right)]
@@ -246,6 +254,30 @@ pub enum BuiltinFunc {
     LookupSeq,
     ConcatSeq,
     SeqLen,
+    UpdateSeq,
+    SeqTake,
+    SeqSkip,
+    SeqSubsequence,
+    SeqContains,
+    SeqIndexOf,
+    SeqFromSlice,
+    SeqToMultiSet,
+    EmptySet,
+    SingleSet,
+    SetUnion,
+    SetIntersection,
+    SetMinus,
+    SetSubset,
+    SetContains,
+    SetLen,
+    EmptyMultiSet,
+    SingleMultiSet,
+    MultiSetUnion,
+    MultiSetIntersection,
+    MultiSetMinus,
+    MultiSetSubset,
+    MultiSetCount,
+    MultiSetLen,
     NewInt,
     Index,
     Len,
@@ -129,7 +129,9 @@ pub struct LoopInvariant {
     /// memory.
     pub maybe_modified_places: Vec<Predicate>,
     pub functional_specifications: Vec<Expression>,
-    pub variant: Option<LoopVariant>,
+    /// The components of the lexicographic loop variant. Empty if the loop
+    /// has no variant.
+    pub variant: Vec<LoopVariant>,
     pub position: Position,
 }
 
@@ -23,6 +23,10 @@ pub enum Type {
     Sequence(Sequence),
     /// A mathematical map.
     Map(Map),
+    /// A mathematical set.
+    Set(Set),
+    /// A mathematical multiset.
+    MultiSet(MultiSet),
     Float(Float),
     TypeVar(TypeVar),
     Tuple(Tuple),
@@ -80,6 +84,18 @@ pub struct Map {
     pub lifetimes: Vec<LifetimeConst>,
 }
 
+#[display(fmt = "Set({})<{}>", element_type, "display::cjoin(lifetimes)")]
+pub struct Set {
+    pub element_type: Box<Type>,
+    pub lifetimes: Vec<LifetimeConst>,
+}
+
+#[display(fmt = "MultiSet({})<{}>", element_type, "display::cjoin(lifetimes)")]
+pub struct MultiSet {
+    pub element_type: Box<Type>,
+    pub lifetimes: Vec<LifetimeConst>,
+}
+
 pub enum Float {
     F32,
     F64,
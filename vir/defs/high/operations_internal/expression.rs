@@ -38,8 +38,20 @@ impl BinaryOpKind {
             | BinaryOpKind::Mul
             | BinaryOpKind::Div
             | BinaryOpKind::Mod
-            | BinaryOpKind::LifetimeIntersection => argument_type,
-        }
+            | BinaryOpKind::LifetimeIntersection
+            | BinaryOpKind::BitAnd
+            | BinaryOpKind::BitOr
+            | BinaryOpKind::BitXor
+            | BinaryOpKind::Shl
+            | BinaryOpKind::LShr
+            | BinaryOpKind::AShr => argument_type,
+        }
+    }
+    pub fn is_shift(self) -> bool {
+        matches!(
+            self,
+            BinaryOpKind::Shl | BinaryOpKind::LShr | BinaryOpKind::AShr
+        )
     }
 }
 
@@ -444,7 +456,11 @@ impl Expression {
                                     lifetime: _,
                                     uniqueness: ty::Uniqueness::Shared,
                                     target_type:
-                                        box Type::Map(_) | box Type::Sequence(_) | box Type::Int(_),
+                                        box Type::Map(_)
+                                        | box Type::Sequence(_)
+                                        | box Type::Set(_)
+                                        | box Type::MultiSet(_)
+                                        | box Type::Int(_),
                                 }),
                             ..
                         }),
@@ -456,7 +472,11 @@ impl Expression {
                                     lifetime: _,
                                     uniqueness: ty::Uniqueness::Shared,
                                     target_type:
-                                        box Type::Map(_) | box Type::Sequence(_) | box Type::Int(_),
+                                        box Type::Map(_)
+                                        | box Type::Sequence(_)
+                                        | box Type::Set(_)
+                                        | box Type::MultiSet(_)
+                                        | box Type::Int(_),
                                 }),
                             ..
                         }),
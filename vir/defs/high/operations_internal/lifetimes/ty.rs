@@ -35,6 +35,8 @@ impl WithLifetimes for ty::Type {
             }) => get_lifetimes_with_arguments(lifetimes, arguments),
             ty::Type::Sequence(ty::Sequence { lifetimes, .. })
             | ty::Type::Map(ty::Map { lifetimes, .. })
+            | ty::Type::Set(ty::Set { lifetimes, .. })
+            | ty::Type::MultiSet(ty::MultiSet { lifetimes, .. })
             | ty::Type::Array(ty::Array { lifetimes, .. })
             | ty::Type::Slice(ty::Slice { lifetimes, .. })
             | ty::Type::Trusted(ty::Trusted { lifetimes, .. }) => lifetimes.clone(),
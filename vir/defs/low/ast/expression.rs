@@ -178,6 +178,10 @@ pub enum ContainerOpKind {
     SeqIndex,
     SeqConcat,
     SeqLen,
+    SeqUpdate,
+    SeqTake,
+    SeqDrop,
+    SeqContains,
     MapEmpty,
     MapUpdate,
     MapContains,
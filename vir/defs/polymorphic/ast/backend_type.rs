@@ -39,3 +39,85 @@ impl WithIdentifier for BackendFuncDecl {
         self.name.clone()
     }
 }
+
+impl CastKind {
+    /// Returns the declaration of the backend function that implements this
+    /// cast for an argument of type `source`, or `None` if the cast does not
+    /// involve floats.
+    pub fn get_float_backend_func(&self, source: &Type) -> Option<BackendFuncDecl> {
+        let (name, arg_type, return_type, domain_name, interpretation) = match (self, source) {
+            (CastKind::IntIntoFloat(bv, float), _) => {
+                let (domain_name, exponent, significand) = float_sort(*float);
+                let (prefix, conversion, width) = match bv {
+                    BitVector::Signed(size) => ("i", "to_fp", bitvector_width(*size)),
+                    BitVector::Unsigned(size) => ("u", "to_fp_unsigned", bitvector_width(*size)),
+                };
+                (
+                    format!("{}_from_{prefix}{width}", float_name(*float)),
+                    Type::BitVector(*bv),
+                    Type::Float(*float),
+                    domain_name,
+                    format!("(_ {conversion} {exponent} {significand}) RNE"),
+                )
+            }
+            (CastKind::FloatIntoFloat(target), Type::Float(source)) if target != source => {
+                let (domain_name, exponent, significand) = float_sort(*target);
+                (
+                    format!("{}_to_{}", float_name(*source), float_name(*target)),
+                    Type::Float(*source),
+                    Type::Float(*target),
+                    domain_name,
+                    format!("(_ to_fp {exponent} {significand}) RNE"),
+                )
+            }
+            (CastKind::FloatIntoInt(bv), Type::Float(source)) => {
+                let (domain_name, _, _) = float_sort(*source);
+                let (prefix, conversion, width) = match bv {
+                    BitVector::Signed(size) => ("i", "fp.to_sbv", bitvector_width(*size)),
+                    BitVector::Unsigned(size) => ("u", "fp.to_ubv", bitvector_width(*size)),
+                };
+                (
+                    format!("{}_to_{prefix}{width}", float_name(*source)),
+                    Type::Float(*source),
+                    Type::BitVector(*bv),
+                    domain_name,
+                    format!("(_ {conversion} {width}) RTZ"),
+                )
+            }
+            _ => return None,
+        };
+        Some(BackendFuncDecl {
+            name,
+            formal_args: vec![LocalVar::new("a", arg_type)],
+            return_type,
+            domain_name: domain_name.to_string(),
+            interpretation,
+        })
+    }
+}
+
+/// The name of the backend type of a float and the widths of its exponent
+/// and significand, as used by SMT-LIB.
+fn float_sort(float: Float) -> (&'static str, u32, u32) {
+    match float {
+        Float::F32 => ("FloatDomain24e8", 8, 24),
+        Float::F64 => ("FloatDomain53e11", 11, 53),
+    }
+}
+
+fn float_name(float: Float) -> &'static str {
+    match float {
+        Float::F32 => "f32",
+        Float::F64 => "f64",
+    }
+}
+
+fn bitvector_width(size: BitVectorSize) -> u32 {
+    match size {
+        BitVectorSize::BV8 => 8,
+        BitVectorSize::BV16 => 16,
+        BitVectorSize::BV32 => 32,
+        BitVectorSize::BV64 => 64,
+        BitVectorSize::BV128 => 128,
+    }
+}
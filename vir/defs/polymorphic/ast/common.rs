@@ -117,7 +117,7 @@ impl Ord for PermAmount {
 }
 
 #[derive(
-    Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord,
+    Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord,
 )]
 pub enum Float {
     F32,
@@ -186,6 +186,8 @@ pub enum Type {
     Float(Float),
     BitVector(BitVector),
     Seq(SeqType),
+    Set(SetType),
+    MultiSet(MultiSetType),
     Map(MapType),
     /// A raw Viper reference
     Ref,
@@ -207,6 +209,8 @@ impl fmt::Display for Type {
             Type::Float(Float::F64) => write!(f, "F64"),
             Type::BitVector(value) => write!(f, "{}", value),
             Type::Seq(seq) => seq.fmt(f),
+            Type::Set(set) => set.fmt(f),
+            Type::MultiSet(multiset) => multiset.fmt(f),
             Type::Map(map) => map.fmt(f),
             Type::TypedRef(_) => write!(f, "Ref({})", self.encode_as_string()),
             Type::Domain(_) => write!(f, "Domain({})", self.encode_as_string()),
@@ -237,6 +241,14 @@ impl Type {
         matches!(self, &Type::Seq(_))
     }
 
+    pub fn is_set(&self) -> bool {
+        matches!(self, &Type::Set(_))
+    }
+
+    pub fn is_multiset(&self) -> bool {
+        matches!(self, &Type::MultiSet(_))
+    }
+
     pub fn is_map(&self) -> bool {
         matches!(self, &Type::Map(_))
     }
@@ -254,6 +266,15 @@ impl Type {
         }
     }
 
+    pub fn is_raw_pointer(&self) -> bool {
+        if let Type::TypedRef(TypedRef { label, .. }) = self {
+            // FIXME: We should not rely on string names for type conversions.
+            label == "raw_ref"
+        } else {
+            false
+        }
+    }
+
     pub fn name(&self) -> String {
         match self {
             Type::Bool => "bool".to_string(),
@@ -267,6 +288,8 @@ impl Type {
             | Type::TypedRef(_)
             | Type::TypeVar(_)
             | Type::Seq(_)
+            | Type::Set(_)
+            | Type::MultiSet(_)
             | Type::Map(..) => self.encode_as_string(),
         }
     }
@@ -374,6 +397,8 @@ impl Type {
             Type::Domain(_) => TypeId::Domain,
             Type::Snapshot(_) => TypeId::Snapshot,
             Type::Seq(_) => TypeId::Seq,
+            Type::Set(_) => TypeId::Set,
+            Type::MultiSet(_) => TypeId::MultiSet,
             Type::Map(..) => TypeId::Map,
             Type::TypeVar(t) => unreachable!("{}", t),
         }
@@ -443,6 +468,12 @@ impl Type {
             Type::Seq(SeqType { box typ }) => {
                 format!("Seq${}", Self::encode_arguments(&[typ.clone()]))
             }
+            Type::Set(SetType { box typ }) => {
+                format!("Set${}", Self::encode_arguments(&[typ.clone()]))
+            }
+            Type::MultiSet(MultiSetType { box typ }) => {
+                format!("MultiSet${}", Self::encode_arguments(&[typ.clone()]))
+            }
             Type::Map(MapType {
                 box key_type,
                 box val_type,
@@ -517,6 +548,32 @@ impl fmt::Display for SeqType {
     }
 }
 
+#[derive(
+    Debug, Clone, serde::Serialize, serde::Deserialize, PartialOrd, Ord, PartialEq, Eq, Hash,
+)]
+pub struct SetType {
+    pub typ: Box<Type>,
+}
+
+impl fmt::Display for SetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Set[{}]", &self.typ)
+    }
+}
+
+#[derive(
+    Debug, Clone, serde::Serialize, serde::Deserialize, PartialOrd, Ord, PartialEq, Eq, Hash,
+)]
+pub struct MultiSetType {
+    pub typ: Box<Type>,
+}
+
+impl fmt::Display for MultiSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Multiset[{}]", &self.typ)
+    }
+}
+
 #[derive(
     Debug, Clone, serde::Serialize, serde::Deserialize, PartialOrd, Ord, PartialEq, Eq, Hash,
 )]
@@ -682,6 +739,8 @@ pub enum TypeId {
     BitVector,
     Ref,
     Seq,
+    Set,
+    MultiSet,
     Map,
     Domain,
     Snapshot,
@@ -37,7 +37,7 @@ pub enum Expr {
     BinOp(BinOp),
     /// Container Operation on a Viper container (e.g. Seq index)
     ContainerOp(ContainerOp),
-    /// Viper Seq
+    /// Viper Seq, Set, or Multiset literal, depending on the type
     Seq(Seq),
     /// Viper Map
     Map(Map),
@@ -123,7 +123,9 @@ macro_rules! __unary_op__ {
 
 __unary_op__! {
     not Not,
-    minus Minus
+    minus Minus,
+    is_nan IsNaN,
+    is_infinite IsInfinite
 }
 
 macro_rules! __binary_op__ {
@@ -311,6 +313,28 @@ impl Expr {
         })
     }
 
+    pub fn cast(kind: CastKind, base: Expr) -> Self {
+        Expr::Cast(Cast {
+            kind,
+            base: Box::new(base),
+            position: Position::default(),
+        })
+    }
+
+    /// A bitwise operation on integers that are encoded as `Int`. The operands
+    /// are converted into bitvectors of the given kind and the result is
+    /// converted back into an `Int`.
+    pub fn int_bit_op(op_kind: BinaryOpKind, bv: BitVector, left: Expr, right: Expr) -> Self {
+        Expr::cast(
+            CastKind::BVIntoInt(bv),
+            Expr::bin_op(
+                op_kind,
+                Expr::cast(CastKind::IntIntoBV(bv), left),
+                Expr::cast(CastKind::IntIntoBV(bv), right),
+            ),
+        )
+    }
+
     pub fn ne_cmp(left: Expr, right: Expr) -> Self {
         Expr::not(Expr::eq_cmp(left, right))
     }
@@ -708,6 +732,15 @@ impl Expr {
     }
 
     /// If self is a MIR reference, dereference it.
+    /// Is this place the memory pointed to by a raw pointer?
+    pub fn is_raw_pointer_target(&self) -> bool {
+        if let Expr::Field(FieldExpr { base, field, .. }) = self {
+            field.name == "val_ref" && base.get_type().is_raw_pointer()
+        } else {
+            false
+        }
+    }
+
     pub fn try_deref(&self) -> Option<Self> {
         let typ = self.get_type();
         if typ.is_mir_reference() {
@@ -930,6 +963,7 @@ impl Expr {
     pub fn get_type(&self) -> &Type {
         lazy_static::lazy_static! {
             static ref FN_PTR_TYPE: Type = Type::typed_ref("FnPtr");
+            static ref NULL_PTR_TYPE: Type = Type::typed_ref("NullPtr");
         }
         match self {
             Expr::Local(Local {
@@ -949,6 +983,10 @@ impl Expr {
                 variable: LocalVar { typ, .. },
                 ..
             }) => typ,
+            Expr::UnaryOp(UnaryOp {
+                op_kind: UnaryOpKind::IsNaN | UnaryOpKind::IsInfinite,
+                ..
+            }) => &Type::Bool,
             Expr::LabelledOld(LabelledOld { base, .. })
             | Expr::Unfolding(Unfolding { base, .. })
             | Expr::UnaryOp(UnaryOp { argument: base, .. }) => base.get_type(),
@@ -1002,6 +1040,7 @@ impl Expr {
                     ..
                 }) => &Type::BitVector(BitVector::Unsigned(BitVectorSize::BV128)),
                 Const::FnPtr => &FN_PTR_TYPE,
+                Const::NullPtr => &NULL_PTR_TYPE,
             },
             Expr::BinOp(BinOp {
                 op_kind,
@@ -1064,12 +1103,43 @@ impl Expr {
                 left,
                 right,
                 ..
-            }) => {
-                todo!("get_type container_op({:?}, {}, {})", op_kind, left, right)
-            }
+            }) => match (op_kind, left.get_type()) {
+                (ContainerOpKind::SeqIndex, Type::Seq(SeqType { typ })) => typ.as_ref(),
+                (ContainerOpKind::MapLookup, Type::Map(MapType { val_type, .. })) => {
+                    val_type.as_ref()
+                }
+                (
+                    ContainerOpKind::SeqLen
+                    | ContainerOpKind::SetCardinality
+                    | ContainerOpKind::MapLen,
+                    _,
+                ) => &Type::Int,
+                (
+                    ContainerOpKind::SetSubset
+                    | ContainerOpKind::SeqContains
+                    | ContainerOpKind::MapContains,
+                    _,
+                ) => &Type::Bool,
+                (ContainerOpKind::SetContains, Type::Set(_)) => &Type::Bool,
+                (ContainerOpKind::SetContains, Type::MultiSet(_)) => &Type::Int,
+                (
+                    ContainerOpKind::SeqConcat
+                    | ContainerOpKind::SeqTake
+                    | ContainerOpKind::SeqDrop
+                    | ContainerOpKind::SetUnion
+                    | ContainerOpKind::SetIntersection
+                    | ContainerOpKind::SetMinus,
+                    typ,
+                ) => typ,
+                _ => unreachable!("get_type container_op({:?}, {}, {})", op_kind, left, right),
+            },
             Expr::Map(Map { typ, .. }) | Expr::Seq(Seq { typ, .. }) => typ,
             Expr::Cast(Cast { kind, .. }) => match kind {
-                CastKind::BVIntoInt(_) => &Type::Int,
+                CastKind::BVIntoInt(_) | CastKind::FloatIntoInt(_) => &Type::Int,
+                CastKind::IntIntoFloat(_, Float::F32) => &Type::Float(Float::F32),
+                CastKind::FloatIntoFloat(Float::F32) => &Type::Float(Float::F32),
+                CastKind::IntIntoFloat(_, Float::F64) => &Type::Float(Float::F64),
+                CastKind::FloatIntoFloat(Float::F64) => &Type::Float(Float::F64),
                 CastKind::IntIntoBV(BitVector::Signed(BitVectorSize::BV8)) => {
                     &Type::BitVector(BitVector::Signed(BitVectorSize::BV8))
                 }
@@ -1116,7 +1186,7 @@ impl Expr {
                     ..
                 })
                 | Expr::UnaryOp(UnaryOp {
-                    op_kind: UnaryOpKind::Not,
+                    op_kind: UnaryOpKind::Not | UnaryOpKind::IsNaN | UnaryOpKind::IsInfinite,
                     ..
                 })
                 | Expr::FuncApp(FuncApp {
@@ -1780,6 +1850,10 @@ pub enum PlaceComponent {
 pub enum UnaryOpKind {
     Not,
     Minus,
+    /// Whether a floating-point value is NaN.
+    IsNaN,
+    /// Whether a floating-point value is positive or negative infinity.
+    IsInfinite,
 }
 
 #[derive(
@@ -1817,6 +1891,26 @@ pub enum ContainerOpKind {
     SeqIndex,
     SeqConcat,
     SeqLen,
+    /// The first `right` elements of the sequence `left`.
+    SeqTake,
+    /// The sequence `left` without its first `right` elements.
+    SeqDrop,
+    /// The sequence is `left`, the element is `right`.
+    SeqContains,
+    // The set operations are shared by sets and multisets, like in Viper.
+    SetUnion,
+    SetIntersection,
+    SetMinus,
+    SetSubset,
+    /// The container is `left`, the element is `right`. On multisets, the
+    /// result is the multiplicity of the element.
+    SetContains,
+    SetCardinality,
+    /// The map is `left`, the key is `right`.
+    MapLookup,
+    /// The map is `left`, the key is `right`.
+    MapContains,
+    MapLen,
 }
 
 #[derive(
@@ -1859,6 +1953,8 @@ pub enum Const {
     /// All function pointers share the same constant, because their function
     /// is determined by the type system.
     FnPtr,
+    /// The address of null raw pointers.
+    NullPtr,
 }
 
 /// Individual structs for different cases of Expr
@@ -2163,6 +2259,20 @@ impl fmt::Display for ContainerOp {
             ContainerOpKind::SeqIndex => write!(f, "{}[{}]", &self.left, &self.right),
             ContainerOpKind::SeqConcat => write!(f, "{} ++ {}", &self.left, &self.right),
             ContainerOpKind::SeqLen => write!(f, "|{}|", &self.left),
+            ContainerOpKind::SeqTake => write!(f, "{}[..{}]", &self.left, &self.right),
+            ContainerOpKind::SeqDrop => write!(f, "{}[{}..]", &self.left, &self.right),
+            ContainerOpKind::SeqContains => write!(f, "{} in {}", &self.right, &self.left),
+            ContainerOpKind::SetUnion => write!(f, "{} union {}", &self.left, &self.right),
+            ContainerOpKind::SetIntersection => {
+                write!(f, "{} intersection {}", &self.left, &self.right)
+            }
+            ContainerOpKind::SetMinus => write!(f, "{} setminus {}", &self.left, &self.right),
+            ContainerOpKind::SetSubset => write!(f, "{} subset {}", &self.left, &self.right),
+            ContainerOpKind::SetContains => write!(f, "{} in {}", &self.right, &self.left),
+            ContainerOpKind::SetCardinality => write!(f, "|{}|", &self.left),
+            ContainerOpKind::MapLookup => write!(f, "{}[{}]", &self.left, &self.right),
+            ContainerOpKind::MapContains => write!(f, "{} in {}", &self.right, &self.left),
+            ContainerOpKind::MapLen => write!(f, "|{}|", &self.left),
         }
     }
 }
@@ -2195,12 +2305,18 @@ impl fmt::Display for Seq {
             .map(|e| format!("{}", e))
             .collect::<Vec<_>>()
             .join(", ");
-        let elem_ty = if let Type::Seq(seq_type) = typ {
-            &*seq_type.typ
-        } else {
-            unreachable!()
-        };
-        write!(f, "Seq[{}]({})", elem_ty, elems_printed)
+        match typ {
+            Type::Seq(SeqType { typ: elem_ty }) => {
+                write!(f, "Seq[{}]({})", elem_ty, elems_printed)
+            }
+            Type::Set(SetType { typ: elem_ty }) => {
+                write!(f, "Set[{}]({})", elem_ty, elems_printed)
+            }
+            Type::MultiSet(MultiSetType { typ: elem_ty }) => {
+                write!(f, "Multiset[{}]({})", elem_ty, elems_printed)
+            }
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -2595,6 +2711,13 @@ impl Hash for DowncastExpr {
 pub enum CastKind {
     BVIntoInt(BitVector),
     IntIntoBV(BitVector),
+    /// Rounds an integer of the given bitvector kind to the nearest float.
+    IntIntoFloat(BitVector, Float),
+    /// Rounds a float to the nearest float of the given precision.
+    FloatIntoFloat(Float),
+    /// Truncates a finite float towards zero into an integer of the given
+    /// bitvector kind. The result is unspecified if it does not fit.
+    FloatIntoInt(BitVector),
 }
 
 #[derive(Debug, Clone, Eq, serde::Serialize, serde::Deserialize, PartialOrd, Ord)]
@@ -2651,6 +2774,8 @@ impl fmt::Display for UnaryOpKind {
         match self {
             UnaryOpKind::Not => write!(f, "!"),
             UnaryOpKind::Minus => write!(f, "-"),
+            UnaryOpKind::IsNaN => write!(f, "isNaN"),
+            UnaryOpKind::IsInfinite => write!(f, "isInfinite"),
         }
     }
 }
@@ -2693,6 +2818,7 @@ impl fmt::Display for Const {
             Const::Float(val) => write!(f, "{:?}", val),
             Const::BitVector(val) => write!(f, "{:?}", val),
             Const::FnPtr => write!(f, "FnPtr"),
+            Const::NullPtr => write!(f, "null"),
         }
     }
 }
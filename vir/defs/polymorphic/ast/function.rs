@@ -111,6 +111,8 @@ pub fn compute_identifier(
             Type::Domain(_) => typ.name(),
             Type::Snapshot(_) => format!("Snap${}", typ.name()),
             Type::Seq(seq_type) => format!("Seq${}", type_name(&seq_type.typ)),
+            Type::Set(set_type) => format!("Set${}", type_name(&set_type.typ)),
+            Type::MultiSet(multiset_type) => format!("MultiSet${}", type_name(&multiset_type.typ)),
             Type::Map(map) => format!(
                 "Map${}${}",
                 type_name(&map.key_type),
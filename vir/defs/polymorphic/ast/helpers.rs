@@ -0,0 +1,67 @@
+use crate::{common::expression::*, polymorphic::ast::*};
+
+impl UnaryOperationHelpers for Expr {
+    type UnaryOperationKind = UnaryOpKind;
+    fn unary_operation(kind: Self::UnaryOperationKind, arg: Self) -> Self {
+        Expr::UnaryOp(UnaryOp {
+            op_kind: kind,
+            argument: Box::new(arg),
+            position: Position::default(),
+        })
+    }
+    fn not(arg: Self) -> Self {
+        Self::unary_operation(UnaryOpKind::Not, arg)
+    }
+    fn minus(arg: Self) -> Self {
+        Self::unary_operation(UnaryOpKind::Minus, arg)
+    }
+}
+
+impl BinaryOperationHelpers for Expr {
+    type BinaryOperationKind = BinaryOpKind;
+    fn binary_operation(kind: Self::BinaryOperationKind, left: Self, right: Self) -> Self {
+        Expr::bin_op(kind, left, right)
+    }
+    fn equals(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::EqCmp, left, right)
+    }
+    fn not_equals(left: Self, right: Self) -> Self {
+        Expr::ne_cmp(left, right)
+    }
+    fn greater_than(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::GtCmp, left, right)
+    }
+    fn greater_equals(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::GeCmp, left, right)
+    }
+    fn less_than(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::LtCmp, left, right)
+    }
+    fn less_equals(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::LeCmp, left, right)
+    }
+    fn add(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Add, left, right)
+    }
+    fn subtract(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Sub, left, right)
+    }
+    fn multiply(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Mul, left, right)
+    }
+    fn divide(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Div, left, right)
+    }
+    fn module(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Mod, left, right)
+    }
+    fn and(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::And, left, right)
+    }
+    fn or(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Or, left, right)
+    }
+    fn implies(left: Self, right: Self) -> Self {
+        Self::binary_operation(BinaryOpKind::Implies, left, right)
+    }
+}
@@ -15,6 +15,7 @@ mod domain;
 mod expr;
 mod expr_transformers;
 mod function;
+mod helpers;
 mod predicate;
 mod stmt;
 mod trigger;
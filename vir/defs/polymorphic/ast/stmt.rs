@@ -103,11 +103,15 @@ pub enum AssignKind {
     /// Encodes the initialization of a mutable borrow.
     /// The permissions in the `rhs` move to the `lhs`, but they can be restored when the borrow dies.
     MutableBorrow(Borrow),
+    /// Encodes the reservation of a two-phase mutable borrow. The `rhs` can still be read until
+    /// the borrow is activated, at which point its permissions are transferred to the `lhs`.
+    TwoPhaseBorrow(Borrow),
     /// Encodes the initialization of a shared borrow.
     /// The permissions in the `rhs` are duplicated to the `lhs`.
     SharedBorrow(Borrow),
     /// Used to mark that the assignment is to a ghost variable and should be ignored by
-    /// the fold-unfold algorithm.
+    /// the fold-unfold algorithm. Also used for storing addresses in raw pointers, which
+    /// do not own the memory they point to.
     Ghost,
 }
 
@@ -227,6 +231,13 @@ impl fmt::Display for Assign {
                     self.target, self.source, borrow
                 )
             }
+            AssignKind::TwoPhaseBorrow(borrow) => {
+                write!(
+                    f,
+                    "{} := two-phase borrow {} // {:?}",
+                    self.target, self.source, borrow
+                )
+            }
             AssignKind::SharedBorrow(borrow) => {
                 write!(
                     f,
@@ -165,6 +165,8 @@ impl Type {
     pub fn contains_type_variables(&self) -> bool {
         match self {
             Self::Sequence(Sequence { element_type, .. })
+            | Self::Set(Set { element_type, .. })
+            | Self::MultiSet(MultiSet { element_type, .. })
             | Self::Array(Array { element_type, .. })
             | Self::Slice(Slice { element_type, .. }) => element_type.is_type_var(),
             Self::Reference(Reference { target_type, .. })
@@ -473,17 +475,26 @@ impl Typed for BinaryOp {
             | BinaryOpKind::Mul
             | BinaryOpKind::Div
             | BinaryOpKind::Mod
-            | BinaryOpKind::LifetimeIntersection => {
+            | BinaryOpKind::LifetimeIntersection
+            | BinaryOpKind::BitAnd
+            | BinaryOpKind::BitOr
+            | BinaryOpKind::BitXor => {
                 let ty1 = self.left.get_type();
                 let ty2 = self.right.get_type();
                 assert_eq!(ty1, ty2, "expr: {:?}", self);
                 ty1
             }
+            // The shift amount may have a different type than the shifted value.
+            BinaryOpKind::Shl | BinaryOpKind::LShr | BinaryOpKind::AShr => self.left.get_type(),
         }
     }
     fn set_type(&mut self, new_type: Type) {
-        self.left.set_type(new_type.clone());
-        self.right.set_type(new_type);
+        if self.op_kind.is_shift() {
+            self.left.set_type(new_type);
+        } else {
+            self.left.set_type(new_type.clone());
+            self.right.set_type(new_type);
+        }
     }
 }
 
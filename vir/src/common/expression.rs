@@ -80,6 +80,32 @@ pub fn implies<E: BinaryOperationHelpers>(left: E, right: E) -> E {
     BinaryOperationHelpers::implies(left, right)
 }
 
+/// Encodes that `lower` is lexicographically smaller than `upper`. If all
+/// common components are equal, the shorter measure is the larger one: a
+/// missing component is treated as the greatest possible value.
+pub fn lexicographically_less<E>(lower: &[E], upper: &[E]) -> E
+where
+    E: BinaryOperationHelpers + From<bool> + Clone,
+{
+    let mut result: Option<E> = if lower.len() > upper.len() {
+        Some(true.into())
+    } else {
+        None
+    };
+    for (lower, upper) in lower.iter().zip(upper).rev() {
+        let less = E::less_than(lower.clone(), upper.clone());
+        result = Some(if let Some(result) = result {
+            E::or(
+                less,
+                E::and(E::equals(lower.clone(), upper.clone()), result),
+            )
+        } else {
+            less
+        });
+    }
+    result.unwrap_or_else(|| false.into())
+}
+
 pub trait ConditionalHelpers: Sized {
     fn conditional(guard: Self, then_expr: Self, else_expr: Self) -> Self;
 }
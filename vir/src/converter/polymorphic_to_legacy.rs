@@ -95,6 +95,10 @@ impl From<polymorphic::Type> for legacy::Type {
             polymorphic::Type::Float(float) => legacy::Type::Float(float.into()),
             polymorphic::Type::BitVector(vector) => legacy::Type::BitVector(vector.into()),
             polymorphic::Type::Seq(seq) => legacy::Type::Seq(Box::new((*seq.typ).into())),
+            polymorphic::Type::Set(set) => legacy::Type::Set(Box::new((*set.typ).into())),
+            polymorphic::Type::MultiSet(multiset) => {
+                legacy::Type::MultiSet(Box::new((*multiset.typ).into()))
+            }
             polymorphic::Type::Map(map) => legacy::Type::Map(
                 Box::new((*map.key_type).into()),
                 Box::new((*map.val_type).into()),
@@ -117,6 +121,8 @@ impl From<polymorphic::TypeId> for legacy::TypeId {
             polymorphic::TypeId::Float => legacy::TypeId::Float,
             polymorphic::TypeId::Ref => legacy::TypeId::Ref,
             polymorphic::TypeId::Seq => legacy::TypeId::Seq,
+            polymorphic::TypeId::Set => legacy::TypeId::Set,
+            polymorphic::TypeId::MultiSet => legacy::TypeId::MultiSet,
             polymorphic::TypeId::Map => legacy::TypeId::Map,
             polymorphic::TypeId::Domain => legacy::TypeId::Domain,
             polymorphic::TypeId::Snapshot => legacy::TypeId::Snapshot,
@@ -428,6 +434,8 @@ impl From<polymorphic::UnaryOpKind> for legacy::UnaryOpKind {
         match unary_op_kind {
             polymorphic::UnaryOpKind::Not => legacy::UnaryOpKind::Not,
             polymorphic::UnaryOpKind::Minus => legacy::UnaryOpKind::Minus,
+            polymorphic::UnaryOpKind::IsNaN => legacy::UnaryOpKind::IsNaN,
+            polymorphic::UnaryOpKind::IsInfinite => legacy::UnaryOpKind::IsInfinite,
         }
     }
 }
@@ -467,6 +475,20 @@ impl From<polymorphic::ContainerOpKind> for legacy::ContainerOpKind {
             polymorphic::ContainerOpKind::SeqIndex => legacy::ContainerOpKind::SeqIndex,
             polymorphic::ContainerOpKind::SeqConcat => legacy::ContainerOpKind::SeqConcat,
             polymorphic::ContainerOpKind::SeqLen => legacy::ContainerOpKind::SeqLen,
+            polymorphic::ContainerOpKind::SeqTake => legacy::ContainerOpKind::SeqTake,
+            polymorphic::ContainerOpKind::SeqDrop => legacy::ContainerOpKind::SeqDrop,
+            polymorphic::ContainerOpKind::SeqContains => legacy::ContainerOpKind::SeqContains,
+            polymorphic::ContainerOpKind::SetUnion => legacy::ContainerOpKind::SetUnion,
+            polymorphic::ContainerOpKind::SetIntersection => {
+                legacy::ContainerOpKind::SetIntersection
+            }
+            polymorphic::ContainerOpKind::SetMinus => legacy::ContainerOpKind::SetMinus,
+            polymorphic::ContainerOpKind::SetSubset => legacy::ContainerOpKind::SetSubset,
+            polymorphic::ContainerOpKind::SetContains => legacy::ContainerOpKind::SetContains,
+            polymorphic::ContainerOpKind::SetCardinality => legacy::ContainerOpKind::SetCardinality,
+            polymorphic::ContainerOpKind::MapLookup => legacy::ContainerOpKind::MapLookup,
+            polymorphic::ContainerOpKind::MapContains => legacy::ContainerOpKind::MapContains,
+            polymorphic::ContainerOpKind::MapLen => legacy::ContainerOpKind::MapLen,
         }
     }
 }
@@ -476,6 +498,15 @@ impl From<polymorphic::CastKind> for legacy::CastKind {
         match container_op_kind {
             polymorphic::CastKind::BVIntoInt(size) => legacy::CastKind::BVIntoInt(size.into()),
             polymorphic::CastKind::IntIntoBV(size) => legacy::CastKind::IntIntoBV(size.into()),
+            polymorphic::CastKind::IntIntoFloat(size, float) => {
+                legacy::CastKind::IntIntoFloat(size.into(), float.into())
+            }
+            polymorphic::CastKind::FloatIntoFloat(float) => {
+                legacy::CastKind::FloatIntoFloat(float.into())
+            }
+            polymorphic::CastKind::FloatIntoInt(size) => {
+                legacy::CastKind::FloatIntoInt(size.into())
+            }
         }
     }
 }
@@ -507,6 +538,7 @@ impl From<polymorphic::Const> for legacy::Const {
             polymorphic::Const::Float(float_value) => legacy::Const::Float(float_value.into()),
             polymorphic::Const::BitVector(value) => legacy::Const::BitVector(value.into()),
             polymorphic::Const::FnPtr => legacy::Const::FnPtr,
+            polymorphic::Const::NullPtr => legacy::Const::NullPtr,
         }
     }
 }
@@ -705,6 +737,9 @@ impl From<polymorphic::AssignKind> for legacy::AssignKind {
             polymorphic::AssignKind::MutableBorrow(borrow) => {
                 legacy::AssignKind::MutableBorrow(borrow.into())
             }
+            polymorphic::AssignKind::TwoPhaseBorrow(borrow) => {
+                legacy::AssignKind::TwoPhaseBorrow(borrow.into())
+            }
             polymorphic::AssignKind::SharedBorrow(borrow) => {
                 legacy::AssignKind::SharedBorrow(borrow.into())
             }
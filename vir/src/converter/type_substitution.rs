@@ -37,6 +37,14 @@ impl Generic for Type {
                 *seq.typ = typ.substitute(map);
                 Type::Seq(seq)
             }
+            Type::Set(mut set) => {
+                *set.typ = set.typ.substitute(map);
+                Type::Set(set)
+            }
+            Type::MultiSet(mut multiset) => {
+                *multiset.typ = multiset.typ.substitute(map);
+                Type::MultiSet(multiset)
+            }
             Type::Map(mut m) => {
                 *m.key_type = m.key_type.substitute(map);
                 *m.val_type = m.val_type.substitute(map);
@@ -185,6 +185,8 @@ pub enum Type {
     Float(Float),
     BitVector(BitVector),
     Seq(Box<Type>),
+    Set(Box<Type>),
+    MultiSet(Box<Type>),
     Map(Box<Type>, Box<Type>),
     /// A Raw Viper reference, used to define builtin operations
     Ref,
@@ -202,6 +204,8 @@ pub enum TypeId {
     BitVector,
     Ref,
     Seq,
+    Set,
+    MultiSet,
     Map,
     Domain,
     Snapshot,
@@ -220,6 +224,8 @@ impl fmt::Display for Type {
             Type::Domain(ref name) => write!(f, "Domain({name})"),
             Type::Snapshot(ref name) => write!(f, "Snapshot({name})"),
             Type::Seq(ref elem_ty) => write!(f, "Seq[{elem_ty}]"),
+            Type::Set(ref elem_ty) => write!(f, "Set[{elem_ty}]"),
+            Type::MultiSet(ref elem_ty) => write!(f, "Multiset[{elem_ty}]"),
             Type::Map(ref key_type, ref val_type) => write!(f, "Map[{key_type}, {val_type}]"),
         }
     }
@@ -250,6 +256,8 @@ impl Type {
             Type::Domain(ref pred_name) => pred_name.to_string(),
             Type::Snapshot(ref pred_name) => pred_name.to_string(),
             Type::Seq(_) => "Seq".to_string(),
+            Type::Set(_) => "Set".to_string(),
+            Type::MultiSet(_) => "MultiSet".to_string(),
             Type::Map(..) => "Map".to_string(),
         }
     }
@@ -292,6 +300,8 @@ impl Type {
             Type::Domain(_) => TypeId::Domain,
             Type::Snapshot(_) => TypeId::Snapshot,
             Type::Seq(_) => TypeId::Seq,
+            Type::Set(_) => TypeId::Set,
+            Type::MultiSet(_) => TypeId::MultiSet,
             Type::Map(..) => TypeId::Map,
         }
     }
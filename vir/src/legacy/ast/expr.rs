@@ -36,7 +36,7 @@ pub enum Expr {
     BinOp(BinaryOpKind, Box<Expr>, Box<Expr>, Position),
     /// Container Operation on a Viper container (e.g. Seq index)
     ContainerOp(ContainerOpKind, Box<Expr>, Box<Expr>, Position),
-    /// Viper Seq
+    /// Viper Seq, Set, or Multiset literal, depending on the type
     Seq(Type, Vec<Expr>, Position),
     /// Viper Map
     Map(Type, Vec<Expr>, Position),
@@ -89,6 +89,7 @@ pub enum UnaryOpKind {
     Not,
     Minus,
     IsNaN,
+    IsInfinite,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -122,6 +123,24 @@ pub enum ContainerOpKind {
     SeqIndex,
     SeqConcat,
     SeqLen,
+    SeqTake,
+    SeqDrop,
+    /// The sequence is the left operand, the element is the right one.
+    SeqContains,
+    // The set operations are shared by sets and multisets, like in Viper.
+    SetUnion,
+    SetIntersection,
+    SetMinus,
+    SetSubset,
+    /// The container is the left operand, the element is the right one. On
+    /// multisets, the result is the multiplicity of the element.
+    SetContains,
+    SetCardinality,
+    /// The map is the left operand, the key is the right one.
+    MapLookup,
+    /// The map is the left operand, the key is the right one.
+    MapContains,
+    MapLen,
     // more to follow if required
 }
 
@@ -129,6 +148,9 @@ pub enum ContainerOpKind {
 pub enum CastKind {
     BVIntoInt(BitVector),
     IntIntoBV(BitVector),
+    IntIntoFloat(BitVector, Float),
+    FloatIntoFloat(Float),
+    FloatIntoInt(BitVector),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -153,6 +175,8 @@ pub enum Const {
     /// All function pointers share the same constant, because their function
     /// is determined by the type system.
     FnPtr,
+    /// The address of null raw pointers.
+    NullPtr,
 }
 
 impl fmt::Display for Expr {
@@ -172,6 +196,18 @@ impl fmt::Display for Expr {
                 ContainerOpKind::SeqIndex => write!(f, "{left}[{right}]"),
                 ContainerOpKind::SeqConcat => write!(f, "{left} ++ {right}"),
                 ContainerOpKind::SeqLen => write!(f, "|{left}|"),
+                ContainerOpKind::SeqTake => write!(f, "{left}[..{right}]"),
+                ContainerOpKind::SeqDrop => write!(f, "{left}[{right}..]"),
+                ContainerOpKind::SeqContains => write!(f, "{right} in {left}"),
+                ContainerOpKind::SetUnion => write!(f, "{left} union {right}"),
+                ContainerOpKind::SetIntersection => write!(f, "{left} intersection {right}"),
+                ContainerOpKind::SetMinus => write!(f, "{left} setminus {right}"),
+                ContainerOpKind::SetSubset => write!(f, "{left} subset {right}"),
+                ContainerOpKind::SetContains => write!(f, "{right} in {left}"),
+                ContainerOpKind::SetCardinality => write!(f, "|{left}|"),
+                ContainerOpKind::MapLookup => write!(f, "{left}[{right}]"),
+                ContainerOpKind::MapContains => write!(f, "{right} in {left}"),
+                ContainerOpKind::MapLen => write!(f, "|{left}|"),
             },
             Expr::Seq(ty, elems, _) => {
                 let elems_printed = elems
@@ -179,12 +215,14 @@ impl fmt::Display for Expr {
                     .map(|e| format!("{e}"))
                     .collect::<Vec<_>>()
                     .join(", ");
-                let elem_ty = if let Type::Seq(box elem_ty) = ty {
-                    elem_ty
-                } else {
-                    unreachable!()
-                };
-                write!(f, "Seq[{elem_ty}]({elems_printed})")
+                match ty {
+                    Type::Seq(box elem_ty) => write!(f, "Seq[{elem_ty}]({elems_printed})"),
+                    Type::Set(box elem_ty) => write!(f, "Set[{elem_ty}]({elems_printed})"),
+                    Type::MultiSet(box elem_ty) => {
+                        write!(f, "Multiset[{elem_ty}]({elems_printed})")
+                    }
+                    _ => unreachable!(),
+                }
             }
             Expr::Map(..) => {
                 unimplemented!()
@@ -316,6 +354,7 @@ impl fmt::Display for UnaryOpKind {
             UnaryOpKind::Not => write!(f, "!"),
             UnaryOpKind::Minus => write!(f, "-"),
             UnaryOpKind::IsNaN => write!(f, "isNaN"),
+            UnaryOpKind::IsInfinite => write!(f, "isInfinite"),
         }
     }
 }
@@ -358,6 +397,7 @@ impl fmt::Display for Const {
             Const::Float(val) => write!(f, "{val:?}"),
             Const::BitVector(val) => write!(f, "{val:?}"),
             Const::FnPtr => write!(f, "FnPtr"),
+            Const::NullPtr => write!(f, "null"),
         }
     }
 }
@@ -1200,12 +1240,15 @@ impl Expr {
     pub fn get_maybe_type(&self) -> Option<&Type> {
         lazy_static::lazy_static! {
             static ref FN_PTR_TYPE: Type = Type::TypedRef("FnPtr".to_string());
+            static ref NULL_PTR_TYPE: Type = Type::TypedRef("NullPtr".to_string());
         }
         let result = match self {
             Expr::Local(LocalVar { ref typ, .. }, _)
             | Expr::Variant(_, Field { ref typ, .. }, _)
             | Expr::Field(_, Field { ref typ, .. }, _)
             | Expr::AddrOf(_, ref typ, _) => typ,
+            Expr::UnaryOp(UnaryOpKind::IsNaN, _, _)
+            | Expr::UnaryOp(UnaryOpKind::IsInfinite, _, _) => &Type::Bool,
             Expr::LabelledOld(_, box ref base, _)
             | Expr::Unfolding(_, _, box ref base, _, _, _)
             | Expr::UnaryOp(_, box ref base, _)
@@ -1258,6 +1301,7 @@ impl Expr {
                     ..
                 }) => &Type::BitVector(BitVector::Unsigned(BitVectorSize::BV128)),
                 Const::FnPtr => &FN_PTR_TYPE,
+                Const::NullPtr => &NULL_PTR_TYPE,
             },
             Expr::BinOp(ref kind, box ref base1, box ref base2, _pos) => match kind {
                 BinaryOpKind::EqCmp
@@ -1311,7 +1355,11 @@ impl Expr {
             }
             Expr::Seq(ref ty, ..) | Expr::Map(ref ty, ..) => ty,
             Expr::Cast(kind, _, _) => match kind {
-                CastKind::BVIntoInt(_) => &Type::Int,
+                CastKind::BVIntoInt(_) | CastKind::FloatIntoInt(_) => &Type::Int,
+                CastKind::IntIntoFloat(_, Float::F32) => &Type::Float(Float::F32),
+                CastKind::FloatIntoFloat(Float::F32) => &Type::Float(Float::F32),
+                CastKind::IntIntoFloat(_, Float::F64) => &Type::Float(Float::F64),
+                CastKind::FloatIntoFloat(Float::F64) => &Type::Float(Float::F64),
                 CastKind::IntIntoBV(BitVector::Signed(BitVectorSize::BV8)) => {
                     &Type::BitVector(BitVector::Signed(BitVectorSize::BV8))
                 }
@@ -1356,6 +1404,8 @@ impl Expr {
             match self {
                 Expr::Const(Const::Bool(_), _)
                 | Expr::UnaryOp(UnaryOpKind::Not, _, _)
+                | Expr::UnaryOp(UnaryOpKind::IsNaN, _, _)
+                | Expr::UnaryOp(UnaryOpKind::IsInfinite, _, _)
                 | Expr::FuncApp(_, _, _, Type::Bool, _)
                 | Expr::ForAll(..)
                 | Expr::Exists(..) => true,
@@ -181,6 +181,9 @@ impl Typed for ContainerOp {
             ContainerOpKind::SeqEmpty
             | ContainerOpKind::SeqConstructor
             | ContainerOpKind::SeqConcat
+            | ContainerOpKind::SeqUpdate
+            | ContainerOpKind::SeqTake
+            | ContainerOpKind::SeqDrop
             | ContainerOpKind::MapEmpty
             | ContainerOpKind::MapUpdate
             | ContainerOpKind::SetEmpty
@@ -196,12 +199,14 @@ impl Typed for ContainerOp {
             ContainerOpKind::SeqLen
             | ContainerOpKind::MapLen
             | ContainerOpKind::SetCardinality
-            | ContainerOpKind::MultiSetCardinality => &Type::Int,
-            ContainerOpKind::MapContains
+            | ContainerOpKind::MultiSetCardinality
+            // Viper's `in` on multisets yields the multiplicity of the element.
+            | ContainerOpKind::MultiSetContains => &Type::Int,
+            ContainerOpKind::SeqContains
+            | ContainerOpKind::MapContains
             | ContainerOpKind::SetSubset
             | ContainerOpKind::SetContains
-            | ContainerOpKind::MultiSetSubset
-            | ContainerOpKind::MultiSetContains => &Type::Bool,
+            | ContainerOpKind::MultiSetSubset => &Type::Bool,
             ContainerOpKind::SeqIndex => match &self.container_type {
                 Type::Seq(ty::Seq { element_type, .. }) => element_type,
                 _ => unreachable!("Expected Seq type, got {:?}", self.container_type),